@@ -1,7 +1,8 @@
 use crate::SqlIntrospectionResult;
 use datamodel::{
     common::{names::NameNormalizer, PrismaType, PrismaValue},
-    dml, Datamodel, Field, FieldArity, FieldType, IdInfo, IdStrategy, IndexDefinition, Model, OnDeleteStrategy,
+    configuration::is_native_type,
+    dml, Datamodel, Field, FieldArity, FieldType, IdInfo, IdStrategy, IndexDefinition, Model, ReferentialAction,
     RelationInfo, ScalarListStrategy, WithDatabaseName,
 };
 use log::debug;
@@ -36,7 +37,8 @@ fn create_many_to_many_field(foreign_key: &ForeignKey, relation_name: String, is
         name: relation_name,
         to: foreign_key.referenced_table.clone(),
         to_fields: foreign_key.referenced_columns.clone(),
-        on_delete: OnDeleteStrategy::None,
+        on_delete: ReferentialAction::None,
+        on_update: ReferentialAction::None,
     });
 
     let basename = inflector.pluralize(&foreign_key.referenced_table).camel_case();
@@ -61,8 +63,9 @@ fn create_many_to_many_field(foreign_key: &ForeignKey, relation_name: String, is
     }
 }
 
-/// Calculate a data model from a database schema.
-pub fn calculate_model(schema: &SqlSchema) -> SqlIntrospectionResult<Datamodel> {
+/// Calculate a data model from a database schema. The connector type is the name of the source
+/// connecting to the database, which determines the native types fields can have.
+pub fn calculate_model(schema: &SqlSchema, connector_type: &str) -> SqlIntrospectionResult<Datamodel> {
     debug!("Calculating data model");
 
     let mut data_model = Datamodel::new();
@@ -77,7 +80,7 @@ pub fn calculate_model(schema: &SqlSchema) -> SqlIntrospectionResult<Datamodel>
         //Todo: This needs to filter out composite Foreign Key columns, they are merged into one new field
         for column in table.columns.iter() {
             debug!("Handling column {:?}", column);
-            let field_type = calculate_field_type(&schema, &column, &table);
+            let field_type = calculate_field_type(&schema, &column, &table, connector_type);
             let arity = match column.arity {
                 ColumnArity::Required => FieldArity::Required,
                 ColumnArity::Nullable => FieldArity::Optional,
@@ -122,10 +125,10 @@ pub fn calculate_model(schema: &SqlSchema) -> SqlIntrospectionResult<Datamodel>
 
         for index in table.indices.iter() {
             if index.columns.len() > 1 {
-                let tpe = if index.tpe == IndexType::Unique {
-                    datamodel::dml::IndexType::Unique
-                } else {
-                    datamodel::dml::IndexType::Normal
+                let tpe = match index.tpe {
+                    IndexType::Unique => datamodel::dml::IndexType::Unique,
+                    IndexType::Normal => datamodel::dml::IndexType::Normal,
+                    IndexType::Fulltext => datamodel::dml::IndexType::Fulltext,
                 };
 
                 let index_definition: IndexDefinition = IndexDefinition {
@@ -136,10 +139,15 @@ pub fn calculate_model(schema: &SqlSchema) -> SqlIntrospectionResult<Datamodel>
                 model.add_index(index_definition)
             }
             if index.columns.len() == 1 && index.tpe != IndexType::Unique {
+                let tpe = match index.tpe {
+                    IndexType::Fulltext => datamodel::dml::IndexType::Fulltext,
+                    _ => datamodel::dml::IndexType::Normal,
+                };
+
                 let index_definition: IndexDefinition = IndexDefinition {
                     name: Some(index.name.clone()),
                     fields: index.columns.clone(),
-                    tpe: datamodel::dml::IndexType::Normal,
+                    tpe,
                 };
                 model.add_index(index_definition)
             }
@@ -153,11 +161,9 @@ pub fn calculate_model(schema: &SqlSchema) -> SqlIntrospectionResult<Datamodel>
     }
 
     for e in schema.enums.iter() {
-        let mut values: Vec<String> = e.values.iter().cloned().collect();
-        values.sort_unstable();
         data_model.add_enum(dml::Enum {
             name: e.name.clone(),
-            values,
+            values: e.values.clone(),
             database_name: None,
             documentation: None,
         });
@@ -185,7 +191,8 @@ pub fn calculate_model(schema: &SqlSchema) -> SqlIntrospectionResult<Datamodel>
                             name: relation_info.name.clone(),
                             to: model.name.clone(),
                             to_fields: vec![relation_field.name.clone()],
-                            on_delete: OnDeleteStrategy::None,
+                            on_delete: ReferentialAction::None,
+                            on_update: ReferentialAction::None,
                         });
 
                         let arity = match relation_field.arity {
@@ -261,6 +268,7 @@ pub fn calculate_model(schema: &SqlSchema) -> SqlIntrospectionResult<Datamodel>
             schema,
             &table.columns.iter().find(|c| c.name == "value").unwrap(),
             &table,
+            connector_type,
         );
 
         let field = Field {
@@ -433,7 +441,19 @@ fn calculate_relation_name(schema: &SqlSchema, fk: &ForeignKey, table: &Table) -
     }
 }
 
-fn calculate_field_type(schema: &SqlSchema, column: &Column, table: &Table) -> FieldType {
+/// `NO ACTION` rejects the operation like `RESTRICT`. It is only left out where it is the default of the
+/// migration engine, which is the case for `ON UPDATE`, but not for `ON DELETE`.
+fn calculate_referential_action(action: &ForeignKeyAction, no_action: ReferentialAction) -> ReferentialAction {
+    match action {
+        ForeignKeyAction::Cascade => ReferentialAction::Cascade,
+        ForeignKeyAction::Restrict => ReferentialAction::Restrict,
+        ForeignKeyAction::SetNull => ReferentialAction::SetNull,
+        ForeignKeyAction::SetDefault => ReferentialAction::SetDefault,
+        ForeignKeyAction::NoAction => no_action,
+    }
+}
+
+fn calculate_field_type(schema: &SqlSchema, column: &Column, table: &Table, connector_type: &str) -> FieldType {
     debug!("Calculating field type for '{}'", column.name);
     // Look for a foreign key referencing this column
     match table.foreign_keys.iter().find(|fk| fk.columns.contains(&column.name)) {
@@ -450,24 +470,54 @@ fn calculate_field_type(schema: &SqlSchema, column: &Column, table: &Table) -> F
                 name: calculate_relation_name(schema, fk, table),
                 to: fk.referenced_table.clone(),
                 to_fields: vec![referenced_col.clone()],
-                on_delete: match fk.on_delete_action {
-                    ForeignKeyAction::Cascade => OnDeleteStrategy::Cascade,
-                    _ => OnDeleteStrategy::None,
-                },
+                on_delete: calculate_referential_action(&fk.on_delete_action, ReferentialAction::Restrict),
+                on_update: calculate_referential_action(&fk.on_update_action, ReferentialAction::None),
             })
         }
         None => {
             debug!("Found no corresponding foreign key");
-            match column.tpe.family {
-                ColumnTypeFamily::Boolean => FieldType::Base(PrismaType::Boolean),
-                ColumnTypeFamily::DateTime => FieldType::Base(PrismaType::DateTime),
-                ColumnTypeFamily::Float => FieldType::Base(PrismaType::Float),
-                ColumnTypeFamily::Int => FieldType::Base(PrismaType::Int),
-                ColumnTypeFamily::String => FieldType::Base(PrismaType::String),
+            // Postgres enums are named types, but inline MySQL enums have no enum to refer to.
+            if column.tpe.family == ColumnTypeFamily::Enum {
+                return match schema.get_enum(&column.tpe.raw) {
+                    Some(e) => FieldType::Enum(e.name.clone()),
+                    None => FieldType::Base(PrismaType::String),
+                };
+            }
+
+            let base_type = match column.tpe.family {
+                ColumnTypeFamily::Boolean => PrismaType::Boolean,
+                ColumnTypeFamily::DateTime => PrismaType::DateTime,
+                ColumnTypeFamily::Float => PrismaType::Float,
+                ColumnTypeFamily::Int => PrismaType::Int,
+                ColumnTypeFamily::String => PrismaType::String,
+                ColumnTypeFamily::Binary => PrismaType::Bytes,
+                ColumnTypeFamily::Json => PrismaType::Json,
                 // XXX: We made a conscious decision to punt on mapping of ColumnTypeFamily
                 // variants that don't yet have corresponding PrismaType variants
-                _ => FieldType::Base(PrismaType::String),
+                _ => PrismaType::String,
+            };
+
+            // A declared length or precision can't be expressed by the base type, so the
+            // column keeps its native type if the source has one for it, unless it is the
+            // type the migration engine creates for the base type anyway.
+            let has_native_type = column.tpe.raw.contains('(')
+                && is_native_type(connector_type, &column.tpe.raw)
+                && !is_default_column_type(&column.tpe);
+
+            if has_native_type {
+                FieldType::ConnectorSpecific {
+                    base_type,
+                    connector_type: Some(column.tpe.raw.clone()),
+                }
+            } else {
+                FieldType::Base(base_type)
             }
         }
     }
 }
+
+/// Postgres describes the exact numeric columns the migration engine renders for
+/// `Float` fields with their precision and scale.
+fn is_default_column_type(tpe: &ColumnType) -> bool {
+    tpe.family == ColumnTypeFamily::Float && tpe.raw.eq_ignore_ascii_case("numeric(65,30)")
+}
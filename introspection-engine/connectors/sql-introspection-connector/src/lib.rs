@@ -11,12 +11,16 @@ pub type SqlIntrospectionResult<T> = core::result::Result<T, SqlIntrospectionErr
 
 pub struct SqlIntrospectionConnector {
     describer: Box<dyn SqlSchemaDescriberBackend>,
+    connector_type: &'static str,
 }
 
 impl SqlIntrospectionConnector {
     pub fn new(url: &str) -> ConnectorResult<SqlIntrospectionConnector> {
-        let describer = schema_describer_loading::load_describer(&url)?;
-        Ok(SqlIntrospectionConnector { describer })
+        let (describer, connector_type) = schema_describer_loading::load_describer(&url)?;
+        Ok(SqlIntrospectionConnector {
+            describer,
+            connector_type,
+        })
     }
 
     fn list_databases_internal(&self) -> SqlIntrospectionResult<Vec<String>> {
@@ -35,7 +39,7 @@ impl IntrospectionConnector for SqlIntrospectionConnector {
 
     fn introspect(&self, database: &str) -> ConnectorResult<Datamodel> {
         let sql_schema = self.describe(database)?;
-        let data_model = calculate_datamodel::calculate_model(&sql_schema).unwrap();
+        let data_model = calculate_datamodel::calculate_model(&sql_schema, self.connector_type).unwrap();
         Ok(data_model)
     }
 }
@@ -1,4 +1,5 @@
 use crate::{SqlIntrospectionError, SqlIntrospectionResult};
+use datamodel::configuration::{POSTGRES_SOURCE_NAME, SQLITE_SOURCE_NAME};
 use prisma_query::ast::*;
 use prisma_query::connector::{PostgreSql, Queryable, Sqlite, SqliteParams};
 use sql_schema_describer::SqlSchemaDescriberBackend;
@@ -6,17 +7,17 @@ use std::convert::TryFrom;
 use std::sync::{Arc, Mutex};
 use url::Url;
 
-pub fn load_describer(url_str: &str) -> SqlIntrospectionResult<Box<dyn SqlSchemaDescriberBackend>> {
+/// Loads the describer for the database at the given url, together with the connector type
+/// of the source connecting to it.
+pub fn load_describer(url_str: &str) -> SqlIntrospectionResult<(Box<dyn SqlSchemaDescriberBackend>, &'static str)> {
     if url_str.starts_with("postgresql://") {
         let wrapper = PostgresWrapper::new(&url_str)?;
-        Ok(Box::new(sql_schema_describer::postgres::SqlSchemaDescriber::new(
-            Arc::new(wrapper),
-        )))
+        let describer = sql_schema_describer::postgres::SqlSchemaDescriber::new(Arc::new(wrapper));
+        Ok((Box::new(describer), POSTGRES_SOURCE_NAME))
     } else if url_str.starts_with("file:") {
         let wrapper = SqliteWrapper::new(url_str)?;
-        Ok(Box::new(sql_schema_describer::sqlite::SqlSchemaDescriber::new(
-            Arc::new(wrapper),
-        )))
+        let describer = sql_schema_describer::sqlite::SqlSchemaDescriber::new(Arc::new(wrapper));
+        Ok((Box::new(describer), SQLITE_SOURCE_NAME))
     } else {
         Err(SqlIntrospectionError::InvalidUrl {
             message: format!("Could not load connector for the provided url: {}", url_str),
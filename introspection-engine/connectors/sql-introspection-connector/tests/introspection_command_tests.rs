@@ -1,4 +1,3 @@
-use std::collections::HashSet;
 use std::sync::atomic::{AtomicBool, Ordering};
 
 use log::LevelFilter;
@@ -6,7 +5,8 @@ use pretty_assertions::assert_eq;
 
 use datamodel::{
     common::{PrismaType, PrismaValue},
-    dml, Datamodel, Field, FieldArity, FieldType, IdInfo, IdStrategy, Model, OnDeleteStrategy, RelationInfo,
+    configuration::{POSTGRES_SOURCE_NAME, SQLITE_SOURCE_NAME},
+    dml, Datamodel, Field, FieldArity, FieldType, IdInfo, IdStrategy, Model, ReferentialAction, RelationInfo,
     ScalarListStrategy,
 };
 use sql_introspection_connector::calculate_datamodel::calculate_model;
@@ -81,6 +81,8 @@ fn a_data_model_can_be_generated_from_a_schema() {
                         ColumnTypeFamily::Float => FieldType::Base(PrismaType::Float),
                         ColumnTypeFamily::Int => FieldType::Base(PrismaType::Int),
                         ColumnTypeFamily::String => FieldType::Base(PrismaType::String),
+                        ColumnTypeFamily::Binary => FieldType::Base(PrismaType::Bytes),
+                        ColumnTypeFamily::Json => FieldType::Base(PrismaType::Json),
                         // XXX: We made a conscious decision to punt on mapping of ColumnTypeFamily
                         // variants that don't yet have corresponding PrismaType variants
                         _ => FieldType::Base(PrismaType::String),
@@ -130,7 +132,7 @@ fn a_data_model_can_be_generated_from_a_schema() {
         enums: vec![],
         sequences: vec![],
     };
-    let data_model = calculate_model(&schema).expect("calculate data model");
+    let data_model = calculate_model(&schema, POSTGRES_SOURCE_NAME).expect("calculate data model");
 
     assert_eq!(data_model, ref_data_model);
 }
@@ -238,11 +240,161 @@ fn arity_is_preserved_when_generating_data_model_from_a_schema() {
         enums: vec![],
         sequences: vec![],
     };
-    let data_model = calculate_model(&schema).expect("calculate data model");
+    let data_model = calculate_model(&schema, POSTGRES_SOURCE_NAME).expect("calculate data model");
 
     assert_eq!(data_model, ref_data_model);
 }
 
+#[test]
+fn native_types_are_preserved_when_generating_data_model_from_a_schema() {
+    setup();
+
+    let ref_data_model = Datamodel {
+        models: vec![Model {
+            database_name: None,
+            name: "Table1".to_string(),
+            documentation: None,
+            is_embedded: false,
+            fields: vec![
+                Field {
+                    name: "body".to_string(),
+                    arity: FieldArity::Required,
+                    field_type: FieldType::Base(PrismaType::String),
+                    database_name: None,
+                    default_value: None,
+                    is_unique: false,
+                    id_info: None,
+                    scalar_list_strategy: None,
+                    documentation: None,
+                    is_generated: false,
+                    is_updated_at: false,
+                },
+                Field {
+                    name: "amount".to_string(),
+                    arity: FieldArity::Required,
+                    field_type: FieldType::Base(PrismaType::Float),
+                    database_name: None,
+                    default_value: None,
+                    is_unique: false,
+                    id_info: None,
+                    scalar_list_strategy: None,
+                    documentation: None,
+                    is_generated: false,
+                    is_updated_at: false,
+                },
+                Field {
+                    name: "title".to_string(),
+                    arity: FieldArity::Required,
+                    field_type: FieldType::ConnectorSpecific {
+                        base_type: PrismaType::String,
+                        connector_type: Some("varchar(255)".to_string()),
+                    },
+                    database_name: None,
+                    default_value: None,
+                    is_unique: false,
+                    id_info: None,
+                    scalar_list_strategy: None,
+                    documentation: None,
+                    is_generated: false,
+                    is_updated_at: false,
+                },
+            ],
+            is_generated: false,
+            indexes: vec![],
+            id_fields: vec![],
+        }],
+        enums: vec![],
+    };
+
+    let schema = SqlSchema {
+        tables: vec![Table {
+            name: "Table1".to_string(),
+            columns: vec![
+                Column {
+                    name: "body".to_string(),
+                    tpe: ColumnType {
+                        raw: "text".to_string(),
+                        family: ColumnTypeFamily::String,
+                    },
+                    arity: ColumnArity::Required,
+                    default: None,
+                    auto_increment: false,
+                },
+                Column {
+                    name: "amount".to_string(),
+                    tpe: ColumnType {
+                        raw: "numeric(65,30)".to_string(),
+                        family: ColumnTypeFamily::Float,
+                    },
+                    arity: ColumnArity::Required,
+                    default: None,
+                    auto_increment: false,
+                },
+                Column {
+                    name: "title".to_string(),
+                    tpe: ColumnType {
+                        raw: "varchar(255)".to_string(),
+                        family: ColumnTypeFamily::String,
+                    },
+                    arity: ColumnArity::Required,
+                    default: None,
+                    auto_increment: false,
+                },
+            ],
+            indices: vec![],
+            primary_key: None,
+            foreign_keys: vec![],
+        }],
+        enums: vec![],
+        sequences: vec![],
+    };
+    let data_model = calculate_model(&schema, POSTGRES_SOURCE_NAME).expect("calculate data model");
+
+    assert_eq!(data_model, ref_data_model);
+}
+
+#[test]
+fn native_types_unknown_to_the_source_are_not_preserved_when_generating_data_model_from_a_schema() {
+    setup();
+
+    let column = |name: &str, raw: &str, family: ColumnTypeFamily| Column {
+        name: name.to_string(),
+        tpe: ColumnType {
+            raw: raw.to_string(),
+            family,
+        },
+        arity: ColumnArity::Required,
+        default: None,
+        auto_increment: false,
+    };
+
+    let schema = SqlSchema {
+        tables: vec![Table {
+            name: "Table1".to_string(),
+            columns: vec![
+                column("price", "DECIMAL(10,2)", ColumnTypeFamily::Float),
+                column("title", "VARCHAR(255)", ColumnTypeFamily::String),
+            ],
+            indices: vec![],
+            primary_key: None,
+            foreign_keys: vec![],
+        }],
+        enums: vec![],
+        sequences: vec![],
+    };
+    let data_model = calculate_model(&schema, SQLITE_SOURCE_NAME).expect("calculate data model");
+    let model = data_model.find_model("Table1").expect("find model");
+
+    assert_eq!(
+        model.find_field("price").map(|field| &field.field_type),
+        Some(&FieldType::Base(PrismaType::Float))
+    );
+    assert_eq!(
+        model.find_field("title").map(|field| &field.field_type),
+        Some(&FieldType::Base(PrismaType::String))
+    );
+}
+
 #[test]
 fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
     setup();
@@ -389,7 +541,7 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
         enums: vec![],
         sequences: vec![],
     };
-    let data_model = calculate_model(&schema).expect("calculate data model");
+    let data_model = calculate_model(&schema, POSTGRES_SOURCE_NAME).expect("calculate data model");
 
     assert_eq!(data_model, ref_data_model);
 }
@@ -553,7 +705,7 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
         enums: vec![],
         sequences: vec![],
     };
-    let data_model = calculate_model(&schema).expect("calculate data model");
+    let data_model = calculate_model(&schema, POSTGRES_SOURCE_NAME).expect("calculate data model");
 
     assert_eq!(data_model, ref_data_model);
 }
@@ -639,7 +791,7 @@ fn uniqueness_is_preserved_when_generating_data_model_from_a_schema() {
         enums: vec![],
         sequences: vec![],
     };
-    let data_model = calculate_model(&schema).expect("calculate data model");
+    let data_model = calculate_model(&schema, POSTGRES_SOURCE_NAME).expect("calculate data model");
 
     assert_eq!(data_model, ref_data_model);
 }
@@ -718,7 +870,8 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                             name: "".to_string(),
                             to: "City".to_string(),
                             to_fields: vec!["id".to_string()],
-                            on_delete: OnDeleteStrategy::None,
+                            on_delete: ReferentialAction::Restrict,
+                            on_update: ReferentialAction::None,
                         }),
                         database_name: None,
                         default_value: None,
@@ -736,7 +889,8 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                             name: "".to_string(),
                             to: "City".to_string(),
                             to_fields: vec!["name".to_string()],
-                            on_delete: OnDeleteStrategy::None,
+                            on_delete: ReferentialAction::Restrict,
+                            on_update: ReferentialAction::None,
                         }),
                         database_name: None,
                         default_value: None,
@@ -831,6 +985,7 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                     columns: vec!["city-id".to_string(), "city-name".to_string()],
                     referenced_table: "City".to_string(),
                     on_delete_action: ForeignKeyAction::NoAction,
+                    on_update_action: ForeignKeyAction::NoAction,
                     referenced_columns: vec!["id".to_string(), "name".to_string()],
                 }],
             },
@@ -838,7 +993,7 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
         enums: vec![],
         sequences: vec![],
     };
-    let data_model = calculate_model(&schema).expect("calculate data model");
+    let data_model = calculate_model(&schema, POSTGRES_SOURCE_NAME).expect("calculate data model");
 
     assert_eq!(data_model, ref_data_model);
 }
@@ -957,7 +1112,7 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
         enums: vec![],
         sequences: vec![],
     };
-    let data_model = calculate_model(&schema).expect("calculate data model");
+    let data_model = calculate_model(&schema, POSTGRES_SOURCE_NAME).expect("calculate data model");
 
     assert_eq!(data_model, ref_data_model);
 }
@@ -1010,7 +1165,8 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                             to: "User".to_string(),
                             to_fields: vec!["city-id".to_string()],
                             name: "CityToUser".to_string(),
-                            on_delete: OnDeleteStrategy::None,
+                            on_delete: ReferentialAction::Restrict,
+                            on_update: ReferentialAction::None,
                         }),
                         database_name: None,
                         default_value: None,
@@ -1052,7 +1208,8 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                             name: "CityToUser".to_string(),
                             to: "City".to_string(),
                             to_fields: vec!["id".to_string()],
-                            on_delete: OnDeleteStrategy::None,
+                            on_delete: ReferentialAction::Restrict,
+                            on_update: ReferentialAction::None,
                         }),
                         database_name: None,
                         default_value: None,
@@ -1136,6 +1293,7 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                     columns: vec!["city-id".to_string()],
                     referenced_table: "City".to_string(),
                     on_delete_action: ForeignKeyAction::NoAction,
+                    on_update_action: ForeignKeyAction::NoAction,
                     referenced_columns: vec!["id".to_string()],
                 }],
             },
@@ -1143,7 +1301,7 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
         enums: vec![],
         sequences: vec![],
     };
-    let data_model = calculate_model(&schema).expect("calculate data model");
+    let data_model = calculate_model(&schema, POSTGRES_SOURCE_NAME).expect("calculate data model");
 
     assert_eq!(data_model, ref_data_model);
 }
@@ -1162,18 +1320,103 @@ fn enums_are_preserved_when_generating_data_model_from_a_schema() {
         }],
     };
 
-    let mut enum_values = HashSet::new();
-    enum_values.insert("a".to_string());
-    enum_values.insert("b".to_string());
     let schema = SqlSchema {
         tables: vec![],
         enums: vec![Enum {
             name: "Enum".to_string(),
-            values: enum_values,
+            values: vec!["a".to_string(), "b".to_string()],
         }],
         sequences: vec![],
     };
-    let data_model = calculate_model(&schema).expect("calculate data model");
+    let data_model = calculate_model(&schema, POSTGRES_SOURCE_NAME).expect("calculate data model");
 
     assert_eq!(data_model, ref_data_model);
 }
+
+#[test]
+fn referential_actions_are_preserved_when_generating_data_model_from_a_schema() {
+    setup();
+
+    let id_column = Column {
+        name: "id".to_string(),
+        tpe: ColumnType {
+            raw: "integer".to_string(),
+            family: ColumnTypeFamily::Int,
+        },
+        arity: ColumnArity::Required,
+        default: None,
+        auto_increment: true,
+    };
+    let primary_key = Some(PrimaryKey {
+        columns: vec!["id".to_string()],
+        sequence: None,
+    });
+    let foreign_key = |column: &str, on_delete_action, on_update_action| ForeignKey {
+        constraint_name: None,
+        columns: vec![column.to_string()],
+        referenced_table: "City".to_string(),
+        on_delete_action,
+        on_update_action,
+        referenced_columns: vec!["id".to_string()],
+    };
+    let foreign_key_column = |name: &str| Column {
+        name: name.to_string(),
+        tpe: ColumnType {
+            raw: "integer".to_string(),
+            family: ColumnTypeFamily::Int,
+        },
+        arity: ColumnArity::Nullable,
+        default: None,
+        auto_increment: false,
+    };
+
+    let schema = SqlSchema {
+        tables: vec![
+            Table {
+                name: "City".to_string(),
+                columns: vec![id_column.clone()],
+                indices: vec![],
+                primary_key: primary_key.clone(),
+                foreign_keys: vec![],
+            },
+            Table {
+                name: "User".to_string(),
+                columns: vec![
+                    id_column,
+                    foreign_key_column("restricted"),
+                    foreign_key_column("unrestricted"),
+                ],
+                indices: vec![],
+                primary_key,
+                foreign_keys: vec![
+                    foreign_key("restricted", ForeignKeyAction::Restrict, ForeignKeyAction::Restrict),
+                    foreign_key("unrestricted", ForeignKeyAction::NoAction, ForeignKeyAction::NoAction),
+                ],
+            },
+        ],
+        enums: vec![],
+        sequences: vec![],
+    };
+    let data_model = calculate_model(&schema, POSTGRES_SOURCE_NAME).expect("calculate data model");
+
+    let relation_actions = |field_name: &str| {
+        let field = data_model
+            .find_model("User")
+            .and_then(|model| model.find_field(field_name))
+            .expect("find relation field");
+
+        match &field.field_type {
+            FieldType::Relation(info) => (info.on_delete, info.on_update),
+            _ => panic!("Expected a relation field."),
+        }
+    };
+
+    assert_eq!(
+        relation_actions("restricted"),
+        (ReferentialAction::Restrict, ReferentialAction::Restrict)
+    );
+    assert_eq!(
+        relation_actions("unrestricted"),
+        (ReferentialAction::Restrict, ReferentialAction::None)
+    );
+}
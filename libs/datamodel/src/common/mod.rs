@@ -24,6 +24,8 @@ pub enum PrismaType {
     Boolean,
     String,
     DateTime,
+    Bytes,
+    Json,
 }
 
 impl FromStrAndSpan for PrismaType {
@@ -35,6 +37,8 @@ impl FromStrAndSpan for PrismaType {
             "Boolean" => Ok(PrismaType::Boolean),
             "String" => Ok(PrismaType::String),
             "DateTime" => Ok(PrismaType::DateTime),
+            "Bytes" => Ok(PrismaType::Bytes),
+            "Json" => Ok(PrismaType::Json),
             _ => Err(DatamodelError::new_type_not_found_error(s, span)),
         }
     }
@@ -49,6 +53,8 @@ impl ToString for PrismaType {
             PrismaType::Boolean => String::from("Boolean"),
             PrismaType::String => String::from("String"),
             PrismaType::DateTime => String::from("DateTime"),
+            PrismaType::Bytes => String::from("Bytes"),
+            PrismaType::Json => String::from("Json"),
         }
     }
 }
@@ -58,7 +64,8 @@ impl ToString for PrismaType {
 pub enum PrismaValue {
     Int(i32),
     Float(f32),
-    Decimal(f32),
+    /// The decimal literal as written, to keep its exact value.
+    Decimal(String),
     Boolean(bool),
     String(String),
     DateTime(DateTime<Utc>),
@@ -79,6 +79,9 @@ impl ValueValidator {
                 PrismaType::Boolean => self.as_bool().map(dml::Value::Boolean),
                 PrismaType::DateTime => self.as_date_time().map(dml::Value::DateTime),
                 PrismaType::String => self.as_str().map(dml::Value::String),
+                // There are no literals for binary data and JSON.
+                PrismaType::Bytes => Err(self.construct_error("Bytes")),
+                PrismaType::Json => Err(self.construct_error("Json")),
             },
             MaybeExpression::Expression(expr, _) => {
                 if expr.get_type() == scalar_type {
@@ -161,16 +164,14 @@ impl ValueValidator {
         }
     }
 
-    // TODO: Ask which decimal type to take.
     /// Tries to convert the wrapped value to a Prisma Decimal.
-    pub fn as_decimal(&self) -> Result<f32, DatamodelError> {
+    /// The literal is kept as written, so no precision is lost to a float.
+    pub fn as_decimal(&self) -> Result<String, DatamodelError> {
         match &self.value {
-            MaybeExpression::Value(_, ast::Expression::NumericValue(value, _)) => {
-                self.wrap_error_from_result(value.parse::<f32>(), "numeric")
-            }
-            MaybeExpression::Value(_, ast::Expression::Any(value, _)) => {
-                self.wrap_error_from_result(value.parse::<f32>(), "numeric")
-            }
+            MaybeExpression::Value(_, ast::Expression::NumericValue(value, _))
+            | MaybeExpression::Value(_, ast::Expression::Any(value, _)) => self
+                .wrap_error_from_result(value.parse::<f64>(), "numeric")
+                .map(|_| value.clone()),
             _ => Err(self.construct_error("numeric")),
         }
     }
@@ -271,7 +272,7 @@ impl Into<ast::Expression> for &dml::Value {
             dml::Value::String(value) => ast::Expression::StringValue(value.clone(), ast::Span::empty()),
             dml::Value::ConstantLiteral(value) => ast::Expression::ConstantValue(value.clone(), ast::Span::empty()),
             dml::Value::DateTime(value) => ast::Expression::ConstantValue(value.to_rfc3339(), ast::Span::empty()),
-            dml::Value::Decimal(value) => ast::Expression::NumericValue(value.to_string(), ast::Span::empty()),
+            dml::Value::Decimal(value) => ast::Expression::NumericValue(value.clone(), ast::Span::empty()),
             dml::Value::Float(value) => ast::Expression::NumericValue(value.to_string(), ast::Span::empty()),
             dml::Value::Int(value) => ast::Expression::NumericValue(value.to_string(), ast::Span::empty()),
             dml::Value::Expression(name, _, args) => ast::Expression::Function(
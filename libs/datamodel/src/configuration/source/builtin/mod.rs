@@ -1,5 +1,6 @@
 mod mysql_source;
 mod mysql_source_definition;
+mod native_types;
 mod postgres_source;
 mod postgres_source_definition;
 mod sqlite_source;
@@ -7,6 +8,7 @@ mod sqlite_source_definition;
 
 pub use mysql_source::*;
 pub use mysql_source_definition::*;
+pub use native_types::*;
 pub use postgres_source::*;
 pub use postgres_source_definition::*;
 pub use sqlite_source::*;
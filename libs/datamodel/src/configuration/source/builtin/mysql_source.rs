@@ -1,6 +1,101 @@
-use crate::{configuration::*, dml, validator::directive::DirectiveValidator};
+use super::NativeType;
+use crate::{common::PrismaType, configuration::*, dml, validator::directive::DirectiveValidator};
 pub const MYSQL_SOURCE_NAME: &str = "mysql";
 
+/// The native types of the MySQL source, set on fields via `@<source name>.<type>`.
+pub(super) const MYSQL_NATIVE_TYPES: &[NativeType] = &[
+    NativeType {
+        name: "SmallInt",
+        sql_name: "smallint",
+        arguments: &[],
+        scalar_types: &[PrismaType::Int],
+    },
+    NativeType {
+        name: "Int",
+        sql_name: "int",
+        arguments: &[],
+        scalar_types: &[PrismaType::Int],
+    },
+    NativeType {
+        name: "BigInt",
+        sql_name: "bigint",
+        arguments: &[],
+        scalar_types: &[PrismaType::Int],
+    },
+    NativeType {
+        name: "Decimal",
+        sql_name: "decimal",
+        arguments: &["precision", "scale"],
+        scalar_types: &[PrismaType::Float, PrismaType::Decimal],
+    },
+    NativeType {
+        name: "Float",
+        sql_name: "float",
+        arguments: &[],
+        scalar_types: &[PrismaType::Float],
+    },
+    NativeType {
+        name: "Double",
+        sql_name: "double",
+        arguments: &[],
+        scalar_types: &[PrismaType::Float],
+    },
+    NativeType {
+        name: "VarChar",
+        sql_name: "varchar",
+        arguments: &["length"],
+        scalar_types: &[PrismaType::String],
+    },
+    NativeType {
+        name: "Char",
+        sql_name: "char",
+        arguments: &["length"],
+        scalar_types: &[PrismaType::String],
+    },
+    NativeType {
+        name: "Text",
+        sql_name: "text",
+        arguments: &[],
+        scalar_types: &[PrismaType::String],
+    },
+    NativeType {
+        name: "DateTime",
+        sql_name: "datetime",
+        arguments: &[],
+        scalar_types: &[PrismaType::DateTime],
+    },
+    NativeType {
+        name: "Timestamp",
+        sql_name: "timestamp",
+        arguments: &[],
+        scalar_types: &[PrismaType::DateTime],
+    },
+    NativeType {
+        name: "Blob",
+        sql_name: "blob",
+        arguments: &[],
+        scalar_types: &[PrismaType::Bytes],
+    },
+    NativeType {
+        name: "MediumBlob",
+        sql_name: "mediumblob",
+        arguments: &[],
+        scalar_types: &[PrismaType::Bytes],
+    },
+    NativeType {
+        name: "LongBlob",
+        sql_name: "longblob",
+        arguments: &[],
+        scalar_types: &[PrismaType::Bytes],
+    },
+    NativeType {
+        name: "Json",
+        sql_name: "json",
+        arguments: &[],
+        scalar_types: &[PrismaType::Json],
+    },
+];
+
 pub struct MySqlSource {
     pub(super) name: String,
     pub(super) url: StringFromEnvVar,
@@ -32,7 +127,7 @@ impl Source for MySqlSource {
     }
 
     fn get_field_directives(&self) -> Vec<Box<dyn DirectiveValidator<dml::Field>>> {
-        vec![]
+        super::native_type_directives(MYSQL_NATIVE_TYPES)
     }
 
     fn get_model_directives(&self) -> Vec<Box<dyn DirectiveValidator<dml::Model>>> {
@@ -0,0 +1,158 @@
+use super::{
+    mysql_source::MYSQL_NATIVE_TYPES, postgres_source::POSTGRES_NATIVE_TYPES, sqlite_source::SQLITE_NATIVE_TYPES,
+    MYSQL_SOURCE_NAME, POSTGRES_SOURCE_NAME, SQLITE_SOURCE_NAME,
+};
+use crate::common::{argument::Arguments, PrismaType};
+use crate::{ast, dml, error::DatamodelError, validator::directive::DirectiveValidator};
+
+/// A native database type, which can be set on scalar fields via a directive
+/// of the source, e.g. `@db.VarChar(255)`.
+pub struct NativeType {
+    /// The name of the directive.
+    pub name: &'static str,
+    /// The name of the type in the database.
+    pub sql_name: &'static str,
+    /// The names of the integer arguments of the type, e.g. the length.
+    /// The first argument can be given unnamed.
+    pub arguments: &'static [&'static str],
+    /// The scalar types that can be stored in a column of this type.
+    pub scalar_types: &'static [PrismaType],
+}
+
+impl NativeType {
+    /// Renders the type as it is declared in the database, e.g. `varchar(255)`.
+    fn render(&self, arguments: &[i32]) -> String {
+        if arguments.is_empty() {
+            String::from(self.sql_name)
+        } else {
+            let arguments: Vec<String> = arguments.iter().map(|arg| arg.to_string()).collect();
+            format!("{}({})", self.sql_name, arguments.join(","))
+        }
+    }
+
+    /// Parses the arguments of a type declared in the database,
+    /// if the declared type is of this type.
+    fn parse(&self, declared_type: &str) -> Option<Vec<i32>> {
+        let (name, arguments) = match declared_type.find('(') {
+            Some(start) if declared_type.ends_with(')') => (
+                &declared_type[..start],
+                declared_type[start + 1..declared_type.len() - 1]
+                    .split(',')
+                    .map(|arg| arg.trim().parse::<i32>().ok())
+                    .collect::<Option<Vec<i32>>>()?,
+            ),
+            _ => (declared_type, vec![]),
+        };
+
+        if name.eq_ignore_ascii_case(self.sql_name) && arguments.len() == self.arguments.len() {
+            Some(arguments)
+        } else {
+            None
+        }
+    }
+}
+
+/// Returns whether a type declared in the database is one of the native types
+/// of the source with the given connector type, so that a field can keep it.
+pub fn is_native_type(connector_type: &str, declared_type: &str) -> bool {
+    let native_types = match connector_type {
+        MYSQL_SOURCE_NAME => MYSQL_NATIVE_TYPES,
+        POSTGRES_SOURCE_NAME => POSTGRES_NATIVE_TYPES,
+        SQLITE_SOURCE_NAME => SQLITE_NATIVE_TYPES,
+        _ => &[],
+    };
+
+    native_types
+        .iter()
+        .any(|native_type| native_type.parse(declared_type).is_some())
+}
+
+/// Creates the field directives for the given native types.
+pub fn native_type_directives(native_types: &'static [NativeType]) -> Vec<Box<dyn DirectiveValidator<dml::Field>>> {
+    native_types
+        .iter()
+        .map(|native_type| {
+            Box::new(NativeTypeDirectiveValidator { native_type }) as Box<dyn DirectiveValidator<dml::Field>>
+        })
+        .collect()
+}
+
+/// Source directive setting the native type of a scalar field.
+struct NativeTypeDirectiveValidator {
+    native_type: &'static NativeType,
+}
+
+impl DirectiveValidator<dml::Field> for NativeTypeDirectiveValidator {
+    fn directive_name(&self) -> &str {
+        self.native_type.name
+    }
+
+    fn validate_and_apply(&self, args: &mut Arguments, field: &mut dml::Field) -> Result<(), DatamodelError> {
+        let base_type = match field.field_type.scalar_type() {
+            Some(base_type) if self.native_type.scalar_types.contains(&base_type) => base_type,
+            _ => {
+                let scalar_types: Vec<String> = self.native_type.scalar_types.iter().map(|t| t.to_string()).collect();
+
+                return self.error(
+                    &format!(
+                        "The native type {} can only be used on fields of type {}.",
+                        self.native_type.name,
+                        scalar_types.join(" or ")
+                    ),
+                    args.span(),
+                );
+            }
+        };
+
+        let mut arguments = Vec::with_capacity(self.native_type.arguments.len());
+
+        for (idx, name) in self.native_type.arguments.iter().enumerate() {
+            let arg = if idx == 0 {
+                args.default_arg(name)?
+            } else {
+                args.arg(name)?
+            };
+            arguments.push(arg.as_int().map_err(|err| self.parser_error(&err))?);
+        }
+
+        field.field_type = dml::FieldType::ConnectorSpecific {
+            base_type,
+            connector_type: Some(self.native_type.render(&arguments)),
+        };
+
+        Ok(())
+    }
+
+    fn serialize(
+        &self,
+        field: &dml::Field,
+        _datamodel: &dml::Datamodel,
+    ) -> Result<Vec<ast::Directive>, DatamodelError> {
+        if let dml::FieldType::ConnectorSpecific {
+            connector_type: Some(connector_type),
+            ..
+        } = &field.field_type
+        {
+            if let Some(values) = self.native_type.parse(connector_type) {
+                let arguments = self
+                    .native_type
+                    .arguments
+                    .iter()
+                    .zip(values)
+                    .enumerate()
+                    .map(|(idx, (name, value))| {
+                        let name = if idx == 0 { "" } else { *name };
+                        ast::Argument::new(
+                            name,
+                            ast::Expression::NumericValue(value.to_string(), ast::Span::empty()),
+                        )
+                    })
+                    .collect();
+
+                return Ok(vec![ast::Directive::new(self.directive_name(), arguments)]);
+            }
+        }
+
+        Ok(vec![])
+    }
+}
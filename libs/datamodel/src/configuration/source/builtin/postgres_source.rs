@@ -1,6 +1,102 @@
-use crate::{configuration::*, dml, validator::directive::DirectiveValidator};
+use super::NativeType;
+use crate::{common::PrismaType, configuration::*, dml, validator::directive::DirectiveValidator};
 pub const POSTGRES_SOURCE_NAME: &str = "postgresql";
 
+/// The native types of the Postgres source, set on fields via `@<source name>.<type>`.
+/// Only types whose values the query engine can bind and read back are listed.
+pub(super) const POSTGRES_NATIVE_TYPES: &[NativeType] = &[
+    NativeType {
+        name: "SmallInt",
+        sql_name: "int2",
+        arguments: &[],
+        scalar_types: &[PrismaType::Int],
+    },
+    NativeType {
+        name: "Integer",
+        sql_name: "int4",
+        arguments: &[],
+        scalar_types: &[PrismaType::Int],
+    },
+    NativeType {
+        name: "BigInt",
+        sql_name: "int8",
+        arguments: &[],
+        scalar_types: &[PrismaType::Int],
+    },
+    NativeType {
+        name: "Decimal",
+        sql_name: "numeric",
+        arguments: &["precision", "scale"],
+        scalar_types: &[PrismaType::Float, PrismaType::Decimal],
+    },
+    NativeType {
+        name: "Real",
+        sql_name: "float4",
+        arguments: &[],
+        scalar_types: &[PrismaType::Float],
+    },
+    NativeType {
+        name: "DoublePrecision",
+        sql_name: "float8",
+        arguments: &[],
+        scalar_types: &[PrismaType::Float],
+    },
+    NativeType {
+        name: "VarChar",
+        sql_name: "varchar",
+        arguments: &["length"],
+        scalar_types: &[PrismaType::String],
+    },
+    NativeType {
+        name: "Char",
+        sql_name: "bpchar",
+        arguments: &["length"],
+        scalar_types: &[PrismaType::String],
+    },
+    NativeType {
+        name: "Text",
+        sql_name: "text",
+        arguments: &[],
+        scalar_types: &[PrismaType::String],
+    },
+    NativeType {
+        name: "Boolean",
+        sql_name: "bool",
+        arguments: &[],
+        scalar_types: &[PrismaType::Boolean],
+    },
+    NativeType {
+        name: "Timestamp",
+        sql_name: "timestamp",
+        arguments: &[],
+        scalar_types: &[PrismaType::DateTime],
+    },
+    NativeType {
+        name: "Timestamptz",
+        sql_name: "timestamptz",
+        arguments: &[],
+        scalar_types: &[PrismaType::DateTime],
+    },
+    NativeType {
+        name: "ByteA",
+        sql_name: "bytea",
+        arguments: &[],
+        scalar_types: &[PrismaType::Bytes],
+    },
+    NativeType {
+        name: "Json",
+        sql_name: "json",
+        arguments: &[],
+        scalar_types: &[PrismaType::Json],
+    },
+    NativeType {
+        name: "JsonB",
+        sql_name: "jsonb",
+        arguments: &[],
+        scalar_types: &[PrismaType::Json],
+    },
+];
+
 pub struct PostgresSource {
     pub(super) name: String,
     pub(super) url: StringFromEnvVar,
@@ -27,7 +123,7 @@ impl Source for PostgresSource {
         };
     }
     fn get_field_directives(&self) -> Vec<Box<dyn DirectiveValidator<dml::Field>>> {
-        vec![]
+        super::native_type_directives(POSTGRES_NATIVE_TYPES)
     }
     fn get_model_directives(&self) -> Vec<Box<dyn DirectiveValidator<dml::Model>>> {
         vec![]
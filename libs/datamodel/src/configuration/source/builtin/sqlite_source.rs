@@ -1,6 +1,41 @@
-use crate::{configuration::*, dml, validator::directive::DirectiveValidator};
+use super::NativeType;
+use crate::{common::PrismaType, configuration::*, dml, validator::directive::DirectiveValidator};
 pub const SQLITE_SOURCE_NAME: &str = "sqlite";
 
+/// The native types of the SQLite source, set on fields via `@<source name>.<type>`.
+pub(super) const SQLITE_NATIVE_TYPES: &[NativeType] = &[
+    NativeType {
+        name: "Integer",
+        sql_name: "INTEGER",
+        arguments: &[],
+        scalar_types: &[PrismaType::Int, PrismaType::Boolean],
+    },
+    NativeType {
+        name: "Real",
+        sql_name: "REAL",
+        arguments: &[],
+        scalar_types: &[PrismaType::Float],
+    },
+    NativeType {
+        name: "Numeric",
+        sql_name: "NUMERIC",
+        arguments: &[],
+        scalar_types: &[PrismaType::Float, PrismaType::Decimal],
+    },
+    NativeType {
+        name: "Text",
+        sql_name: "TEXT",
+        arguments: &[],
+        scalar_types: &[PrismaType::String, PrismaType::DateTime, PrismaType::Json],
+    },
+    NativeType {
+        name: "Blob",
+        sql_name: "BLOB",
+        arguments: &[],
+        scalar_types: &[PrismaType::Bytes],
+    },
+];
+
 pub struct SqliteSource {
     pub(super) name: String,
     pub(super) url: StringFromEnvVar,
@@ -29,7 +64,7 @@ impl Source for SqliteSource {
     }
 
     fn get_field_directives(&self) -> Vec<Box<dyn DirectiveValidator<dml::Field>>> {
-        vec![]
+        super::native_type_directives(SQLITE_NATIVE_TYPES)
     }
     fn get_model_directives(&self) -> Vec<Box<dyn DirectiveValidator<dml::Model>>> {
         vec![]
@@ -6,7 +6,7 @@ mod traits;
 pub mod builtin;
 
 // TODO: i think these constants should move to a more central place.
-pub use builtin::{MYSQL_SOURCE_NAME, POSTGRES_SOURCE_NAME, SQLITE_SOURCE_NAME};
+pub use builtin::{is_native_type, MYSQL_SOURCE_NAME, POSTGRES_SOURCE_NAME, SQLITE_SOURCE_NAME};
 pub use json::{render_sources_to_json, render_sources_to_json_value, sources_from_json_value_with_plugins};
 pub use loader::*;
 pub use serializer::*;
@@ -33,6 +33,17 @@ pub enum FieldType {
     Base(PrismaType),
 }
 
+impl FieldType {
+    /// The scalar type of base and connector specific fields.
+    pub fn scalar_type(&self) -> Option<PrismaType> {
+        match self {
+            FieldType::Base(scalar_type) => Some(*scalar_type),
+            FieldType::ConnectorSpecific { base_type, .. } => Some(*base_type),
+            _ => None,
+        }
+    }
+}
+
 /// Holds information about an id, or priamry key.
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct IdInfo {
@@ -33,6 +33,7 @@ pub struct IndexDefinition {
 pub enum IndexType {
     Unique,
     Normal,
+    Fulltext,
 }
 
 impl Model {
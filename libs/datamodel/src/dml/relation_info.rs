@@ -12,9 +12,13 @@ pub struct RelationInfo {
     pub to_fields: Vec<String>,
     /// The name of the relation. Internally, an empty string signals no name.
     pub name: String,
-    /// A strategy indicating what happens when
+    /// A strategy indicating what happens to this node when
     /// a related node is deleted.
-    pub on_delete: OnDeleteStrategy,
+    pub on_delete: ReferentialAction,
+    /// A strategy indicating what happens to this node when
+    /// the referenced fields of a related node are updated.
+    #[serde(default)]
+    pub on_update: ReferentialAction,
 }
 
 impl RelationInfo {
@@ -25,7 +29,8 @@ impl RelationInfo {
             to: String::from(to),
             to_fields: Vec::new(),
             name: String::new(),
-            on_delete: OnDeleteStrategy::None,
+            on_delete: ReferentialAction::None,
+            on_update: ReferentialAction::None,
         }
     }
     pub fn new_with_field(to: &str, to_field: &str) -> RelationInfo {
@@ -33,7 +38,8 @@ impl RelationInfo {
             to: String::from(to),
             to_fields: vec![String::from(to_field)],
             name: String::new(),
-            on_delete: OnDeleteStrategy::None,
+            on_delete: ReferentialAction::None,
+            on_update: ReferentialAction::None,
         }
     }
 
@@ -42,34 +48,50 @@ impl RelationInfo {
             to: String::from(to),
             to_fields,
             name: String::new(),
-            on_delete: OnDeleteStrategy::None,
+            on_delete: ReferentialAction::None,
+            on_update: ReferentialAction::None,
         }
     }
 }
 
 /// Describes what happens when related nodes
-/// are deleted.
+/// are deleted or their referenced fields are updated.
 #[derive(Debug, Copy, PartialEq, Clone, Serialize, Deserialize)]
-pub enum OnDeleteStrategy {
+pub enum ReferentialAction {
     Cascade,
+    Restrict,
+    SetNull,
+    SetDefault,
     None,
 }
 
-impl FromStrAndSpan for OnDeleteStrategy {
+impl Default for ReferentialAction {
+    fn default() -> Self {
+        ReferentialAction::None
+    }
+}
+
+impl FromStrAndSpan for ReferentialAction {
     fn from_str_and_span(s: &str, span: ast::Span) -> Result<Self, DatamodelError> {
         match s {
-            "CASCADE" => Ok(OnDeleteStrategy::Cascade),
-            "NONE" => Ok(OnDeleteStrategy::None),
-            _ => Err(DatamodelError::new_literal_parser_error("onDelete strategy", s, span)),
+            "CASCADE" => Ok(ReferentialAction::Cascade),
+            "RESTRICT" => Ok(ReferentialAction::Restrict),
+            "SET_NULL" => Ok(ReferentialAction::SetNull),
+            "SET_DEFAULT" => Ok(ReferentialAction::SetDefault),
+            "NONE" => Ok(ReferentialAction::None),
+            _ => Err(DatamodelError::new_literal_parser_error("referential action", s, span)),
         }
     }
 }
 
-impl ToString for OnDeleteStrategy {
+impl ToString for ReferentialAction {
     fn to_string(&self) -> String {
         match self {
-            OnDeleteStrategy::Cascade => String::from("CASCADE"),
-            OnDeleteStrategy::None => String::from("NONE"),
+            ReferentialAction::Cascade => String::from("CASCADE"),
+            ReferentialAction::Restrict => String::from("RESTRICT"),
+            ReferentialAction::SetNull => String::from("SET_NULL"),
+            ReferentialAction::SetDefault => String::from("SET_DEFAULT"),
+            ReferentialAction::None => String::from("NONE"),
         }
     }
 }
@@ -76,7 +76,8 @@ fn default_value_from_serde(container: &Option<serde_json::Value>, field_type: &
             (serde_json::Value::String(val), PrismaType::String) => dml::Value::String(String::from(val.as_str())),
             (serde_json::Value::Number(val), PrismaType::Float) => dml::Value::Float(val.as_f64().unwrap() as f32),
             (serde_json::Value::Number(val), PrismaType::Int) => dml::Value::Int(val.as_i64().unwrap() as i32),
-            (serde_json::Value::Number(val), PrismaType::Decimal) => dml::Value::Decimal(val.as_f64().unwrap() as f32),
+            (serde_json::Value::String(val), PrismaType::Decimal) => dml::Value::Decimal(val.clone()),
+            (serde_json::Value::Number(val), PrismaType::Decimal) => dml::Value::Decimal(val.to_string()),
             (serde_json::Value::String(val), PrismaType::DateTime) => {
                 dml::Value::DateTime(String::from(val.as_str()).parse::<DateTime<Utc>>().unwrap())
             }
@@ -116,10 +117,10 @@ fn function_from_dmmf(func: &Function, expected_type: PrismaType) -> dml::Value
     dml::Value::Expression(func.name.clone(), expected_type, vec![])
 }
 
-fn get_on_delete_strategy(strategy: &Option<String>) -> dml::OnDeleteStrategy {
+fn get_referential_action(strategy: &Option<String>) -> dml::ReferentialAction {
     match strategy {
-        Some(val) => dml::OnDeleteStrategy::from_str_and_span(&val, Span::empty()).unwrap(),
-        None => dml::OnDeleteStrategy::None,
+        Some(val) => dml::ReferentialAction::from_str_and_span(&val, Span::empty()).unwrap(),
+        None => dml::ReferentialAction::None,
     }
 }
 
@@ -129,7 +130,8 @@ fn get_field_type(field: &Field) -> dml::FieldType {
             to: field.field_type.clone(),
             to_fields: field.relation_to_fields.clone().unwrap_or_default(),
             name: field.relation_name.clone().unwrap_or(String::new()),
-            on_delete: get_on_delete_strategy(&field.relation_on_delete),
+            on_delete: get_referential_action(&field.relation_on_delete),
+            on_update: get_referential_action(&field.relation_on_update),
         }),
         "enum" => dml::FieldType::Enum(field.field_type.clone()),
         "scalar" => dml::FieldType::Base(type_from_string(&field.field_type)),
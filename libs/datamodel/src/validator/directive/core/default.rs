@@ -15,7 +15,7 @@ impl DirectiveValidator<dml::Field> for DefaultDirectiveValidator {
             return self.error("Cannot set a default value on list field.", args.span());
         }
 
-        if let dml::FieldType::Base(scalar_type) = field.field_type {
+        if let Some(scalar_type) = field.field_type.scalar_type() {
             match args.default_arg("value")?.as_type(scalar_type) {
                 // TODO: Here, a default value directive can override the default value syntax sugar.
                 Ok(value) => field.default_value = Some(value),
@@ -28,7 +28,16 @@ impl DirectiveValidator<dml::Field> for DefaultDirectiveValidator {
                 Err(err) => return Err(self.parser_error(&err)),
             }
         } else {
-            return self.error("Cannot set a default value on a relation field.", args.span());
+            // The type of the referenced id is only known once all models are lifted, so the value is
+            // checked against it during validation.
+            let value = args.default_arg("value")?;
+            match value
+                .as_type(dml::ScalarType::Int)
+                .or_else(|_| value.as_type(dml::ScalarType::String))
+            {
+                Ok(value) => field.default_value = Some(value),
+                Err(err) => return Err(self.parser_error(&err)),
+            }
         }
 
         Ok(())
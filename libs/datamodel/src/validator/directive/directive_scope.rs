@@ -9,7 +9,6 @@ use crate::error::DatamodelError;
 /// preprends the source name in front of the directive name.
 pub struct DirectiveScope<T> {
     inner: Box<dyn DirectiveValidator<T>>,
-    #[allow(dead_code)]
     scope: String,
     name: String,
 }
@@ -34,6 +33,12 @@ impl<T> DirectiveValidator<T> for DirectiveScope<T> {
         self.inner.validate_and_apply(args, obj)
     }
     fn serialize(&self, obj: &T, datamodel: &dml::Datamodel) -> Result<Vec<ast::Directive>, DatamodelError> {
-        self.inner.serialize(obj, datamodel)
+        let mut directives = self.inner.serialize(obj, datamodel)?;
+
+        for directive in directives.iter_mut() {
+            directive.name = ast::Identifier::new(&format!("{}.{}", self.scope, directive.name.name));
+        }
+
+        Ok(directives)
     }
 }
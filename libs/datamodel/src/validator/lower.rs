@@ -19,8 +19,7 @@ impl LowerDmlToAst {
     /// the directives defined by the given sources registered.
     ///
     /// The directives defined by the given sources will be namespaced.
-    #[allow(unused)]
-    fn with_sources(sources: &[Box<dyn configuration::Source>]) -> LowerDmlToAst {
+    pub fn with_sources(sources: &[Box<dyn configuration::Source>]) -> LowerDmlToAst {
         LowerDmlToAst {
             directives: DirectiveBox::with_sources(sources),
         }
@@ -128,6 +127,8 @@ impl LowerDmlToAst {
     ) -> ast::Identifier {
         match field_type {
             dml::FieldType::Base(tpe) => ast::Identifier::new(&tpe.to_string()),
+            // The connector specific type is rendered by the directive of the source.
+            dml::FieldType::ConnectorSpecific { base_type, .. } => ast::Identifier::new(&base_type.to_string()),
             dml::FieldType::Enum(tpe) => ast::Identifier::new(&tpe.to_string()),
             dml::FieldType::Relation(rel) => {
                 let related_model = datamodel.find_model(&rel.to).expect(STATE_ERROR);
@@ -154,7 +155,6 @@ impl LowerDmlToAst {
                     ast::Identifier::new(&rel.to)
                 }
             }
-            _ => unimplemented!("Connector specific types are not supported atm."),
         }
     }
 }
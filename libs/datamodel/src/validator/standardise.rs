@@ -1,5 +1,5 @@
 use super::common::*;
-use crate::{ast, common::names::*, configuration, dml, error::ErrorCollection, OnDeleteStrategy};
+use crate::{ast, common::names::*, configuration, dml, error::ErrorCollection, ReferentialAction};
 use prisma_inflector;
 
 /// Helper for standardsing a datamodel.
@@ -167,7 +167,8 @@ impl Standardiser {
                 to: model.name.clone(),
                 to_fields: model.id_field_names(),
                 name: String::from(relation_name), // Will be corrected in later step
-                on_delete: dml::OnDeleteStrategy::None,
+                on_delete: dml::ReferentialAction::None,
+                on_update: dml::ReferentialAction::None,
             }),
         )
     }
@@ -296,7 +297,8 @@ impl Standardiser {
                         to: model.name.clone(),
                         to_fields: vec![],
                         name: rel.name.clone(),
-                        on_delete: OnDeleteStrategy::None,
+                        on_delete: ReferentialAction::None,
+                        on_update: ReferentialAction::None,
                     };
 
                     let (arity, field_name) = if field.arity.is_singular() {
@@ -43,6 +43,9 @@ impl Validator {
             if let Err(err) = self.validate_embedded_types_have_no_back_relation(ast_schema, schema, model) {
                 errors.push(err);
             }
+            if let Err(err) = self.validate_relation_defaults(ast_schema, schema, model) {
+                errors.push(err);
+            }
         }
 
         if errors.has_errors() {
@@ -81,10 +84,14 @@ impl Validator {
 
     fn validate_id_fields_valid(&self, ast_schema: &ast::SchemaAst, model: &dml::Model) -> Result<(), DatamodelError> {
         for id_field in model.singular_id_fields() {
-            let is_valid = match (&id_field.default_value, &id_field.field_type, &id_field.arity) {
+            let is_valid = match (
+                &id_field.default_value,
+                id_field.field_type.scalar_type(),
+                &id_field.arity,
+            ) {
                 (
                     Some(dml::Value::Expression(name, return_type, args)),
-                    dml::FieldType::Base(dml::ScalarType::String),
+                    Some(dml::ScalarType::String),
                     dml::FieldArity::Required,
                 ) => {
                     let name_eq = name == "cuid" || name == "uuid";
@@ -93,7 +100,7 @@ impl Validator {
 
                     name_eq && type_eq && args_eq
                 }
-                (None, dml::FieldType::Base(dml::ScalarType::Int), dml::FieldArity::Required) => true,
+                (None, Some(dml::ScalarType::Int), dml::FieldArity::Required) => true,
                 _ => false,
             };
 
@@ -108,6 +115,39 @@ impl Validator {
         Ok(())
     }
 
+    /// Ensures that the default values of relation fields are of the type of the referenced id.
+    fn validate_relation_defaults(
+        &self,
+        ast_schema: &ast::SchemaAst,
+        datamodel: &dml::Datamodel,
+        model: &dml::Model,
+    ) -> Result<(), DatamodelError> {
+        for field in model.fields() {
+            if let (dml::FieldType::Relation(rel), Some(default_value)) = (&field.field_type, &field.default_value) {
+                let id_type = datamodel
+                    .find_model(&rel.to)
+                    .and_then(|related| related.singular_id_fields().next())
+                    .and_then(|id_field| id_field.field_type.scalar_type());
+
+                let is_valid = match (default_value, id_type) {
+                    (dml::Value::Int(_), Some(dml::ScalarType::Int)) => true,
+                    (dml::Value::String(_), Some(dml::ScalarType::String)) => true,
+                    _ => false,
+                };
+
+                if !is_valid {
+                    return Err(DatamodelError::new_model_validation_error(
+                        "The default value of a relation field must be of the type of the id field of the related model.",
+                        &model.name,
+                        ast_schema.find_field(&model.name, &field.name).expect(STATE_ERROR).span,
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     /// Ensures that embedded types do not have back relations
     /// to their parent types.
     fn validate_embedded_types_have_no_back_relation(
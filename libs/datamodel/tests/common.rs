@@ -8,7 +8,8 @@ pub trait FieldAsserts {
     fn assert_enum_type(&self, en: &str) -> &Self;
     fn assert_relation_name(&self, t: &str) -> &Self;
     fn assert_relation_to(&self, t: &str) -> &Self;
-    fn assert_relation_delete_strategy(&self, t: dml::OnDeleteStrategy) -> &Self;
+    fn assert_relation_delete_strategy(&self, t: dml::ReferentialAction) -> &Self;
+    fn assert_relation_update_strategy(&self, t: dml::ReferentialAction) -> &Self;
     fn assert_relation_to_fields(&self, t: &[&str]) -> &Self;
     fn assert_arity(&self, arity: &dml::FieldArity) -> &Self;
     fn assert_with_db_name(&self, t: &str) -> &Self;
@@ -86,7 +87,7 @@ impl FieldAsserts for dml::Field {
         self
     }
 
-    fn assert_relation_delete_strategy(&self, t: dml::OnDeleteStrategy) -> &Self {
+    fn assert_relation_delete_strategy(&self, t: dml::ReferentialAction) -> &Self {
         if let dml::FieldType::Relation(info) = &self.field_type {
             assert_eq!(info.on_delete, t);
         } else {
@@ -96,6 +97,16 @@ impl FieldAsserts for dml::Field {
         self
     }
 
+    fn assert_relation_update_strategy(&self, t: dml::ReferentialAction) -> &Self {
+        if let dml::FieldType::Relation(info) = &self.field_type {
+            assert_eq!(info.on_update, t);
+        } else {
+            panic!("Relation expected, but found {:?}", self.field_type);
+        }
+
+        self
+    }
+
     fn assert_relation_to_fields(&self, t: &[&str]) -> &Self {
         if let dml::FieldType::Relation(info) = &self.field_type {
             assert_eq!(info.to_fields, t);
@@ -2,7 +2,7 @@ use crate::common::*;
 use datamodel::{ast::Span, error::DatamodelError};
 
 #[test]
-fn should_error_if_default_value_for_related() {
+fn should_error_if_default_value_for_related_is_not_of_the_referenced_id_type() {
     let dml = r#"
     model Model {
         id Int @id
@@ -16,10 +16,10 @@ fn should_error_if_default_value_for_related() {
 
     let errors = parse_error(dml);
 
-    errors.assert_is(DatamodelError::new_directive_validation_error(
-        "Cannot set a default value on a relation field.",
-        "default",
-        Span::new(53, 64),
+    errors.assert_is(DatamodelError::new_model_validation_error(
+        "The default value of a relation field must be of the type of the id field of the related model.",
+        "Model",
+        Span::new(46, 64),
     ));
 }
 
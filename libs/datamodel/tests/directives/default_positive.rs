@@ -29,7 +29,7 @@ fn should_set_default_for_all_scalar_types() {
     user_model
         .assert_has_field("decimal")
         .assert_base_type(&PrismaType::Decimal)
-        .assert_default_value(PrismaValue::Decimal(3.15));
+        .assert_default_value(PrismaValue::Decimal(String::from("3.15")));
     user_model
         .assert_has_field("string")
         .assert_base_type(&PrismaType::String)
@@ -68,3 +68,25 @@ fn should_set_default_an_enum_type() {
         .assert_enum_type("Role")
         .assert_default_value(PrismaValue::ConstantLiteral(String::from("A_VARIANT_WITH_UNDERSCORES")));
 }
+
+#[test]
+fn should_set_default_for_relation_fields() {
+    let dml = r#"
+    model Post {
+        id Int @id
+        user User @default(1) @relation(onDelete: SET_DEFAULT)
+    }
+
+    model User {
+        id Int @id
+        posts Post[]
+    }
+    "#;
+
+    let datamodel = parse(dml);
+    datamodel
+        .assert_has_model("Post")
+        .assert_has_field("user")
+        .assert_relation_to("User")
+        .assert_default_value(PrismaValue::Int(1));
+}
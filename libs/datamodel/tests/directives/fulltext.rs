@@ -0,0 +1,101 @@
+use datamodel::{ast::Span, error::*, render_datamodel_to_string, IndexDefinition, IndexType};
+
+use crate::common::*;
+
+#[test]
+fn basic_fulltext_index_must_work() {
+    let dml = r#"
+    model Post {
+        id    Int    @id
+        title String
+        body  String
+
+        @@fulltext([title,body])
+    }
+    "#;
+
+    let schema = parse(dml);
+    let post_model = schema.assert_has_model("Post");
+    post_model.assert_has_index(IndexDefinition {
+        name: None,
+        fields: vec!["title".to_string(), "body".to_string()],
+        tpe: IndexType::Fulltext,
+    });
+}
+
+#[test]
+fn the_name_argument_must_work() {
+    let dml = r#"
+    model Post {
+        id    Int    @id
+        title String
+        body  String
+
+        @@fulltext([title,body], name: "PostSearch")
+    }
+    "#;
+
+    let schema = parse(dml);
+    let post_model = schema.assert_has_model("Post");
+    post_model.assert_has_index(IndexDefinition {
+        name: Some("PostSearch".to_string()),
+        fields: vec!["title".to_string(), "body".to_string()],
+        tpe: IndexType::Fulltext,
+    });
+}
+
+#[test]
+fn must_error_when_unknown_fields_are_used() {
+    let dml = r#"
+    model Post {
+        id Int @id
+
+        @@fulltext([foo,bar])
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_model_validation_error(
+        "The fulltext index definition refers to the unknown fields foo, bar.",
+        "Post",
+        Span::new(48, 67),
+    ));
+}
+
+#[test]
+fn must_error_when_non_string_fields_are_used() {
+    let dml = r#"
+    model Post {
+        id    Int    @id
+        title String
+        likes Int
+
+        @@fulltext([title,likes])
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_model_validation_error(
+        "The fulltext index definition refers to the fields likes, which are not of type String.",
+        "Post",
+        Span::new(93, 116),
+    ));
+}
+
+#[test]
+fn fulltext_directives_must_serialize_to_valid_dml() {
+    let dml = r#"
+        model Post {
+            id    Int    @id
+            title String
+            body  String
+
+            @@fulltext([title,body], name: "customName")
+        }
+    "#;
+    let schema = parse(dml);
+
+    assert!(datamodel::parse_datamodel(&render_datamodel_to_string(&schema).unwrap()).is_ok());
+}
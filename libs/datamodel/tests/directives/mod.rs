@@ -1,6 +1,7 @@
 pub mod builtin_directives;
 pub mod default_negative;
 pub mod default_positive;
+pub mod fulltext;
 pub mod id_negative;
 pub mod id_positive;
 pub mod index;
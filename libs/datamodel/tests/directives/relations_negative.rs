@@ -179,3 +179,26 @@ fn should_fail_on_conflicting_generated_back_relation_fields() {
         Span::new(98, 152),
     ));
 }
+
+#[test]
+fn should_fail_on_unknown_referential_action() {
+    let dml = r#"
+    model User {
+        id Int @id
+        posts Post[]
+    }
+
+    model Post {
+        id Int @id
+        user User @relation(onDelete: DROP)
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_literal_parser_error(
+        "referential action",
+        "DROP",
+        Span::new(139, 143),
+    ));
+}
@@ -107,3 +107,42 @@ fn must_generate_back_relation_fields_for_named_relation_fields() {
         .assert_relation_name("AssignedTodos")
         .assert_relation_to("Todo");
 }
+
+#[test]
+fn must_parse_referential_actions() {
+    let dml = r#"
+    model User {
+        id Int @id
+        posts Post[]
+        comments Comment[]
+    }
+
+    model Post {
+        id Int @id
+        user User @relation(onDelete: CASCADE, onUpdate: CASCADE)
+    }
+
+    model Comment {
+        id Int @id
+        user User? @relation(onDelete: SET_NULL, onUpdate: RESTRICT)
+    }
+    "#;
+
+    let datamodel = parse(dml);
+
+    datamodel
+        .assert_has_model("Post")
+        .assert_has_field("user")
+        .assert_relation_delete_strategy(dml::ReferentialAction::Cascade)
+        .assert_relation_update_strategy(dml::ReferentialAction::Cascade);
+    datamodel
+        .assert_has_model("Comment")
+        .assert_has_field("user")
+        .assert_relation_delete_strategy(dml::ReferentialAction::SetNull)
+        .assert_relation_update_strategy(dml::ReferentialAction::Restrict);
+    datamodel
+        .assert_has_model("User")
+        .assert_has_field("posts")
+        .assert_relation_delete_strategy(dml::ReferentialAction::None)
+        .assert_relation_update_strategy(dml::ReferentialAction::None);
+}
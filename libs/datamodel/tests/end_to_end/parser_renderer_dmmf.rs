@@ -194,6 +194,7 @@ const DMFF_WITHOUT_RELATION_NAME: &str = r#"
           "type": "Post",
           "relationToFields": [],
           "relationOnDelete": "NONE",
+          "relationOnUpdate": "NONE",
           "isGenerated": false,
           "isUpdatedAt": false
         }
@@ -231,6 +232,7 @@ const DMFF_WITHOUT_RELATION_NAME: &str = r#"
             "id"
           ],
           "relationOnDelete": "NONE",
+          "relationOnUpdate": "NONE",
           "isGenerated": false,
           "isUpdatedAt": false
         }
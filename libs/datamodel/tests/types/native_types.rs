@@ -0,0 +1,136 @@
+use crate::common::*;
+use datamodel::{ast, common::PrismaType, dml, error::DatamodelError};
+
+fn assert_connector_type(field: &dml::Field, base_type: PrismaType, connector_type: &str) {
+    assert_eq!(
+        field.field_type,
+        dml::FieldType::ConnectorSpecific {
+            base_type,
+            connector_type: Some(String::from(connector_type)),
+        }
+    );
+}
+
+#[test]
+fn should_apply_native_types_of_the_source() {
+    let dml = r#"
+    datasource db {
+        provider = "postgresql"
+        url = "postgresql://localhost/postgres"
+    }
+
+    model Blog {
+        id        Int      @id @db.SmallInt
+        title     String   @db.VarChar(255)
+        code      String   @db.Char(3)
+        rating    Float    @db.Decimal(10, scale: 2)
+        createdAt DateTime @db.Timestamptz
+        content   String
+    }
+    "#;
+
+    let datamodel = parse(dml);
+    let blog = datamodel.assert_has_model("Blog");
+
+    assert_connector_type(blog.assert_has_field("id"), PrismaType::Int, "int2");
+    assert_connector_type(blog.assert_has_field("title"), PrismaType::String, "varchar(255)");
+    assert_connector_type(blog.assert_has_field("code"), PrismaType::String, "bpchar(3)");
+    assert_connector_type(blog.assert_has_field("rating"), PrismaType::Float, "numeric(10,2)");
+    assert_connector_type(blog.assert_has_field("createdAt"), PrismaType::DateTime, "timestamptz");
+    blog.assert_has_field("content").assert_base_type(&PrismaType::String);
+}
+
+#[test]
+fn should_keep_defaults_and_ids_on_fields_with_native_types() {
+    let dml = r#"
+    datasource db {
+        provider = "mysql"
+        url = "mysql://localhost/test"
+    }
+
+    model Blog {
+        id    String @id @db.VarChar(30) @default(cuid())
+        views Int    @db.BigInt @default(0)
+    }
+    "#;
+
+    let datamodel = parse(dml);
+    let blog = datamodel.assert_has_model("Blog");
+
+    let id = blog.assert_has_field("id");
+    id.assert_is_id(true);
+    assert_connector_type(id, PrismaType::String, "varchar(30)");
+
+    let views = blog.assert_has_field("views");
+    views.assert_default_value(dml::Value::Int(0));
+    assert_connector_type(views, PrismaType::Int, "bigint");
+}
+
+#[test]
+fn should_render_native_types_with_the_source() {
+    let dml = r#"
+    datasource db {
+        provider = "postgresql"
+        url = "postgresql://localhost/postgres"
+    }
+
+    model Blog {
+        id     Int    @id
+        title  String @db.VarChar(255)
+        rating Float  @db.Decimal(10, scale: 2)
+    }
+    "#;
+
+    let config = datamodel::parse_configuration(dml).unwrap();
+    let datamodel = parse(dml);
+    let rendered = datamodel::render_datamodel_and_config_to_string(&datamodel, &config).unwrap();
+
+    assert!(rendered.contains("@db.VarChar(255)"));
+    assert!(rendered.contains("@db.Decimal(10, scale: 2)"));
+    assert_eq!(parse(&rendered), datamodel);
+}
+
+#[test]
+fn should_fail_on_native_type_for_incompatible_field_type() {
+    let dml = r#"
+    datasource db {
+        provider = "postgresql"
+        url = "postgresql://localhost/postgres"
+    }
+
+    model Blog {
+        id    Int    @id
+        title String @db.Integer
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_directive_validation_error(
+        "The native type Integer can only be used on fields of type Int.",
+        "Integer",
+        ast::Span::new(172, 182),
+    ));
+}
+
+#[test]
+fn should_fail_on_native_types_of_other_connectors() {
+    let dml = r#"
+    datasource db {
+        provider = "sqlite"
+        url = "file:dev.db"
+    }
+
+    model Blog {
+        id    Int    @id
+        title String @db.VarChar(255)
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_directive_not_known_error(
+        "db.VarChar",
+        ast::Span::new(148, 158),
+    ));
+}
@@ -3,16 +3,31 @@ use slog_async::Async;
 use slog_json::Json;
 use slog_scope::{logger, GlobalLoggerGuard};
 use slog_term::{FullFormat, TermDecorator};
+use std::io::Write;
 
 pub struct Logger {
     _scope_guard: GlobalLoggerGuard,
     _log_guard: (),
 }
 
+/// Where JSON logs are written to.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LogOutput {
+    Stdout,
+
+    /// For applications that use stdout to talk to their clients.
+    Stderr,
+}
+
 impl Logger {
     /// Builds a new logger. Depending on `LOG_FORMAT` environment variable,
     /// either produces colorful text or JSON.
     pub fn build(application: &'static str) -> Logger {
+        Self::build_with_output(application, LogOutput::Stdout)
+    }
+
+    /// Builds a new logger like `build`, writing JSON logs to the given output.
+    pub fn build_with_output(application: &'static str, output: LogOutput) -> Logger {
         match std::env::var("RUST_LOG_FORMAT").as_ref().map(|s| s.as_str()) {
             Ok("devel") => {
                 let decorator = TermDecorator::new().build();
@@ -28,7 +43,12 @@ impl Logger {
                 }
             }
             _ => {
-                let drain = Json::new(std::io::stdout()).add_default_keys().build().fuse();
+                let writer: Box<dyn Write + Send> = match output {
+                    LogOutput::Stdout => Box::new(std::io::stdout()),
+                    LogOutput::Stderr => Box::new(std::io::stderr()),
+                };
+
+                let drain = Json::new(writer).add_default_keys().build().fuse();
                 let drain = slog_envlogger::new(drain);
                 let drain = Async::new(drain)
                     .chan_size(524288)
@@ -2,7 +2,6 @@
 use failure::Fail;
 use prisma_query::ast::ParameterizedValue;
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
 use std::fmt;
 
 pub mod mysql;
@@ -15,11 +14,25 @@ pub enum SqlSchemaDescriberError {
     /// An unknown error occurred.
     #[fail(display = "unknown")]
     UnknownError,
+    /// Querying the database for its description failed.
+    #[fail(display = "Querying the database description failed: {}", _0)]
+    QueryError(String),
+}
+
+impl From<prisma_query::error::Error> for SqlSchemaDescriberError {
+    fn from(error: prisma_query::error::Error) -> Self {
+        SqlSchemaDescriberError::QueryError(format!("{}", error))
+    }
 }
 
 /// The result type.
 pub type SqlSchemaDescriberResult<T> = core::result::Result<T, SqlSchemaDescriberError>;
 
+/// The error for a value missing in the rows returned when describing the database.
+fn missing_value(name: &str) -> SqlSchemaDescriberError {
+    SqlSchemaDescriberError::QueryError(format!("The value '{}' is missing in the description.", name))
+}
+
 /// Connection abstraction for the description backends.
 pub trait SqlConnection: Send + Sync + 'static {
     /// Make raw SQL query.
@@ -159,6 +172,8 @@ pub enum IndexType {
     Unique,
     /// Normal type.
     Normal,
+    /// Full-text search index.
+    Fulltext,
 }
 
 /// An index of a table.
@@ -262,6 +277,8 @@ pub enum ColumnTypeFamily {
     Binary,
     /// JSON types.
     Json,
+    /// Enum types, which are named types on Postgres and inline types on MySQL.
+    Enum,
     /// UUID types.
     Uuid,
     /// Geometric types.
@@ -286,6 +303,7 @@ impl fmt::Display for ColumnTypeFamily {
             Self::DateTime => "dateTime",
             Self::Binary => "binary",
             Self::Json => "json",
+            Self::Enum => "enum",
             Self::Uuid => "uuid",
             Self::Geometric => "geometric",
             Self::LogSequenceNumber => "logSequenceNumber",
@@ -345,6 +363,8 @@ pub struct ForeignKey {
     pub referenced_columns: Vec<String>,
     /// Action on deletion.
     pub on_delete_action: ForeignKeyAction,
+    /// Action on update of the referenced columns.
+    pub on_update_action: ForeignKeyAction,
 }
 
 /// A SQL enum.
@@ -353,8 +373,8 @@ pub struct ForeignKey {
 pub struct Enum {
     /// Enum name.
     pub name: String,
-    /// Possible enum values.
-    pub values: HashSet<String>,
+    /// Possible enum values, in their declared order.
+    pub values: Vec<String>,
 }
 
 /// A SQL sequence.
@@ -76,7 +76,7 @@ impl SqlSchemaDescriber {
         // information schema column names became upper-case in MySQL 8, causing the code fetching
         // the result values by column name below to fail.
         let sql = "
-            SELECT column_name column_name, data_type data_type, column_default column_default, is_nullable is_nullable, extra extra
+            SELECT column_name column_name, data_type data_type, column_type column_type, column_default column_default, is_nullable is_nullable, extra extra
             FROM information_schema.columns
             WHERE table_schema = ? AND table_name = ?
             ORDER BY column_name";
@@ -91,6 +91,10 @@ impl SqlSchemaDescriber {
                 debug!("Got column: {:?}", col);
 
                 let data_type = col.get("data_type").and_then(|x| x.to_string()).expect("get data_type");
+                let column_type = col
+                    .get("column_type")
+                    .and_then(|x| x.to_string())
+                    .expect("get column_type");
                 let is_nullable = col
                     .get("is_nullable")
                     .and_then(|x| x.to_string())
@@ -101,7 +105,7 @@ impl SqlSchemaDescriber {
                     "yes" => false,
                     x => panic!(format!("unrecognized is_nullable variant '{}'", x)),
                 };
-                let tpe = get_column_type(data_type.as_ref());
+                let tpe = get_column_type(data_type.as_ref(), column_type.as_ref());
                 let arity = if tpe.raw.starts_with("_") {
                     ColumnArity::List
                 } else if is_required {
@@ -151,7 +155,8 @@ impl SqlSchemaDescriber {
                 kcu.referenced_table_name referenced_table_name,
                 kcu.referenced_column_name referenced_column_name,
                 kcu.ordinal_position ordinal_position,
-                rc.delete_rule delete_rule
+                rc.delete_rule delete_rule,
+                rc.update_rule update_rule
             FROM information_schema.key_column_usage AS kcu
             INNER JOIN information_schema.referential_constraints AS rc ON
             kcu.constraint_name = rc.constraint_name
@@ -187,20 +192,16 @@ impl SqlSchemaDescriber {
                 .get("ordinal_position")
                 .and_then(|x| x.as_i64())
                 .expect("get ordinal_position");
-            let on_delete_action = match row
+            let delete_rule = row
                 .get("delete_rule")
                 .and_then(|x| x.to_string())
-                .expect("get delete_rule")
-                .to_lowercase()
-                .as_str()
-            {
-                "cascade" => ForeignKeyAction::Cascade,
-                "set null" => ForeignKeyAction::SetNull,
-                "set default" => ForeignKeyAction::SetDefault,
-                "restrict" => ForeignKeyAction::Restrict,
-                "no action" => ForeignKeyAction::NoAction,
-                s @ _ => panic!(format!("Unrecognized on delete action '{}'", s)),
-            };
+                .expect("get delete_rule");
+            let update_rule = row
+                .get("update_rule")
+                .and_then(|x| x.to_string())
+                .expect("get update_rule");
+            let on_delete_action = parse_foreign_key_action(&delete_rule);
+            let on_update_action = parse_foreign_key_action(&update_rule);
             match intermediate_fks.get_mut(&constraint_name) {
                 Some(fk) => {
                     let pos = ord_pos as usize - 1;
@@ -220,6 +221,7 @@ impl SqlSchemaDescriber {
                         referenced_table,
                         referenced_columns: vec![referenced_column],
                         on_delete_action,
+                        on_update_action,
                     };
                     intermediate_fks.insert(constraint_name, fk);
                 }
@@ -256,7 +258,8 @@ impl SqlSchemaDescriber {
                 index_name AS index_name,
                 non_unique AS non_unique,
                 column_name AS column_name,
-                seq_in_index AS seq_in_index
+                seq_in_index AS seq_in_index,
+                index_type AS index_type
             FROM INFORMATION_SCHEMA.STATISTICS
             WHERE table_schema = ? AND table_name = ?
             ORDER BY index_name, seq_in_index
@@ -280,6 +283,7 @@ impl SqlSchemaDescriber {
             let index_name = row.get("index_name").and_then(|x| x.to_string()).expect("index_name");
             let is_unique = !row.get("non_unique").and_then(|x| x.as_bool()).expect("non_unique");
             let column_name = row.get("column_name").and_then(|x| x.to_string()).expect("column_name");
+            let is_fulltext = row.get("index_type").and_then(|x| x.to_string()) == Some("FULLTEXT".to_string());
             let is_pk = index_name.to_lowercase() == "primary";
             if is_pk {
                 debug!("Column '{}' is part of the primary key", column_name);
@@ -313,9 +317,10 @@ impl SqlSchemaDescriber {
                         Index {
                             name: index_name,
                             columns: vec![column_name],
-                            tpe: match is_unique {
-                                true => IndexType::Unique,
-                                false => IndexType::Normal,
+                            tpe: match (is_unique, is_fulltext) {
+                                (_, true) => IndexType::Fulltext,
+                                (true, false) => IndexType::Unique,
+                                (false, false) => IndexType::Normal,
                             },
                         },
                     );
@@ -335,7 +340,7 @@ impl SqlSchemaDescriber {
     }
 }
 
-fn get_column_type(data_type: &str) -> ColumnType {
+fn get_column_type(data_type: &str, column_type: &str) -> ColumnType {
     let family = match data_type {
         "int" => ColumnTypeFamily::Int,
         "smallint" => ColumnTypeFamily::Int,
@@ -357,8 +362,7 @@ fn get_column_type(data_type: &str) -> ColumnType {
         "tinytext" => ColumnTypeFamily::String,
         "mediumtext" => ColumnTypeFamily::String,
         "longtext" => ColumnTypeFamily::String,
-        // XXX: Is this correct?
-        "enum" => ColumnTypeFamily::String,
+        "enum" => ColumnTypeFamily::Enum,
         "set" => ColumnTypeFamily::String,
         "binary" => ColumnTypeFamily::Binary,
         "varbinary" => ColumnTypeFamily::Binary,
@@ -377,8 +381,25 @@ fn get_column_type(data_type: &str) -> ColumnType {
         "json" => ColumnTypeFamily::Json,
         x => panic!(format!("type '{}' is not supported here yet.", x)),
     };
+    // The full column type includes the declared length or precision of the column,
+    // or the values of an inline enum.
+    let raw = match data_type {
+        "char" | "varchar" | "decimal" | "numeric" | "enum" => column_type,
+        _ => data_type,
+    };
     ColumnType {
-        raw: data_type.to_string(),
+        raw: raw.to_string(),
         family: family,
     }
 }
+
+fn parse_foreign_key_action(action: &str) -> ForeignKeyAction {
+    match action.to_lowercase().as_str() {
+        "cascade" => ForeignKeyAction::Cascade,
+        "set null" => ForeignKeyAction::SetNull,
+        "set default" => ForeignKeyAction::SetDefault,
+        "restrict" => ForeignKeyAction::Restrict,
+        "no action" => ForeignKeyAction::NoAction,
+        s @ _ => panic!(format!("Unrecognized foreign key action '{}'", s)),
+    }
+}
@@ -2,7 +2,7 @@
 use super::*;
 use log::debug;
 use regex::Regex;
-use std::collections::{HashMap, HashSet};
+use std::collections::HashMap;
 use std::sync::Arc;
 
 pub struct SqlSchemaDescriber {
@@ -17,12 +17,22 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
     fn describe(&self, schema: &str) -> SqlSchemaDescriberResult<SqlSchema> {
         debug!("describing schema '{}'", schema);
         let sequences = self.get_sequences(schema)?;
+        let enums = self.get_enums(schema)?;
+        let fulltext_indices = self.get_fulltext_indices(schema)?;
         let tables = self
             .get_table_names(schema)
             .into_iter()
-            .map(|t| self.get_table(schema, &t, &sequences))
+            .map(|t| {
+                let mut table = self.get_table(schema, &t, &sequences, &enums);
+                table.indices.extend(
+                    fulltext_indices
+                        .iter()
+                        .filter(|(table_name, _)| table_name == &t)
+                        .map(|(_, index)| index.clone()),
+                );
+                table
+            })
             .collect();
-        let enums = self.get_enums(schema)?;
         Ok(SqlSchema {
             enums,
             sequences,
@@ -61,9 +71,9 @@ impl SqlSchemaDescriber {
         names
     }
 
-    fn get_table(&self, schema: &str, name: &str, sequences: &Vec<Sequence>) -> Table {
+    fn get_table(&self, schema: &str, name: &str, sequences: &Vec<Sequence>, enums: &Vec<Enum>) -> Table {
         debug!("Getting table '{}'", name);
-        let columns = self.get_columns(schema, name);
+        let columns = self.get_columns(schema, name, enums);
         let (indices, primary_key) = self.get_indices(schema, name, sequences);
         let foreign_keys = self.get_foreign_keys(schema, name);
         Table {
@@ -75,8 +85,9 @@ impl SqlSchemaDescriber {
         }
     }
 
-    fn get_columns(&self, schema: &str, table: &str) -> Vec<Column> {
-        let sql = "SELECT column_name, udt_name, column_default, is_nullable, is_identity, data_type
+    fn get_columns(&self, schema: &str, table: &str, enums: &Vec<Enum>) -> Vec<Column> {
+        let sql = "SELECT column_name, udt_name, column_default, is_nullable, is_identity, data_type,
+                character_maximum_length::integer, numeric_precision::integer, numeric_scale::integer
             FROM information_schema.columns
             WHERE table_schema = $1 AND table_name = $2
             ORDER BY column_name";
@@ -93,6 +104,7 @@ impl SqlSchemaDescriber {
                     .and_then(|x| x.to_string())
                     .expect("get column name");
                 let udt = col.get("udt_name").and_then(|x| x.to_string()).expect("get udt_name");
+                let is_list = col.get("data_type").and_then(|x| x.to_string()).expect("get data_type") == "ARRAY";
                 let is_identity_str = col
                     .get("is_identity")
                     .and_then(|x| x.to_string())
@@ -113,8 +125,22 @@ impl SqlSchemaDescriber {
                     "yes" => false,
                     x => panic!(format!("unrecognized is_nullable variant '{}'", x)),
                 };
-                let tpe = get_column_type(udt.as_ref());
-                let arity = if tpe.raw.starts_with("_") {
+                let arguments = column_type_arguments(udt.as_ref())
+                    .iter()
+                    .filter_map(|name| col.get(name).and_then(|x| x.as_i64()))
+                    .collect();
+                // Columns of an enum type have the name of the enum as their type, the element type of
+                // an array is named with a leading underscore.
+                let element_udt = if is_list { &udt[1..] } else { udt.as_str() };
+                let tpe = if enums.iter().any(|e| e.name == element_udt) {
+                    ColumnType {
+                        raw: element_udt.to_string(),
+                        family: ColumnTypeFamily::Enum,
+                    }
+                } else {
+                    get_column_type(udt.as_ref(), arguments)
+                };
+                let arity = if is_list {
                     ColumnArity::List
                 } else if is_required {
                     ColumnArity::Required
@@ -150,6 +176,7 @@ impl SqlSchemaDescriber {
                 cl.relname as \"parent_table\", 
                 att.attname as \"parent_column\",
                 con.confdeltype,
+                con.confupdtype,
                 conname as constraint_name
             FROM
             (SELECT 
@@ -159,7 +186,8 @@ impl SqlSchemaDescriber {
                     con1.confrelid, 
                     con1.conrelid,
                     con1.conname,
-                    con1.confdeltype
+                    con1.confdeltype,
+                    con1.confupdtype
                 FROM
                     pg_class cl
                     join pg_namespace ns on cl.relnamespace = ns.oid
@@ -205,18 +233,16 @@ impl SqlSchemaDescriber {
                 .get("confdeltype")
                 .and_then(|x| x.as_char())
                 .expect("get confdeltype");
+            let confupdtype = row
+                .get("confupdtype")
+                .and_then(|x| x.as_char())
+                .expect("get confupdtype");
             let constraint_name = row
                 .get("constraint_name")
                 .and_then(|x| x.to_string())
                 .expect("get constraint_name");
-            let on_delete_action = match confdeltype {
-                'a' => ForeignKeyAction::NoAction,
-                'r' => ForeignKeyAction::Restrict,
-                'c' => ForeignKeyAction::Cascade,
-                'n' => ForeignKeyAction::SetNull,
-                'd' => ForeignKeyAction::SetDefault,
-                _ => panic!(format!("unrecognized foreign key action '{}'", confdeltype)),
-            };
+            let on_delete_action = parse_foreign_key_action(confdeltype);
+            let on_update_action = parse_foreign_key_action(confupdtype);
             match intermediate_fks.get_mut(&id) {
                 Some(fk) => {
                     fk.columns.push(column);
@@ -229,6 +255,7 @@ impl SqlSchemaDescriber {
                         referenced_table,
                         referenced_columns: vec![referenced_column],
                         on_delete_action,
+                        on_update_action,
                     };
                     intermediate_fks.insert(id, fk);
                 }
@@ -325,6 +352,64 @@ impl SqlSchemaDescriber {
         (indices, pk)
     }
 
+    /// Full-text indices are GIN indices on a `to_tsvector` expression, which the regular index query
+    /// doesn't return, since they don't reference columns directly. The columns are recovered from the
+    /// index expression, in the order they appear in it. Returns the indices together with the name of
+    /// the table they index.
+    fn get_fulltext_indices(&self, schema: &str) -> SqlSchemaDescriberResult<Vec<(String, Index)>> {
+        let sql = "SELECT tableInfos.relname as table_name, indexInfos.relname as name,
+            array_agg(columnInfos.attname ORDER BY
+                strpos(pg_get_expr(rawIndex.indexprs, rawIndex.indrelid), columnInfos.attname)) as column_names
+            FROM
+            pg_class tableInfos, pg_class indexInfos, pg_index rawIndex, pg_attribute columnInfos,
+            pg_namespace schemaInfo,
+            -- pg_am stores the index access methods: https://www.postgresql.org/docs/current/catalog-pg-am.html
+            pg_am accessMethod
+            WHERE
+            tableInfos.oid = rawIndex.indrelid
+            AND indexInfos.oid = rawIndex.indexrelid
+            AND accessMethod.oid = indexInfos.relam
+            AND accessMethod.amname = 'gin'
+            AND pg_get_expr(rawIndex.indexprs, rawIndex.indrelid) LIKE 'to_tsvector(%'
+            -- find the columns referenced by the index expression
+            AND columnInfos.attrelid = tableInfos.oid
+            AND columnInfos.attnum > 0
+            AND pg_get_expr(rawIndex.indexprs, rawIndex.indrelid) ~ ('\\m' || columnInfos.attname || '\\M')
+            AND tableInfos.relkind = 'r'
+            AND tableInfos.relnamespace = schemaInfo.oid
+            AND schemaInfo.nspname = $1
+            GROUP BY tableInfos.relname, indexInfos.relname";
+        debug!("Getting fulltext indices: {}", sql);
+        let rows = self.conn.query_raw(&sql, schema, &[schema.into()])?;
+        let indices = rows
+            .into_iter()
+            .map(|index| {
+                let get_name = |column: &str| {
+                    index
+                        .get(column)
+                        .and_then(|x| x.to_string())
+                        .ok_or_else(|| missing_value(column))
+                };
+                let columns = index
+                    .get("column_names")
+                    .and_then(|x| x.clone().into_vec::<String>())
+                    .ok_or_else(|| missing_value("column_names"))?;
+
+                Ok((
+                    get_name("table_name")?,
+                    Index {
+                        name: get_name("name")?,
+                        columns,
+                        tpe: IndexType::Fulltext,
+                    },
+                ))
+            })
+            .collect::<SqlSchemaDescriberResult<Vec<(String, Index)>>>()?;
+
+        debug!("Found fulltext indices: {:?}", indices);
+        Ok(indices)
+    }
+
     fn infer_primary_key(
         &self,
         schema: &str,
@@ -408,21 +493,22 @@ impl SqlSchemaDescriber {
             FROM pg_type t 
             JOIN pg_enum e ON t.oid = e.enumtypid  
             JOIN pg_catalog.pg_namespace n ON n.oid = t.typnamespace
-            WHERE n.nspname = $1";
+            WHERE n.nspname = $1
+            ORDER BY e.enumsortorder";
         let rows = self
             .conn
             .query_raw(&sql, schema, &[schema.into()])
             .expect("querying for enums");
-        let mut enum_values: HashMap<String, HashSet<String>> = HashMap::new();
+        let mut enum_values: HashMap<String, Vec<String>> = HashMap::new();
         for row in rows.into_iter() {
             debug!("Got enum row: {:?}", row);
             let name = row.get("name").and_then(|x| x.to_string()).expect("get name");
             let value = row.get("value").and_then(|x| x.to_string()).expect("get value");
             if !enum_values.contains_key(&name) {
-                enum_values.insert(name.clone(), HashSet::new());
+                enum_values.insert(name.clone(), Vec::new());
             }
             let vals = enum_values.get_mut(&name).expect("get enum values");
-            vals.insert(value);
+            vals.push(value);
         }
 
         let enums: Vec<Enum> = enum_values
@@ -434,7 +520,16 @@ impl SqlSchemaDescriber {
     }
 }
 
-fn get_column_type(udt: &str) -> ColumnType {
+/// The columns holding the declared length or precision of a column of the given type.
+fn column_type_arguments(udt: &str) -> &'static [&'static str] {
+    match udt {
+        "varchar" | "bpchar" => &["character_maximum_length"],
+        "numeric" => &["numeric_precision", "numeric_scale"],
+        _ => &[],
+    }
+}
+
+fn get_column_type(udt: &str, arguments: Vec<i64>) -> ColumnType {
     let family = match udt {
         "int2" => ColumnTypeFamily::Int,
         "int4" => ColumnTypeFamily::Int,
@@ -475,12 +570,30 @@ fn get_column_type(udt: &str) -> ColumnType {
         "_float8" => ColumnTypeFamily::Float,
         "_float4" => ColumnTypeFamily::Float,
         "_int4" => ColumnTypeFamily::Int,
+        "_int8" => ColumnTypeFamily::Int,
+        "_numeric" => ColumnTypeFamily::Float,
+        "_timestamp" => ColumnTypeFamily::DateTime,
+        "_jsonb" => ColumnTypeFamily::Json,
         "_text" => ColumnTypeFamily::String,
         "_varchar" => ColumnTypeFamily::String,
         x => panic!(format!("type '{}' is not supported here yet.", x)),
     };
-    ColumnType {
-        raw: udt.to_string(),
-        family: family,
+    let raw = if arguments.is_empty() {
+        udt.to_string()
+    } else {
+        let arguments: Vec<String> = arguments.iter().map(|arg| arg.to_string()).collect();
+        format!("{}({})", udt, arguments.join(","))
+    };
+    ColumnType { raw, family }
+}
+
+fn parse_foreign_key_action(action: char) -> ForeignKeyAction {
+    match action {
+        'a' => ForeignKeyAction::NoAction,
+        'r' => ForeignKeyAction::Restrict,
+        'c' => ForeignKeyAction::Cascade,
+        'n' => ForeignKeyAction::SetNull,
+        'd' => ForeignKeyAction::SetDefault,
+        _ => panic!(format!("unrecognized foreign key action '{}'", action)),
     }
 }
@@ -17,11 +17,26 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
 
     fn describe(&self, schema: &str) -> SqlSchemaDescriberResult<SqlSchema> {
         debug!("describing schema '{}'", schema);
+        let fulltext_indices = self.get_fulltext_indices(schema)?;
         let tables = self
             .get_table_names(schema)
             .into_iter()
             .filter(|table| !is_system_table(&table))
-            .map(|t| self.get_table(schema, &t))
+            .filter(|table| {
+                !fulltext_indices
+                    .iter()
+                    .any(|(_, index)| is_fulltext_table(&table, &index.name))
+            })
+            .map(|t| {
+                let mut table = self.get_table(schema, &t);
+                table.indices.extend(
+                    fulltext_indices
+                        .iter()
+                        .filter(|(content_table, _)| content_table == &t)
+                        .map(|(_, index)| index.clone()),
+                );
+                table
+            })
             .collect();
         Ok(SqlSchema {
             // There's no enum type in SQLite.
@@ -160,6 +175,7 @@ impl SqlSchemaDescriber {
             pub referenced_table: String,
             pub referenced_columns: HashMap<i64, String>,
             pub on_delete_action: ForeignKeyAction,
+            pub on_update_action: ForeignKeyAction,
         }
 
         let sql = format!(r#"PRAGMA "{}".foreign_key_list("{}");"#, schema, table);
@@ -190,25 +206,16 @@ impl SqlSchemaDescriber {
                     columns.insert(seq, column);
                     let mut referenced_columns: HashMap<i64, String> = HashMap::new();
                     referenced_columns.insert(seq, referenced_column);
-                    let on_delete_action = match row
-                        .get("on_delete")
-                        .and_then(|x| x.to_string())
-                        .expect("on_delete")
-                        .to_lowercase()
-                        .as_str()
-                    {
-                        "no action" => ForeignKeyAction::NoAction,
-                        "restrict" => ForeignKeyAction::Restrict,
-                        "set null" => ForeignKeyAction::SetNull,
-                        "set default" => ForeignKeyAction::SetDefault,
-                        "cascade" => ForeignKeyAction::Cascade,
-                        s @ _ => panic!(format!("Unrecognized on delete action '{}'", s)),
-                    };
+                    let on_delete_action =
+                        parse_foreign_key_action(&row.get("on_delete").and_then(|x| x.to_string()).expect("on_delete"));
+                    let on_update_action =
+                        parse_foreign_key_action(&row.get("on_update").and_then(|x| x.to_string()).expect("on_update"));
                     let fk = IntermediateForeignKey {
                         columns,
                         referenced_table,
                         referenced_columns,
                         on_delete_action,
+                        on_update_action,
                     };
                     intermediate_fks.insert(id, fk);
                 }
@@ -240,6 +247,7 @@ impl SqlSchemaDescriber {
                     referenced_table: intermediate_fk.referenced_table.to_owned(),
                     referenced_columns,
                     on_delete_action: intermediate_fk.on_delete_action.to_owned(),
+                    on_update_action: intermediate_fk.on_update_action.to_owned(),
 
                     // Not relevant in SQLite since we cannot ALTER or DROP foreign keys by
                     // constraint name.
@@ -293,6 +301,78 @@ impl SqlSchemaDescriber {
             })
             .collect()
     }
+
+    /// Full-text indices are FTS5 virtual tables using the indexed table as external content.
+    /// Returns the indices together with the name of the table they index.
+    fn get_fulltext_indices(&self, schema: &str) -> SqlSchemaDescriberResult<Vec<(String, Index)>> {
+        let sql = format!(
+            r#"SELECT name, sql FROM "{}".sqlite_master
+            WHERE type='table' AND sql LIKE 'CREATE VIRTUAL TABLE%USING fts5%'"#,
+            schema
+        );
+        debug!("describing fulltext indices, SQL: '{}'", sql);
+        let result_set = self.conn.query_raw(&sql, schema, &[])?;
+        debug!("Got fulltext indices description results: {:?}", result_set);
+
+        let mut indices = Vec::new();
+
+        for row in result_set.into_iter() {
+            let name = row
+                .get("name")
+                .and_then(|x| x.to_string())
+                .ok_or_else(|| missing_value("name"))?;
+            let create_sql = row
+                .get("sql")
+                .and_then(|x| x.to_string())
+                .ok_or_else(|| missing_value("sql"))?;
+
+            // Virtual tables without external content aren't created by us.
+            let content_table = match fulltext_content_table(&create_sql) {
+                Some(content_table) => content_table,
+                None => continue,
+            };
+
+            let sql = format!(r#"PRAGMA "{}".table_info ("{}")"#, schema, name);
+            debug!("describing fulltext index '{}', SQL: '{}'", name, sql);
+            let columns = self
+                .conn
+                .query_raw(&sql, schema, &[])?
+                .into_iter()
+                .map(|row| {
+                    row.get("name")
+                        .and_then(|x| x.to_string())
+                        .ok_or_else(|| missing_value("name"))
+                })
+                .collect::<SqlSchemaDescriberResult<Vec<String>>>()?;
+
+            indices.push((
+                content_table,
+                Index {
+                    name,
+                    columns,
+                    tpe: IndexType::Fulltext,
+                },
+            ));
+        }
+
+        Ok(indices)
+    }
+}
+
+/// The external content table of an FTS5 table, from the statement creating it.
+fn fulltext_content_table(create_sql: &str) -> Option<String> {
+    let content_start = create_sql.find("content='")? + "content='".len();
+    let content_len = create_sql[content_start..].find('\'')?;
+
+    Some(create_sql[content_start..content_start + content_len].to_string())
+}
+
+/// Whether the table is the FTS5 table of the given full-text index or one of its shadow tables.
+fn is_fulltext_table(table_name: &str, index_name: &str) -> bool {
+    table_name == index_name
+        || ["data", "idx", "content", "docsize", "config"]
+            .iter()
+            .any(|suffix| table_name == format!("{}_{}", index_name, suffix))
 }
 
 fn get_column_type(tpe: &str) -> ColumnType {
@@ -311,6 +391,7 @@ fn get_column_type(tpe: &str) -> ColumnType {
         "date" => ColumnTypeFamily::DateTime,
         "datetime" => ColumnTypeFamily::DateTime,
         "binary" => ColumnTypeFamily::Binary,
+        "blob" => ColumnTypeFamily::Binary,
         "double" => ColumnTypeFamily::Float,
         "binary[]" => ColumnTypeFamily::Binary,
         "boolean[]" => ColumnTypeFamily::Boolean,
@@ -343,3 +424,14 @@ const SQLITE_SYSTEM_TABLES: &[&str] = &[
     "sqlite_stat3",
     "sqlite_stat4",
 ];
+
+fn parse_foreign_key_action(action: &str) -> ForeignKeyAction {
+    match action.to_lowercase().as_str() {
+        "no action" => ForeignKeyAction::NoAction,
+        "restrict" => ForeignKeyAction::Restrict,
+        "set null" => ForeignKeyAction::SetNull,
+        "set default" => ForeignKeyAction::SetDefault,
+        "cascade" => ForeignKeyAction::Cascade,
+        s @ _ => panic!(format!("Unrecognized foreign key action '{}'", s)),
+    }
+}
@@ -29,8 +29,8 @@ fn int_type(db_type: DbType) -> String {
 
 fn varchar_type(db_type: DbType, length: u64) -> String {
     match db_type {
-        DbType::Postgres => "varchar".to_string(),
-        DbType::MySql => "varchar".to_string(),
+        DbType::Postgres => format!("varchar({})", length),
+        DbType::MySql => format!("varchar({})", length),
         DbType::Sqlite => format!("VARCHAR({})", length),
     }
 }
@@ -110,7 +110,7 @@ fn foreign_keys_must_work() {
                 auto_increment: false,
             }];
 
-            let on_delete_action = match db_type {
+            let default_action = match db_type {
                 DbType::MySql => ForeignKeyAction::Restrict,
                 _ => ForeignKeyAction::NoAction,
             };
@@ -130,7 +130,8 @@ fn foreign_keys_must_work() {
                         columns: vec!["city".to_string()],
                         referenced_columns: vec!["id".to_string()],
                         referenced_table: "City".to_string(),
-                        on_delete_action,
+                        on_delete_action: default_action.clone(),
+                        on_update_action: default_action,
                     }],
                 }
             );
@@ -194,7 +195,7 @@ fn multi_column_foreign_keys_must_work() {
                 },
             ];
 
-            let on_delete_action = match db_type {
+            let default_action = match db_type {
                 DbType::MySql => ForeignKeyAction::Restrict,
                 _ => ForeignKeyAction::NoAction,
             };
@@ -215,7 +216,8 @@ fn multi_column_foreign_keys_must_work() {
                         columns: vec!["city".to_string(), "city_name".to_string()],
                         referenced_columns: vec!["id".to_string(), "name".to_string()],
                         referenced_table: "City".to_string(),
-                        on_delete_action,
+                        on_delete_action: default_action.clone(),
+                        on_update_action: default_action,
                     },],
                 }
             );
@@ -282,8 +284,8 @@ fn composite_primary_keys_must_work() {
             let table = schema.get_table("User").expect("couldn't get User table");
             let (exp_int, exp_varchar) = match db_type {
                 DbType::Sqlite => ("INTEGER", "VARCHAR(255)"),
-                DbType::MySql => ("int", "varchar"),
-                DbType::Postgres => ("int4", "varchar"),
+                DbType::MySql => ("int", "varchar(255)"),
+                DbType::Postgres => ("int4", "varchar(255)"),
             };
             let mut expected_columns = vec![
                 Column {
@@ -124,7 +124,7 @@ fn all_mysql_column_types_must_work() {
         Column {
             name: "decimal_col".to_string(),
             tpe: ColumnType {
-                raw: "decimal".to_string(),
+                raw: "decimal(10,0)".to_string(),
                 family: ColumnTypeFamily::Float,
             },
             arity: ColumnArity::Required,
@@ -134,7 +134,7 @@ fn all_mysql_column_types_must_work() {
         Column {
             name: "numeric_col".to_string(),
             tpe: ColumnType {
-                raw: "decimal".to_string(),
+                raw: "decimal(10,0)".to_string(),
                 family: ColumnTypeFamily::Float,
             },
             arity: ColumnArity::Required,
@@ -214,7 +214,7 @@ fn all_mysql_column_types_must_work() {
         Column {
             name: "char_col".to_string(),
             tpe: ColumnType {
-                raw: "char".to_string(),
+                raw: "char(1)".to_string(),
                 family: ColumnTypeFamily::String,
             },
             arity: ColumnArity::Required,
@@ -224,7 +224,7 @@ fn all_mysql_column_types_must_work() {
         Column {
             name: "varchar_col".to_string(),
             tpe: ColumnType {
-                raw: "varchar".to_string(),
+                raw: "varchar(255)".to_string(),
                 family: ColumnTypeFamily::String,
             },
             arity: ColumnArity::Required,
@@ -274,8 +274,8 @@ fn all_mysql_column_types_must_work() {
         Column {
             name: "enum_col".to_string(),
             tpe: ColumnType {
-                raw: "enum".to_string(),
-                family: ColumnTypeFamily::String,
+                raw: "enum('a','b')".to_string(),
+                family: ColumnTypeFamily::Enum,
             },
             arity: ColumnArity::Required,
             default: None,
@@ -550,6 +550,7 @@ fn mysql_foreign_key_on_delete_must_be_handled() {
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
                     on_delete_action: ForeignKeyAction::NoAction,
+                    on_update_action: ForeignKeyAction::Restrict,
                 },
                 ForeignKey {
                     constraint_name: Some("User_ibfk_2".to_owned()),
@@ -557,6 +558,7 @@ fn mysql_foreign_key_on_delete_must_be_handled() {
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
                     on_delete_action: ForeignKeyAction::Cascade,
+                    on_update_action: ForeignKeyAction::Restrict,
                 },
                 ForeignKey {
                     constraint_name: Some("User_ibfk_3".to_owned()),
@@ -564,6 +566,7 @@ fn mysql_foreign_key_on_delete_must_be_handled() {
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
                     on_delete_action: ForeignKeyAction::Restrict,
+                    on_update_action: ForeignKeyAction::Restrict,
                 },
                 ForeignKey {
                     constraint_name: Some("User_ibfk_4".to_owned()),
@@ -571,6 +574,7 @@ fn mysql_foreign_key_on_delete_must_be_handled() {
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
                     on_delete_action: ForeignKeyAction::SetNull,
+                    on_update_action: ForeignKeyAction::Restrict,
                 },
             ],
         }
@@ -1,7 +1,6 @@
 use barrel::{types, Migration};
 use pretty_assertions::assert_eq;
 use sql_schema_describer::*;
-use std::collections::HashSet;
 
 mod common;
 mod postgres;
@@ -235,7 +234,7 @@ fn all_postgres_column_types_must_work() {
         Column {
             name: "string2_col".into(),
             tpe: ColumnType {
-                raw: "varchar".into(),
+                raw: "varchar(1)".into(),
                 family: ColumnTypeFamily::String,
             },
             arity: ColumnArity::Required,
@@ -298,7 +297,7 @@ fn all_postgres_column_types_must_work() {
         Column {
             name: "char_col".into(),
             tpe: ColumnType {
-                raw: "bpchar".into(),
+                raw: "bpchar(1)".into(),
                 family: ColumnTypeFamily::String,
             },
             arity: ColumnArity::Required,
@@ -645,6 +644,7 @@ fn postgres_foreign_key_on_delete_must_be_handled() {
                     referenced_columns: vec!["id".into()],
                     referenced_table: "City".into(),
                     on_delete_action: ForeignKeyAction::NoAction,
+                    on_update_action: ForeignKeyAction::NoAction,
                 },
                 ForeignKey {
                     constraint_name: Some("User_city_cascade_fkey".to_owned()),
@@ -652,6 +652,7 @@ fn postgres_foreign_key_on_delete_must_be_handled() {
                     referenced_columns: vec!["id".into()],
                     referenced_table: "City".into(),
                     on_delete_action: ForeignKeyAction::Cascade,
+                    on_update_action: ForeignKeyAction::NoAction,
                 },
                 ForeignKey {
                     constraint_name: Some("User_city_restrict_fkey".to_owned()),
@@ -659,6 +660,7 @@ fn postgres_foreign_key_on_delete_must_be_handled() {
                     referenced_columns: vec!["id".into()],
                     referenced_table: "City".into(),
                     on_delete_action: ForeignKeyAction::Restrict,
+                    on_update_action: ForeignKeyAction::NoAction,
                 },
                 ForeignKey {
                     constraint_name: Some("User_city_set_default_fkey".to_owned()),
@@ -666,6 +668,7 @@ fn postgres_foreign_key_on_delete_must_be_handled() {
                     referenced_columns: vec!["id".into()],
                     referenced_table: "City".into(),
                     on_delete_action: ForeignKeyAction::SetDefault,
+                    on_update_action: ForeignKeyAction::NoAction,
                 },
                 ForeignKey {
                     constraint_name: Some("User_city_set_null_fkey".to_owned()),
@@ -673,6 +676,7 @@ fn postgres_foreign_key_on_delete_must_be_handled() {
                     referenced_columns: vec!["id".into()],
                     referenced_table: "City".into(),
                     on_delete_action: ForeignKeyAction::SetNull,
+                    on_update_action: ForeignKeyAction::NoAction,
                 },
             ],
         }
@@ -691,7 +695,7 @@ fn postgres_enums_must_work() {
     let schema = inspector.describe(SCHEMA).expect("describing");
     let got_enum = schema.get_enum("mood").expect("get enum");
 
-    let values: HashSet<String> = ["happy".into(), "ok".into(), "sad".into()].iter().cloned().collect();
+    let values: Vec<String> = vec!["sad".into(), "ok".into(), "happy".into()];
     assert_eq!(
         got_enum,
         &Enum {
@@ -701,6 +705,51 @@ fn postgres_enums_must_work() {
     );
 }
 
+#[test]
+fn postgres_enum_columns_must_work() {
+    setup();
+
+    let inspector = get_postgres_describer(&format!(
+        "CREATE TYPE \"{0}\".\"mood\" AS ENUM ('sad', 'ok', 'happy');
+        CREATE TABLE \"{0}\".\"User\" (\"mood\" \"{0}\".\"mood\" NOT NULL);",
+        SCHEMA
+    ));
+
+    let schema = inspector.describe(SCHEMA).expect("describing");
+    let column = schema.table_bang("User").column("mood").expect("get mood column");
+
+    assert_eq!(
+        column.tpe,
+        ColumnType {
+            raw: "mood".into(),
+            family: ColumnTypeFamily::Enum,
+        }
+    );
+}
+
+#[test]
+fn postgres_enum_list_columns_must_work() {
+    setup();
+
+    let inspector = get_postgres_describer(&format!(
+        "CREATE TYPE \"{0}\".\"mood\" AS ENUM ('sad', 'ok', 'happy');
+        CREATE TABLE \"{0}\".\"User\" (\"moods\" \"{0}\".\"mood\"[]);",
+        SCHEMA
+    ));
+
+    let schema = inspector.describe(SCHEMA).expect("describing");
+    let column = schema.table_bang("User").column("moods").expect("get moods column");
+
+    assert_eq!(
+        column.tpe,
+        ColumnType {
+            raw: "mood".into(),
+            family: ColumnTypeFamily::Enum,
+        }
+    );
+    assert_eq!(column.arity, ColumnArity::List);
+}
+
 #[test]
 fn postgres_sequences_must_work() {
     setup();
@@ -6,7 +6,6 @@ use log::{debug, LevelFilter};
 use pretty_assertions::assert_eq;
 use prisma_query::connector::{Queryable, Sqlite as SqliteDatabaseClient};
 use sql_schema_describer::*;
-use std::collections::HashSet;
 use std::fs::File;
 use std::path::Path;
 use std::sync::atomic::{AtomicBool, Ordering};
@@ -51,9 +50,6 @@ fn setup() {
 fn database_schema_is_serializable() {
     setup();
 
-    let mut enum_values = HashSet::new();
-    enum_values.insert("option1".to_string());
-    enum_values.insert("option2".to_string());
     let schema = SqlSchema {
         tables: vec![
             Table {
@@ -105,6 +101,7 @@ fn database_schema_is_serializable() {
                     referenced_table: "table2".to_string(),
                     referenced_columns: vec!["id".to_string()],
                     on_delete_action: ForeignKeyAction::NoAction,
+                    on_update_action: ForeignKeyAction::NoAction,
                 }],
             },
             Table {
@@ -129,7 +126,7 @@ fn database_schema_is_serializable() {
         ],
         enums: vec![Enum {
             name: "enum1".to_string(),
-            values: enum_values,
+            values: vec!["option1".to_string(), "option2".to_string()],
         }],
         sequences: vec![Sequence {
             name: "sequence1".to_string(),
@@ -351,6 +348,7 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     referenced_table: "table2".to_string(),
                     referenced_columns: vec!["id".to_string()],
                     on_delete_action: ForeignKeyAction::NoAction,
+                    on_update_action: ForeignKeyAction::NoAction,
                 },
                 ForeignKey {
                     constraint_name: None,
@@ -358,6 +356,7 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     referenced_table: "table2".to_string(),
                     referenced_columns: vec!["id".to_string()],
                     on_delete_action: ForeignKeyAction::Restrict,
+                    on_update_action: ForeignKeyAction::NoAction,
                 },
                 ForeignKey {
                     constraint_name: None,
@@ -365,6 +364,7 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     referenced_table: "table2".to_string(),
                     referenced_columns: vec!["id".to_string()],
                     on_delete_action: ForeignKeyAction::Cascade,
+                    on_update_action: ForeignKeyAction::NoAction,
                 },
                 ForeignKey {
                     constraint_name: None,
@@ -372,6 +372,7 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     referenced_table: "table2".to_string(),
                     referenced_columns: vec!["id".to_string()],
                     on_delete_action: ForeignKeyAction::SetNull,
+                    on_update_action: ForeignKeyAction::NoAction,
                 },
                 ForeignKey {
                     constraint_name: None,
@@ -379,6 +380,7 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     referenced_table: "table2".to_string(),
                     referenced_columns: vec!["id".to_string()],
                     on_delete_action: ForeignKeyAction::SetDefault,
+                    on_update_action: ForeignKeyAction::NoAction,
                 },
             ],
         }],
@@ -183,6 +183,7 @@ fn sqlite_foreign_key_on_delete_must_be_handled() {
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
                     on_delete_action: ForeignKeyAction::NoAction,
+                    on_update_action: ForeignKeyAction::NoAction,
                 },
                 ForeignKey {
                     constraint_name: None,
@@ -190,6 +191,7 @@ fn sqlite_foreign_key_on_delete_must_be_handled() {
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
                     on_delete_action: ForeignKeyAction::Cascade,
+                    on_update_action: ForeignKeyAction::NoAction,
                 },
                 ForeignKey {
                     constraint_name: None,
@@ -197,6 +199,7 @@ fn sqlite_foreign_key_on_delete_must_be_handled() {
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
                     on_delete_action: ForeignKeyAction::Restrict,
+                    on_update_action: ForeignKeyAction::NoAction,
                 },
                 ForeignKey {
                     constraint_name: None,
@@ -204,6 +207,7 @@ fn sqlite_foreign_key_on_delete_must_be_handled() {
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
                     on_delete_action: ForeignKeyAction::SetDefault,
+                    on_update_action: ForeignKeyAction::NoAction,
                 },
                 ForeignKey {
                     constraint_name: None,
@@ -211,6 +215,7 @@ fn sqlite_foreign_key_on_delete_must_be_handled() {
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
                     on_delete_action: ForeignKeyAction::SetNull,
+                    on_update_action: ForeignKeyAction::NoAction,
                 },
             ],
         }
@@ -248,3 +253,36 @@ fn sqlite_text_primary_keys_must_be_inferred_on_table_and_not_as_separate_indexe
         }
     );
 }
+
+#[test]
+fn sqlite_fulltext_indices_must_be_described_on_their_content_table() {
+    setup();
+
+    let mut migration = Migration::new().schema(SCHEMA);
+    migration.create_table("Post", move |t| {
+        t.add_column("id", types::primary());
+        t.add_column("title", types::text());
+        t.add_column("body", types::text());
+    });
+    let full_sql = format!(
+        "{};\nCREATE VIRTUAL TABLE \"{}\".\"PostSearch\" USING fts5(\"title\",\"body\", content='Post');",
+        migration.make::<barrel::backend::Sqlite>(),
+        SCHEMA
+    );
+
+    let inspector = get_sqlite_describer(&full_sql);
+    let result = inspector.describe(SCHEMA).expect("describing");
+
+    let table_names: Vec<&str> = result.tables.iter().map(|table| table.name.as_str()).collect();
+    assert_eq!(table_names, vec!["Post"]);
+
+    let table = result.get_table("Post").expect("couldn't get Post table");
+    assert_eq!(
+        table.indices,
+        vec![Index {
+            name: "PostSearch".to_string(),
+            columns: vec!["title".to_string(), "body".to_string()],
+            tpe: IndexType::Fulltext,
+        }]
+    );
+}
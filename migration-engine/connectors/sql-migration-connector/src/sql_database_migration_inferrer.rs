@@ -1,3 +1,4 @@
+use crate::sql_renderer::SqlRenderer;
 use crate::sql_schema_calculator::SqlSchemaCalculator;
 use crate::sql_schema_differ::{SqlSchemaDiff, SqlSchemaDiffer};
 use crate::*;
@@ -21,7 +22,7 @@ impl DatabaseMigrationInferrer<SqlMigration> for SqlDatabaseMigrationInferrer {
         _steps: &[MigrationStep],
     ) -> ConnectorResult<SqlMigration> {
         let current_database_schema: SqlSchema = self.introspect(&self.schema_name)?;
-        let expected_database_schema = SqlSchemaCalculator::calculate(next)?;
+        let expected_database_schema = SqlSchemaCalculator::calculate(next, self.sql_family)?;
         infer(
             &current_database_schema,
             &expected_database_schema,
@@ -36,8 +37,8 @@ impl DatabaseMigrationInferrer<SqlMigration> for SqlDatabaseMigrationInferrer {
         next: &Datamodel,
         _steps: &[MigrationStep],
     ) -> ConnectorResult<SqlMigration> {
-        let current_database_schema: SqlSchema = SqlSchemaCalculator::calculate(previous)?;
-        let expected_database_schema = SqlSchemaCalculator::calculate(next)?;
+        let current_database_schema: SqlSchema = SqlSchemaCalculator::calculate(previous, self.sql_family)?;
+        let expected_database_schema = SqlSchemaCalculator::calculate(next, self.sql_family)?;
         infer(
             &current_database_schema,
             &expected_database_schema,
@@ -86,17 +87,25 @@ fn infer_database_migration_steps_and_fix(
     schema_name: &str,
     sql_family: SqlFamily,
 ) -> SqlResult<(Vec<SqlMigrationStep>, Vec<SqlMigrationStep>)> {
-    let diff: SqlSchemaDiff = SqlSchemaDiffer::diff(&from, &to);
+    let diff: SqlSchemaDiff = SqlSchemaDiffer::diff(&from, &to, sql_family);
     let is_sqlite = sql_family == SqlFamily::Sqlite;
 
     let corrected_steps = if is_sqlite {
         fix_stupid_sqlite(diff, &from, &to, &schema_name)?
     } else {
         let steps = delay_foreign_key_creation(diff);
-        fix_id_column_type_change(&from, &to, schema_name, steps)?
+        let steps = fix_id_column_type_change(&from, &to, schema_name, steps, sql_family)?;
+        if sql_family == SqlFamily::Postgres {
+            fix_postgres_enums(&from, &to, schema_name, steps)
+        } else {
+            steps
+        }
     };
 
-    Ok((SqlSchemaDiffer::diff(&from, &to).into_steps(), corrected_steps))
+    Ok((
+        SqlSchemaDiffer::diff(&from, &to, sql_family).into_steps(),
+        corrected_steps,
+    ))
 }
 
 fn fix_id_column_type_change(
@@ -104,6 +113,7 @@ fn fix_id_column_type_change(
     to: &SqlSchema,
     _schema_name: &str,
     steps: Vec<SqlMigrationStep>,
+    sql_family: SqlFamily,
 ) -> SqlResult<Vec<SqlMigrationStep>> {
     let has_id_type_change = steps
         .iter()
@@ -144,7 +154,12 @@ fn fix_id_column_type_change(
             .map(|t| t.name.clone())
             .collect();
         radical_steps.push(SqlMigrationStep::DropTables(DropTables { names: tables_to_drop }));
-        let diff_from_empty: SqlSchemaDiff = SqlSchemaDiffer::diff(&SqlSchema::empty(), &to);
+        // The enums are kept, so they must not be created again.
+        let without_tables = SqlSchema {
+            tables: Vec::new(),
+            ..from.clone()
+        };
+        let diff_from_empty: SqlSchemaDiff = SqlSchemaDiffer::diff(&without_tables, &to, sql_family);
         let mut steps_from_empty = delay_foreign_key_creation(diff_from_empty);
         radical_steps.append(&mut steps_from_empty);
 
@@ -224,6 +239,16 @@ fn fix_stupid_sqlite(
             SqlMigrationStep::CreateIndex(ref create_index) if fixed_tables.contains(&create_index.table) => {
                 // The fixed alter table step will already create the index
             }
+            SqlMigrationStep::CreateIndex(create_index) => {
+                result.extend(sqlite_create_index_steps(
+                    &create_index.table,
+                    &create_index.index,
+                    schema_name,
+                ));
+            }
+            SqlMigrationStep::DropIndex(ref drop_index) if is_fulltext_index(current_database_schema, drop_index) => {
+                result.extend(sqlite_drop_fulltext_index_steps(drop_index, schema_name));
+            }
             SqlMigrationStep::AlterIndex(AlterIndex { table, .. }) => {
                 result.extend(sqlite_fix_table(
                     current_database_schema,
@@ -311,17 +336,10 @@ fn fix(current: &Table, next: &Table, schema_name: &str) -> Vec<SqlMigrationStep
         name: name_of_temporary_table,
         new_name: next.name.clone(),
     });
-    result.append(
-        &mut next
-            .indices
+    result.extend(
+        next.indices
             .iter()
-            .map(|index| {
-                SqlMigrationStep::CreateIndex(CreateIndex {
-                    table: next.name.clone(),
-                    index: index.clone(),
-                })
-            })
-            .collect(),
+            .flat_map(|index| sqlite_create_index_steps(&next.name, index, schema_name)),
     );
     // todo: recreate triggers
     result.push(SqlMigrationStep::RawSql {
@@ -335,9 +353,226 @@ fn fix(current: &Table, next: &Table, schema_name: &str) -> Vec<SqlMigrationStep
     result
 }
 
+/// Postgres can add values to an enum, but not drop them. When values are dropped, the enum is
+/// recreated and the columns using it are converted to the new enum before the old one is dropped.
+///
+/// Postgres orders enum values by their declaration, so added values are placed next to their
+/// neighbours in the datamodel. Reordering existing values is not detected.
+fn fix_postgres_enums(
+    from: &SqlSchema,
+    to: &SqlSchema,
+    schema_name: &str,
+    steps: Vec<SqlMigrationStep>,
+) -> Vec<SqlMigrationStep> {
+    let mut result = Vec::new();
+    for step in steps {
+        match step {
+            SqlMigrationStep::AlterEnum(alter_enum) => {
+                result.extend(postgres_alter_enum_steps(from, to, &alter_enum, schema_name))
+            }
+            x => result.push(x),
+        }
+    }
+    result
+}
+
+fn postgres_alter_enum_steps(
+    from: &SqlSchema,
+    to: &SqlSchema,
+    alter_enum: &AlterEnum,
+    schema_name: &str,
+) -> Vec<SqlMigrationStep> {
+    let renderer = SqlRenderer::for_family(&SqlFamily::Postgres);
+    let enum_name = format!("\"{}\".\"{}\"", schema_name, alter_enum.name);
+    let values: Vec<String> = to
+        .get_enum(&alter_enum.name)
+        .map(|next_enum| next_enum.values.clone())
+        .unwrap_or_default();
+
+    if alter_enum.dropped_values.is_empty() {
+        return alter_enum
+            .created_values
+            .iter()
+            .map(|value| SqlMigrationStep::RawSql {
+                raw: format!(
+                    "ALTER TYPE {} ADD VALUE {}{}",
+                    enum_name,
+                    renderer.quote_string(value),
+                    render_enum_value_position(renderer, value, &values, &alter_enum.created_values)
+                ),
+            })
+            .collect();
+    }
+
+    let old_name = format!("{}_old", alter_enum.name);
+
+    let mut result = vec![
+        SqlMigrationStep::RawSql {
+            raw: format!("ALTER TYPE {} RENAME TO \"{}\"", enum_name, old_name),
+        },
+        SqlMigrationStep::CreateEnum(CreateEnum {
+            name: alter_enum.name.clone(),
+            values,
+        }),
+    ];
+
+    for table in &from.tables {
+        let enum_columns = table
+            .columns
+            .iter()
+            .filter(|column| column.tpe.family == ColumnTypeFamily::Enum && column.tpe.raw == alter_enum.name);
+        for column in enum_columns {
+            let array_suffix = if column.arity == ColumnArity::List { "[]" } else { "" };
+            // The default still has the old enum type, so it has to be dropped before the conversion.
+            let mut changes = vec![
+                format!("ALTER COLUMN \"{}\" DROP DEFAULT", column.name),
+                format!(
+                    "ALTER COLUMN \"{column}\" TYPE {tpe}{suffix} USING (\"{column}\"::text{suffix}::{tpe}{suffix})",
+                    column = column.name,
+                    tpe = enum_name,
+                    suffix = array_suffix
+                ),
+            ];
+            let next_default = to
+                .table(&table.name)
+                .ok()
+                .and_then(|next_table| next_table.column(&column.name))
+                .filter(|next_column| next_column.is_required())
+                .and_then(|next_column| next_column.default.as_ref());
+            if let Some(default) = next_default {
+                let default = if default.starts_with("'") {
+                    default.clone()
+                } else {
+                    renderer.quote_string(default)
+                };
+                changes.push(format!("ALTER COLUMN \"{}\" SET DEFAULT {}", column.name, default));
+            }
+            result.push(SqlMigrationStep::RawSql {
+                raw: format!(
+                    "ALTER TABLE \"{}\".\"{}\" {}",
+                    schema_name,
+                    table.name,
+                    changes.join(", ")
+                ),
+            });
+        }
+    }
+
+    result.push(SqlMigrationStep::RawSql {
+        raw: format!("DROP TYPE \"{}\".\"{}\"", schema_name, old_name),
+    });
+
+    result
+}
+
+/// Values are added in their declared order, so a value goes after its predecessor, which is either an
+/// existing value or was just added. The first value goes before the first existing value.
+fn render_enum_value_position(
+    renderer: &dyn SqlRenderer,
+    value: &str,
+    values: &[String],
+    created_values: &[String],
+) -> String {
+    let index = values.iter().position(|v| v == value);
+    let previous = index.filter(|index| *index > 0).map(|index| &values[index - 1]);
+    let first_existing = values.iter().find(|v| !created_values.contains(v));
+
+    match (previous, first_existing) {
+        (Some(previous), _) => format!(" AFTER {}", renderer.quote_string(previous)),
+        (None, Some(first_existing)) => format!(" BEFORE {}", renderer.quote_string(first_existing)),
+        (None, None) => String::new(),
+    }
+}
+
 pub fn wrap_as_step<T, F>(steps: Vec<T>, mut wrap_fn: F) -> Vec<SqlMigrationStep>
 where
     F: FnMut(T) -> SqlMigrationStep,
 {
     steps.into_iter().map(|x| wrap_fn(x)).collect()
 }
+
+/// Creating a full-text index on SQLite also needs the triggers keeping the FTS5 table in sync with the
+/// indexed table, and a rebuild to index the existing records.
+fn sqlite_create_index_steps(table: &str, index: &Index, schema_name: &str) -> Vec<SqlMigrationStep> {
+    let mut result = vec![SqlMigrationStep::CreateIndex(CreateIndex {
+        table: table.to_string(),
+        index: index.clone(),
+    })];
+
+    if index.tpe != sql_schema_describer::IndexType::Fulltext {
+        return result;
+    }
+
+    let fts_columns = index
+        .columns
+        .iter()
+        .map(|c| format!("\"{}\"", c))
+        .collect::<Vec<String>>()
+        .join(",");
+    let values = |record: &str| {
+        index
+            .columns
+            .iter()
+            .map(|c| format!("{}.\"{}\"", record, c))
+            .collect::<Vec<String>>()
+            .join(",")
+    };
+    let insert = format!(
+        "INSERT INTO \"{}\"(rowid,{}) VALUES (new.rowid,{});",
+        index.name,
+        fts_columns,
+        values("new")
+    );
+    let delete = format!(
+        "INSERT INTO \"{}\"(\"{}\",rowid,{}) VALUES ('delete',old.rowid,{});",
+        index.name,
+        index.name,
+        fts_columns,
+        values("old")
+    );
+    let trigger = |suffix: &str, event: &str, body: String| SqlMigrationStep::RawSql {
+        raw: format!(
+            "CREATE TRIGGER IF NOT EXISTS \"{}\".\"{}_{}\" AFTER {} ON \"{}\" BEGIN {} END",
+            schema_name, index.name, suffix, event, table, body
+        ),
+    };
+
+    result.push(trigger("ai", "INSERT", insert.clone()));
+    result.push(trigger("ad", "DELETE", delete.clone()));
+    result.push(trigger("au", "UPDATE", format!("{} {}", delete, insert)));
+    result.push(SqlMigrationStep::RawSql {
+        raw: format!(
+            "INSERT INTO \"{}\".\"{}\"(\"{}\") VALUES ('rebuild')",
+            schema_name, index.name, index.name
+        ),
+    });
+
+    result
+}
+
+fn sqlite_drop_fulltext_index_steps(drop_index: &DropIndex, schema_name: &str) -> Vec<SqlMigrationStep> {
+    let mut result: Vec<SqlMigrationStep> = ["ai", "ad", "au"]
+        .iter()
+        .map(|suffix| SqlMigrationStep::RawSql {
+            raw: format!(
+                "DROP TRIGGER IF EXISTS \"{}\".\"{}_{}\"",
+                schema_name, drop_index.name, suffix
+            ),
+        })
+        .collect();
+
+    result.push(SqlMigrationStep::RawSql {
+        raw: format!("DROP TABLE \"{}\".\"{}\"", schema_name, drop_index.name),
+    });
+
+    result
+}
+
+fn is_fulltext_index(schema: &SqlSchema, drop_index: &DropIndex) -> bool {
+    schema
+        .table(&drop_index.table)
+        .ok()
+        .and_then(|table| table.indices.iter().find(|index| index.name == drop_index.name))
+        .map(|index| index.tpe == sql_schema_describer::IndexType::Fulltext)
+        .unwrap_or(false)
+}
@@ -119,7 +119,7 @@ fn render_raw_sql(step: &SqlMigrationStep, sql_family: SqlFamily, schema_name: &
         }
         SqlMigrationStep::AlterTable(AlterTable { table, changes }) => {
             let mut lines = Vec::new();
-            for change in changes.clone() {
+            for change in changes.iter() {
                 match change {
                     TableChange::AddColumn(AddColumn { column }) => {
                         let col_sql = renderer.render_column(&schema_name, &table, &column, true);
@@ -130,6 +130,25 @@ fn render_raw_sql(step: &SqlMigrationStep, sql_family: SqlFamily, schema_name: &
                         let name = renderer.quote(&name);
                         lines.push(format!("DROP COLUMN {}", name));
                     }
+                    // MySQL enums are inline column types, so their values change by redefining the column.
+                    TableChange::AlterColumn(AlterColumn { column, .. })
+                        if sql_family == SqlFamily::Mysql && column.tpe.family == ColumnTypeFamily::Enum =>
+                    {
+                        let col_sql = renderer.render_column(&schema_name, &table, &column, true);
+                        lines.push(format!("MODIFY COLUMN {}", col_sql));
+                    }
+                    // Text columns become native enum columns on Postgres, converting the data instead of dropping it.
+                    TableChange::AlterColumn(AlterColumn { name, column })
+                        if sql_family == SqlFamily::Postgres && column.tpe.family == ColumnTypeFamily::Enum =>
+                    {
+                        lines.extend(render_postgres_alter_enum_column(
+                            renderer,
+                            &schema_name,
+                            &table,
+                            &name,
+                            &column,
+                        ));
+                    }
                     TableChange::AlterColumn(AlterColumn { name, column }) => {
                         let name = renderer.quote(&name);
                         lines.push(format!("DROP COLUMN {}", name));
@@ -151,11 +170,15 @@ fn render_raw_sql(step: &SqlMigrationStep, sql_family: SqlFamily, schema_name: &
                 lines.join(",\n")
             )
         }
+        SqlMigrationStep::CreateIndex(CreateIndex { table, index }) if index.tpe == IndexType::Fulltext => {
+            render_create_fulltext_index(renderer, sql_family, &schema_name, &table, &index)
+        }
         SqlMigrationStep::CreateIndex(CreateIndex { table, index }) => {
             let Index { name, columns, tpe } = index;
             let index_type = match tpe {
                 IndexType::Unique => "UNIQUE",
                 IndexType::Normal => "",
+                IndexType::Fulltext => unreachable!("Fulltext indices are rendered separately."),
             };
             let index_name = match sql_family {
                 SqlFamily::Sqlite => renderer.quote_with_schema(&schema_name, &name),
@@ -202,10 +225,127 @@ fn render_raw_sql(step: &SqlMigrationStep, sql_family: SqlFamily, schema_name: &
             ),
             SqlFamily::Sqlite => unimplemented!("Index renaming on SQLite."),
         },
+        SqlMigrationStep::CreateEnum(CreateEnum { name, values }) => match sql_family {
+            SqlFamily::Postgres => {
+                let values: Vec<String> = values.iter().map(|value| renderer.quote_string(value)).collect();
+                format!(
+                    "CREATE TYPE {} AS ENUM ({})",
+                    renderer.quote_with_schema(&schema_name, &name),
+                    values.join(",")
+                )
+            }
+            _ => unreachable!("Only Postgres has enum types."),
+        },
+        SqlMigrationStep::DropEnum(DropEnum { name }) => match sql_family {
+            SqlFamily::Postgres => format!("DROP TYPE {}", renderer.quote_with_schema(&schema_name, &name)),
+            _ => unreachable!("Only Postgres has enum types."),
+        },
+        SqlMigrationStep::AlterEnum(_) => unreachable!("Enum alterations are corrected into raw SQL steps."),
         SqlMigrationStep::RawSql { raw } => raw.to_string(),
     }
 }
 
+/// MySQL supports full-text indices natively. On Postgres, the index is a GIN index over the `tsvector`
+/// of every column, so that searches on a single column can use it. On SQLite, the index is an FTS5
+/// table with the indexed table as external content, which is kept in sync by triggers.
+///
+/// Postgres only uses an index on `to_tsvector` with a fixed text search configuration, so the index is
+/// created with the `default_text_search_config` of the database at the time of the migration. It can be
+/// configured with `ALTER DATABASE ... SET default_text_search_config`.
+fn render_create_fulltext_index(
+    renderer: &dyn SqlRenderer,
+    sql_family: SqlFamily,
+    schema_name: &str,
+    table: &str,
+    index: &Index,
+) -> String {
+    match sql_family {
+        SqlFamily::Postgres => {
+            let vectors: Vec<String> = index
+                .columns
+                .iter()
+                .map(|c| format!("to_tsvector(%1$L, {})", escape_format_string(&renderer.quote(c))))
+                .collect();
+            let create_index = format!(
+                "CREATE INDEX {} ON {} USING GIN ({})",
+                escape_format_string(&renderer.quote(&index.name)),
+                escape_format_string(&renderer.quote_with_schema(&schema_name, &table)),
+                vectors.join(",")
+            );
+
+            format!(
+                "DO $$ BEGIN EXECUTE format('{}', current_setting('default_text_search_config')); END $$",
+                create_index
+            )
+        }
+        SqlFamily::Sqlite => {
+            let columns: Vec<String> = index.columns.iter().map(|c| renderer.quote(c)).collect();
+            format!(
+                "CREATE VIRTUAL TABLE IF NOT EXISTS {} USING fts5({}, content='{}')",
+                renderer.quote_with_schema(&schema_name, &index.name),
+                columns.join(","),
+                table
+            )
+        }
+        SqlFamily::Mysql => format!(
+            "CREATE FULLTEXT INDEX {} ON {}({})",
+            renderer.quote(&index.name),
+            renderer.quote_with_schema(&schema_name, &table),
+            index
+                .columns
+                .iter()
+                .map(|c| renderer.quote(c))
+                .collect::<Vec<String>>()
+                .join(",")
+        ),
+    }
+}
+
+/// Changes the type of a Postgres column to its enum with a cast, so that the values are kept. The
+/// previous default can't be cast, so it is dropped and the new one set afterwards.
+fn render_postgres_alter_enum_column(
+    renderer: &dyn SqlRenderer,
+    schema_name: &str,
+    table: &Table,
+    name: &str,
+    column: &Column,
+) -> Vec<String> {
+    let name = renderer.quote(name);
+    let array_suffix = if column.arity == ColumnArity::List { "[]" } else { "" };
+    let tpe = renderer.quote_with_schema(schema_name, &column.tpe.raw);
+    let mut lines = vec![
+        format!("ALTER COLUMN {} DROP DEFAULT", name),
+        format!(
+            "ALTER COLUMN {name} TYPE {tpe}{suffix} USING ({name}::text{suffix}::{tpe}{suffix})",
+            name = name,
+            tpe = tpe,
+            suffix = array_suffix
+        ),
+    ];
+
+    if column.is_required() && !table.is_part_of_foreign_key(&column.name) {
+        lines.push(format!("ALTER COLUMN {} SET NOT NULL", name));
+    } else {
+        lines.push(format!("ALTER COLUMN {} DROP NOT NULL", name));
+    }
+
+    if let Some(default) = column.default.as_ref().filter(|_| column.is_required()) {
+        let default = if default.starts_with("'") {
+            default.clone()
+        } else {
+            renderer.quote_string(default)
+        };
+        lines.push(format!("ALTER COLUMN {} SET DEFAULT {}", name, default));
+    }
+
+    lines
+}
+
+/// Escapes a string for a literal format string of the Postgres `format` function.
+fn escape_format_string(s: &str) -> String {
+    s.replace('\'', "''").replace('%', "%%")
+}
+
 fn create_table_suffix(sql_family: SqlFamily) -> &'static str {
     match sql_family {
         SqlFamily::Sqlite => "",
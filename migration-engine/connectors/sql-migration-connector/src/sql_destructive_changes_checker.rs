@@ -1,6 +1,6 @@
 use crate::{
-    DropColumn, DropTable, DropTables, MigrationDatabase, SqlError, SqlMigration, SqlMigrationStep, SqlResult,
-    TableChange,
+    AlterColumn, AlterEnum, DropColumn, DropTable, DropTables, MigrationDatabase, SqlError, SqlMigration,
+    SqlMigrationStep, SqlResult, TableChange,
 };
 use migration_connector::*;
 use prisma_query::ast::*;
@@ -42,24 +42,7 @@ impl SqlDestructiveChangesChecker {
         table: &sql_schema_describer::Table,
         diagnostics: &mut DestructiveChangeDiagnostics,
     ) -> SqlResult<()> {
-        let query = Select::from_table((self.schema_name.as_str(), table.name.as_str()))
-            .value(count(prisma_query::ast::Column::new(drop_column.name.as_str())))
-            .so_that(drop_column.name.as_str().is_not_null());
-
-        let values_count: i64 = self
-            .database
-            .query(&self.schema_name, query.into())
-            .map_err(SqlError::from)
-            .and_then(|result_set| {
-                result_set
-                    .first()
-                    .as_ref()
-                    .and_then(|row| row.at(0))
-                    .and_then(|count| count.as_i64())
-                    .ok_or_else(|| {
-                        SqlError::Generic("Unexpected result set shape when checking dropped columns.".to_owned())
-                    })
-            })?;
+        let values_count = self.count_non_null_values(table, &drop_column.name)?;
 
         if values_count > 0 {
             diagnostics.add_warning(MigrationWarning {
@@ -74,6 +57,59 @@ impl SqlDestructiveChangesChecker {
 
         Ok(())
     }
+
+    /// Emit a warning when we change the type of a column that contains non-null values. Columns
+    /// are either recreated or their values cast to the new type, which can fail or lose data.
+    fn check_column_type_change(
+        &self,
+        alter_column: &AlterColumn,
+        table: &sql_schema_describer::Table,
+        diagnostics: &mut DestructiveChangeDiagnostics,
+    ) -> SqlResult<()> {
+        let previous_family = match table.column(&alter_column.name) {
+            Some(previous_column) if previous_column.tpe.family != alter_column.column.tpe.family => {
+                &previous_column.tpe.family
+            }
+            _ => return Ok(()),
+        };
+
+        let values_count = self.count_non_null_values(table, &alter_column.name)?;
+
+        if values_count > 0 {
+            diagnostics.add_warning(MigrationWarning {
+                description: format!(
+                    "You are about to change the type of the column `{column_name}` on the `{table_name}` table from {previous_family:?} to {next_family:?}, which still contains {values_count} non-null values.",
+                    column_name = alter_column.name,
+                    table_name = &table.name,
+                    previous_family = previous_family,
+                    next_family = alter_column.column.tpe.family,
+                    values_count = values_count,
+                )
+            })
+        }
+
+        Ok(())
+    }
+
+    fn count_non_null_values(&self, table: &sql_schema_describer::Table, column_name: &str) -> SqlResult<i64> {
+        let query = Select::from_table((self.schema_name.as_str(), table.name.as_str()))
+            .value(count(prisma_query::ast::Column::new(column_name)))
+            .so_that(column_name.is_not_null());
+
+        self.database
+            .query(&self.schema_name, query.into())
+            .map_err(SqlError::from)
+            .and_then(|result_set| {
+                result_set
+                    .first()
+                    .as_ref()
+                    .and_then(|row| row.at(0))
+                    .and_then(|count| count.as_i64())
+                    .ok_or_else(|| {
+                        SqlError::Generic("Unexpected result set shape when checking column values.".to_owned())
+                    })
+            })
+    }
 }
 
 impl DestructiveChangesChecker<SqlMigration> for SqlDestructiveChangesChecker {
@@ -104,6 +140,14 @@ impl DestructiveChangesChecker<SqlMigration> for SqlDestructiveChangesChecker {
                                     })?;
                                 self.check_column_drop(drop_column, before_table, &mut diagnostics)
                             }
+                            TableChange::AlterColumn(ref alter_column) => {
+                                match database_migration.before.get_table(&alter_table.table.name) {
+                                    Some(before_table) => {
+                                        self.check_column_type_change(alter_column, before_table, &mut diagnostics)
+                                    }
+                                    None => Ok(()),
+                                }
+                            }
                             _ => Ok(()),
                         })
                         .collect::<Result<(), SqlError>>()?;
@@ -118,6 +162,16 @@ impl DestructiveChangesChecker<SqlMigration> for SqlDestructiveChangesChecker {
                         self.check_table_drop(name, &mut diagnostics)?;
                     }
                 }
+                // Records still using a dropped value would make the migration fail.
+                SqlMigrationStep::AlterEnum(AlterEnum {
+                    name, dropped_values, ..
+                }) if !dropped_values.is_empty() => diagnostics.add_warning(MigrationWarning {
+                    description: format!(
+                        "You are about to drop the values [{dropped_values}] of the enum `{enum_name}`. The migration will fail if they are still used.",
+                        dropped_values = dropped_values.join(", "),
+                        enum_name = name,
+                    ),
+                }),
                 // do nothing
                 _ => (),
             }
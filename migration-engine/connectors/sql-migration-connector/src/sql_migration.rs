@@ -43,6 +43,9 @@ pub enum SqlMigrationStep {
     CreateIndex(CreateIndex),
     DropIndex(DropIndex),
     AlterIndex(AlterIndex),
+    CreateEnum(CreateEnum),
+    DropEnum(DropEnum),
+    AlterEnum(AlterEnum),
 }
 
 /// A helper struct to serialize an [SqlMigrationStep](/sql-migration/enum.SqlMigrationStep.html)
@@ -124,3 +127,23 @@ pub struct AlterIndex {
     pub index_name: String,
     pub index_new_name: String,
 }
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct CreateEnum {
+    pub name: String,
+    pub values: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct DropEnum {
+    pub name: String,
+}
+
+/// Postgres can only add values to an existing enum. Dropping values is done by recreating the
+/// enum, see the migration inferrer.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct AlterEnum {
+    pub name: String,
+    pub created_values: Vec<String>,
+    pub dropped_values: Vec<String>,
+}
@@ -13,7 +13,7 @@ pub fn render_default(column: &Column) -> String {
     match &column.default {
         Some(value) => {
             let default = match column.tpe.family {
-                ColumnTypeFamily::String | ColumnTypeFamily::DateTime => {
+                ColumnTypeFamily::String | ColumnTypeFamily::DateTime | ColumnTypeFamily::Enum => {
                     // TODO: find a better solution for this amazing hack. the default value must not be a String
                     if value.starts_with("'") {
                         format!("DEFAULT {}", value)
@@ -44,6 +44,17 @@ pub fn render_on_delete(on_delete: &ForeignKeyAction) -> &'static str {
     }
 }
 
+/// `NO ACTION` is rendered explicitly, because MySQL otherwise reports the implicit default as `RESTRICT`.
+pub fn render_on_update(on_update: &ForeignKeyAction) -> &'static str {
+    match on_update {
+        ForeignKeyAction::NoAction => "ON UPDATE NO ACTION",
+        ForeignKeyAction::SetNull => "ON UPDATE SET NULL",
+        ForeignKeyAction::Cascade => "ON UPDATE CASCADE",
+        ForeignKeyAction::SetDefault => "ON UPDATE SET DEFAULT",
+        ForeignKeyAction::Restrict => "ON UPDATE RESTRICT",
+    }
+}
+
 // TODO: this returns None for expressions
 // TODO: bring back once values for columns are not untyped Strings anymore
 //fn render_value(value: &Value) -> Option<String> {
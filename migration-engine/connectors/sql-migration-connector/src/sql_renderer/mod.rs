@@ -17,8 +17,15 @@ pub trait SqlRenderer {
 
     fn quote(&self, name: &str) -> String;
 
+    /// Renders a string literal, e.g. an enum value.
+    fn quote_string(&self, value: &str) -> String {
+        format!("'{}'", value.replace('\'', "''"))
+    }
+
     fn render_column(&self, schema_name: &str, table: &Table, column: &Column, add_fk_prefix: bool) -> String;
 
+    /// Renders the type of a column, which is the raw type for columns
+    /// with a native type and otherwise derived from the type family.
     fn render_column_type(&self, t: &ColumnType) -> String;
 
     fn render_references(&self, schema_name: &str, foreign_key: Option<&ForeignKey>) -> String;
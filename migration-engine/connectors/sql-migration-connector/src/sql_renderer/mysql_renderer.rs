@@ -7,6 +7,11 @@ impl super::SqlRenderer for MySqlRenderer {
         format!("`{}`", name)
     }
 
+    /// MySQL also treats backslashes in string literals as escape characters.
+    fn quote_string(&self, value: &str) -> String {
+        format!("'{}'", value.replace('\\', "\\\\").replace('\'', "''"))
+    }
+
     fn render_column(&self, schema_name: &str, table: &Table, column: &Column, add_fk_prefix: bool) -> String {
         let column_name = self.quote(&column.name);
         let tpe_str = self.render_column_type(&column.tpe);
@@ -33,14 +38,21 @@ impl super::SqlRenderer for MySqlRenderer {
     }
 
     fn render_column_type(&self, t: &ColumnType) -> String {
+        if !t.raw.is_empty() {
+            return t.raw.clone();
+        }
+
         match &t.family {
             ColumnTypeFamily::Boolean => format!("boolean"),
             ColumnTypeFamily::DateTime => format!("datetime(3)"),
+            // Exact, so it also holds `Decimal` fields without losing precision.
             ColumnTypeFamily::Float => format!("Decimal(65,30)"),
             ColumnTypeFamily::Int => format!("int"),
             // we use varchar right now as mediumtext doesn't allow default values
             // a bigger length would not allow to use such a column as primary key
             ColumnTypeFamily::String => format!("varchar(191)"),
+            ColumnTypeFamily::Binary => format!("LONGBLOB"),
+            ColumnTypeFamily::Json => format!("JSON"),
             x => unimplemented!("{:?} not handled yet", x),
         }
     }
@@ -48,11 +60,12 @@ impl super::SqlRenderer for MySqlRenderer {
     fn render_references(&self, schema_name: &str, foreign_key: Option<&ForeignKey>) -> String {
         match foreign_key {
             Some(fk) => format!(
-                "REFERENCES `{}`.`{}`(`{}`) {}",
+                "REFERENCES `{}`.`{}`(`{}`) {} {}",
                 schema_name,
                 fk.referenced_table,
                 fk.referenced_columns.first().unwrap(),
-                render_on_delete(&fk.on_delete_action)
+                render_on_delete(&fk.on_delete_action),
+                render_on_update(&fk.on_update_action)
             ),
             None => "".to_string(),
         }
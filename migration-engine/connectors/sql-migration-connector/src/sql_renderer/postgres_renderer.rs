@@ -9,7 +9,16 @@ impl super::SqlRenderer for PostgresRenderer {
 
     fn render_column(&self, schema_name: &str, table: &Table, column: &Column, _add_fk_prefix: bool) -> String {
         let column_name = self.quote(&column.name);
-        let tpe_str = self.render_column_type(&column.tpe);
+        // Enum types live in the schema, just like the tables using them.
+        let base_tpe_str = match column.tpe.family {
+            ColumnTypeFamily::Enum => self.quote_with_schema(&schema_name, &column.tpe.raw),
+            _ => self.render_column_type(&column.tpe),
+        };
+        let tpe_str = match column.arity {
+            // Embedded scalar lists are stored in native arrays.
+            ColumnArity::List => format!("{}[]", base_tpe_str),
+            _ => base_tpe_str,
+        };
         let nullability_str = render_nullability(&table, &column);
         let default_str = render_default(&column);
         let foreign_key = table.foreign_key_for_column(&column.name);
@@ -28,12 +37,19 @@ impl super::SqlRenderer for PostgresRenderer {
     }
 
     fn render_column_type(&self, t: &ColumnType) -> String {
+        if !t.raw.is_empty() {
+            return t.raw.clone();
+        }
+
         match &t.family {
             ColumnTypeFamily::Boolean => format!("boolean"),
             ColumnTypeFamily::DateTime => format!("timestamp(3)"),
+            // Exact, so it also holds `Decimal` fields without losing precision.
             ColumnTypeFamily::Float => format!("Decimal(65,30)"),
             ColumnTypeFamily::Int => format!("integer"),
             ColumnTypeFamily::String => format!("text"),
+            ColumnTypeFamily::Binary => format!("bytea"),
+            ColumnTypeFamily::Json => format!("jsonb"),
             x => unimplemented!("{:?} not handled yet", x),
         }
     }
@@ -41,11 +57,12 @@ impl super::SqlRenderer for PostgresRenderer {
     fn render_references(&self, schema_name: &str, foreign_key: Option<&ForeignKey>) -> String {
         match foreign_key {
             Some(fk) => format!(
-                "REFERENCES \"{}\".\"{}\"(\"{}\") {}",
+                "REFERENCES \"{}\".\"{}\"(\"{}\") {} {}",
                 schema_name,
                 fk.referenced_table,
                 fk.referenced_columns.first().unwrap(),
-                render_on_delete(&fk.on_delete_action)
+                render_on_delete(&fk.on_delete_action),
+                render_on_update(&fk.on_update_action)
             ),
             None => "".to_string(),
         }
@@ -27,12 +27,21 @@ impl super::SqlRenderer for SqliteRenderer {
     }
 
     fn render_column_type(&self, t: &ColumnType) -> String {
+        if !t.raw.is_empty() {
+            return t.raw.clone();
+        }
+
         match &t.family {
             ColumnTypeFamily::Boolean => format!("BOOLEAN"),
             ColumnTypeFamily::DateTime => format!("DATE"),
+            // SQLite has no exact decimal storage, `Decimal` fields end up here too. Decimals bound as text
+            // take the column's numeric affinity, so they compare and order numerically.
             ColumnTypeFamily::Float => format!("REAL"),
             ColumnTypeFamily::Int => format!("INTEGER"),
             ColumnTypeFamily::String => format!("TEXT"),
+            ColumnTypeFamily::Binary => format!("BLOB"),
+            // SQLite has no JSON type, the JSON functions work on text.
+            ColumnTypeFamily::Json => format!("TEXT"),
             x => unimplemented!("{:?} not handled yet", x),
         }
     }
@@ -40,10 +49,11 @@ impl super::SqlRenderer for SqliteRenderer {
     fn render_references(&self, _schema_name: &str, foreign_key: Option<&ForeignKey>) -> String {
         match foreign_key {
             Some(fk) => format!(
-                "REFERENCES \"{}\"({}) {}",
+                "REFERENCES \"{}\"({}) {} {}",
                 fk.referenced_table,
                 fk.referenced_columns.first().unwrap(),
-                render_on_delete(&fk.on_delete_action)
+                render_on_delete(&fk.on_delete_action),
+                render_on_update(&fk.on_update_action)
             ),
             None => "".to_string(),
         }
@@ -1,4 +1,4 @@
-use crate::SqlResult;
+use crate::{sql_renderer::SqlRenderer, SqlError, SqlFamily, SqlResult};
 use chrono::*;
 use datamodel::common::*;
 use datamodel::*;
@@ -8,15 +8,18 @@ use sql_schema_describer as sql;
 
 pub struct SqlSchemaCalculator<'a> {
     data_model: &'a Datamodel,
+    sql_family: SqlFamily,
 }
 
 impl<'a> SqlSchemaCalculator<'a> {
-    pub fn calculate(data_model: &Datamodel) -> SqlResult<sql::SqlSchema> {
-        let calculator = SqlSchemaCalculator { data_model };
+    pub fn calculate(data_model: &Datamodel, sql_family: SqlFamily) -> SqlResult<sql::SqlSchema> {
+        let calculator = SqlSchemaCalculator { data_model, sql_family };
         calculator.calculate_internal()
     }
 
     fn calculate_internal(&self) -> SqlResult<sql::SqlSchema> {
+        self.check_embedded_scalar_lists()?;
+
         let mut tables = Vec::new();
         let model_tables_without_inline_relations = self.calculate_model_tables()?;
         let mut model_tables = self.add_inline_relations_to_model_tables(model_tables_without_inline_relations)?;
@@ -32,7 +35,7 @@ impl<'a> SqlSchemaCalculator<'a> {
             table.columns.sort_unstable_by_key(|col| col.name.clone());
         }
 
-        let enums = Vec::new();
+        let enums = self.calculate_enums();
         let sequences = Vec::new();
 
         Ok(sql::SqlSchema {
@@ -49,14 +52,18 @@ impl<'a> SqlSchemaCalculator<'a> {
                 let columns = model
                     .fields()
                     .flat_map(|f| match (&f.field_type, &f.arity) {
-                        (FieldType::Base(_), arity) | (FieldType::Enum(_), arity) if arity != &FieldArity::List => {
+                        (FieldType::Base(_), arity)
+                        | (FieldType::ConnectorSpecific { .. }, arity)
+                        | (FieldType::Enum(_), arity)
+                            if arity != &FieldArity::List || f.is_embedded_list() =>
+                        {
                             Some(sql::Column {
                                 name: f.db_name(),
-                                tpe: column_type(f),
+                                tpe: self.column_type(f),
                                 arity: column_arity(&f),
                                 default: f.migration_value_new(&self.data_model),
                                 auto_increment: {
-                                    if column_type(f).family == sql::ColumnTypeFamily::Int {
+                                    if self.column_type(f).family == sql::ColumnTypeFamily::Int {
                                         f.is_id()
                                     } else {
                                         false
@@ -103,10 +110,10 @@ impl<'a> SqlSchemaCalculator<'a> {
                         // The model index definition uses the model field names, but the SQL Index
                         // wants the column names.
                         columns: referenced_fields.iter().map(|field| field.db_name()).collect(),
-                        tpe: if index_definition.tpe == IndexType::Unique {
-                            sql::IndexType::Unique
-                        } else {
-                            sql::IndexType::Normal
+                        tpe: match index_definition.tpe {
+                            IndexType::Unique => sql::IndexType::Unique,
+                            IndexType::Normal => sql::IndexType::Normal,
+                            IndexType::Fulltext => sql::IndexType::Fulltext,
                         },
                     }
                 });
@@ -133,7 +140,7 @@ impl<'a> SqlSchemaCalculator<'a> {
         for model in self.data_model.models() {
             let list_fields: Vec<&Field> = model
                 .fields()
-                .filter(|f| f.arity == FieldArity::List && is_scalar(f))
+                .filter(|f| f.arity == FieldArity::List && is_scalar(f) && !f.is_embedded_list())
                 .collect();
             for field in list_fields {
                 let id_field = model.id_field()?;
@@ -147,13 +154,14 @@ impl<'a> SqlSchemaCalculator<'a> {
                     referenced_table: model.db_name(),
                     referenced_columns: vec![model.id_field()?.db_name()],
                     on_delete_action: sql::ForeignKeyAction::Cascade,
+                    on_update_action: sql::ForeignKeyAction::NoAction,
                 }];
                 let table = sql::Table {
                     name: format!("{}_{}", model.db_name(), field.db_name()),
                     columns: vec![
                         sql::Column {
                             name: "nodeId".to_string(),
-                            tpe: column_type(&id_field),
+                            tpe: self.column_type(&id_field),
                             arity: sql::ColumnArity::Required,
                             default: None,
                             auto_increment: false,
@@ -167,7 +175,7 @@ impl<'a> SqlSchemaCalculator<'a> {
                         },
                         sql::Column {
                             name: "value".to_string(),
-                            tpe: column_type(&field),
+                            tpe: self.column_type(&field),
                             arity: sql::ColumnArity::Required,
                             default: None,
                             auto_increment: false,
@@ -184,6 +192,67 @@ impl<'a> SqlSchemaCalculator<'a> {
         Ok(result)
     }
 
+    /// Embedded scalar lists are stored in native array columns, which only Postgres offers.
+    fn check_embedded_scalar_lists(&self) -> SqlResult<()> {
+        if self.sql_family == SqlFamily::Postgres {
+            return Ok(());
+        }
+
+        for model in self.data_model.models() {
+            if let Some(field) = model.fields().find(|f| f.is_embedded_list()) {
+                return Err(SqlError::Generic(format!(
+                    "The scalar list field `{}.{}` uses the EMBEDDED strategy, which is only supported on Postgres.",
+                    model.name, field.name
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Postgres has named enum types. MySQL declares the enum inline on the column and SQLite stores
+    /// enums as text, so neither has enums in its schema.
+    fn calculate_enums(&self) -> Vec<sql::Enum> {
+        if self.sql_family != SqlFamily::Postgres {
+            return Vec::new();
+        }
+
+        self.data_model
+            .enums()
+            .map(|e| sql::Enum {
+                name: e.db_name(),
+                values: e.values.iter().cloned().collect(),
+            })
+            .collect()
+    }
+
+    fn column_type(&self, field: &Field) -> sql::ColumnType {
+        match &field.field_type {
+            FieldType::Enum(ref enum_name) => {
+                let inum = self
+                    .data_model
+                    .find_enum(&enum_name)
+                    .expect(&format!("Enum {} was not present in the Datamodel.", enum_name));
+                enum_column_type(inum, self.sql_family)
+            }
+            _ => column_type(field),
+        }
+    }
+
+    /// Maps a datamodel referential action to a foreign key action, falling back to `default` when none is set.
+    /// InnoDB rejects `SET DEFAULT`, so on MySQL the foreign key leaves it to the query engine, which links
+    /// the records to the default before deleting or updating the referenced record.
+    fn foreign_key_action(&self, action: ReferentialAction, default: sql::ForeignKeyAction) -> sql::ForeignKeyAction {
+        match action {
+            ReferentialAction::Cascade => sql::ForeignKeyAction::Cascade,
+            ReferentialAction::Restrict => sql::ForeignKeyAction::Restrict,
+            ReferentialAction::SetNull => sql::ForeignKeyAction::SetNull,
+            ReferentialAction::SetDefault if self.sql_family == SqlFamily::Mysql => sql::ForeignKeyAction::NoAction,
+            ReferentialAction::SetDefault => sql::ForeignKeyAction::SetDefault,
+            ReferentialAction::None => default,
+        }
+    }
+
     fn add_inline_relations_to_model_tables(&self, model_tables: Vec<ModelTable>) -> SqlResult<Vec<sql::Table>> {
         let mut result = Vec::new();
         let relations = self.calculate_relations();
@@ -200,18 +269,23 @@ impl<'a> SqlSchemaCalculator<'a> {
                             (&relation.model_b, &relation.model_a)
                         };
                         let field = model.fields().find(|f| &f.db_name() == column).unwrap();
+                        let (on_delete, on_update) = match &field.field_type {
+                            FieldType::Relation(relation_info) => (relation_info.on_delete, relation_info.on_update),
+                            _ => (ReferentialAction::None, ReferentialAction::None),
+                        };
                         let foreign_key = sql::ForeignKey {
                             constraint_name: None,
                             columns: vec![column.to_string()],
                             referenced_table: related_model.db_name(),
                             referenced_columns: vec![related_model.id_field()?.db_name()],
-                            on_delete_action: sql::ForeignKeyAction::SetNull,
+                            on_delete_action: self.foreign_key_action(on_delete, sql::ForeignKeyAction::SetNull),
+                            on_update_action: self.foreign_key_action(on_update, sql::ForeignKeyAction::NoAction),
                         };
                         let column = sql::Column {
                             name: column.to_string(),
-                            tpe: column_type(related_model.id_field()?),
+                            tpe: self.column_type(related_model.id_field()?),
                             arity: column_arity(&field),
-                            default: None,
+                            default: relation_column_default(&field),
                             auto_increment: false,
                         };
                         model_table.table.columns.push(column);
@@ -237,6 +311,7 @@ impl<'a> SqlSchemaCalculator<'a> {
                             referenced_table: relation.model_a.db_name(),
                             referenced_columns: vec![relation.model_a.id_field()?.db_name()],
                             on_delete_action: sql::ForeignKeyAction::Cascade,
+                            on_update_action: sql::ForeignKeyAction::NoAction,
                         },
                         sql::ForeignKey {
                             constraint_name: None,
@@ -244,6 +319,7 @@ impl<'a> SqlSchemaCalculator<'a> {
                             referenced_table: relation.model_b.db_name(),
                             referenced_columns: vec![relation.model_b.id_field()?.db_name()],
                             on_delete_action: sql::ForeignKeyAction::Cascade,
+                            on_update_action: sql::ForeignKeyAction::NoAction,
                         },
                     ];
                     let table = sql::Table {
@@ -251,14 +327,14 @@ impl<'a> SqlSchemaCalculator<'a> {
                         columns: vec![
                             sql::Column {
                                 name: relation.model_a_column(),
-                                tpe: column_type(relation.model_a.id_field()?),
+                                tpe: self.column_type(relation.model_a.id_field()?),
                                 arity: sql::ColumnArity::Required,
                                 default: None,
                                 auto_increment: false,
                             },
                             sql::Column {
                                 name: relation.model_b_column(),
-                                tpe: column_type(relation.model_b.id_field()?),
+                                tpe: self.column_type(relation.model_b.id_field()?),
                                 arity: sql::ColumnArity::Required,
                                 default: None,
                                 auto_increment: false,
@@ -310,11 +386,24 @@ impl ModelExtensions for Model {
     }
 }
 
+pub trait EnumExtensions {
+    fn db_name(&self) -> String;
+}
+
+impl EnumExtensions for Enum {
+    fn db_name(&self) -> String {
+        self.database_name.clone().unwrap_or_else(|| self.name.clone())
+    }
+}
+
 pub trait FieldExtensions {
     fn is_id(&self) -> bool;
 
     fn is_list(&self) -> bool;
 
+    /// Scalar lists with the embedded strategy are stored in a native array column of the model table.
+    fn is_embedded_list(&self) -> bool;
+
     fn is_required(&self) -> bool;
 
     fn db_name(&self) -> String;
@@ -333,6 +422,10 @@ impl FieldExtensions for Field {
         self.arity == FieldArity::List
     }
 
+    fn is_embedded_list(&self) -> bool {
+        self.is_list() && self.scalar_list_strategy == Some(ScalarListStrategy::Embedded)
+    }
+
     fn is_required(&self) -> bool {
         self.arity == FieldArity::Required
     }
@@ -378,7 +471,13 @@ impl FieldExtensions for Field {
                 unreachable!("expressions must have been filtered out in the preceding pattern match")
             }
         };
-        if self.is_id() {
+        // MySQL does not allow defaults on binary and JSON columns.
+        let has_no_default = match self.field_type.scalar_type() {
+            Some(PrismaType::Bytes) | Some(PrismaType::Json) => true,
+            _ => false,
+        };
+
+        if self.is_id() || has_no_default {
             None
         } else {
             Some(result)
@@ -387,18 +486,19 @@ impl FieldExtensions for Field {
 }
 
 fn default_migration_value(field_type: &FieldType, datamodel: &Datamodel) -> Value {
-    match field_type {
-        FieldType::Base(PrismaType::Boolean) => Value::Boolean(false),
-        FieldType::Base(PrismaType::Int) => Value::Int(0),
-        FieldType::Base(PrismaType::Float) => Value::Float(0.0),
-        FieldType::Base(PrismaType::String) => Value::String("".to_string()),
-        FieldType::Base(PrismaType::Decimal) => Value::Decimal(0.0),
-        FieldType::Base(PrismaType::DateTime) => {
+    match (field_type, field_type.scalar_type()) {
+        (_, Some(PrismaType::Boolean)) => Value::Boolean(false),
+        (_, Some(PrismaType::Int)) => Value::Int(0),
+        (_, Some(PrismaType::Float)) => Value::Float(0.0),
+        (_, Some(PrismaType::String)) => Value::String("".to_string()),
+        (_, Some(PrismaType::Decimal)) => Value::Decimal("0".to_string()),
+        (_, Some(PrismaType::Bytes)) | (_, Some(PrismaType::Json)) => Value::String("".to_string()),
+        (_, Some(PrismaType::DateTime)) => {
             let naive = NaiveDateTime::from_timestamp(0, 0);
             let datetime: DateTime<Utc> = DateTime::from_utc(naive, Utc);
             PrismaValue::DateTime(datetime)
         }
-        FieldType::Enum(ref enum_name) => {
+        (FieldType::Enum(ref enum_name), _) => {
             let inum = datamodel
                 .find_enum(&enum_name)
                 .expect(&format!("Enum {} was not present in the Datamodel.", enum_name));
@@ -415,6 +515,7 @@ fn default_migration_value(field_type: &FieldType, datamodel: &Datamodel) -> Val
 fn is_scalar(field: &Field) -> bool {
     match field.field_type {
         FieldType::Base(_) => true,
+        FieldType::ConnectorSpecific { .. } => true,
         FieldType::Enum(_) => true,
         _ => false,
     }
@@ -423,7 +524,14 @@ fn is_scalar(field: &Field) -> bool {
 fn column_type(field: &Field) -> sql::ColumnType {
     match &field.field_type {
         FieldType::Base(ref scalar) => column_type_for_scalar_type(&scalar),
-        FieldType::Enum(_) => column_type_for_scalar_type(&ScalarType::String),
+        // The connector specific type is the native type of the column, e.g. `varchar(255)`.
+        FieldType::ConnectorSpecific {
+            base_type,
+            connector_type,
+        } => sql::ColumnType {
+            raw: connector_type.clone().unwrap_or_default(),
+            family: column_type_for_scalar_type(base_type).family,
+        },
         x => panic!(format!(
             "This field type is not suported here. Field type is {:?} on field {}",
             x, field.name
@@ -431,6 +539,35 @@ fn column_type(field: &Field) -> sql::ColumnType {
     }
 }
 
+/// Enum columns reference the enum type on Postgres and declare their values inline on MySQL.
+fn enum_column_type(inum: &Enum, sql_family: SqlFamily) -> sql::ColumnType {
+    match sql_family {
+        SqlFamily::Postgres => sql::ColumnType {
+            raw: inum.db_name(),
+            family: sql::ColumnTypeFamily::Enum,
+        },
+        SqlFamily::Mysql => {
+            let renderer = SqlRenderer::for_family(&sql_family);
+            let values: Vec<String> = inum.values.iter().map(|v| renderer.quote_string(v)).collect();
+            sql::ColumnType {
+                raw: format!("ENUM({})", values.join(",")),
+                family: sql::ColumnTypeFamily::Enum,
+            }
+        }
+        SqlFamily::Sqlite => column_type_for_scalar_type(&ScalarType::String),
+    }
+}
+
+/// Relation fields can default to the id of a related record, which `onDelete: SET_DEFAULT` and
+/// `onUpdate: SET_DEFAULT` link the records to.
+fn relation_column_default(field: &Field) -> Option<String> {
+    match &field.default_value {
+        Some(Value::Int(x)) => Some(format!("{}", x)),
+        Some(Value::String(x)) => Some(x.clone()),
+        _ => None,
+    }
+}
+
 fn column_arity(field: &Field) -> sql::ColumnArity {
     match &field.arity {
         FieldArity::Required => sql::ColumnArity::Required,
@@ -446,6 +583,9 @@ fn column_type_for_scalar_type(scalar_type: &ScalarType) -> sql::ColumnType {
         ScalarType::Boolean => sql::ColumnType::pure(sql::ColumnTypeFamily::Boolean),
         ScalarType::String => sql::ColumnType::pure(sql::ColumnTypeFamily::String),
         ScalarType::DateTime => sql::ColumnType::pure(sql::ColumnTypeFamily::DateTime),
-        ScalarType::Decimal => unimplemented!(),
+        ScalarType::Bytes => sql::ColumnType::pure(sql::ColumnTypeFamily::Binary),
+        ScalarType::Json => sql::ColumnType::pure(sql::ColumnTypeFamily::Json),
+        // Floats are already stored as exact decimals where the database supports them, see the renderers.
+        ScalarType::Decimal => sql::ColumnType::pure(sql::ColumnTypeFamily::Float),
     }
 }
@@ -8,6 +8,7 @@ const MIGRATION_TABLE_NAME: &str = "_Migration";
 pub struct SqlSchemaDiffer<'a> {
     previous: &'a SqlSchema,
     next: &'a SqlSchema,
+    sql_family: SqlFamily,
 }
 
 #[derive(Debug, Clone)]
@@ -18,6 +19,9 @@ pub struct SqlSchemaDiff {
     pub create_indexes: Vec<CreateIndex>,
     pub drop_indexes: Vec<DropIndex>,
     pub alter_indexes: Vec<AlterIndex>,
+    pub create_enums: Vec<CreateEnum>,
+    pub drop_enums: Vec<DropEnum>,
+    pub alter_enums: Vec<AlterEnum>,
 }
 
 impl SqlSchemaDiff {
@@ -25,6 +29,8 @@ impl SqlSchemaDiff {
         let mut steps = Vec::new();
         steps.append(&mut wrap_as_step(self.drop_indexes, |x| SqlMigrationStep::DropIndex(x)));
         steps.append(&mut wrap_as_step(self.drop_tables, |x| SqlMigrationStep::DropTable(x)));
+        steps.append(&mut wrap_as_step(self.create_enums, |x| SqlMigrationStep::CreateEnum(x)));
+        steps.append(&mut wrap_as_step(self.alter_enums, |x| SqlMigrationStep::AlterEnum(x)));
         steps.append(&mut wrap_as_step(self.create_tables, |x| {
             SqlMigrationStep::CreateTable(x)
         }));
@@ -37,13 +43,19 @@ impl SqlSchemaDiff {
         steps.append(&mut wrap_as_step(self.alter_indexes, |x| {
             SqlMigrationStep::AlterIndex(x)
         }));
+        // Enums can only be dropped once no column uses them anymore.
+        steps.append(&mut wrap_as_step(self.drop_enums, |x| SqlMigrationStep::DropEnum(x)));
         steps
     }
 }
 
 impl<'a> SqlSchemaDiffer<'a> {
-    pub fn diff(previous: &SqlSchema, next: &SqlSchema) -> SqlSchemaDiff {
-        let differ = SqlSchemaDiffer { previous, next };
+    pub fn diff(previous: &SqlSchema, next: &SqlSchema, sql_family: SqlFamily) -> SqlSchemaDiff {
+        let differ = SqlSchemaDiffer {
+            previous,
+            next,
+            sql_family,
+        };
         differ.diff_internal()
     }
 
@@ -57,6 +69,9 @@ impl<'a> SqlSchemaDiffer<'a> {
             create_indexes: self.create_indexes(&alter_indexes),
             drop_indexes: self.drop_indexes(&alter_indexes),
             alter_indexes,
+            create_enums: self.create_enums(),
+            drop_enums: self.drop_enums(),
+            alter_enums: self.alter_enums(),
         }
     }
 
@@ -92,10 +107,10 @@ impl<'a> SqlSchemaDiffer<'a> {
         for previous_table in &self.previous.tables {
             if let Ok(next_table) = self.next.table(&previous_table.name) {
                 let mut changes = Vec::new();
-                changes.extend(Self::drop_foreign_keys(&previous_table, &next_table));
+                changes.extend(self.drop_foreign_keys(&previous_table, &next_table));
                 changes.append(&mut Self::drop_columns(&previous_table, &next_table));
                 changes.append(&mut Self::add_columns(&previous_table, &next_table));
-                changes.append(&mut Self::alter_columns(&previous_table, &next_table));
+                changes.append(&mut self.alter_columns(&previous_table, &next_table));
 
                 if !changes.is_empty() {
                     let update = AlterTable {
@@ -135,7 +150,7 @@ impl<'a> SqlSchemaDiffer<'a> {
         result
     }
 
-    fn alter_columns(previous: &Table, next: &Table) -> Vec<TableChange> {
+    fn alter_columns(&self, previous: &Table, next: &Table) -> Vec<TableChange> {
         let mut result = Vec::new();
         for next_column in &next.columns {
             if let Some(previous_column) = previous.column(&next_column.name) {
@@ -145,10 +160,10 @@ impl<'a> SqlSchemaDiffer<'a> {
                 // TODO: use differs function again
                 let is_fk_case = previous_fk.is_some() && next_fk.is_some(); // to cater for the temporary ignorance of NOT NULL constraint
                 let differs_in_something = previous_column.name != next_column.name
-                    || previous_column.tpe.family != next_column.tpe.family
+                    || column_type_changed(&previous_column.tpe, &next_column.tpe, self.sql_family)
                     || (previous_column.arity != next_column.arity && !is_fk_case);
 
-                if differs_in_something || foreign_key_changed(previous_fk, next_fk) {
+                if differs_in_something || foreign_key_changed(previous_fk, next_fk, self.sql_family) {
                     let change = AlterColumn {
                         name: previous_column.name.clone(),
                         column: next_column.clone(),
@@ -160,14 +175,16 @@ impl<'a> SqlSchemaDiffer<'a> {
         result
     }
 
-    fn drop_foreign_keys(previous: &'a Table, next: &'a Table) -> impl Iterator<Item = TableChange> + 'a {
+    fn drop_foreign_keys(&self, previous: &'a Table, next: &'a Table) -> impl Iterator<Item = TableChange> + 'a {
+        let sql_family = self.sql_family;
+
         previous
             .foreign_keys
             .iter()
             .filter(move |previous_fk| {
                 next.foreign_keys
                     .iter()
-                    .find(|next_fk| foreign_keys_match(previous_fk, next_fk))
+                    .find(|next_fk| foreign_keys_match(previous_fk, next_fk, sql_family))
                     .is_none()
             })
             .filter_map(|foreign_key| foreign_key.constraint_name.as_ref())
@@ -242,6 +259,47 @@ impl<'a> SqlSchemaDiffer<'a> {
         result
     }
 
+    fn create_enums(&self) -> Vec<CreateEnum> {
+        self.next
+            .enums
+            .iter()
+            .filter(|next_enum| self.previous.get_enum(&next_enum.name).is_none())
+            .map(|next_enum| CreateEnum {
+                name: next_enum.name.clone(),
+                values: next_enum.values.clone(),
+            })
+            .collect()
+    }
+
+    fn drop_enums(&self) -> Vec<DropEnum> {
+        self.previous
+            .enums
+            .iter()
+            .filter(|previous_enum| self.next.get_enum(&previous_enum.name).is_none())
+            .map(|previous_enum| DropEnum {
+                name: previous_enum.name.clone(),
+            })
+            .collect()
+    }
+
+    fn alter_enums(&self) -> Vec<AlterEnum> {
+        self.previous
+            .enums
+            .iter()
+            .filter_map(|previous_enum| {
+                self.next
+                    .get_enum(&previous_enum.name)
+                    .map(|next_enum| (previous_enum, next_enum))
+            })
+            .map(|(previous_enum, next_enum)| AlterEnum {
+                name: next_enum.name.clone(),
+                created_values: values_missing_in(&next_enum.values, &previous_enum.values),
+                dropped_values: values_missing_in(&previous_enum.values, &next_enum.values),
+            })
+            .filter(|alter_enum| !alter_enum.created_values.is_empty() || !alter_enum.dropped_values.is_empty())
+            .collect()
+    }
+
     /// An iterator over the tables that are present in both schemas. The yielded tuples should be interpreted as `(previous_table, next_table)`.
     fn table_pairs(&self) -> impl Iterator<Item = (&Table, &Table)> {
         self.previous.tables.iter().filter_map(move |previous_table| {
@@ -279,26 +337,57 @@ impl<'a> SqlSchemaDiffer<'a> {
     }
 }
 
+/// The enum values of `values` that `other` doesn't have, in their declared order.
+fn values_missing_in(values: &[String], other: &[String]) -> Vec<String> {
+    values.iter().filter(|value| !other.contains(value)).cloned().collect()
+}
+
 /// Compare two SQL indexes and return whether they only differ by name or type.
 fn indexes_are_equivalent(first: &Index, second: &Index) -> bool {
     first.columns == second.columns && first.tpe == second.tpe
 }
 
+/// Compare two column types and return whether a migration needs to be applied.
+/// Columns with a native type must match it exactly, all other columns only need to be of
+/// the same type family.
+fn column_type_changed(previous: &ColumnType, next: &ColumnType, sql_family: SqlFamily) -> bool {
+    if !next.raw.is_empty() {
+        return !previous.raw.eq_ignore_ascii_case(&next.raw);
+    }
+
+    match (sql_family, &previous.family, &next.family) {
+        // JSON is stored as text on SQLite, so the column is described as a string column.
+        (SqlFamily::Sqlite, ColumnTypeFamily::String, ColumnTypeFamily::Json) => false,
+        (_, previous, next) => previous != next,
+    }
+}
+
 /// Compare two [ForeignKey](/sql-schema-describer/struct.ForeignKey.html)s and return whether a
 /// migration needs to be applied.
-fn foreign_key_changed(previous: Option<&ForeignKey>, next: Option<&ForeignKey>) -> bool {
+fn foreign_key_changed(previous: Option<&ForeignKey>, next: Option<&ForeignKey>, sql_family: SqlFamily) -> bool {
     match (previous, next) {
         (None, None) => false,
-        (Some(previous), Some(next)) => !foreign_keys_match(previous, next),
+        (Some(previous), Some(next)) => !foreign_keys_match(previous, next, sql_family),
         _ => true,
     }
 }
 
 /// Compare two [ForeignKey](/sql-schema-describer/struct.ForeignKey.html)s and return whether they
 /// should be considered equivalent for schema diffing purposes.
-fn foreign_keys_match(previous: &ForeignKey, next: &ForeignKey) -> bool {
+fn foreign_keys_match(previous: &ForeignKey, next: &ForeignKey, sql_family: SqlFamily) -> bool {
     previous.referenced_table == next.referenced_table
         && previous.referenced_columns == next.referenced_columns
         && previous.columns == next.columns
-        && previous.on_delete_action == next.on_delete_action
+        && foreign_key_actions_match(&previous.on_delete_action, &next.on_delete_action, sql_family)
+        && foreign_key_actions_match(&previous.on_update_action, &next.on_update_action, sql_family)
+}
+
+/// InnoDB checks foreign keys immediately, so `NO ACTION` is the same as `RESTRICT` on MySQL and
+/// the database may describe either of them.
+fn foreign_key_actions_match(previous: &ForeignKeyAction, next: &ForeignKeyAction, sql_family: SqlFamily) -> bool {
+    match (sql_family, previous, next) {
+        (SqlFamily::Mysql, ForeignKeyAction::Restrict, ForeignKeyAction::NoAction)
+        | (SqlFamily::Mysql, ForeignKeyAction::NoAction, ForeignKeyAction::Restrict) => true,
+        (_, previous, next) => previous == next,
+    }
 }
@@ -163,7 +163,8 @@ fn infer_CreateField_if_relation_field_does_not_exist_yet() {
                 to: "Post".to_string(),
                 to_fields: vec![],
                 name: String::from("BlogToPost"),
-                on_delete: OnDeleteStrategy::None,
+                on_delete: ReferentialAction::None,
+                on_update: ReferentialAction::None,
             }),
             arity: FieldArity::List,
             db_name: None,
@@ -181,7 +182,8 @@ fn infer_CreateField_if_relation_field_does_not_exist_yet() {
                 to: "Blog".to_string(),
                 to_fields: vec![String::from("id")],
                 name: String::from("BlogToPost"),
-                on_delete: OnDeleteStrategy::None,
+                on_delete: ReferentialAction::None,
+                on_update: ReferentialAction::None,
             }),
             arity: FieldArity::Optional,
             db_name: None,
@@ -362,7 +364,8 @@ fn infer_CreateField_on_self_relation() {
         name: "invitedBy".into(),
         tpe: FieldType::Relation(RelationInfo {
             name: "UserToUser".into(),
-            on_delete: OnDeleteStrategy::None,
+            on_delete: ReferentialAction::None,
+            on_update: ReferentialAction::None,
             to: "User".into(),
             to_fields: vec!["id".to_owned()],
         }),
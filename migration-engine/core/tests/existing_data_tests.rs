@@ -178,3 +178,51 @@ fn dropping_a_column_with_non_null_values_should_warn() {
         );
     });
 }
+
+#[test]
+fn changing_the_type_of_a_column_with_non_null_values_should_warn() {
+    test_each_connector_with_ignores(vec![SqlFamily::Sqlite], |test_setup, engine| {
+        let dm = r#"
+            model Test {
+                id String @id @default(cuid())
+                status String?
+            }
+        "#;
+
+        let original_database_schema = infer_and_apply(test_setup, engine, &dm).sql_schema;
+
+        let insert = Insert::multi_into((SCHEMA_NAME, "Test"), vec!["id", "status"])
+            .values(("a", "OK"))
+            .values(("b", "ERROR"));
+
+        test_setup.database.execute(SCHEMA_NAME, insert.into()).unwrap();
+
+        let dm = r#"
+            model Test {
+                id String @id @default(cuid())
+                status Status?
+            }
+
+            enum Status {
+                OK
+                ERROR
+            }
+        "#;
+
+        let InferAndApplyOutput {
+            migration_output,
+            sql_schema: final_database_schema,
+        } = infer_and_apply(test_setup, engine, &dm);
+
+        // The schema should not change because the migration should not run if there are warnings
+        // and the force flag isn't passed.
+        assert_eq!(original_database_schema, final_database_schema);
+
+        assert_eq!(
+            migration_output.warnings,
+            &[MigrationWarning {
+                description: "You are about to change the type of the column `status` on the `Test` table from String to Enum, which still contains 2 non-null values.".to_owned(),
+            }]
+        );
+    });
+}
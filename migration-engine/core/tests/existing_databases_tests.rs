@@ -162,7 +162,8 @@ fn creating_a_scalar_list_field_for_an_existing_table_must_work() {
             if table.name == "Blog_tags" {
                 for fk in &mut table.foreign_keys {
                     if fk.columns == &["nodeId"] {
-                        fk.on_delete_action = ForeignKeyAction::Cascade
+                        fk.on_delete_action = ForeignKeyAction::Cascade;
+                        fk.on_update_action = ForeignKeyAction::NoAction;
                     }
                 }
                 //                table.primary_key = Some(PrimaryKey {
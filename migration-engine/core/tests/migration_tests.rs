@@ -2,7 +2,7 @@
 #![allow(unused)]
 mod test_harness;
 use pretty_assertions::{assert_eq, assert_ne};
-use sql_migration_connector::{AlterIndex, CreateIndex, DropIndex, SqlFamily, SqlMigrationStep};
+use sql_migration_connector::{AlterIndex, CreateIndex, DropIndex, SqlFamily, SqlMigrationStep, TableChange};
 use sql_schema_describer::*;
 use test_harness::*;
 
@@ -14,6 +14,8 @@ fn adding_a_scalar_field_must_work() {
                 id String @id @default(cuid())
                 int Int
                 float Float
+                decimal Decimal
+                bytes Bytes
                 boolean Boolean
                 string String
                 dateTime DateTime
@@ -31,10 +33,16 @@ fn adding_a_scalar_field_must_work() {
 
         assert_eq!(table.column_bang("int").tpe.family, ColumnTypeFamily::Int);
         assert_eq!(table.column_bang("float").tpe.family, ColumnTypeFamily::Float);
+        assert_eq!(table.column_bang("decimal").tpe.family, ColumnTypeFamily::Float);
+        assert_eq!(table.column_bang("bytes").tpe.family, ColumnTypeFamily::Binary);
         assert_eq!(table.column_bang("boolean").tpe.family, ColumnTypeFamily::Boolean);
         assert_eq!(table.column_bang("string").tpe.family, ColumnTypeFamily::String);
         assert_eq!(table.column_bang("dateTime").tpe.family, ColumnTypeFamily::DateTime);
-        assert_eq!(table.column_bang("enum").tpe.family, ColumnTypeFamily::String);
+        let enum_family = match test_setup.sql_family {
+            SqlFamily::Sqlite => ColumnTypeFamily::String,
+            _ => ColumnTypeFamily::Enum,
+        };
+        assert_eq!(table.column_bang("enum").tpe.family, enum_family);
     });
 }
 
@@ -241,6 +249,7 @@ fn changing_the_type_of_an_id_field_must_work() {
                 referenced_table: "B".to_string(),
                 referenced_columns: vec!["id".to_string()],
                 on_delete_action: ForeignKeyAction::SetNull,
+                on_update_action: ForeignKeyAction::NoAction,
             }]
         );
 
@@ -269,6 +278,7 @@ fn changing_the_type_of_an_id_field_must_work() {
                 referenced_table: "B".to_string(),
                 referenced_columns: vec!["id".to_string()],
                 on_delete_action: ForeignKeyAction::SetNull,
+                on_update_action: ForeignKeyAction::NoAction,
             }]
         );
     });
@@ -328,6 +338,7 @@ fn changing_a_relation_field_to_a_scalar_field_must_work() {
                 referenced_table: "B".to_string(),
                 referenced_columns: vec!["id".to_string()],
                 on_delete_action: ForeignKeyAction::SetNull,
+                on_update_action: ForeignKeyAction::NoAction,
             }]
         );
 
@@ -392,11 +403,156 @@ fn changing_a_scalar_field_to_a_relation_field_must_work() {
                 referenced_table: "B".to_string(),
                 referenced_columns: vec!["id".to_string()],
                 on_delete_action: ForeignKeyAction::SetNull,
+                on_update_action: ForeignKeyAction::NoAction,
             }]
         );
     });
 }
 
+#[test]
+fn referential_actions_of_relation_fields_must_be_rendered_into_foreign_keys() {
+    test_each_connector(|test_setup, api| {
+        let dm1 = r#"
+            model A {
+                id Int @id
+                b  B   @relation(references: [id], onDelete: CASCADE, onUpdate: CASCADE)
+            }
+            model B {
+                id Int @id
+            }
+        "#;
+        let result = infer_and_apply(test_setup, api, &dm1).sql_schema;
+        let foreign_key = &result.table_bang("A").foreign_keys[0];
+        assert_eq!(foreign_key.on_delete_action, ForeignKeyAction::Cascade);
+        assert_eq!(foreign_key.on_update_action, ForeignKeyAction::Cascade);
+
+        let dm2 = r#"
+            model A {
+                id Int @id
+                b  B   @relation(references: [id], onDelete: RESTRICT)
+            }
+            model B {
+                id Int @id
+            }
+        "#;
+        let result = infer_and_apply(test_setup, api, &dm2).sql_schema;
+        let foreign_key = &result.table_bang("A").foreign_keys[0];
+        assert_eq!(foreign_key.on_delete_action, ForeignKeyAction::Restrict);
+        assert_eq!(foreign_key.on_update_action, ForeignKeyAction::NoAction);
+    });
+}
+
+#[test]
+fn referential_actions_must_not_cause_foreign_keys_to_be_recreated() {
+    test_each_connector(|test_setup, api| {
+        let dm = r#"
+            model A {
+                id Int @id
+                b  B   @relation(references: [id], onDelete: RESTRICT)
+                c  C   @relation(references: [id], onDelete: CASCADE, onUpdate: RESTRICT)
+            }
+            model B {
+                id Int @id
+            }
+            model C {
+                id Int @id
+            }
+        "#;
+        infer_and_apply(test_setup, api, &dm);
+
+        let result = infer_and_apply(test_setup, api, &dm);
+        assert!(result.sql_migration().is_empty());
+    });
+}
+
+#[test]
+fn default_values_of_relation_fields_must_be_rendered_into_columns() {
+    test_each_connector(|test_setup, api| {
+        let dm = r#"
+            model A {
+                id Int @id
+                b  B   @default(1) @relation(references: [id], onDelete: SET_DEFAULT)
+            }
+            model B {
+                id Int @id
+            }
+        "#;
+        let result = infer_and_apply(test_setup, api, &dm).sql_schema;
+        let table = result.table_bang("A");
+        let on_delete_action = match test_setup.sql_family {
+            SqlFamily::Mysql => ForeignKeyAction::NoAction,
+            _ => ForeignKeyAction::SetDefault,
+        };
+        assert_eq!(table.foreign_keys[0].on_delete_action, on_delete_action);
+        assert_eq!(table.column_bang("b").default.as_ref().map(String::as_str), Some("1"));
+    });
+}
+
+#[test]
+fn native_types_of_fields_must_be_rendered_into_columns() {
+    test_each_connector(|test_setup, api| {
+        let (provider, native_type, raw) = match test_setup.sql_family {
+            SqlFamily::Postgres => ("postgresql", "VarChar(255)", "varchar(255)"),
+            SqlFamily::Mysql => ("mysql", "VarChar(255)", "varchar(255)"),
+            SqlFamily::Sqlite => ("sqlite", "Text", "TEXT"),
+        };
+        let dm = format!(
+            r#"
+            datasource db {{
+                provider = "{}"
+                url = "unused"
+            }}
+            model A {{
+                id    Int    @id
+                title String @db.{}
+            }}
+        "#,
+            provider, native_type
+        );
+        let result = infer_and_apply(test_setup, api, &dm).sql_schema;
+        let column = result.table_bang("A").column_bang("title");
+        assert_eq!(column.tpe.raw, raw);
+        assert_eq!(column.tpe.family, ColumnTypeFamily::String);
+    });
+}
+
+#[test]
+fn json_fields_must_be_rendered_into_json_columns() {
+    test_each_connector(|test_setup, api| {
+        let dm1 = r#"
+            model A {
+                id   Int  @id
+                data Json
+            }
+        "#;
+        let result = infer_and_apply(test_setup, api, &dm1).sql_schema;
+        let column = result.table_bang("A").column_bang("data");
+        let family = match test_setup.sql_family {
+            // SQLite stores JSON as text.
+            SqlFamily::Sqlite => ColumnTypeFamily::String,
+            _ => ColumnTypeFamily::Json,
+        };
+        assert_eq!(column.tpe.family, family);
+
+        let dm2 = r#"
+            model A {
+                id    Int     @id
+                data  Json
+                title String?
+            }
+        "#;
+        let result = infer_and_apply(test_setup, api, &dm2);
+        let alters_data = result.sql_migration().iter().any(|step| match step {
+            SqlMigrationStep::AlterTable(alter_table) => alter_table.changes.iter().any(|change| match change {
+                TableChange::AlterColumn(alter_column) => alter_column.name == "data",
+                _ => false,
+            }),
+            _ => false,
+        });
+        assert!(!alters_data);
+    });
+}
+
 #[test]
 fn adding_a_many_to_many_relation_must_result_in_a_prisma_style_relation_table() {
     // TODO: one model should have an id of different type. Not possible right now due to barrel limitation.
@@ -434,6 +590,7 @@ fn adding_a_many_to_many_relation_must_result_in_a_prisma_style_relation_table()
                     referenced_table: "A".to_string(),
                     referenced_columns: vec!["id".to_string()],
                     on_delete_action: ForeignKeyAction::Cascade,
+                    on_update_action: ForeignKeyAction::NoAction,
                 },
                 ForeignKey {
                     constraint_name: match test_setup.sql_family {
@@ -445,6 +602,7 @@ fn adding_a_many_to_many_relation_must_result_in_a_prisma_style_relation_table()
                     referenced_table: "B".to_string(),
                     referenced_columns: vec!["id".to_string()],
                     on_delete_action: ForeignKeyAction::Cascade,
+                    on_update_action: ForeignKeyAction::NoAction,
                 },
             ]
         );
@@ -487,6 +645,7 @@ fn adding_a_many_to_many_relation_with_custom_name_must_work() {
                     referenced_table: "A".to_string(),
                     referenced_columns: vec!["id".to_string()],
                     on_delete_action: ForeignKeyAction::Cascade,
+                    on_update_action: ForeignKeyAction::NoAction,
                 },
                 ForeignKey {
                     constraint_name: match test_setup.sql_family {
@@ -498,6 +657,7 @@ fn adding_a_many_to_many_relation_with_custom_name_must_work() {
                     referenced_table: "B".to_string(),
                     referenced_columns: vec!["id".to_string()],
                     on_delete_action: ForeignKeyAction::Cascade,
+                    on_update_action: ForeignKeyAction::NoAction,
                 }
             ]
         );
@@ -561,6 +721,7 @@ fn adding_an_inline_relation_must_result_in_a_foreign_key_in_the_model_table() {
                 referenced_table: "B".to_string(),
                 referenced_columns: vec!["id".to_string()],
                 on_delete_action: ForeignKeyAction::SetNull,
+                on_update_action: ForeignKeyAction::NoAction,
             }]
         );
     });
@@ -595,6 +756,7 @@ fn specifying_a_db_name_for_an_inline_relation_must_work() {
                 referenced_table: "B".to_string(),
                 referenced_columns: vec!["id".to_string()],
                 on_delete_action: ForeignKeyAction::SetNull,
+                on_update_action: ForeignKeyAction::NoAction,
             }]
         );
     });
@@ -629,6 +791,7 @@ fn adding_an_inline_relation_to_a_model_with_an_exotic_id_type() {
                 referenced_table: "B".to_string(),
                 referenced_columns: vec!["id".to_string()],
                 on_delete_action: ForeignKeyAction::SetNull,
+                on_update_action: ForeignKeyAction::NoAction,
             }]
         );
     });
@@ -693,6 +856,7 @@ fn moving_an_inline_relation_to_the_other_side_must_work() {
                 referenced_table: "B".to_string(),
                 referenced_columns: vec!["id".to_string()],
                 on_delete_action: ForeignKeyAction::SetNull,
+                on_update_action: ForeignKeyAction::NoAction,
             }]
         );
 
@@ -720,6 +884,7 @@ fn moving_an_inline_relation_to_the_other_side_must_work() {
                 referenced_table: "A".to_string(),
                 referenced_columns: vec!["id".to_string()],
                 on_delete_action: ForeignKeyAction::SetNull,
+                on_update_action: ForeignKeyAction::NoAction,
             }]
         );
     });
@@ -1325,6 +1490,117 @@ fn updating_a_model_with_a_scalar_list_to_a_different_id_type_must_work() {
     });
 }
 
+#[test]
+fn embedded_scalar_lists_must_be_stored_in_native_arrays() {
+    test_only_connector(SqlFamily::Postgres, |test_setup, api| {
+        let dm = r#"
+            model A {
+                id      Int      @id
+                strings String[] @scalarList(strategy: EMBEDDED)
+                ints    Int[]    @scalarList(strategy: EMBEDDED)
+            }
+        "#;
+        let result = infer_and_apply(test_setup, api, &dm).sql_schema;
+        let table = result.table_bang("A");
+        let strings_column = table.column_bang("strings");
+        assert_eq!(strings_column.arity, ColumnArity::List);
+        assert_eq!(strings_column.tpe.family, ColumnTypeFamily::String);
+        let ints_column = table.column_bang("ints");
+        assert_eq!(ints_column.arity, ColumnArity::List);
+        assert_eq!(ints_column.tpe.family, ColumnTypeFamily::Int);
+        assert!(result.table("A_strings").is_err());
+
+        let result = infer_and_apply(test_setup, api, &dm);
+        assert!(result.sql_migration().is_empty());
+    });
+}
+
+#[test]
+fn enums_must_be_stored_in_native_enum_columns() {
+    test_each_connector_with_ignores(vec![SqlFamily::Sqlite], |test_setup, api| {
+        let sql_family = test_setup.sql_family;
+        let dm1 = r#"
+            model A {
+                id     Int    @id
+                status Status
+            }
+
+            enum Status {
+                OK
+                ERROR
+            }
+        "#;
+        let result = infer_and_apply(test_setup, api, &dm1).sql_schema;
+        let column = result.table_bang("A").column_bang("status");
+        assert_eq!(column.tpe.family, ColumnTypeFamily::Enum);
+        match sql_family {
+            SqlFamily::Postgres => assert_eq!(enum_values(&result, "Status"), vec!["OK", "ERROR"]),
+            _ => assert_eq!(column.tpe.raw, "enum('OK','ERROR')"),
+        }
+
+        let dm2 = r#"
+            model A {
+                id     Int    @id
+                status Status
+            }
+
+            enum Status {
+                OK
+                PENDING
+            }
+        "#;
+        let result = infer_and_apply(test_setup, api, &dm2).sql_schema;
+        let column = result.table_bang("A").column_bang("status");
+        assert_eq!(column.tpe.family, ColumnTypeFamily::Enum);
+        match sql_family {
+            SqlFamily::Postgres => assert_eq!(enum_values(&result, "Status"), vec!["OK", "PENDING"]),
+            _ => assert_eq!(column.tpe.raw, "enum('OK','PENDING')"),
+        }
+
+        let result = infer_and_apply(test_setup, api, &dm2);
+        assert!(result.sql_migration().is_empty());
+    });
+}
+
+#[test]
+fn added_enum_values_must_keep_their_declared_order() {
+    test_only_connector(SqlFamily::Postgres, |test_setup, api| {
+        let dm1 = r#"
+            enum Status {
+                B
+                D
+            }
+        "#;
+        let result = infer_and_apply(test_setup, api, &dm1).sql_schema;
+        assert_eq!(enum_values(&result, "Status"), vec!["B", "D"]);
+
+        let dm2 = r#"
+            enum Status {
+                A
+                B
+                C
+                D
+                E
+            }
+        "#;
+        let result = infer_and_apply(test_setup, api, &dm2).sql_schema;
+        assert_eq!(enum_values(&result, "Status"), vec!["A", "B", "C", "D", "E"]);
+
+        let result = infer_and_apply(test_setup, api, &dm2);
+        assert!(result.sql_migration().is_empty());
+    });
+}
+
+fn enum_values<'a>(schema: &'a SqlSchema, name: &str) -> Vec<&'a str> {
+    schema
+        .get_enum(name)
+        .expect("get enum")
+        .values
+        .iter()
+        .map(|value| value.as_str())
+        .collect()
+}
+
 #[test]
 fn reserved_sql_key_words_must_work() {
     // Group is a reserved keyword
@@ -1353,6 +1629,7 @@ fn reserved_sql_key_words_must_work() {
                 referenced_table: "Group".to_string(),
                 referenced_columns: vec!["id".to_string()],
                 on_delete_action: ForeignKeyAction::SetNull,
+                on_update_action: ForeignKeyAction::NoAction,
             }]
         );
     });
@@ -1468,3 +1745,31 @@ fn removing_a_relation_field_must_work() {
         assert!(address_name_field.is_none());
     })
 }
+
+#[test]
+fn fulltext_indexes_must_be_created_and_not_recreated() {
+    test_each_connector(|test_setup, api| {
+        let dm = r#"
+            model Post {
+                id    Int    @id
+                title String
+                body  String
+
+                @@fulltext([title, body], name: "PostSearch")
+            }
+        "#;
+
+        let sql_schema = infer_and_apply(test_setup, api, &dm).sql_schema;
+        let index = sql_schema
+            .table_bang("Post")
+            .indices
+            .iter()
+            .find(|index| index.name == "PostSearch")
+            .expect("index is present");
+        assert_eq!(index.tpe, IndexType::Fulltext);
+        assert_eq!(index.columns, &["title", "body"]);
+
+        let result = infer_and_apply(test_setup, api, &dm);
+        assert!(result.sql_migration().is_empty());
+    });
+}
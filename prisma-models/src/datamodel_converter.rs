@@ -9,13 +9,96 @@ pub struct DatamodelConverter<'a> {
 
 #[allow(unused)]
 impl<'a> DatamodelConverter<'a> {
-    pub fn convert_string(datamodel: String) -> InternalDataModelTemplate {
+    pub fn convert_string(datamodel: String) -> DomainResult<InternalDataModelTemplate> {
         let datamodel = datamodel::parse_datamodel(&datamodel).unwrap();
         Self::convert(&datamodel)
     }
 
-    pub fn convert(datamodel: &dml::Datamodel) -> InternalDataModelTemplate {
-        DatamodelConverter::new(datamodel).convert_internal()
+    pub fn convert(datamodel: &dml::Datamodel) -> DomainResult<InternalDataModelTemplate> {
+        Self::validate(datamodel)?;
+        Ok(DatamodelConverter::new(datamodel).convert_internal())
+    }
+
+    /// Rejects what the query engine can't handle yet: Records are related through a single id column
+    /// on each side, so relations to or from models with a compound id are not supported. The same goes
+    /// for scalar lists, which reference their record by a single id column. Compound ids are made of ids,
+    /// so only the field types of ids are supported in them. Records are selected by scalar fields only,
+    /// so compound unique indexes including a relation field are not supported either.
+    fn validate(datamodel: &dml::Datamodel) -> DomainResult<()> {
+        for model in datamodel.models() {
+            if model.id_fields.len() > 1 {
+                for name in model.id_fields.iter() {
+                    let is_supported = model
+                        .find_field(name)
+                        .map(|field| match field.type_identifier() {
+                            TypeIdentifier::String | TypeIdentifier::Int | TypeIdentifier::UUID => true,
+                            TypeIdentifier::GraphQLID => true,
+                            _ => false,
+                        })
+                        .unwrap_or(false);
+
+                    if !is_supported {
+                        return Err(DomainError::UnsupportedCompoundIdField {
+                            name: name.clone(),
+                            model: model.name.clone(),
+                        });
+                    }
+                }
+
+                let scalar_list = model.fields().find(|field| match field.field_type {
+                    dml::FieldType::Relation(_) => false,
+                    _ => field.is_list(),
+                });
+
+                if let Some(field) = scalar_list {
+                    return Err(DomainError::CompoundIdScalarList {
+                        field: field.name.clone(),
+                        model: model.name.clone(),
+                    });
+                }
+            }
+
+            for field in model.fields() {
+                if let dml::FieldType::Relation(relation_info) = &field.field_type {
+                    let related_model = datamodel.find_model(&relation_info.to);
+
+                    for m in Some(model).into_iter().chain(related_model) {
+                        if m.id_fields.len() > 1 {
+                            return Err(DomainError::CompoundIdRelation {
+                                field: field.name.clone(),
+                                model: model.name.clone(),
+                                compound_id_model: m.name.clone(),
+                            });
+                        }
+                    }
+                }
+            }
+
+            let compound_uniques = model
+                .indexes
+                .iter()
+                .filter(|index| index.tpe == dml::IndexType::Unique && index.fields.len() > 1);
+
+            for index in compound_uniques {
+                let relation_field = index
+                    .fields
+                    .iter()
+                    .filter_map(|name| model.find_field(name))
+                    .find(|field| match field.field_type {
+                        dml::FieldType::Relation(_) => true,
+                        _ => false,
+                    });
+
+                if let Some(field) = relation_field {
+                    return Err(DomainError::CompoundUniqueRelationField {
+                        field: field.name.clone(),
+                        model: model.name.clone(),
+                    });
+                }
+            }
+        }
+
+        Ok(())
     }
 
     fn new(datamodel: &dml::Datamodel) -> DatamodelConverter {
@@ -52,6 +135,13 @@ impl<'a> DatamodelConverter<'a> {
                 is_embedded: model.is_embedded,
                 fields: self.convert_fields(model),
                 manifestation: model.database_name.clone(),
+                id_field_names: model.id_fields.clone(),
+                compound_unique_field_names: model
+                    .indexes
+                    .iter()
+                    .filter(|index| index.tpe == dml::IndexType::Unique && index.fields.len() > 1)
+                    .map(|index| index.fields.clone())
+                    .collect(),
             })
             .collect()
     }
@@ -83,6 +173,7 @@ impl<'a> DatamodelConverter<'a> {
                         manifestation: field.manifestation(),
                         relation_name: relation.name(),
                         relation_side: relation.relation_side(field),
+                        default_value: field.default_value(),
                     })
                 }
                 ti => FieldTemplate::Scalar(ScalarFieldTemplate {
@@ -97,6 +188,7 @@ impl<'a> DatamodelConverter<'a> {
                     behaviour: field.behaviour(),
                     default_value: field.default_value(),
                     internal_enum: field.internal_enum(self.datamodel),
+                    fulltext_index: field.fulltext_index(model),
                 }),
             })
             .collect()
@@ -107,8 +199,10 @@ impl<'a> DatamodelConverter<'a> {
             .iter()
             .map(|r| RelationTemplate {
                 name: r.name(),
-                model_a_on_delete: OnDelete::SetNull,
-                model_b_on_delete: OnDelete::SetNull,
+                model_a_on_delete: r.field_a.on_delete(),
+                model_b_on_delete: r.field_b.on_delete(),
+                model_a_on_update: r.field_a.on_update(),
+                model_b_on_update: r.field_b.on_update(),
                 manifestation: Some(r.manifestation()),
                 model_a_name: r.model_a.name.clone(),
                 model_b_name: r.model_b.name.clone(),
@@ -320,6 +414,9 @@ trait DatamodelFieldExtensions {
     fn final_db_name(&self) -> String;
     fn internal_enum(&self, datamodel: &dml::Datamodel) -> Option<InternalEnum>;
     fn default_value(&self) -> Option<PrismaValue>;
+    fn fulltext_index(&self, model: &dml::Model) -> Option<FulltextIndex>;
+    fn on_delete(&self) -> OnDelete;
+    fn on_update(&self) -> OnDelete;
 }
 
 impl DatamodelFieldExtensions for dml::Field {
@@ -328,24 +425,25 @@ impl DatamodelFieldExtensions for dml::Field {
         match self.field_type {
             dml::FieldType::Enum(_) => TypeIdentifier::Enum,
             dml::FieldType::Relation(_) => TypeIdentifier::Relation,
-            dml::FieldType::Base(scalar) => match scalar {
-                dml::ScalarType::Boolean => TypeIdentifier::Boolean,
-                dml::ScalarType::DateTime => TypeIdentifier::DateTime,
-                dml::ScalarType::Decimal => TypeIdentifier::Float,
-                dml::ScalarType::Float => TypeIdentifier::Float,
-                dml::ScalarType::Int => TypeIdentifier::Int,
-                dml::ScalarType::String => match self.default_value {
-                    Some(datamodel::common::PrismaValue::Expression(ref expr, _, _)) if expr == "cuid" => {
-                        TypeIdentifier::GraphQLID
-                    }
-                    Some(datamodel::common::PrismaValue::Expression(ref expr, _, _)) if expr == "uuid" => {
-                        TypeIdentifier::UUID
-                    }
-                    _ => TypeIdentifier::String,
-                },
-            },
-            dml::FieldType::ConnectorSpecific { .. } => {
-                unimplemented!("Connector Specific types are not supported here yet")
+            dml::FieldType::Base(scalar) | dml::FieldType::ConnectorSpecific { base_type: scalar, .. } => {
+                match scalar {
+                    dml::ScalarType::Boolean => TypeIdentifier::Boolean,
+                    dml::ScalarType::DateTime => TypeIdentifier::DateTime,
+                    dml::ScalarType::Decimal => TypeIdentifier::Decimal,
+                    dml::ScalarType::Float => TypeIdentifier::Float,
+                    dml::ScalarType::Int => TypeIdentifier::Int,
+                    dml::ScalarType::Bytes => TypeIdentifier::Bytes,
+                    dml::ScalarType::Json => TypeIdentifier::Json,
+                    dml::ScalarType::String => match self.default_value {
+                        Some(datamodel::common::PrismaValue::Expression(ref expr, _, _)) if expr == "cuid" => {
+                            TypeIdentifier::GraphQLID
+                        }
+                        Some(datamodel::common::PrismaValue::Expression(ref expr, _, _)) if expr == "uuid" => {
+                            TypeIdentifier::UUID
+                        }
+                        _ => TypeIdentifier::String,
+                    },
+                }
             }
         }
     }
@@ -417,6 +515,62 @@ impl DatamodelFieldExtensions for dml::Field {
         self.database_name.clone().unwrap_or_else(|| self.name.clone())
     }
 
+    fn fulltext_index(&self, model: &dml::Model) -> Option<FulltextIndex> {
+        model
+            .indexes
+            .iter()
+            .find(|index| index.tpe == dml::IndexType::Fulltext && index.fields.contains(&self.name))
+            .map(|index| {
+                let columns: Vec<String> = index
+                    .fields
+                    .iter()
+                    .filter_map(|name| model.find_field(name))
+                    .map(|field| field.final_db_name())
+                    .collect();
+
+                // Unnamed indices get the same name as in the migration engine.
+                let name = index.name.clone().unwrap_or_else(|| {
+                    format!(
+                        "{}.{}",
+                        model.database_name.clone().unwrap_or_else(|| model.name.clone()),
+                        columns.join("_")
+                    )
+                });
+
+                FulltextIndex { name, columns }
+            })
+    }
+
+    /// The action applies to the records holding this field. List fields never hold the reference,
+    /// so they and relations without an explicit action keep the default of unlinking the records.
+    fn on_delete(&self) -> OnDelete {
+        match &self.field_type {
+            dml::FieldType::Relation(_) if self.is_list() => OnDelete::SetNull,
+            dml::FieldType::Relation(relation_info) => match relation_info.on_delete {
+                dml::ReferentialAction::Cascade => OnDelete::Cascade,
+                dml::ReferentialAction::Restrict => OnDelete::Restrict,
+                dml::ReferentialAction::SetDefault => OnDelete::SetDefault,
+                dml::ReferentialAction::SetNull | dml::ReferentialAction::None => OnDelete::SetNull,
+            },
+            _ => OnDelete::SetNull,
+        }
+    }
+
+    /// Without an explicit action, updating a referenced id is rejected like with a foreign key
+    /// without an `ON UPDATE` action.
+    fn on_update(&self) -> OnDelete {
+        match &self.field_type {
+            dml::FieldType::Relation(_) if self.is_list() => OnDelete::Restrict,
+            dml::FieldType::Relation(relation_info) => match relation_info.on_update {
+                dml::ReferentialAction::Cascade => OnDelete::Cascade,
+                dml::ReferentialAction::SetNull => OnDelete::SetNull,
+                dml::ReferentialAction::SetDefault => OnDelete::SetDefault,
+                dml::ReferentialAction::Restrict | dml::ReferentialAction::None => OnDelete::Restrict,
+            },
+            _ => OnDelete::Restrict,
+        }
+    }
+
     fn internal_enum(&self, datamodel: &dml::Datamodel) -> Option<InternalEnum> {
         match self.field_type {
             dml::FieldType::Enum(ref name) => {
@@ -439,7 +593,7 @@ impl DatamodelFieldExtensions for dml::Field {
             datamodel::common::PrismaValue::Float(x) => Some(PrismaValue::Float(f64::from(*x))),
             datamodel::common::PrismaValue::String(x) => Some(PrismaValue::String(x.clone())),
             datamodel::common::PrismaValue::DateTime(x) => Some(PrismaValue::DateTime(*x)),
-            datamodel::common::PrismaValue::Decimal(x) => Some(PrismaValue::Float(f64::from(*x))), // TODO: not sure if this mapping is correct
+            datamodel::common::PrismaValue::Decimal(x) => x.parse().ok().map(PrismaValue::Decimal),
             datamodel::common::PrismaValue::ConstantLiteral(x) => {
                 Some(PrismaValue::Enum(EnumValue::string(x.clone(), x.clone())))
             }
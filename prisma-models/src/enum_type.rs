@@ -31,17 +31,24 @@ impl EnumValue {
     pub fn as_string(&self) -> String {
         match &self.value {
             EnumValueWrapper::String(s) => s.clone(),
-            EnumValueWrapper::OrderBy(ob) => format!("{}_{}", ob.field.name, ob.sort_order.abbreviated()),
+            EnumValueWrapper::OrderBy(ob) => format!("{}_{}", ob.name(), ob.sort_order.abbreviated()),
         }
     }
 
     pub fn order_by<T>(name: T, field: Arc<ScalarField>, sort_order: SortOrder) -> Self
+    where
+        T: Into<String>,
+    {
+        Self::ordering(name, OrderBy::scalar(field, sort_order))
+    }
+
+    pub fn ordering<T>(name: T, order_by: OrderBy) -> Self
     where
         T: Into<String>,
     {
         EnumValue {
             name: name.into(),
-            value: EnumValueWrapper::OrderBy(OrderBy { field, sort_order }),
+            value: EnumValueWrapper::OrderBy(order_by),
         }
     }
 
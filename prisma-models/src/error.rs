@@ -25,4 +25,32 @@ pub enum DomainError {
 
     #[fail(display = "Conversion from `{}` to `{}` failed.", _0, _1)]
     ConversionFailure(&'static str, &'static str),
+
+    #[fail(
+        display = "The relation field `{}` on model `{}` is not supported, because model `{}` has a compound id.",
+        field, model, compound_id_model
+    )]
+    CompoundIdRelation {
+        field: String,
+        model: String,
+        compound_id_model: String,
+    },
+
+    #[fail(
+        display = "The field `{}` on model `{}` is part of a compound id, which only supports String, Int and UUID fields.",
+        name, model
+    )]
+    UnsupportedCompoundIdField { name: String, model: String },
+
+    #[fail(
+        display = "The scalar list field `{}` on model `{}` is not supported, because the model has a compound id.",
+        field, model
+    )]
+    CompoundIdScalarList { field: String, model: String },
+
+    #[fail(
+        display = "The compound unique index including the relation field `{}` on model `{}` is not supported.",
+        field, model
+    )]
+    CompoundUniqueRelationField { field: String, model: String },
 }
@@ -32,6 +32,7 @@ pub struct FieldManifestation {
 pub enum TypeIdentifier {
     String,
     Float,
+    Decimal,
     Boolean,
     Enum,
     Json,
@@ -39,6 +40,7 @@ pub enum TypeIdentifier {
     GraphQLID,
     UUID,
     Int,
+    Bytes,
     Relation,
 }
 
@@ -126,6 +128,7 @@ impl FieldTemplate {
                     behaviour: st.behaviour,
                     model,
                     default_value: st.default_value,
+                    fulltext_index: st.fulltext_index,
                 };
 
                 Field::Scalar(Arc::new(scalar))
@@ -141,6 +144,7 @@ impl FieldTemplate {
                     is_unique: rt.is_unique,
                     relation_name: rt.relation_name,
                     relation_side: rt.relation_side,
+                    default_value: rt.default_value,
                     model,
                     relation: OnceCell::new(),
                 };
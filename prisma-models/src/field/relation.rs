@@ -20,6 +20,7 @@ pub struct RelationFieldTemplate {
     pub manifestation: Option<FieldManifestation>,
     pub relation_name: String,
     pub relation_side: RelationSide,
+    pub default_value: Option<PrismaValue>,
 }
 
 #[derive(DebugStub)]
@@ -32,6 +33,7 @@ pub struct RelationField {
     pub is_auto_generated: bool,
     pub relation_name: String,
     pub relation_side: RelationSide,
+    pub default_value: Option<PrismaValue>,
     #[debug_stub = "#ModelWeakRef#"]
     pub model: ModelWeakRef,
     pub relation: OnceCell<RelationWeakRef>,
@@ -147,6 +149,31 @@ impl RelationField {
         }
     }
 
+    /// What happens to the records of this field's model when the related record is deleted.
+    pub fn on_delete(&self) -> OnDelete {
+        match self.relation_side {
+            RelationSide::A => self.relation().model_a_on_delete,
+            RelationSide::B => self.relation().model_b_on_delete,
+        }
+    }
+
+    /// What happens to the records of this field's model when the id of the related record is updated.
+    pub fn on_update(&self) -> OnDelete {
+        match self.relation_side {
+            RelationSide::A => self.relation().model_a_on_update,
+            RelationSide::B => self.relation().model_b_on_update,
+        }
+    }
+
+    /// The value the references of this field's records are replaced with when they are unlinked from
+    /// the related record by the given action.
+    pub fn unlink_value(&self, action: OnDelete) -> PrismaValue {
+        match (action, &self.default_value) {
+            (OnDelete::SetDefault, Some(value)) => value.clone(),
+            _ => PrismaValue::Null,
+        }
+    }
+
     pub fn as_column(&self) -> Column<'static> {
         let model = self.model();
         let internal_data_model = model.internal_data_model();
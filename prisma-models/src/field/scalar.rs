@@ -29,6 +29,9 @@ pub struct ScalarFieldTemplate {
 
     #[serde(rename = "enum")]
     pub internal_enum: Option<InternalEnum>,
+
+    /// The full-text index covering the field, if any.
+    pub fulltext_index: Option<FulltextIndex>,
 }
 
 #[derive(DebugStub)]
@@ -45,10 +48,21 @@ pub struct ScalarField {
     #[debug_stub = "#ModelWeakRef#"]
     pub model: ModelWeakRef,
     pub default_value: Option<PrismaValue>,
+    pub fulltext_index: Option<FulltextIndex>,
 
     pub(crate) is_unique: bool,
 }
 
+/// A full-text index. Databases search all columns of the index at once, or can only use the index
+/// with the exact expression it was created on.
+#[derive(Debug, Deserialize, Serialize, Clone, Eq, PartialEq)]
+pub struct FulltextIndex {
+    pub name: String,
+
+    /// The database names of the indexed columns, in index order.
+    pub columns: Vec<String>,
+}
+
 #[derive(Debug, Deserialize, Serialize, Clone, Eq, PartialEq)]
 #[serde(rename_all = "camelCase", tag = "type")]
 pub enum FieldBehaviour {
@@ -171,6 +185,17 @@ impl ScalarField {
         }
     }
 
+    /// Scalar lists with the embedded strategy are stored in a native array column of the model table
+    /// instead of a separate scalar list table.
+    pub fn is_embedded_list(&self) -> bool {
+        match self.behaviour {
+            Some(FieldBehaviour::ScalarList {
+                strategy: ScalarListStrategy::Embedded,
+            }) => self.is_list,
+            _ => false,
+        }
+    }
+
     pub fn scalar_list_table(&self) -> ScalarListTable {
         ScalarListTable::new(self)
     }
@@ -9,6 +9,7 @@ use std::{
 pub struct Fields {
     pub all: Vec<Field>,
     id: OnceCell<Weak<ScalarField>>,
+    id_fields: OnceCell<Vec<Weak<ScalarField>>>,
     scalar: OnceCell<Vec<Weak<ScalarField>>>,
     relation: OnceCell<Vec<Weak<RelationField>>>,
     model: ModelWeakRef,
@@ -21,6 +22,7 @@ impl Fields {
         Fields {
             all,
             id: OnceCell::new(),
+            id_fields: OnceCell::new(),
             scalar: OnceCell::new(),
             relation: OnceCell::new(),
             created_at: OnceCell::new(),
@@ -29,13 +31,23 @@ impl Fields {
         }
     }
 
+    /// The single id field of the model. Panics for models with a compound id, use `id_fields` for those.
+    /// Relations are resolved through a single id column on each side, so the datamodel conversion rejects
+    /// relations to or from models with a compound id, and relation code can rely on this field.
     pub fn id(&self) -> Arc<ScalarField> {
         self.id
             .get_or_init(|| {
+                let model = self.model.upgrade().unwrap();
+
+                if model.has_compound_id() {
+                    panic!("Model {} has a compound id and no single id field.", model.name);
+                }
+
                 self.all
                     .iter()
                     .fold(None, |acc, field| match field {
                         Field::Scalar(sf) if sf.is_id() => Some(Arc::downgrade(sf)),
+                        Field::Scalar(sf) if model.id_field_names == [sf.name.clone()] => Some(Arc::downgrade(sf)),
                         _ => acc,
                     })
                     .expect("No id field defined!")
@@ -44,6 +56,34 @@ impl Fields {
             .unwrap()
     }
 
+    /// All fields identifying a record, in the order of the compound id. Contains only the id field
+    /// for models without a compound id.
+    pub fn id_fields(&self) -> Vec<Arc<ScalarField>> {
+        self.id_fields
+            .get_or_init(|| {
+                let model = self.model.upgrade().unwrap();
+
+                if model.has_compound_id() {
+                    model
+                        .id_field_names
+                        .iter()
+                        .map(|name| {
+                            self.scalar_weak()
+                                .iter()
+                                .find(|sf| &sf.upgrade().unwrap().name == name)
+                                .cloned()
+                                .unwrap_or_else(|| panic!("Id field {} not found on model {}.", name, model.name))
+                        })
+                        .collect()
+                } else {
+                    vec![Arc::downgrade(&self.id())]
+                }
+            })
+            .iter()
+            .map(|f| f.upgrade().unwrap())
+            .collect()
+    }
+
     pub fn created_at(&self) -> &Option<Arc<ScalarField>> {
         self.created_at.get_or_init(|| {
             self.scalar_weak()
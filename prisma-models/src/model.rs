@@ -14,6 +14,14 @@ pub struct ModelTemplate {
     pub is_embedded: bool,
     pub fields: Vec<FieldTemplate>,
     pub manifestation: Option<String>,
+
+    /// The fields of a compound id. Empty if the model is identified by a single id field.
+    #[serde(default)]
+    pub id_field_names: Vec<String>,
+
+    /// The fields of each unique index spanning more than one field.
+    #[serde(default)]
+    pub compound_unique_field_names: Vec<Vec<String>>,
 }
 
 #[derive(DebugStub)]
@@ -21,6 +29,8 @@ pub struct Model {
     pub name: String,
     pub is_embedded: bool,
     manifestation: Option<String>,
+    pub(crate) id_field_names: Vec<String>,
+    compound_unique_field_names: Vec<Vec<String>>,
 
     fields: OnceCell<Fields>,
 
@@ -35,6 +45,8 @@ impl ModelTemplate {
             is_embedded: self.is_embedded,
             fields: OnceCell::new(),
             manifestation: self.manifestation,
+            id_field_names: self.id_field_names,
+            compound_unique_field_names: self.compound_unique_field_names,
             internal_data_model,
         });
 
@@ -60,7 +72,13 @@ impl PartialEq for Model {
 }
 
 impl Model {
-    pub fn generate_id(&self) -> GraphqlId {
+    /// Generates a new id for a record. Compound ids and ids of other types than ID and UUID can't be
+    /// generated, they must be given on create or are generated by the database.
+    pub fn generate_id(&self) -> Option<GraphqlId> {
+        if self.has_compound_id() {
+            return None;
+        }
+
         match self.fields().id().type_identifier {
             // This will panic when:
             //
@@ -69,10 +87,9 @@ impl Model {
             // - Time cannot be converted to a string.
             //
             // Panic is a better choice than bubbling this up
-            TypeIdentifier::GraphQLID => GraphqlId::String(cuid::cuid().unwrap()),
-            TypeIdentifier::UUID => GraphqlId::UUID(Uuid::new_v4()),
-            TypeIdentifier::Int => panic!("Cannot generate integer ids."),
-            t => panic!("You shouldn't even use ids of type {:?}", t),
+            TypeIdentifier::GraphQLID => Some(GraphqlId::String(cuid::cuid().unwrap())),
+            TypeIdentifier::UUID => Some(GraphqlId::UUID(Uuid::new_v4())),
+            _ => None,
         }
     }
 
@@ -105,7 +122,48 @@ impl Model {
             .expect("InternalDataModel does not exist anymore. Parent internal_data_model is deleted without deleting the child internal_data_model.")
     }
 
+    /// The column of the single id field. Relations are only supported between models with a single id,
+    /// see `Fields::id`.
     pub fn id_column(&self) -> Column<'static> {
         self.fields().id().as_column()
     }
+
+    /// True if the model is identified by more than one field.
+    pub fn has_compound_id(&self) -> bool {
+        self.id_field_names.len() > 1
+    }
+
+    /// The names of the fields identifying a record of the model.
+    pub fn id_field_names(&self) -> Vec<String> {
+        self.fields().id_fields().iter().map(|f| f.name.clone()).collect()
+    }
+
+    pub fn id_columns(&self) -> Vec<Column<'static>> {
+        self.fields().id_fields().iter().map(|f| f.as_column()).collect()
+    }
+
+    /// All sets of more than one field identifying a record: the compound id and the compound unique indexes.
+    /// Compound unique indexes only consist of scalar fields, the datamodel conversion rejects the others.
+    pub fn compound_unique_fields(&self) -> Vec<Vec<Arc<ScalarField>>> {
+        let mut result = Vec::new();
+
+        if self.has_compound_id() {
+            result.push(self.fields().id_fields());
+        }
+
+        for field_names in self.compound_unique_field_names.iter() {
+            if field_names != &self.id_field_names {
+                let fields: DomainResult<Vec<Arc<ScalarField>>> = field_names
+                    .iter()
+                    .map(|name| self.fields().find_from_scalar(name))
+                    .collect();
+
+                if let Ok(fields) = fields {
+                    result.push(fields);
+                }
+            }
+        }
+
+        result
+    }
 }
@@ -1,4 +1,4 @@
-use crate::{ModelRef, ScalarField};
+use crate::{ModelRef, RelationField, ScalarField};
 use std::sync::Arc;
 
 pub trait IntoOrderBy {
@@ -23,6 +23,70 @@ impl SortOrder {
 
 #[derive(Clone, Debug)]
 pub struct OrderBy {
-    pub field: Arc<ScalarField>,
+    /// To-one relations traversed from the ordered model to reach the target.
+    /// Empty if the target is on the ordered model itself.
+    pub path: Vec<Arc<RelationField>>,
+    pub target: OrderByTarget,
     pub sort_order: SortOrder,
 }
+
+#[derive(Clone, Debug)]
+pub enum OrderByTarget {
+    /// Order by the value of a scalar field.
+    Field(Arc<ScalarField>),
+
+    /// Order by the number of records related via a to-many relation field.
+    RelationCount(Arc<RelationField>),
+}
+
+impl OrderBy {
+    /// Ordering by a scalar field of the ordered model itself.
+    pub fn scalar(field: Arc<ScalarField>, sort_order: SortOrder) -> Self {
+        OrderBy {
+            path: vec![],
+            target: OrderByTarget::Field(field),
+            sort_order,
+        }
+    }
+
+    /// Ordering by a scalar field of a record reached via the given to-one relations.
+    pub fn related_scalar(path: Vec<Arc<RelationField>>, field: Arc<ScalarField>, sort_order: SortOrder) -> Self {
+        OrderBy {
+            path,
+            target: OrderByTarget::Field(field),
+            sort_order,
+        }
+    }
+
+    /// Ordering by the number of records related via the given to-many relation field.
+    pub fn relation_count(path: Vec<Arc<RelationField>>, field: Arc<RelationField>, sort_order: SortOrder) -> Self {
+        OrderBy {
+            path,
+            target: OrderByTarget::RelationCount(field),
+            sort_order,
+        }
+    }
+
+    /// Returns the scalar field if the ordering is on a scalar field of the ordered model itself.
+    pub fn scalar_field(&self) -> Option<&Arc<ScalarField>> {
+        match (self.path.is_empty(), &self.target) {
+            (true, OrderByTarget::Field(field)) => Some(field),
+            _ => None,
+        }
+    }
+
+    /// The name of the ordering, e.g. `name`, `author_name` or `posts_count`.
+    pub fn name(&self) -> String {
+        let mut parts: Vec<&str> = self.path.iter().map(|rf| rf.name.as_str()).collect();
+
+        match &self.target {
+            OrderByTarget::Field(field) => parts.push(&field.name),
+            OrderByTarget::RelationCount(field) => {
+                parts.push(&field.name);
+                parts.push("count");
+            }
+        };
+
+        parts.join("_")
+    }
+}
@@ -5,17 +5,23 @@ use std::collections::{btree_map::Keys, BTreeMap};
 #[derive(Debug, PartialEq, Clone, Default)]
 pub struct PrismaArgs {
     pub args: BTreeMap<String, PrismaValue>,
+
+    /// Values to append to scalar lists stored in native arrays, by field name.
+    pub pushes: BTreeMap<String, Vec<PrismaValue>>,
 }
 
 impl From<BTreeMap<String, PrismaValue>> for PrismaArgs {
     fn from(args: BTreeMap<String, PrismaValue>) -> Self {
-        Self { args }
+        Self {
+            args,
+            pushes: BTreeMap::new(),
+        }
     }
 }
 
 impl PrismaArgs {
     pub fn new() -> Self {
-        Self { args: BTreeMap::new() }
+        Self::default()
     }
 
     pub fn insert<T, V>(&mut self, key: T, arg: V)
@@ -26,6 +32,17 @@ impl PrismaArgs {
         self.args.insert(key.into(), arg.into());
     }
 
+    pub fn push<T>(&mut self, key: T, values: Vec<PrismaValue>)
+    where
+        T: Into<String>,
+    {
+        self.pushes.entry(key.into()).or_insert_with(Vec::new).extend(values);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.args.is_empty() && self.pushes.is_empty()
+    }
+
     pub fn has_arg_for(&self, field: &str) -> bool {
         self.args.contains_key(field)
     }
@@ -61,7 +78,7 @@ impl PrismaArgs {
     }
 
     pub fn update_datetimes(&mut self, model: ModelRef, list_causes_update: bool) {
-        if !self.args.is_empty() || list_causes_update {
+        if !self.is_empty() || list_causes_update {
             if let Some(field) = model.fields().updated_at() {
                 self.args
                     .insert(field.name.to_string(), PrismaValue::DateTime(Utc::now()));
@@ -1,4 +1,5 @@
 use crate::{DomainError, DomainResult, EnumValue};
+use bigdecimal::BigDecimal;
 use chrono::prelude::*;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
@@ -15,6 +16,32 @@ pub enum GraphqlId {
     String(String),
     Int(usize),
     UUID(Uuid),
+    /// The id of a model with a compound primary key, one part per id field.
+    Compound(Vec<GraphqlId>),
+}
+
+impl GraphqlId {
+    /// The parts of the id, in the order of the id fields of the model.
+    pub fn parts(&self) -> Vec<&GraphqlId> {
+        match self {
+            GraphqlId::Compound(parts) => parts.iter().collect(),
+            id => vec![id],
+        }
+    }
+}
+
+impl fmt::Display for GraphqlId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GraphqlId::String(x) => x.fmt(f),
+            GraphqlId::Int(x) => x.fmt(f),
+            GraphqlId::UUID(x) => x.fmt(f),
+            GraphqlId::Compound(parts) => {
+                let parts: Vec<String> = parts.iter().map(|part| part.to_string()).collect();
+                write!(f, "({})", parts.join(", "))
+            }
+        }
+    }
 }
 
 #[cfg(feature = "sql")]
@@ -37,6 +64,10 @@ pub enum PrismaValue {
     #[serde(rename = "float")]
     Float(f64),
 
+    /// An arbitrary-precision decimal number, e.g. of a money column.
+    #[serde(rename = "decimal")]
+    Decimal(BigDecimal),
+
     #[serde(rename = "bool")]
     Boolean(bool),
 
@@ -63,6 +94,10 @@ pub enum PrismaValue {
 
     #[serde(rename = "list")]
     List(PrismaListValue),
+
+    /// Binary data, transported as base64 encoded string.
+    #[serde(rename = "bytes")]
+    Bytes(Vec<u8>),
 }
 
 impl PrismaValue {
@@ -79,6 +114,7 @@ impl fmt::Display for PrismaValue {
         match self {
             PrismaValue::String(x) => x.fmt(f),
             PrismaValue::Float(x) => x.fmt(f),
+            PrismaValue::Decimal(x) => x.fmt(f),
             PrismaValue::Boolean(x) => x.fmt(f),
             PrismaValue::DateTime(x) => x.fmt(f),
             PrismaValue::Enum(x) => x.as_string().fmt(f),
@@ -86,15 +122,12 @@ impl fmt::Display for PrismaValue {
             PrismaValue::Int(x) => x.fmt(f),
             PrismaValue::Null => "null".fmt(f),
             PrismaValue::Uuid(x) => x.fmt(f),
-            PrismaValue::GraphqlId(x) => match x {
-                GraphqlId::String(x) => x.fmt(f),
-                GraphqlId::Int(x) => x.fmt(f),
-                GraphqlId::UUID(x) => x.fmt(f),
-            },
+            PrismaValue::GraphqlId(x) => x.fmt(f),
             PrismaValue::List(x) => {
                 let as_string = format!("{:?}", x);
                 as_string.fmt(f)
             }
+            PrismaValue::Bytes(x) => base64::encode(x).fmt(f),
         }
     }
 }
@@ -123,6 +156,12 @@ impl From<f32> for PrismaValue {
     }
 }
 
+impl From<BigDecimal> for PrismaValue {
+    fn from(d: BigDecimal) -> Self {
+        PrismaValue::Decimal(d)
+    }
+}
+
 impl From<bool> for PrismaValue {
     fn from(b: bool) -> Self {
         PrismaValue::Boolean(b)
@@ -222,6 +261,9 @@ impl TryFrom<PrismaValue> for i64 {
     }
 }
 
+/// Compound ids have no single value, they are compared column by column with the conditions of their
+/// record finder. Only relations and scalar lists compare ids as values, and both are rejected for models
+/// with a compound id when converting the datamodel.
 #[cfg(feature = "sql")]
 impl<'a> From<GraphqlId> for DatabaseValue<'a> {
     fn from(id: GraphqlId) -> Self {
@@ -229,6 +271,7 @@ impl<'a> From<GraphqlId> for DatabaseValue<'a> {
             GraphqlId::String(s) => s.into(),
             GraphqlId::Int(i) => (i as i64).into(),
             GraphqlId::UUID(u) => u.to_string().into(),
+            GraphqlId::Compound(_) => panic!("Compound ids must be compared by their record finder."),
         }
     }
 }
@@ -243,20 +286,35 @@ impl<'a> From<&GraphqlId> for DatabaseValue<'a> {
 #[cfg(feature = "sql")]
 impl<'a> From<PrismaValue> for DatabaseValue<'a> {
     fn from(pv: PrismaValue) -> Self {
-        match pv {
-            PrismaValue::String(s) => s.into(),
-            PrismaValue::Float(f) => (f as f64).into(),
-            PrismaValue::Boolean(b) => b.into(),
-            PrismaValue::DateTime(d) => d.into(),
-            PrismaValue::Enum(e) => e.as_string().into(),
-            PrismaValue::Json(j) => j.to_string().into(),
-            PrismaValue::Int(i) => (i as i64).into(),
-            PrismaValue::Null => DatabaseValue::Parameterized(ParameterizedValue::Null),
-            PrismaValue::Uuid(u) => u.to_string().into(),
-            PrismaValue::GraphqlId(id) => id.into(),
-            PrismaValue::List(Some(l)) => l.into(),
-            PrismaValue::List(_) => panic!("List values are not supported here"),
+        DatabaseValue::Parameterized(parameterized_value(pv))
+    }
+}
+
+#[cfg(feature = "sql")]
+fn parameterized_value<'a>(pv: PrismaValue) -> ParameterizedValue<'a> {
+    match pv {
+        PrismaValue::String(s) => ParameterizedValue::Text(s.into()),
+        PrismaValue::Float(f) => ParameterizedValue::Real(f as f64),
+        PrismaValue::Decimal(d) => ParameterizedValue::Text(d.to_string().into()),
+        PrismaValue::Boolean(b) => ParameterizedValue::Boolean(b),
+        PrismaValue::DateTime(d) => ParameterizedValue::DateTime(d),
+        // The binary format of a Postgres enum is its label, so native enum columns take text parameters.
+        PrismaValue::Enum(e) => ParameterizedValue::Text(e.as_string().into()),
+        PrismaValue::Json(j) => ParameterizedValue::Text(j.to_string().into()),
+        PrismaValue::Int(i) => ParameterizedValue::Integer(i as i64),
+        PrismaValue::Null => ParameterizedValue::Null,
+        PrismaValue::Uuid(u) => ParameterizedValue::Text(u.to_string().into()),
+        PrismaValue::GraphqlId(GraphqlId::String(s)) => ParameterizedValue::Text(s.into()),
+        PrismaValue::GraphqlId(GraphqlId::Int(i)) => ParameterizedValue::Integer(i as i64),
+        PrismaValue::GraphqlId(GraphqlId::UUID(u)) => ParameterizedValue::Text(u.to_string().into()),
+        PrismaValue::GraphqlId(GraphqlId::Compound(_)) => {
+            panic!("Compound ids must be compared by their record finder.")
         }
+        // Lists are bound as one array parameter, which is what native array columns take.
+        PrismaValue::List(Some(l)) => ParameterizedValue::Array(l.into_iter().map(parameterized_value).collect()),
+        PrismaValue::List(_) => panic!("List values are not supported here"),
+        // The database driver has no binary parameters, bytes are stored in their transport encoding.
+        PrismaValue::Bytes(b) => ParameterizedValue::Text(base64::encode(&b).into()),
     }
 }
 
@@ -6,25 +6,45 @@ use std::sync::{Arc, Weak};
 pub type RelationRef = Arc<Relation>;
 pub type RelationWeakRef = Weak<Relation>;
 
+/// What happens to the records of one side of a relation when the related record
+/// on the other side is deleted, or its id is updated.
 #[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum OnDelete {
     SetNull,
     Cascade,
+    Restrict,
+    SetDefault,
 }
 
 impl OnDelete {
     pub fn is_cascade(self) -> bool {
         match self {
             OnDelete::Cascade => true,
-            OnDelete::SetNull => false,
+            _ => false,
         }
     }
 
     pub fn is_set_null(self) -> bool {
         match self {
-            OnDelete::Cascade => false,
             OnDelete::SetNull => true,
+            _ => false,
+        }
+    }
+
+    /// True if the references to the record are replaced, with null or the default value of the
+    /// relation field.
+    pub fn is_unlink(self) -> bool {
+        match self {
+            OnDelete::SetNull | OnDelete::SetDefault => true,
+            _ => false,
+        }
+    }
+
+    pub fn is_restrict(self) -> bool {
+        match self {
+            OnDelete::Restrict => true,
+            _ => false,
         }
     }
 }
@@ -66,6 +86,10 @@ pub struct RelationTemplate {
     pub name: String,
     pub model_a_on_delete: OnDelete,
     pub model_b_on_delete: OnDelete,
+    #[serde(default = "default_on_update")]
+    pub model_a_on_update: OnDelete,
+    #[serde(default = "default_on_update")]
+    pub model_b_on_update: OnDelete,
     pub manifestation: Option<RelationLinkManifestation>, // TODO: remove the option after the switch to v2 is completed
 
     #[serde(rename = "modelAId")]
@@ -86,6 +110,9 @@ pub struct Relation {
     pub model_a_on_delete: OnDelete,
     pub model_b_on_delete: OnDelete,
 
+    pub model_a_on_update: OnDelete,
+    pub model_b_on_update: OnDelete,
+
     model_a: OnceCell<ModelWeakRef>,
     model_b: OnceCell<ModelWeakRef>,
 
@@ -98,6 +125,11 @@ pub struct Relation {
     pub internal_data_model: InternalDataModelWeakRef,
 }
 
+/// Foreign keys reject updates of referenced ids by default.
+fn default_on_update() -> OnDelete {
+    OnDelete::Restrict
+}
+
 impl RelationTemplate {
     pub fn build(self, internal_data_model: InternalDataModelWeakRef) -> RelationRef {
         let relation = Relation {
@@ -107,6 +139,8 @@ impl RelationTemplate {
             model_b_name: self.model_b_name,
             model_a_on_delete: self.model_a_on_delete,
             model_b_on_delete: self.model_b_on_delete,
+            model_a_on_update: self.model_a_on_update,
+            model_b_on_update: self.model_b_on_update,
             model_a: OnceCell::new(),
             model_b: OnceCell::new(),
             field_a: OnceCell::new(),
@@ -92,7 +92,7 @@ impl SelectedFields {
     }
 
     pub fn id(model: ModelRef) -> Self {
-        Self::from(model.fields().id())
+        Self::from(model.fields().id_fields())
     }
 
     pub fn add_scalar(&mut self, field: Arc<ScalarField>) {
@@ -103,7 +103,7 @@ impl SelectedFields {
     pub fn columns(&self) -> &[Column<'static>] {
         self.columns
             .get_or_init(|| {
-                let mut result: Vec<Column<'static>> = self.scalar_non_list().iter().map(|f| f.as_column()).collect();
+                let mut result: Vec<Column<'static>> = self.scalar_columns().iter().map(|f| f.as_column()).collect();
 
                 for rf in self.relation_inlined().iter() {
                     result.push(rf.as_column());
@@ -133,7 +133,7 @@ impl SelectedFields {
     }
 
     pub fn names(&self) -> Vec<String> {
-        let mut result: Vec<String> = self.scalar_non_list().iter().map(|f| f.name.clone()).collect();
+        let mut result: Vec<String> = self.scalar_columns().iter().map(|f| f.name.clone()).collect();
 
         for rf in self.relation_inlined().iter() {
             result.push(rf.name.clone());
@@ -148,7 +148,7 @@ impl SelectedFields {
     }
 
     pub fn type_identifiers(&self) -> Vec<TypeIdentifier> {
-        let mut result: Vec<TypeIdentifier> = self.scalar_non_list().iter().map(|sf| sf.type_identifier).collect();
+        let mut result: Vec<TypeIdentifier> = self.scalar_columns().iter().map(|sf| sf.type_identifier).collect();
 
         for rf in self.relation_inlined().iter() {
             result.push(rf.type_identifier);
@@ -201,10 +201,20 @@ impl SelectedFields {
             .collect()
     }
 
+    /// Scalar fields stored in columns of the model table, including lists stored in native arrays.
+    pub fn scalar_columns(&self) -> Vec<Arc<ScalarField>> {
+        self.scalar
+            .iter()
+            .filter(|sf| !sf.field.is_list || sf.field.is_embedded_list())
+            .map(|sf| sf.field.clone())
+            .collect()
+    }
+
+    /// Scalar lists stored in separate scalar list tables.
     pub fn scalar_lists(&self) -> Vec<Arc<ScalarField>> {
         self.scalar
             .iter()
-            .filter(|sf| sf.field.is_list)
+            .filter(|sf| sf.field.is_list && !sf.field.is_embedded_list())
             .map(|sf| sf.field.clone())
             .collect()
     }
@@ -49,8 +49,11 @@ fn models_with_only_scalar_fields() {
                 id Int @id
                 int Int
                 float Float
+                decimal Decimal
                 boolean Boolean
                 dateTime DateTime
+                bytes Bytes
+                json Json
                 stringOpt String?
                 intList Int[]
             }
@@ -74,6 +77,10 @@ fn models_with_only_scalar_fields() {
         .assert_scalar_field("float")
         .assert_type_identifier(TypeIdentifier::Float)
         .assert_no_behaviour();
+    model
+        .assert_scalar_field("decimal")
+        .assert_type_identifier(TypeIdentifier::Decimal)
+        .assert_no_behaviour();
     model
         .assert_scalar_field("boolean")
         .assert_type_identifier(TypeIdentifier::Boolean)
@@ -82,6 +89,14 @@ fn models_with_only_scalar_fields() {
         .assert_scalar_field("dateTime")
         .assert_type_identifier(TypeIdentifier::DateTime)
         .assert_no_behaviour();
+    model
+        .assert_scalar_field("bytes")
+        .assert_type_identifier(TypeIdentifier::Bytes)
+        .assert_no_behaviour();
+    model
+        .assert_scalar_field("json")
+        .assert_type_identifier(TypeIdentifier::Json)
+        .assert_no_behaviour();
     model
         .assert_scalar_field("stringOpt")
         .assert_type_identifier(TypeIdentifier::String)
@@ -153,6 +168,192 @@ fn unique_works() {
         .assert_unique();
 }
 
+#[test]
+fn fulltext_indices_work() {
+    let datamodel = convert(
+        r#"
+            model Post {
+                id String @id @default(cuid())
+                title String
+                body String @map("content")
+                tags String
+
+                @@fulltext([title, body])
+                @@fulltext([tags], name: "PostTags")
+            }
+        "#,
+    );
+
+    let model = datamodel.assert_model("Post");
+    let title_index = FulltextIndex {
+        name: "Post.title_content".to_string(),
+        columns: vec!["title".to_string(), "content".to_string()],
+    };
+
+    assert_eq!(
+        model.assert_scalar_field("title").fulltext_index,
+        Some(title_index.clone())
+    );
+    assert_eq!(model.assert_scalar_field("body").fulltext_index, Some(title_index));
+    assert_eq!(
+        model.assert_scalar_field("tags").fulltext_index,
+        Some(FulltextIndex {
+            name: "PostTags".to_string(),
+            columns: vec!["tags".to_string()],
+        })
+    );
+    assert_eq!(model.assert_scalar_field("id").fulltext_index, None);
+}
+
+#[test]
+fn compound_ids_must_work() {
+    let datamodel = convert(
+        r#"
+            model Membership {
+                tenant String
+                user Int
+                role String
+
+                @@id([tenant, user])
+            }
+        "#,
+    );
+
+    let model = datamodel.assert_model("Membership");
+    let id_fields: Vec<String> = model.fields().id_fields().iter().map(|f| f.name.clone()).collect();
+
+    assert!(model.has_compound_id());
+    assert_eq!(id_fields, vec!["tenant".to_string(), "user".to_string()]);
+    assert_eq!(model.id_field_names(), id_fields);
+}
+
+#[test]
+fn relations_to_compound_id_models_must_be_rejected() {
+    let error = convert_error(
+        r#"
+            model Membership {
+                tenant String
+                user Int
+                notes Note[]
+
+                @@id([tenant, user])
+            }
+
+            model Note {
+                id Int @id
+                membership Membership
+            }
+        "#,
+    );
+
+    match error {
+        DomainError::CompoundIdRelation { compound_id_model, .. } => assert_eq!(compound_id_model, "Membership"),
+        err => panic!("Unexpected error: {}", err),
+    }
+}
+
+#[test]
+fn scalar_lists_on_compound_id_models_must_be_rejected() {
+    let error = convert_error(
+        r#"
+            model Membership {
+                tenant String
+                user Int
+                roles String[]
+
+                @@id([tenant, user])
+            }
+        "#,
+    );
+
+    match error {
+        DomainError::CompoundIdScalarList { field, model } => {
+            assert_eq!(field, "roles");
+            assert_eq!(model, "Membership");
+        }
+        err => panic!("Unexpected error: {}", err),
+    }
+}
+
+#[test]
+fn compound_ids_with_unsupported_field_types_must_be_rejected() {
+    let error = convert_error(
+        r#"
+            model Event {
+                source String
+                at DateTime
+
+                @@id([source, at])
+            }
+        "#,
+    );
+
+    match error {
+        DomainError::UnsupportedCompoundIdField { name, model } => {
+            assert_eq!(name, "at");
+            assert_eq!(model, "Event");
+        }
+        err => panic!("Unexpected error: {}", err),
+    }
+}
+
+#[test]
+fn compound_unique_indexes_must_work() {
+    let datamodel = convert(
+        r#"
+            model User {
+                id Int @id
+                email String
+                tenantId String
+                name String
+
+                @@unique([email, tenantId])
+                @@index([name, tenantId])
+            }
+        "#,
+    );
+
+    let model = datamodel.assert_model("User");
+    let compound_uniques: Vec<Vec<String>> = model
+        .compound_unique_fields()
+        .iter()
+        .map(|fields| fields.iter().map(|f| f.name.clone()).collect())
+        .collect();
+
+    assert_eq!(
+        compound_uniques,
+        vec![vec!["email".to_string(), "tenantId".to_string()]]
+    );
+}
+
+#[test]
+fn compound_unique_indexes_with_relation_fields_must_be_rejected() {
+    let error = convert_error(
+        r#"
+            model Like {
+                id Int @id
+                user User
+                postId Int
+
+                @@unique([user, postId])
+            }
+
+            model User {
+                id Int @id
+                likes Like[]
+            }
+        "#,
+    );
+
+    match error {
+        DomainError::CompoundUniqueRelationField { field, model } => {
+            assert_eq!(field, "user");
+            assert_eq!(model, "Like");
+        }
+        err => panic!("Unexpected error: {}", err),
+    }
+}
+
 #[test]
 fn uuid_fields_must_work() {
     let datamodel = convert(
@@ -417,10 +618,19 @@ fn ambiguous_relations() {
 
 fn convert(datamodel: &str) -> Arc<InternalDataModel> {
     let datamodel = dbg!(datamodel::parse_datamodel(datamodel).unwrap());
-    let template = DatamodelConverter::convert(&datamodel);
+    let template = DatamodelConverter::convert(&datamodel).unwrap();
     template.build("not_important".to_string())
 }
 
+fn convert_error(datamodel: &str) -> DomainError {
+    let datamodel = datamodel::parse_datamodel(datamodel).unwrap();
+
+    match DatamodelConverter::convert(&datamodel) {
+        Ok(_) => panic!("Expected the conversion to fail."),
+        Err(err) => err,
+    }
+}
+
 trait DatamodelAssertions {
     fn assert_model(&self, name: &str) -> Arc<Model>;
     fn assert_relation(&self, name: &str) -> Arc<Relation>;
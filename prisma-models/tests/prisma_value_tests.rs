@@ -0,0 +1,35 @@
+#![cfg(feature = "sql")]
+use prisma_models::*;
+use prisma_query::ast::*;
+
+#[test]
+fn enum_values_must_be_bound_as_text() {
+    let value = DatabaseValue::from(PrismaValue::Enum(EnumValue::from("happy".to_string())));
+
+    match value {
+        DatabaseValue::Parameterized(ParameterizedValue::Text(e)) => assert_eq!(e, "happy"),
+        value => panic!("Expected a text parameter, got {:?}", value),
+    }
+}
+
+#[test]
+fn lists_must_be_bound_as_one_array() {
+    let value = DatabaseValue::from(PrismaValue::List(Some(vec![
+        PrismaValue::String("a".to_string()),
+        PrismaValue::String("b".to_string()),
+    ])));
+
+    match value {
+        DatabaseValue::Parameterized(ParameterizedValue::Array(values)) => {
+            let values: Vec<PrismaValue> = values.into_iter().map(PrismaValue::from).collect();
+            assert_eq!(
+                values,
+                vec![
+                    PrismaValue::String("a".to_string()),
+                    PrismaValue::String("b".to_string())
+                ]
+            )
+        }
+        value => panic!("Expected an array parameter, got {:?}", value),
+    }
+}
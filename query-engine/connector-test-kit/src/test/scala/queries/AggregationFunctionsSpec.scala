@@ -0,0 +1,107 @@
+package queries
+
+import org.scalatest.{FlatSpec, Matchers}
+import util._
+
+class AggregationFunctionsSpec extends FlatSpec with Matchers with ApiSpecBase {
+
+  val project = SchemaDsl.fromStringV11() {
+    """
+      |model Item {
+      |  id       String @id @default(cuid())
+      |  category String
+      |  quantity Int
+      |  price    Float
+      |}
+    """
+  }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+    createItem("a", 1, 1.5)
+    createItem("a", 2, 2.5)
+    createItem("b", 3, 3.0)
+    createItem("c", 4, 4.0)
+  }
+
+  "Summing an Int field" should "return an Int" in {
+    val result = server.query(
+      """
+        |{
+        |  aggregateItem {
+        |    count
+        |    sum { quantity price }
+        |    avg { quantity }
+        |    min { quantity }
+        |    max { price }
+        |  }
+        |}
+      """,
+      project
+    )
+
+    result.toString should be(
+      """{"data":{"aggregateItem":{"count":4,"sum":{"quantity":10,"price":11},"avg":{"quantity":2.5},"min":{"quantity":1},"max":{"price":4}}}}""")
+  }
+
+  "Grouping" should "aggregate each group in ascending group order" in {
+    val result = server.query(
+      """
+        |{
+        |  groupByItem(by: [category]) {
+        |    category
+        |    _count
+        |    _sum { quantity }
+        |  }
+        |}
+      """,
+      project
+    )
+
+    result.toString should be(
+      """{"data":{"groupByItem":[{"category":"a","_count":2,"_sum":{"quantity":3}},{"category":"b","_count":1,"_sum":{"quantity":3}},{"category":"c","_count":1,"_sum":{"quantity":4}}]}}""")
+  }
+
+  "Grouping with last" should "return the last groups in ascending group order" in {
+    val result = server.query(
+      """
+        |{
+        |  groupByItem(by: [category], last: 2) {
+        |    category
+        |    _max { quantity }
+        |  }
+        |}
+      """,
+      project
+    )
+
+    result.toString should be("""{"data":{"groupByItem":[{"category":"b","_max":{"quantity":3}},{"category":"c","_max":{"quantity":4}}]}}""")
+  }
+
+  "Grouping with first and last" should "fail" in {
+    server.queryThatMustFail(
+      """
+        |{
+        |  groupByItem(by: [category], first: 1, last: 1) {
+        |    category
+        |  }
+        |}
+      """,
+      project,
+      errorCode = "P2009",
+      errorContains = "Groups can either be paginated with 'first' or with 'last', not both"
+    )
+  }
+
+  def createItem(category: String, quantity: Int, price: Double) = {
+    server.query(
+      s"""mutation {
+         |  createItem(data: {category: "$category", quantity: $quantity, price: $price}) {
+         |    id
+         |  }
+         |}""".stripMargin,
+      project
+    )
+  }
+}
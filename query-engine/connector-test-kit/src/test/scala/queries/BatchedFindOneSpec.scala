@@ -0,0 +1,72 @@
+package queries
+
+import org.scalatest.{FlatSpec, Matchers}
+import util._
+
+class BatchedFindOneSpec extends FlatSpec with Matchers with ApiSpecBase {
+
+  val project = SchemaDsl.fromStringV11() {
+    """
+      |model Artist {
+      |  id     String  @id @default(cuid())
+      |  name   String  @unique
+      |  albums Album[]
+      |}
+      |
+      |model Album {
+      |  id     String @id @default(cuid())
+      |  title  String @unique
+      |  artist Artist
+      |}
+    """
+  }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+
+    server.query(
+      """
+        |mutation {
+        |  a: createArtist(data: {name: "ArtistA", albums: { create: [{title: "Album1"}, {title: "Album2"}] }}){ id }
+        |  b: createArtist(data: {name: "ArtistB"}){ id }
+        |}
+      """,
+      project
+    )
+  }
+
+  "findOne queries on the same field" should "be answered in document order with their own selections" in {
+    val result = server.query(
+      """
+        |{
+        |  b: artist(where: {name: "ArtistB"}) { name }
+        |  missing: artist(where: {name: "ArtistC"}) { name }
+        |  a: artist(where: {name: "ArtistA"}) { id name albums { title } }
+        |  again: artist(where: {name: "ArtistB"}) { albums { title } }
+        |}
+      """,
+      project
+    )
+
+    result.pathAsString("data.b.name") should be("ArtistB")
+    result.pathAsJsValue("data.missing").toString should be("null")
+    result.pathAsString("data.a.name") should be("ArtistA")
+    result.pathAsJsValue("data.a.albums").toString should be("""[{"title":"Album1"},{"title":"Album2"}]""")
+    result.pathAsJsValue("data.again").toString should be("""{"albums":[]}""")
+  }
+
+  "findOne queries that all miss" should "return null for every query" in {
+    val result = server.query(
+      """
+        |{
+        |  x: album(where: {title: "nope"}) { title }
+        |  y: album(where: {title: "neither"}) { title }
+        |}
+      """,
+      project
+    )
+
+    result.toString should be("""{"data":{"x":null,"y":null}}""")
+  }
+}
@@ -0,0 +1,87 @@
+package queries
+
+import org.scalatest.{FlatSpec, Matchers}
+import util._
+
+class CompoundCursorSpec extends FlatSpec with Matchers with ApiSpecBase {
+
+  val project = SchemaDsl.fromStringV11() {
+    """
+      |model Member {
+      |  id     String @id @default(cuid())
+      |  tenant String
+      |  email  String
+      |  joined Int
+      |
+      |  @@unique([tenant, email])
+      |}
+    """
+  }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+
+    server.query(
+      """
+        |mutation {
+        |  a: createMember(data: {tenant: "acme", email: "ada@example.com", joined: 1}){ id }
+        |  b: createMember(data: {tenant: "acme", email: "alan@example.com", joined: 2}){ id }
+        |  c: createMember(data: {tenant: "initech", email: "ada@example.com", joined: 3}){ id }
+        |  d: createMember(data: {tenant: "initech", email: "grace@example.com", joined: 4}){ id }
+        |}
+      """,
+      project
+    )
+  }
+
+  "A cursor" should "select the cursor record by a compound unique" in {
+    val result = server.query(
+      """
+        |{
+        |  members(orderBy: joined_ASC, after: {tenant_email: {tenant: "initech", email: "ada@example.com"}}) { joined }
+        |}
+      """,
+      project
+    )
+
+    result.toString should be("""{"data":{"members":[{"joined":4}]}}""")
+
+    val before = server.query(
+      """
+        |{
+        |  members(orderBy: joined_DESC, before: {tenant_email: {tenant: "acme", email: "alan@example.com"}}, last: 1) { joined }
+        |}
+      """,
+      project
+    )
+
+    before.toString should be("""{"data":{"members":[{"joined":3}]}}""")
+  }
+
+  "A cursor" should "respect the ordering by the fields of the compound unique" in {
+    val result = server.query(
+      """
+        |{
+        |  members(orderBy: email_ASC, after: {tenant_email: {tenant: "acme", email: "alan@example.com"}}) { tenant email }
+        |}
+      """,
+      project
+    )
+
+    result.toString should be("""{"data":{"members":[{"tenant":"initech","email":"grace@example.com"}]}}""")
+  }
+
+  "A cursor selecting no record" should "return an empty page" in {
+    val result = server.query(
+      """
+        |{
+        |  members(after: {tenant_email: {tenant: "acme", email: "nobody@example.com"}}) { joined }
+        |}
+      """,
+      project
+    )
+
+    result.toString should be("""{"data":{"members":[]}}""")
+  }
+}
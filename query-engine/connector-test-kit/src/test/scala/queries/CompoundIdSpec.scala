@@ -0,0 +1,80 @@
+package queries
+
+import org.scalatest.{FlatSpec, Matchers}
+import util._
+
+class CompoundIdSpec extends FlatSpec with Matchers with ApiSpecBase {
+
+  val project = SchemaDsl.fromStringV11() {
+    """
+      |model Membership {
+      |  tenant String
+      |  user   Int
+      |  role   String
+      |
+      |  @@id([tenant, user])
+      |}
+    """
+  }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+  }
+
+  override def beforeEach(): Unit = database.truncateProjectTables(project)
+
+  "Records with a compound id" should "be found, updated and deleted by all id fields" in {
+    server.query(
+      """
+        |mutation {
+        |  a: createMembership(data: {tenant: "acme", user: 1, role: "admin"}){ tenant user }
+        |  b: createMembership(data: {tenant: "acme", user: 2, role: "member"}){ tenant user }
+        |}
+      """,
+      project
+    )
+
+    server
+      .query("""{ membership(where: {tenant_user: {tenant: "acme", user: 2}}) { role } }""", project)
+      .toString should be("""{"data":{"membership":{"role":"member"}}}""")
+
+    server
+      .query(
+        """mutation { updateMembership(where: {tenant_user: {tenant: "acme", user: 2}}, data: {role: "owner"}) { role } }""",
+        project
+      )
+      .toString should be("""{"data":{"updateMembership":{"role":"owner"}}}""")
+
+    server
+      .query("""mutation { deleteMembership(where: {tenant_user: {tenant: "acme", user: 1}}) { role } }""", project)
+      .toString should be("""{"data":{"deleteMembership":{"role":"admin"}}}""")
+
+    server.query("""{ memberships(orderBy: user_ASC) { user role } }""", project).toString should be(
+      """{"data":{"memberships":[{"user":2,"role":"owner"}]}}""")
+  }
+
+  "findOne queries by a compound id" should "not be mixed up when asked for in the same document" in {
+    server.query(
+      """
+        |mutation {
+        |  a: createMembership(data: {tenant: "acme", user: 1, role: "admin"}){ user }
+        |  b: createMembership(data: {tenant: "initech", user: 1, role: "member"}){ user }
+        |}
+      """,
+      project
+    )
+
+    val result = server.query(
+      """
+        |{
+        |  b: membership(where: {tenant_user: {tenant: "initech", user: 1}}) { role }
+        |  a: membership(where: {tenant_user: {tenant: "acme", user: 1}}) { role }
+        |}
+      """,
+      project
+    )
+
+    result.toString should be("""{"data":{"b":{"role":"member"},"a":{"role":"admin"}}}""")
+  }
+}
@@ -0,0 +1,61 @@
+package queries
+
+import org.scalatest.{FlatSpec, Matchers}
+import util._
+
+class DecimalSpec extends FlatSpec with Matchers with ApiSpecBase {
+
+  val project = SchemaDsl.fromStringV11() {
+    """
+      |model Item {
+      |  id     String  @id @default(cuid())
+      |  name   String  @unique
+      |  amount Decimal
+      |}
+    """
+  }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+
+    server.query(
+      """
+        |mutation {
+        |  a: createItem(data: {name: "a", amount: "10.25"}){ id }
+        |  b: createItem(data: {name: "b", amount: "9.5"}){ id }
+        |  c: createItem(data: {name: "c", amount: "100"}){ id }
+        |}
+      """,
+      project
+    )
+  }
+
+  "Decimal values" should "be returned as strings" in {
+    val result = server.query("""{ item(where: {name: "a"}) { amount } }""", project)
+
+    result.toString should be("""{"data":{"item":{"amount":"10.25"}}}""")
+  }
+
+  "Decimal values" should "be ordered numerically" in {
+    val result = server.query("""{ items(orderBy: amount_ASC) { name } }""", project)
+
+    result.toString should be("""{"data":{"items":[{"name":"b"},{"name":"a"},{"name":"c"}]}}""")
+  }
+
+  "Decimal filters" should "compare numerically" in {
+    val result = server.query(
+      """
+        |{
+        |  gt: items(where: {amount_gt: "9.75"}, orderBy: name_ASC) { name }
+        |  lte: items(where: {amount_lte: "10.25"}, orderBy: name_ASC) { name }
+        |  equals: items(where: {amount: "100"}) { name }
+        |}
+      """,
+      project
+    )
+
+    result.toString should be(
+      """{"data":{"gt":[{"name":"a"},{"name":"c"}],"lte":[{"name":"a"},{"name":"b"}],"equals":[{"name":"c"}]}}""")
+  }
+}
@@ -0,0 +1,100 @@
+package queries
+
+import org.scalatest.{FlatSpec, Matchers}
+import util._
+
+class MultiFieldOrderBySpec extends FlatSpec with Matchers with ApiSpecBase {
+
+  val project = SchemaDsl.fromStringV11() {
+    """
+      |model Person {
+      |  id        String @id @default(cuid())
+      |  nr        Int    @unique
+      |  firstName String
+      |  lastName  String
+      |}
+    """
+  }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+
+    createPerson(1, "Ada", "Lovelace")
+    createPerson(2, "Alan", "Turing")
+    createPerson(3, "Grace", "Hopper")
+    createPerson(4, "Alan", "Kay")
+    createPerson(5, "Ada", "Byron")
+  }
+
+  "Ordering by multiple fields" should "sort by the fields in the given order" in {
+    val result = server.query(
+      """
+        |{
+        |  persons(orderBy: [firstName_ASC, lastName_DESC]) {
+        |    nr
+        |  }
+        |}
+      """,
+      project
+    )
+
+    result.toString should be("""{"data":{"persons":[{"nr":1},{"nr":5},{"nr":2},{"nr":4},{"nr":3}]}}""")
+  }
+
+  "Ordering by multiple fields" should "keep cursors stable with compound sort keys" in {
+    val result = server.query(
+      """
+        |{
+        |  persons(orderBy: [firstName_ASC, lastName_DESC], after: {nr: 5}, first: 2) {
+        |    nr
+        |  }
+        |}
+      """,
+      project
+    )
+
+    result.toString should be("""{"data":{"persons":[{"nr":2},{"nr":4}]}}""")
+
+    val before = server.query(
+      """
+        |{
+        |  persons(orderBy: [firstName_ASC, lastName_DESC], before: {nr: 4}, last: 2) {
+        |    nr
+        |  }
+        |}
+      """,
+      project
+    )
+
+    before.toString should be("""{"data":{"persons":[{"nr":5},{"nr":2}]}}""")
+  }
+
+  "Ordering by multiple fields" should "break ties by id" in {
+    createPerson(6, "Ada", "Lovelace")
+
+    val result = server.query(
+      """
+        |{
+        |  persons(where: {firstName: "Ada"}, orderBy: [firstName_ASC, lastName_DESC]) {
+        |    nr
+        |  }
+        |}
+      """,
+      project
+    )
+
+    result.toString should be("""{"data":{"persons":[{"nr":1},{"nr":6},{"nr":5}]}}""")
+  }
+
+  private def createPerson(nr: Int, firstName: String, lastName: String): Unit = {
+    server.query(
+      s"""
+        |mutation {
+        |  createPerson(data: {nr: $nr, firstName: "$firstName", lastName: "$lastName"}){ id }
+        |}
+      """,
+      project
+    )
+  }
+}
@@ -152,7 +152,7 @@ class MultiItemConnectionQuerySpec extends FlatSpec with Matchers with ApiSpecBa
            |    followers_some: {
            |      name: "x"
            |    },
-           |  }, first: 2, after: "${a.pathAsString("data.createUser.id")}") {
+           |  }, first: 2, after: {id: "${a.pathAsString("data.createUser.id")}"}) {
            |   aggregate {
            |	    count
            |    }
@@ -0,0 +1,49 @@
+package queries.filters
+
+import org.scalatest.{FlatSpec, Matchers}
+import util._
+
+class FilterKeySpec extends FlatSpec with Matchers with ApiSpecBase {
+
+  val project = SchemaDsl.fromStringV11() {
+    """
+      |model Item {
+      |  id     String @id @default(cuid())
+      |  name   String @unique
+      |  is_not String
+      |}
+    """
+  }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+
+    server.query(
+      """
+        |mutation {
+        |  a: createItem(data: {name: "a", is_not: "abc"}){ id }
+        |  b: createItem(data: {name: "b", is_not: "xyz"}){ id }
+        |}
+      """,
+      project
+    )
+  }
+
+  "Filters on fields named like an operation" should "apply the longest suffix following the field name" in {
+    val result = server.query(
+      """
+        |{
+        |  contains: items(where: {is_not_contains: "b"}) { name }
+        |  notContains: items(where: {is_not_not_contains: "b"}) { name }
+        |  not: items(where: {is_not_not: "abc"}) { name }
+        |  equals: items(where: {is_not: "abc"}) { name }
+        |}
+      """,
+      project
+    )
+
+    result.toString should be(
+      """{"data":{"contains":[{"name":"a"}],"notContains":[{"name":"b"}],"not":[{"name":"b"}],"equals":[{"name":"a"}]}}""")
+  }
+}
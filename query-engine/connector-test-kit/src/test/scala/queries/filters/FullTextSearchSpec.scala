@@ -0,0 +1,92 @@
+package queries.filters
+
+import org.scalatest.{FlatSpec, Matchers}
+import util.ConnectorCapability.FullTextSearchCapability
+import util._
+
+class FullTextSearchSpec extends FlatSpec with Matchers with ApiSpecBase {
+  override def runOnlyForCapabilities = Set(FullTextSearchCapability)
+
+  val project = SchemaDsl.fromStringV11() {
+    """
+      |model Post {
+      |  id     String  @id @default(cuid())
+      |  title  String  @unique
+      |  body   String
+      |  author String
+      |
+      |  @@fulltext([title, body])
+      |}
+    """
+  }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+
+    server.query(
+      """
+        |mutation {
+        |  a: createPost(data: {title: "Rust engines", body: "Writing a query engine", author: "Ada"}){ id }
+        |  b: createPost(data: {title: "Query planning", body: "Rust helps", author: "Alan"}){ id }
+        |  c: createPost(data: {title: "Gardening", body: "Nothing about code", author: "Grace"}){ id }
+        |}
+      """,
+      project
+    )
+  }
+
+  "A search" should "only match the words in the searched field" in {
+    val result = server.query(
+      """
+        |{
+        |  title: posts(where: {title_search: "rust"}, orderBy: title_ASC) { title }
+        |  body: posts(where: {body_search: "rust"}, orderBy: title_ASC) { title }
+        |  both: posts(where: {title_search: "query planning"}, orderBy: title_ASC) { title }
+        |}
+      """,
+      project
+    )
+
+    result.toString should be(
+      """{"data":{"title":[{"title":"Rust engines"}],"body":[{"title":"Query planning"}],"both":[{"title":"Query planning"}]}}""")
+  }
+
+  "A search" should "not interpret the query syntax of the database" in {
+    val result = server.query(
+      """
+        |{
+        |  posts(where: {title_search: "rust OR gardening"}) { title }
+        |}
+      """,
+      project
+    )
+
+    result.toString should be("""{"data":{"posts":[]}}""")
+  }
+
+  "A search without words" should "match nothing" in {
+    val result = server.query(
+      """
+        |{
+        |  posts(where: {body_search: " "}) { title }
+        |}
+      """,
+      project
+    )
+
+    result.toString should be("""{"data":{"posts":[]}}""")
+  }
+
+  "A search" should "only be offered on fields covered by a full-text index" in {
+    server.queryThatMustFail(
+      """
+        |{
+        |  posts(where: {author_search: "ada"}) { title }
+        |}
+      """,
+      project,
+      errorCode = "P2009"
+    )
+  }
+}
@@ -0,0 +1,131 @@
+package queries.filters
+
+import org.scalatest.{FlatSpec, Matchers}
+import util.ConnectorCapability.InsensitiveFiltersCapability
+import util._
+
+class InsensitiveFilterSpec extends FlatSpec with Matchers with ApiSpecBase {
+  override def runOnlyForCapabilities = Set(InsensitiveFiltersCapability)
+
+  val project = SchemaDsl.fromStringV11() {
+    """
+      |model Person {
+      |  id    String  @id @default(cuid())
+      |  name  String  @unique
+      |  age   Int
+      |  posts Post[]
+      |}
+      |
+      |model Post {
+      |  id     String @id @default(cuid())
+      |  title  String @unique
+      |  author Person
+      |}
+    """
+  }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+
+    server.query(
+      """
+        |mutation {
+        |  a: createPerson(data: {name: "Ada Lovelace", age: 36, posts: {create: [{title: "Notes on the Engine"}]}}){ id }
+        |  b: createPerson(data: {name: "alan turing", age: 41}){ id }
+        |  c: createPerson(data: {name: "100% Grace", age: 85}){ id }
+        |}
+      """,
+      project
+    )
+  }
+
+  "Pattern filters in insensitive mode" should "ignore the case" in {
+    val result = server.query(
+      """
+        |{
+        |  contains: persons(where: {name_contains: "LOVE", name_mode: insensitive}) { name }
+        |  startsWith: persons(where: {name_starts_with: "AL", name_mode: insensitive}) { name }
+        |  endsWith: persons(where: {name_ends_with: "TURING", name_mode: insensitive}) { name }
+        |  notContains: persons(where: {name_not_contains: "A", name_mode: insensitive}) { name }
+        |}
+      """,
+      project
+    )
+
+    result.toString should be(
+      """{"data":{"contains":[{"name":"Ada Lovelace"}],"startsWith":[{"name":"alan turing"}],"endsWith":[{"name":"alan turing"}],"notContains":[]}}""")
+  }
+
+  "Pattern filters in insensitive mode" should "apply inside of relation filters" in {
+    val result = server.query(
+      """
+        |{
+        |  persons(where: {posts_some: {title_contains: "ENGINE", title_mode: insensitive}}) { name }
+        |}
+      """,
+      project
+    )
+
+    result.toString should be("""{"data":{"persons":[{"name":"Ada Lovelace"}]}}""")
+  }
+
+  "Pattern filters" should "match wildcard characters literally" in {
+    val result = server.query(
+      """
+        |{
+        |  persons(where: {name_starts_with: "100%", name_mode: insensitive}) { name }
+        |}
+      """,
+      project
+    )
+
+    result.toString should be("""{"data":{"persons":[{"name":"100% Grace"}]}}""")
+  }
+
+  "The insensitive mode" should "be rejected for filters that don't match a pattern" in {
+    server.queryThatMustFail(
+      """
+        |{
+        |  persons(where: {name: "ada lovelace", name_mode: insensitive}) { name }
+        |}
+      """,
+      project,
+      errorCode = "P2009",
+      errorContains = "Filter 'name' can't be used with query mode 'insensitive'"
+    )
+
+    server.queryThatMustFail(
+      """
+        |{
+        |  persons(where: {name_in: ["ADA LOVELACE"], name_mode: insensitive}) { name }
+        |}
+      """,
+      project,
+      errorCode = "P2009",
+      errorContains = "Filter 'name_in' can't be used with query mode 'insensitive'"
+    )
+  }
+
+  "A query mode" should "be rejected for fields other than String fields" in {
+    server.queryThatMustFail(
+      """
+        |{
+        |  persons(where: {age_mode: insensitive}) { name }
+        |}
+      """,
+      project,
+      errorCode = "P2009"
+    )
+
+    server.queryThatMustFail(
+      """
+        |{
+        |  persons(where: {posts_mode: insensitive}) { name }
+        |}
+      """,
+      project,
+      errorCode = "P2009"
+    )
+  }
+}
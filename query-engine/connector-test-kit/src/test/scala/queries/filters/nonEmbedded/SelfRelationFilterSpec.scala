@@ -159,7 +159,7 @@ class SelfRelationFilterSpec extends FlatSpec with Matchers with ApiSpecBase {
                                             }
                                           }""".stripMargin
 
-    server.queryThatMustFail(filterDaughterNull, project, errorCode = 3033)
+    server.queryThatMustFail(filterDaughterNull, project, errorCode = "P2009")
   }
 
   "Filter Queries along OneToMany self relations" should "succeed with empty filter {}" in {
@@ -249,7 +249,7 @@ class SelfRelationFilterSpec extends FlatSpec with Matchers with ApiSpecBase {
                                             }
                                           }""".stripMargin
 
-    server.queryThatMustFail(filterGroupies, project, errorCode = 3033)
+    server.queryThatMustFail(filterGroupies, project, errorCode = "P2009")
   }
 
   "Filter Queries along ManyToMany self relations" should "succeed with {} filter _some" in {
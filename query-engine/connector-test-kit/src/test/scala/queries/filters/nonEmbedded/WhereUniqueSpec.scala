@@ -34,7 +34,7 @@ class WhereUniqueSpec extends FlatSpec with Matchers with ApiSpecBase {
     server.queryThatMustFail(
       s"""query{user(where: {}){unique}}""",
       project,
-      errorCode = 3040,
+      errorCode = "P2009",
       errorContains = """You provided an invalid argument for the where selector on User. Please provide exactly one unique field and value."""
     )
   }
@@ -47,7 +47,7 @@ class WhereUniqueSpec extends FlatSpec with Matchers with ApiSpecBase {
     server.queryThatMustFail(
       s"""query{user(where: {id:"wrong", email: "test@test.com"}){unique}}""",
       project,
-      errorCode = 3045,
+      errorCode = "P2009",
       errorContains =
         """You provided more than one field for the unique selector on User. If you want that behavior you can use the many query and combine fields with AND / OR."""
     )
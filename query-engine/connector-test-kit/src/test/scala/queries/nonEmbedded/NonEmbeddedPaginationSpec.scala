@@ -163,7 +163,7 @@ trait NonEmbeddedPaginationSpec extends FlatSpec with Matchers with ApiSpecBase
     val result2 = server.query(
       s"""
         |{
-        |  listsConnection(after: "$cursor", first: 3, orderBy: createdAt_ASC) {
+        |  listsConnection(after: {id: "$cursor"}, first: 3, orderBy: createdAt_ASC) {
         |    pageInfo {
         |      hasNextPage
         |      hasPreviousPage
@@ -210,7 +210,7 @@ trait NonEmbeddedPaginationSpec extends FlatSpec with Matchers with ApiSpecBase
     val result2 = server.query(
       s"""
          |{
-         |  listsConnection(after: "$cursor", first: 3) {
+         |  listsConnection(after: {id: "$cursor"}, first: 3) {
          |    pageInfo {
          |      hasNextPage
          |      hasPreviousPage
@@ -253,7 +253,7 @@ trait NonEmbeddedPaginationSpec extends FlatSpec with Matchers with ApiSpecBase
         |{
         |  list(where: {name: "1"}) {
         |    name
-        |    todos(after: "$cursor", first: 3, orderBy: createdAt_ASC){
+        |    todos(after: {id: "$cursor"}, first: 3, orderBy: createdAt_ASC){
         |      id
         |      title
         |    }
@@ -289,7 +289,7 @@ trait NonEmbeddedPaginationSpec extends FlatSpec with Matchers with ApiSpecBase
          |{
          |  list(where: {name: "1"}) {
          |    name
-         |    todos(after: "$cursor", orderBy: createdAt_ASC){
+         |    todos(after: {id: "$cursor"}, orderBy: createdAt_ASC){
          |      id
          |      title
          |    }
@@ -347,7 +347,7 @@ trait NonEmbeddedPaginationSpec extends FlatSpec with Matchers with ApiSpecBase
     val result2 = server.query(
       s"""
          |{
-         |  lists(first: 2 after:"$cursor", orderBy: createdAt_ASC) {
+         |  lists(first: 2 after: {id: "$cursor"}, orderBy: createdAt_ASC) {
          |    name
          |  }
          |}
@@ -376,7 +376,7 @@ trait NonEmbeddedPaginationSpec extends FlatSpec with Matchers with ApiSpecBase
     val result2 = server.query(
       s"""
          |{
-         |  lists(after:"$cursor", orderBy: createdAt_ASC) {
+         |  lists(after: {id: "$cursor"}, orderBy: createdAt_ASC) {
          |    name
          |  }
          |}
@@ -0,0 +1,129 @@
+package queries.nonEmbedded
+
+import org.scalatest.{FlatSpec, Matchers}
+import util.ConnectorCapability.JoinRelationLinksCapability
+import util._
+
+class NonEmbeddedRelatedOrderBySpec extends FlatSpec with Matchers with ApiSpecBase {
+  override def runOnlyForCapabilities = Set(JoinRelationLinksCapability)
+
+  val project = SchemaDsl.fromStringV11() {
+    """
+      |model Blog {
+      |  id    String @id @default(cuid())
+      |  name  String @unique
+      |  posts Post[]
+      |}
+      |
+      |model Post {
+      |  id       String    @id @default(cuid())
+      |  title    String    @unique
+      |  blog     Blog
+      |  author   User
+      |  comments Comment[]
+      |}
+      |
+      |model User {
+      |  id    String @id @default(cuid())
+      |  name  String @unique
+      |  posts Post[]
+      |}
+      |
+      |model Comment {
+      |  id   String @id @default(cuid())
+      |  text String
+      |  post Post
+      |}
+    """
+  }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+
+    server.query(
+      """
+        |mutation {
+        |  a: createUser(data: {name: "Bob"}){ id }
+        |  b: createUser(data: {name: "Alice"}){ id }
+        |  c: createUser(data: {name: "Carol"}){ id }
+        |  d: createBlog(data: {name: "blog"}){ id }
+        |}
+      """,
+      project
+    )
+
+    createPost("1", "Carol", 0)
+    createPost("2", "Alice", 2)
+    createPost("3", "Bob", 1)
+  }
+
+  "Paginated related records" should "be ordered by a field of a related record" in {
+    val result = server.query(
+      """
+        |{
+        |  blog(where: {name: "blog"}) {
+        |    posts(orderBy: [author_name_ASC], first: 2) {
+        |      title
+        |    }
+        |  }
+        |}
+      """,
+      project
+    )
+
+    result.toString should be("""{"data":{"blog":{"posts":[{"title":"2"},{"title":"3"}]}}}""")
+  }
+
+  "Paginated related records" should "be ordered by the count of a to-many relation" in {
+    val result = server.query(
+      """
+        |{
+        |  blog(where: {name: "blog"}) {
+        |    posts(orderBy: [comments_count_DESC], first: 2) {
+        |      title
+        |    }
+        |  }
+        |}
+      """,
+      project
+    )
+
+    result.toString should be("""{"data":{"blog":{"posts":[{"title":"2"},{"title":"3"}]}}}""")
+  }
+
+  "Paginated related records" should "be ordered by multiple orderings taken from the end" in {
+    val result = server.query(
+      """
+        |{
+        |  blog(where: {name: "blog"}) {
+        |    posts(orderBy: [author_name_DESC, title_ASC], last: 2) {
+        |      title
+        |    }
+        |  }
+        |}
+      """,
+      project
+    )
+
+    result.toString should be("""{"data":{"blog":{"posts":[{"title":"3"},{"title":"2"}]}}}""")
+  }
+
+  private def createPost(title: String, author: String, comments: Int): Unit = {
+    val commentData = (1 to comments).map(i => s"""{text: "$i"}""").mkString("[", ", ", "]")
+
+    server.query(
+      s"""
+        |mutation {
+        |  createPost(data: {
+        |    title: "$title"
+        |    blog: { connect: {name: "blog"} }
+        |    author: { connect: {name: "$author"} }
+        |    comments: { create: $commentData }
+        |  }){ id }
+        |}
+      """,
+      project
+    )
+  }
+}
@@ -33,6 +33,10 @@ object ConnectorCapability extends Enumeratum[ConnectorCapability] {
   object IdSequenceCapability extends IdCapability
 
   object Prisma2Capability extends ConnectorCapability
+
+  object InsensitiveFiltersCapability extends ConnectorCapability // pattern filters take a query mode
+  object FullTextSearchCapability     extends ConnectorCapability // fields covered by a full-text index can be searched
+  object NativeScalarListsCapability  extends ConnectorCapability // scalar lists can be stored in native arrays
 }
 
 case class ConnectorCapabilities(capabilities: Set[ConnectorCapability]) {
@@ -56,16 +60,16 @@ object ConnectorCapabilities {
   def apply(capabilities: ConnectorCapability*): ConnectorCapabilities = ConnectorCapabilities(Set(capabilities: _*))
 
   lazy val sqlite: ConnectorCapabilities = {
-    ConnectorCapabilities(sqlShared)
+    ConnectorCapabilities(sqlShared ++ Set(InsensitiveFiltersCapability, FullTextSearchCapability))
   }
 
   lazy val postgres: ConnectorCapabilities = {
-    val capas = sqlShared ++ Set(UuidIdCapability)
+    val capas = sqlShared ++ Set(UuidIdCapability, NativeScalarListsCapability)
     ConnectorCapabilities(capas)
   }
 
   lazy val mysql: ConnectorCapabilities = {
-    ConnectorCapabilities(sqlShared)
+    ConnectorCapabilities(sqlShared ++ Set(InsensitiveFiltersCapability))
   }
 
   private lazy val sqlShared: Set[ConnectorCapability] = {
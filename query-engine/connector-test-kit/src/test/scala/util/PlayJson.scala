@@ -79,7 +79,7 @@ trait PlayJsonExtensions extends JsonUtils {
 
     def getFirstErrorMessage = jsValue.pathAsSeq("errors").head.pathAsString("message")
 
-    def getFirstErrorCode = jsValue.pathAsSeq("errors").head.pathAsLong("code")
+    def getFirstErrorCode = jsValue.pathAsSeq("errors").head.pathAsString("code")
 
     def getFirstFunctionErrorMessage = jsValue.pathAsSeq("errors").head.pathAsString("functionError")
   }
@@ -99,7 +99,7 @@ trait PlayJsonExtensions extends JsonUtils {
       }
     }
 
-    def assertFailingResponse(errorCode: Int, errorCount: Int, errorContains: String): Unit = {
+    def assertFailingResponse(errorCode: String, errorCount: Int, errorContains: String): Unit = {
       require(
         requirement = hasErrors,
         message = s"The query had to result in an error but it returned no errors. Here's the response: \n $json"
@@ -109,8 +109,8 @@ trait PlayJsonExtensions extends JsonUtils {
       val errors = json.pathAsSeq("errors")
       require(requirement = errors.size == errorCount, message = s"expected exactly $errorCount errors, but got ${errors.size} instead.")
 
-      if (errorCode != 0) {
-        val errorCodeInResult = errors.head.pathAsLong("code")
+      if (errorCode != "") {
+        val errorCodeInResult = errors.head.pathAsString("code")
         require(
           requirement = errorCodeInResult == errorCode,
           message = s"Expected the error code $errorCode, but got $errorCodeInResult. Here's the response: \n $json"
@@ -26,7 +26,7 @@ class BringYourOwnIdMongoSpec extends FlatSpec with Matchers with ApiSpecBase wi
          |  createParent(data: {p: "Parent", id: "5c88f558dee5fb6fe357c7a9"}){p, id}
          |}""",
         project = project,
-        errorCode = 3010,
+        errorCode = "P2002",
         errorContains = "A unique constraint would be violated on Parent. Details: Field name: id"
       )
     }
@@ -42,7 +42,7 @@ class BringYourOwnIdMongoSpec extends FlatSpec with Matchers with ApiSpecBase wi
          |  createParent(data: {p: "Parent", id: 12}){p, id}
          |}""",
         project = project,
-        errorCode = 3044,
+        errorCode = "P2009",
         errorContains = "You provided an ID that was not a valid MongoObjectId: 12"
       )
     }
@@ -58,7 +58,7 @@ class BringYourOwnIdMongoSpec extends FlatSpec with Matchers with ApiSpecBase wi
          |  createParent(data: {p: "Parent", id: true}){p, id}
          |}""",
         project = project,
-        errorCode = 0,
+        errorCode = "P2009",
         errorContains = "Reason: 'id' String or Int value expected"
       )
     }
@@ -74,7 +74,7 @@ class BringYourOwnIdMongoSpec extends FlatSpec with Matchers with ApiSpecBase wi
          |  createParent(data: {p: "Parent", id: "this is probably way to long, lets see what error it throws"}){p, id}
          |}""",
         project = project,
-        errorCode = 3044,
+        errorCode = "P2009",
         errorContains = "You provided an ID that was not a valid MongoObjectId: this is probably way to long, lets see what error it throws"
       )
     }
@@ -100,7 +100,7 @@ class BringYourOwnIdMongoSpec extends FlatSpec with Matchers with ApiSpecBase wi
          |createParent(data: {p: "Parent 2", id: "5c88f558dee5fb6fe357c7a3", childOpt:{create:{c:"Child 2", id: "5c88f558dee5fb6fe357c7a5"}}}){p, id, childOpt { c, id} }
          |}""",
         project = project,
-        errorCode = 3010,
+        errorCode = "P2002",
         errorContains = "A unique constraint would be violated on Child. Details: Field name: id"
       )
     }
@@ -116,7 +116,7 @@ class BringYourOwnIdMongoSpec extends FlatSpec with Matchers with ApiSpecBase wi
          |createParent(data: {p: "Parent 2", id: "5c88f558dee5fb6fe357c7a9", childOpt:{create:{c:"Child 2", id: "5c88f558dee5fb6fe357c7a9afafasfsadfasdf"}}}){p, id, childOpt { c, id} }
          |}""",
         project = project,
-        errorCode = 3044,
+        errorCode = "P2009",
         errorContains = "You provided an ID that was not a valid MongoObjectId: 5c88f558dee5fb6fe357c7a9afafasfsadfasdf"
       )
     }
@@ -158,7 +158,7 @@ class BringYourOwnIdMongoSpec extends FlatSpec with Matchers with ApiSpecBase wi
          |  {p, id}
          |}""",
         project = project,
-        errorCode = 3044,
+        errorCode = "P2009",
         errorContains = "You provided an ID that was not a valid MongoObjectId: 5c88f558dee5fb6fe357c7a9aggfasffgasdgasg"
       )
     }
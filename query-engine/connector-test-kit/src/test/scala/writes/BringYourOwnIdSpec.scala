@@ -27,7 +27,7 @@ class BringYourOwnIdSpec extends FlatSpec with Matchers with ApiSpecBase with Sc
          |  createParent(data: {p: "Parent2", id: "Own Id"}){p, id}
          |}""",
         project = project,
-        errorCode = 3010,
+        errorCode = "P2002",
         errorContains = "A unique constraint would be violated on Parent. Details: Field name = id"
       )
     }
@@ -60,7 +60,7 @@ class BringYourOwnIdSpec extends FlatSpec with Matchers with ApiSpecBase with Sc
          |  createParent(data: {p: "Parent", id: true}){p, id}
          |}""",
         project = project,
-        errorCode = 0,
+        errorCode = "P2009",
         errorContains = "Reason: 'id' String or Int value expected"
       )
     }
@@ -76,7 +76,7 @@ class BringYourOwnIdSpec extends FlatSpec with Matchers with ApiSpecBase with Sc
          |  createParent(data: {p: "Parent", id: "this is probably way to long, lets see what error it throws"}){p, id}
          |}""",
         project = project,
-        errorCode = 3007,
+        errorCode = "P5000",
         errorContains = "Value for field id is too long."
       )
     }
@@ -101,7 +101,7 @@ class BringYourOwnIdSpec extends FlatSpec with Matchers with ApiSpecBase with Sc
          |createParent(data: {p: "Parent 2", id: "Own Id 2", childOpt:{create:{c:"Child 2", id: "Own Child Id"}}}){p, id, childOpt { c, id} }
          |}""",
         project = project,
-        errorCode = 3010,
+        errorCode = "P2002",
         errorContains = "A unique constraint would be violated on Child. Details: Field name = id"
       )
     }
@@ -117,7 +117,7 @@ class BringYourOwnIdSpec extends FlatSpec with Matchers with ApiSpecBase with Sc
          |createParent(data: {p: "Parent 2", id: "Own Id 2", childOpt:{create:{c:"Child 2", id: "This is way too long and should error"}}}){p, id, childOpt { c, id} }
          |}""",
         project = project,
-        errorCode = 3007,
+        errorCode = "P5000",
         errorContains = "Value for field id is too long."
       )
     }
@@ -159,7 +159,7 @@ class BringYourOwnIdSpec extends FlatSpec with Matchers with ApiSpecBase with Sc
          |  {p, id}
          |}""",
         project = project,
-        errorCode = 3007,
+        errorCode = "P5000",
         errorContains = "Value for field id is too long."
       )
     }
@@ -143,7 +143,7 @@ class CascadingDeleteSpec extends FlatSpec with Matchers with ApiSpecBase {
     server.query("""mutation{createP(data:{p:"p", c: {create:{c: "c"}}}){p, c {c}}}""", project)
     server.query("""mutation{createP(data:{p:"p2", c: {create:{c: "c2"}}}){p, c {c}}}""", project)
 
-    server.queryThatMustFail("""mutation{deleteP(where: {p:"p"}){id}}""", project, errorCode = 3042)
+    server.queryThatMustFail("""mutation{deleteP(where: {p:"p"}){id}}""", project, errorCode = "P2014")
     server.query("""query{ps{p, c {c}}}""", project).toString should be("""{"data":{"ps":[{"p":"p","c":{"c":"c"}},{"p":"p2","c":{"c":"c2"}}]}}""")
     server.query("""query{cs{c, p {p}}}""", project).toString should be("""{"data":{"cs":[{"c":"c","p":{"p":"p"}},{"c":"c2","p":{"p":"p2"}}]}}""")
 
@@ -254,7 +254,7 @@ class CascadingDeleteSpec extends FlatSpec with Matchers with ApiSpecBase {
     server.query("""mutation{createP(data:{p:"p", c: {create:{c: "c", gc :{create:{gc: "gc"}}}}}){p, c {c, gc{gc}}}}""", project)
     server.query("""mutation{createP(data:{p:"p2", c: {create:{c: "c2", gc :{create:{gc: "gc2"}}}}}){p, c {c,gc{gc}}}}""", project)
 
-    server.queryThatMustFail("""mutation{deleteP(where: {p:"p"}){id}}""", project, errorCode = 3042)
+    server.queryThatMustFail("""mutation{deleteP(where: {p:"p"}){id}}""", project, errorCode = "P2014")
     server.query("""query{ps{p, c {c}}}""", project).toString should be("""{"data":{"ps":[{"p":"p","c":{"c":"c"}},{"p":"p2","c":{"c":"c2"}}]}}""")
     server.query("""query{cs{c, p {p}}}""", project).toString should be("""{"data":{"cs":[{"c":"c","p":{"p":"p"}},{"c":"c2","p":{"p":"p2"}}]}}""")
 
@@ -541,7 +541,7 @@ class CascadingDeleteSpec extends FlatSpec with Matchers with ApiSpecBase {
     server.queryThatMustFail(
       """mutation{deleteP(where: {p:"p"}){id}}""",
       project,
-      errorCode = 3042,
+      errorCode = "P2014",
       errorContains = """The change you are trying to make would violate the required relation 'EToF' between E and F"""
     )
 
@@ -612,7 +612,7 @@ class CascadingDeleteSpec extends FlatSpec with Matchers with ApiSpecBase {
     server.queryThatMustFail(
       """mutation{deleteA(where: {a:"2020"}){a}}""",
       project,
-      errorCode = 3042,
+      errorCode = "P2014",
       errorContains = "The change you are trying to make would violate the required relation 'CToE' between C and E"
     )
   }
@@ -675,7 +675,7 @@ class CascadingDeleteSpec extends FlatSpec with Matchers with ApiSpecBase {
     server.queryThatMustFail(
       """mutation{deleteA(where: {a:"a"}){a}}""",
       project,
-      errorCode = 3042,
+      errorCode = "P2014",
       errorContains = "The change you are trying to make would violate the required relation 'AToD' between A and D"
     )
 
@@ -768,7 +768,7 @@ class CascadingDeleteSpec extends FlatSpec with Matchers with ApiSpecBase {
     server.queryThatMustFail(
       """mutation{updateC(where: {c:"c"} data: {p: {delete: true}}){id}}""",
       project,
-      errorCode = 0,
+      errorCode = "P2009",
       errorContains = "Argument 'data' expected model 'CUpdateInput!'"
     )
   }
@@ -843,7 +843,7 @@ class CascadingDeleteSpec extends FlatSpec with Matchers with ApiSpecBase {
     server.queryThatMustFail(
       """mutation{updateP(where: {p:"p"}, data: { c: {delete: true}}){id}}""",
       project,
-      errorCode = 0,
+      errorCode = "P2009",
       errorContains = "Argument 'data' expected model 'PUpdateInput!'"
     )
   }
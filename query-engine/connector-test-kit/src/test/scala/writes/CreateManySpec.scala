@@ -0,0 +1,101 @@
+package writes
+
+import org.scalatest.{FlatSpec, Matchers}
+import util._
+
+class CreateManySpec extends FlatSpec with Matchers with ApiSpecBase {
+
+  val project = SchemaDsl.fromStringV11() {
+    """
+      |model Todo {
+      |  id    String  @id @default(cuid())
+      |  title String  @unique
+      |  note  String?
+      |}
+      |
+      |model Counter {
+      |  id   Int    @id
+      |  name String
+      |}
+    """
+  }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+  }
+
+  override def beforeEach(): Unit = database.truncateProjectTables(project)
+
+  "createMany" should "create records setting different fields and return the count" in {
+    val result = server.query(
+      """
+        |mutation {
+        |  createManyTodos(data: [{title: "a"}, {title: "b", note: "with note"}, {title: "c"}]) {
+        |    count
+        |  }
+        |}
+      """,
+      project
+    )
+
+    result.toString should be("""{"data":{"createManyTodos":{"count":3}}}""")
+    server.query("{ todos(orderBy: title_ASC) { title note } }", project).toString should be(
+      """{"data":{"todos":[{"title":"a","note":null},{"title":"b","note":"with note"},{"title":"c","note":null}]}}""")
+  }
+
+  "createMany" should "return the generated ids in the order of the given records" in {
+    val result = server.query(
+      """
+        |mutation {
+        |  createManyTodos(data: [{title: "a"}, {title: "b", note: "with note"}, {title: "c"}]) {
+        |    ids
+        |  }
+        |}
+      """,
+      project
+    )
+
+    val ids = result.pathAsSeq("data.createManyTodos.ids").map(_.as[String])
+    val titles = ids.map(id => server.query(s"""{ todo(where: {id: "$id"}) { title } }""", project).pathAsString("data.todo.title"))
+
+    titles should be(Seq("a", "b", "c"))
+  }
+
+  "createMany" should "return the ids generated by the database in the order of the given records" in {
+    val result = server.query(
+      """
+        |mutation {
+        |  createManyCounters(data: [{name: "first"}, {name: "second"}, {name: "third"}]) {
+        |    count
+        |    ids
+        |  }
+        |}
+      """,
+      project
+    )
+
+    result.pathAsLong("data.createManyCounters.count") should be(3)
+
+    val ids = result.pathAsSeq("data.createManyCounters.ids").map(_.toString)
+    val names = ids.map(id => server.query(s"""{ counter(where: {id: $id}) { name } }""", project).pathAsString("data.counter.name"))
+
+    names should be(Seq("first", "second", "third"))
+  }
+
+  "createMany" should "fail as a whole if one of the records violates a unique constraint" in {
+    server.queryThatMustFail(
+      """
+        |mutation {
+        |  createManyTodos(data: [{title: "a"}, {title: "a"}]) {
+        |    count
+        |  }
+        |}
+      """,
+      project,
+      errorCode = "P2002"
+    )
+
+    server.query("{ todos { title } }", project).toString should be("""{"data":{"todos":[]}}""")
+  }
+}
@@ -107,7 +107,7 @@ class CreateMutationSpec extends FlatSpec with Matchers with ApiSpecBase {
     server.queryThatMustFail(
       s"""mutation {createScalarModel(data: {optString: "$reallyLongString", optInt: 1337, optFloat: 1.234, optBoolean: true, optEnum: A, optDateTime: "2016-07-31T23:59:01.000Z", }){optString, optInt, optFloat, optBoolean, optEnum, optDateTime }}""",
       project = project,
-      errorCode = 3007
+      errorCode = "P5000"
     )
   }
 
@@ -134,6 +134,6 @@ class CreateMutationSpec extends FlatSpec with Matchers with ApiSpecBase {
   "A Create Mutation" should "gracefully fail when a unique violation occurs" in {
     val mutation = s"""mutation {createScalarModel(data: {optUnique: "test"}){optUnique}}"""
     server.query(mutation, project)
-    server.queryThatMustFail(mutation, project, errorCode = 3010)
+    server.queryThatMustFail(mutation, project, errorCode = "P2002")
   }
 }
@@ -51,7 +51,7 @@ class DeleteManyMutationRelationsSpec extends FlatSpec with Matchers with ApiSpe
          |}
       """.stripMargin,
       project,
-      errorCode = 3042,
+      errorCode = "P2014",
       errorContains = "The change you are trying to make would violate the required relation 'ChildToParent' between Child and Parent"
     )
 
@@ -103,7 +103,7 @@ class DeleteManyMutationRelationsSpec extends FlatSpec with Matchers with ApiSpe
          |}
       """.stripMargin,
       project,
-      errorCode = 3042,
+      errorCode = "P2014",
       errorContains = "The change you are trying to make would violate the required relation 'ChildToParent' between Child and Parent"
     )
 
@@ -150,7 +150,7 @@ class DeleteManyMutationRelationsSpec extends FlatSpec with Matchers with ApiSpe
          |}
       """.stripMargin,
         project,
-        errorCode = 3042,
+        errorCode = "P2014",
         errorContains = "The change you are trying to make would violate the required relation 'ChildToParent' between Child and Parent"
       )
 
@@ -330,7 +330,7 @@ class DeleteManyMutationRelationsSpec extends FlatSpec with Matchers with ApiSpe
          |}
       """.stripMargin,
         project,
-        errorCode = 3042,
+        errorCode = "P2014",
         errorContains = "The change you are trying to make would violate the required relation 'ChildToParent' between Child and Parent"
       )
 
@@ -417,7 +417,7 @@ class DeleteManyMutationRelationsSpec extends FlatSpec with Matchers with ApiSpe
          |}
       """.stripMargin,
         project,
-        errorCode = 3042,
+        errorCode = "P2014",
         errorContains = "The change you are trying to make would violate the required relation 'ChildToParent' between Child and Parent"
       )
 //      dataResolver(project).countByTable(project.schema.getModelByName_!("Parent").dbName).await should be(1)
@@ -37,7 +37,7 @@ class DeleteMutationSpec extends FlatSpec with Matchers with ApiSpecBase {
     server.queryThatMustFail(
       s"""mutation {deleteScalarModel(where: {id: "5beea4aa6183dd734b2dbd9b"}){id}}""",
       project = project,
-      errorCode = 3039,
+      errorCode = "P2001",
       errorContains = "No Node for the model ScalarModel with value 5beea4aa6183dd734b2dbd9b for id found"
     )
     server.query(s"""query {scalarModels{string}}""", project = project, dataContains = s"""{"scalarModels":[{"string":"test"}]}""")
@@ -59,7 +59,7 @@ class DeleteMutationSpec extends FlatSpec with Matchers with ApiSpecBase {
     server.queryThatMustFail(
       s"""mutation {deleteScalarModel(where: {unicorn: "c"}){unicorn}}""",
       project = project,
-      errorCode = 3039,
+      errorCode = "P2001",
       errorContains = "No Node for the model ScalarModel with value c for unicorn found"
     )
     server.query(s"""query {scalarModels{unicorn}}""", project = project, dataContains = s"""{"scalarModels":[{"unicorn":"a"}]}""")
@@ -70,7 +70,7 @@ class DeleteMutationSpec extends FlatSpec with Matchers with ApiSpecBase {
     server.queryThatMustFail(
       s"""mutation {deleteScalarModel(where: {unicorn: null}){unicorn}}""",
       project = project,
-      errorCode = 3040,
+      errorCode = "P2009",
       errorContains = "You provided an invalid argument for the where selector on ScalarModel."
     )
     server.query(s"""query {scalarModels{unicorn}}""", project = project, dataContains = s"""{"scalarModels":[{"unicorn":"a"}]}""")
@@ -81,7 +81,7 @@ class DeleteMutationSpec extends FlatSpec with Matchers with ApiSpecBase {
     server.queryThatMustFail(
       s"""mutation {deleteScalarModel(where: {string: "a"}){string}}""",
       project = project,
-      errorCode = 0,
+      errorCode = "P2009",
       errorContains = s"""Argument 'where' expected model 'ScalarModelWhereUniqueInput!' but got: {string: \\"a\\"}"""
     )
     server.query(s"""query {scalarModels{string}}""", project = project, dataContains = s"""{"scalarModels":[{"string":"a"}]}""")
@@ -53,7 +53,7 @@ class DeleteSpec extends FlatSpec with Matchers with ApiSpecBase {
         |}
       """.stripMargin,
       project,
-      errorCode = 3039,
+      errorCode = "P2001",
       errorContains = """No Node for the model Todo with value does not exist for title found."""
     )
 
@@ -0,0 +1,76 @@
+package writes
+
+import org.scalatest.{FlatSpec, Matchers}
+import util.ConnectorCapability.NativeScalarListsCapability
+import util._
+
+class EmbeddedScalarListSpec extends FlatSpec with Matchers with ApiSpecBase {
+  override def runOnlyForCapabilities = Set(NativeScalarListsCapability)
+
+  val project = SchemaDsl.fromStringV11() {
+    """
+      |model Item {
+      |  id   String   @id @default(cuid())
+      |  name String   @unique
+      |  tags String[] @scalarList(strategy: EMBEDDED)
+      |  ints Int[]    @scalarList(strategy: EMBEDDED)
+      |}
+    """
+  }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+  }
+
+  override def beforeEach(): Unit = {
+    database.truncateProjectTables(project)
+
+    server.query(
+      """
+        |mutation {
+        |  a: createItem(data: {name: "a", tags: {set: ["x", "y"]}, ints: {set: [1, 2]}}){ id }
+        |  b: createItem(data: {name: "b", tags: {set: []}, ints: {set: []}}){ id }
+        |}
+      """,
+      project
+    )
+  }
+
+  "Embedded scalar lists" should "be read with the record" in {
+    val result = server.query("""{ items(orderBy: name_ASC) { name tags ints } }""", project)
+
+    result.toString should be("""{"data":{"items":[{"name":"a","tags":["x","y"],"ints":[1,2]},{"name":"b","tags":[],"ints":[]}]}}""")
+  }
+
+  "Pushing to an embedded scalar list" should "append the values to the stored list" in {
+    server.query("""mutation { updateItem(where: {name: "a"}, data: {tags: {push: ["z"]}}){ id } }""", project)
+    server.query("""mutation { updateManyItems(data: {ints: {push: [3]}}){ count } }""", project)
+
+    val result = server.query("""{ items(orderBy: name_ASC) { name tags ints } }""", project)
+
+    result.toString should be("""{"data":{"items":[{"name":"a","tags":["x","y","z"],"ints":[1,2,3]},{"name":"b","tags":[],"ints":[3]}]}}""")
+  }
+
+  "Setting and pushing in one update" should "append the pushed values to the new list" in {
+    server.query("""mutation { updateItem(where: {name: "a"}, data: {tags: {set: ["q"], push: ["r"]}}){ id } }""", project)
+
+    val result = server.query("""{ item(where: {name: "a"}) { tags } }""", project)
+
+    result.toString should be("""{"data":{"item":{"tags":["q","r"]}}}""")
+  }
+
+  "The is_empty filter" should "match empty lists" in {
+    val result = server.query(
+      """
+        |{
+        |  empty: items(where: {tags_is_empty: true}) { name }
+        |  notEmpty: items(where: {tags_is_empty: false}) { name }
+        |}
+      """,
+      project
+    )
+
+    result.toString should be("""{"data":{"empty":[{"name":"b"}],"notEmpty":[{"name":"a"}]}}""")
+  }
+}
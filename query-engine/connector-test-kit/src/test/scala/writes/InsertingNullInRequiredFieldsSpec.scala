@@ -64,7 +64,7 @@ class InsertingNullInRequiredFieldsSpec extends FlatSpec with Matchers with ApiS
         |  }
         |}""",
       project,
-      errorCode = 0,
+      errorCode = "P2009",
       errorContains = """Argument 'data' expected model 'ACreateInput!' but got: {b: \"abc\", key: null}. Reason: 'key' String value expected"""
     )
   }
@@ -0,0 +1,86 @@
+package writes
+
+import org.scalatest.{FlatSpec, Matchers}
+import util.ConnectorCapability.JoinRelationLinksCapability
+import util._
+
+class ReferentialActionsSpec extends FlatSpec with Matchers with ApiSpecBase {
+  override def runOnlyForCapabilities = Set(JoinRelationLinksCapability)
+
+  val project = SchemaDsl.fromStringV11() {
+    """
+      |model User {
+      |  id       Int       @id
+      |  name     String    @unique
+      |  posts    Post[]
+      |  comments Comment[]
+      |  likes    Like[]
+      |}
+      |
+      |model Post {
+      |  id     Int    @id
+      |  title  String @unique
+      |  author User   @default(1) @relation(references: [id], onDelete: SET_DEFAULT, onUpdate: CASCADE)
+      |}
+      |
+      |model Comment {
+      |  id     Int    @id
+      |  text   String @unique
+      |  author User?  @relation(references: [id], onUpdate: SET_NULL)
+      |}
+      |
+      |model Like {
+      |  id   Int   @id
+      |  user User? @relation(references: [id])
+      |}
+    """
+  }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+  }
+
+  override def beforeEach(): Unit = {
+    database.truncateProjectTables(project)
+
+    server.query("""mutation { createUser(data: {id: 1, name: "Anonymous"}){ id } }""", project)
+    server.query("""mutation { createUser(data: {id: 2, name: "Ada"}){ id } }""", project)
+  }
+
+  "Deleting a record" should "link records of SET_DEFAULT relations to the default of the relation field" in {
+    server.query("""mutation { createPost(data: {id: 1, title: "Notes", author: {connect: {id: 2}}}){ id } }""", project)
+    server.query("""mutation { deleteUser(where: {id: 2}){ id } }""", project)
+
+    val result = server.query("""{ post(where: {title: "Notes"}) { author { name } } }""", project)
+    result.toString should be("""{"data":{"post":{"author":{"name":"Anonymous"}}}}""")
+  }
+
+  "Updating the id of a record" should "update the links of CASCADE relations" in {
+    server.query("""mutation { createPost(data: {id: 1, title: "Notes", author: {connect: {id: 2}}}){ id } }""", project)
+    server.query("""mutation { updateUser(where: {id: 2}, data: {id: 3}){ id } }""", project)
+
+    val result = server.query("""{ post(where: {title: "Notes"}) { author { id name } } }""", project)
+    result.toString should be("""{"data":{"post":{"author":{"id":3,"name":"Ada"}}}}""")
+  }
+
+  "Updating the id of a record" should "unlink the records of SET_NULL relations" in {
+    server.query("""mutation { createComment(data: {id: 1, text: "Nice", author: {connect: {id: 2}}}){ id } }""", project)
+    server.query("""mutation { updateUser(where: {id: 2}, data: {id: 3}){ id } }""", project)
+
+    val result = server.query("""{ comment(where: {text: "Nice"}) { author { name } } }""", project)
+    result.toString should be("""{"data":{"comment":{"author":null}}}""")
+  }
+
+  "Updating the id of a record" should "fail if records of restricting relations are linked to it" in {
+    server.query("""mutation { createLike(data: {id: 1, user: {connect: {id: 2}}}){ id } }""", project)
+
+    server.queryThatMustFail(
+      """mutation { updateUser(where: {id: 2}, data: {id: 3}){ id } }""",
+      project,
+      errorCode = "P2014"
+    )
+
+    server.query("""{ user(where: {name: "Ada"}) { id } }""", project).toString should be("""{"data":{"user":{"id":2}}}""")
+  }
+}
@@ -161,7 +161,7 @@ class UpdateMutationSpec extends FlatSpec with Matchers with ApiSpecBase {
          |  }
          |}""".stripMargin,
       project,
-      errorCode = 3039,
+      errorCode = "P2001",
       errorContains = "No Node for the model Todo with value NOT A VALID ALIAS for alias found"
     )
   }
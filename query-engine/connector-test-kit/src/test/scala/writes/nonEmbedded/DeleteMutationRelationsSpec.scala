@@ -67,7 +67,7 @@ class DeleteMutationRelationsSpec extends FlatSpec with Matchers with ApiSpecBas
          |}
       """.stripMargin,
         project,
-        errorCode = 3042,
+        errorCode = "P2014",
         errorContains = "The change you are trying to make would violate the required relation 'ChildToParent' between Child and Parent"
       )
 
@@ -115,7 +115,7 @@ class DeleteMutationRelationsSpec extends FlatSpec with Matchers with ApiSpecBas
          |}
       """.stripMargin,
         project,
-        errorCode = 3042,
+        errorCode = "P2014",
         errorContains = "The change you are trying to make would violate the required relation 'ChildToParent' between Child and Parent"
       )
 
@@ -288,7 +288,7 @@ class DeleteMutationRelationsSpec extends FlatSpec with Matchers with ApiSpecBas
          |}
       """.stripMargin,
         project,
-        errorCode = 3042,
+        errorCode = "P2014",
         errorContains = "The change you are trying to make would violate the required relation 'ChildToParent' between Child and Parent"
       )
 
@@ -372,7 +372,7 @@ class DeleteMutationRelationsSpec extends FlatSpec with Matchers with ApiSpecBas
          |}
       """.stripMargin,
         project,
-        errorCode = 3042,
+        errorCode = "P2014",
         errorContains = "The change you are trying to make would violate the required relation 'ChildToParent' between Child and Parent"
       )
     //dataResolver(project).countByTable(project.schema.getModelByName_!("Parent").dbName).await should be(1)
@@ -148,7 +148,7 @@ class RelationGraphQLSpec extends FlatSpec with Matchers with ApiSpecBase {
         |  }
         |}""",
         project,
-        errorCode = 3042,
+        errorCode = "P2014",
         errorContains = "The change you are trying to make would violate the required relation 'CatToOwner' between Cat and Owner"
       )
 
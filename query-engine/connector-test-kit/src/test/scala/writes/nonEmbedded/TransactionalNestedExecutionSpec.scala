@@ -186,7 +186,7 @@ class TransactionalNestedExecutionSpec extends FlatSpec with Matchers with ApiSp
          |}
       """.stripMargin,
       project,
-      errorCode = 3039,
+      errorCode = "P2001",
       errorContains = s"No Node for the model Todo with value $falseWhereInError2 for innerUnique found."
     )
 
@@ -214,7 +214,7 @@ class TransactionalNestedExecutionSpec extends FlatSpec with Matchers with ApiSp
          |}
       """.stripMargin,
       project,
-      errorCode = 3039,
+      errorCode = "P2001",
       errorContains = s"No Node for the model Todo with value $falseWhereInError for innerUnique found."
     )
 
@@ -272,7 +272,7 @@ class TransactionalNestedExecutionSpec extends FlatSpec with Matchers with ApiSp
          |}
       """.stripMargin,
       project,
-      errorCode = 3041,
+      errorCode = "P2017",
       errorContains =
         s"The relation NoteToTodo has no node for the model Note connected to a Node for the model Todo with the value 'Some Inner ID2' for the field 'innerUnique' on your mutation path."
     )
@@ -323,7 +323,7 @@ class TransactionalNestedExecutionSpec extends FlatSpec with Matchers with ApiSp
          |}
       """.stripMargin,
       project,
-      errorCode = 3039,
+      errorCode = "P2001",
       errorContains = s"No Node for the model Todo with value $falseWhereInError for innerUnique found."
     )
 
@@ -350,7 +350,7 @@ class TransactionalNestedExecutionSpec extends FlatSpec with Matchers with ApiSp
          |}
       """.stripMargin,
       project,
-      errorCode = 3039,
+      errorCode = "P2001",
       errorContains = s"No Node for the model Note with value $falseWhereInError for outerUnique found."
     )
 
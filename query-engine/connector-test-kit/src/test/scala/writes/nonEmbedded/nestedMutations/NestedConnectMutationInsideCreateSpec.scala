@@ -46,7 +46,7 @@ class NestedConnectMutationInsideCreateSpec extends FlatSpec with Matchers with
            |}
         """.stripMargin,
         project,
-        errorCode = 3042,
+        errorCode = "P2014",
         errorContains = "The change you are trying to make would violate the required relation 'ChildToParent' between Child and Parent"
       )
 
@@ -94,7 +94,7 @@ class NestedConnectMutationInsideCreateSpec extends FlatSpec with Matchers with
            |}
         """.stripMargin,
         project,
-        errorCode = 3042,
+        errorCode = "P2014",
         errorContains = "The change you are trying to make would violate the required relation 'ChildToParent' between Child and Parent"
       )
 
@@ -501,7 +501,7 @@ class NestedConnectMutationInsideCreateSpec extends FlatSpec with Matchers with
            |}
         """.stripMargin,
         project,
-        errorCode = 3039,
+        errorCode = "P2001",
         errorContains = "No Node for the model Child with value DOES NOT EXIST for c found."
       )
 
@@ -872,7 +872,7 @@ class NestedConnectMutationInsideCreateSpec extends FlatSpec with Matchers with
          |}
       """.stripMargin,
       project,
-      errorCode = 3039,
+      errorCode = "P2001",
       errorContains = "No Node for the model Comment with value 5beea4aa6183dd734b2dbd9b for id found."
     )
   }
@@ -908,7 +908,7 @@ class NestedConnectMutationInsideCreateSpec extends FlatSpec with Matchers with
          |}
       """.stripMargin,
       project,
-      errorCode = 3039,
+      errorCode = "P2001",
       errorContains = "No Node for the model Todo with value 5beea4aa6183dd734b2dbd9b for id found."
     )
   }
@@ -962,7 +962,7 @@ class NestedConnectMutationInsideCreateSpec extends FlatSpec with Matchers with
          |}
       """.stripMargin,
       project,
-      errorCode = 3039,
+      errorCode = "P2001",
       errorContains = s"No Node for the model Comment with value $todoId for id found."
     )
   }
@@ -63,7 +63,7 @@ class NestedConnectMutationInsideUpdateSpec extends FlatSpec with Matchers with
          |}
       """,
         project,
-        errorCode = 3042,
+        errorCode = "P2014",
         errorContains = "The change you are trying to make would violate the required relation 'ChildToParent' between Child and Parent"
       )
 
@@ -128,7 +128,7 @@ class NestedConnectMutationInsideUpdateSpec extends FlatSpec with Matchers with
          |}
       """,
         project,
-        errorCode = 3042,
+        errorCode = "P2014",
         errorContains = "The change you are trying to make would violate the required relation 'ChildToParent' between Child and Parent"
       )
 
@@ -696,7 +696,7 @@ class NestedConnectMutationInsideUpdateSpec extends FlatSpec with Matchers with
          |}
       """,
         project,
-        errorCode = 3042,
+        errorCode = "P2014",
         errorContains = "The change you are trying to make would violate the required relation 'ChildToParent' between Child and Parent"
       )
 
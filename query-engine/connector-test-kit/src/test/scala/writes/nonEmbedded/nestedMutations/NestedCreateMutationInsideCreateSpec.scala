@@ -460,7 +460,7 @@ class NestedCreateMutationInsideCreateSpec extends WordSpecLike with Matchers wi
         |  }
       """.stripMargin,
       project,
-      errorCode = 3010,
+      errorCode = "P2002",
       errorContains = "A unique constraint would be violated on User. Details: Field name = unique"
     )
 
@@ -516,7 +516,7 @@ class NestedCreateMutationInsideCreateSpec extends WordSpecLike with Matchers wi
         |  }
       """.stripMargin,
       project,
-      errorCode = 3010,
+      errorCode = "P2002",
       errorContains = "A unique constraint would be violated on Post. Details: Field name = uniquePost"
     )
 
@@ -636,7 +636,7 @@ class NestedCreateMutationInsideCreateSpec extends WordSpecLike with Matchers wi
         |}
       """.stripMargin,
       project,
-      errorCode = 3032,
+      errorCode = "P2009",
       errorContains = "The field 'todo' on model 'Comment' is required. Performing this mutation would violate that constraint"
     )
   }
@@ -699,7 +699,7 @@ class NestedCreateMutationInsideCreateSpec extends WordSpecLike with Matchers wi
         |}
       """.stripMargin,
       project,
-      errorCode = 3032,
+      errorCode = "P2009",
       errorContains = "The field 'todo' on model 'Comment' is required. Performing this mutation would violate that constraint"
     )
 
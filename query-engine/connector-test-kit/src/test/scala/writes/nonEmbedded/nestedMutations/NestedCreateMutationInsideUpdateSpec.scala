@@ -51,7 +51,7 @@ class NestedCreateMutationInsideUpdateSpec extends FlatSpec with Matchers with A
          |}
       """.stripMargin,
         project,
-        errorCode = 3042,
+        errorCode = "P2014",
         errorContains = "The change you are trying to make would violate the required relation 'ChildToParent' between Child and Parent"
       )
 
@@ -291,7 +291,7 @@ class NestedCreateMutationInsideUpdateSpec extends FlatSpec with Matchers with A
          |}
       """.stripMargin,
         project,
-        errorCode = 3042,
+        errorCode = "P2014",
         errorContains = "The change you are trying to make would violate the required relation 'ChildToParent' between Child and Parent"
       )
 
@@ -42,7 +42,7 @@ class NestedDeleteManyMutationInsideUpdateSpec extends FlatSpec with Matchers wi
          |}
       """.stripMargin,
         project,
-        errorCode = 0,
+        errorCode = "P2009",
         errorContains = """ Reason: 'childOpt.deleteMany' Field 'deleteMany' is not defined in the input model 'ChildUpdateOneWithoutParentOptInput'."""
       )
     }
@@ -50,7 +50,7 @@ class NestedDeleteMutationInsideUpdateSpec extends FlatSpec with Matchers with A
          |}
       """.stripMargin,
         project,
-        errorCode = 0,
+        errorCode = "P2009",
         errorContains = "Argument 'data' expected model 'ParentUpdateInput!'"
       )
 
@@ -102,7 +102,7 @@ class NestedDeleteMutationInsideUpdateSpec extends FlatSpec with Matchers with A
          |}
       """.stripMargin,
         project,
-        errorCode = 0,
+        errorCode = "P2009",
         errorContains = "Argument 'data' expected model 'ParentUpdateInput!'"
       )
 
@@ -247,7 +247,7 @@ class NestedDeleteMutationInsideUpdateSpec extends FlatSpec with Matchers with A
          |}
       """.stripMargin,
         project,
-        errorCode = 3041
+        errorCode = "P2017"
       )
 
     //dataResolver(project).countByTable(project.schema.getModelByName_!("Parent").dbName).await should be(1)
@@ -436,7 +436,7 @@ class NestedDeleteMutationInsideUpdateSpec extends FlatSpec with Matchers with A
          |}
       """.stripMargin,
         project,
-        errorCode = 0,
+        errorCode = "P2009",
         errorContains = "Argument 'data' expected model 'ParentUpdateInput!'"
       )
 
@@ -782,7 +782,7 @@ class NestedDeleteMutationInsideUpdateSpec extends FlatSpec with Matchers with A
          |}
       """.stripMargin,
       project,
-      errorCode = 3042,
+      errorCode = "P2014",
       errorContains = """The change you are trying to make would violate the required relation 'ChildToOther' between Child and Other"""
     )
 
@@ -1302,7 +1302,7 @@ class NestedDeleteMutationInsideUpdateSpec extends FlatSpec with Matchers with A
          |}
       """.stripMargin,
       project,
-      errorCode = 3041,
+      errorCode = "P2017",
       errorContains =
         s"The relation NoteToTodo has no node for the model Note with the value '$noteId' for the field 'id' connected to a node for the model Todo on your mutation path."
     )
@@ -51,7 +51,7 @@ class NestedDeleteMutationInsideUpsertSpec extends FlatSpec with Matchers with A
          |}
       """,
         project,
-        errorCode = 0,
+        errorCode = "P2009",
         errorContains = "Argument 'update' expected model 'ParentUpdateInput!'"
       )
 
@@ -104,7 +104,7 @@ class NestedDeleteMutationInsideUpsertSpec extends FlatSpec with Matchers with A
          |}
       """,
         project,
-        errorCode = 0,
+        errorCode = "P2009",
         errorContains = "Argument 'update' expected model 'ParentUpdateInput!'"
       )
 
@@ -215,7 +215,7 @@ class NestedDeleteMutationInsideUpsertSpec extends FlatSpec with Matchers with A
          |}
       """,
         project,
-        errorCode = 3041
+        errorCode = "P2017"
       )
 
     ////dataResolver(project).countByTable(project.schema.getModelByName_!("Parent").dbName).await should be(1)
@@ -411,7 +411,7 @@ class NestedDeleteMutationInsideUpsertSpec extends FlatSpec with Matchers with A
          |}
       """,
         project,
-        errorCode = 0,
+        errorCode = "P2009",
         errorContains = "Argument 'update' expected model 'ParentUpdateInput!'"
       )
     }
@@ -588,7 +588,7 @@ class NestedDeleteMutationInsideUpsertSpec extends FlatSpec with Matchers with A
          |}
       """,
       project,
-      errorCode = 3042,
+      errorCode = "P2014",
       errorContains = """The change you are trying to make would violate the required relation 'ChildToReqOther' between Child and ReqOther"""
     )
 
@@ -1047,7 +1047,7 @@ class NestedDeleteMutationInsideUpsertSpec extends FlatSpec with Matchers with A
          |}
       """,
       project,
-      errorCode = 3041,
+      errorCode = "P2017",
       errorContains =
         s"The relation NoteToTodo has no node for the model Note with the value '$noteId' for the field 'id' connected to a node for the model Todo on your mutation path."
     )
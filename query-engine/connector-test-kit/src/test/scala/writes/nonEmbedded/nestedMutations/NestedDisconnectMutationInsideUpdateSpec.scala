@@ -106,7 +106,7 @@ class NestedDisconnectMutationInsideUpdateSpec extends FlatSpec with Matchers wi
          |}
       """.stripMargin,
         project,
-        errorCode = 3041
+        errorCode = "P2017"
       )
 
     //ifConnectorIsActive { dataResolver(project).countByTable("_ChildToParent").await should be(0) }
@@ -151,7 +151,7 @@ class NestedDisconnectMutationInsideUpdateSpec extends FlatSpec with Matchers wi
          |}
       """.stripMargin,
         project,
-        errorCode = 3042
+        errorCode = "P2014"
       )
 
     //ifConnectorIsActive { dataResolver(project).countByTable("_ChildToParent").await should be(1) }
@@ -196,7 +196,7 @@ class NestedDisconnectMutationInsideUpdateSpec extends FlatSpec with Matchers wi
          |}
       """.stripMargin,
         project,
-        errorCode = 3042,
+        errorCode = "P2014",
         errorContains = "The change you are trying to make would violate the required relation 'ChildToParent' between Child and Parent"
       )
 
@@ -945,7 +945,7 @@ class NestedDisconnectMutationInsideUpdateSpec extends FlatSpec with Matchers wi
          |}
       """.stripMargin,
       project,
-      errorCode = 3041
+      errorCode = "P2017"
 //      ,
 //      errorContains =
 //        "The relation UserFollows has no Node for the model User with value `Paul` for username connected to a Node for the model User with value `Anton` for username"
@@ -111,7 +111,7 @@ class NestedDisconnectMutationInsideUpsertSpec extends FlatSpec with Matchers wi
          |}
       """,
         project,
-        errorCode = 3041
+        errorCode = "P2017"
       )
 
     //ifConnectorIsActive { dataResolver(project).countByTable("_ChildToParent").await should be(0) }
@@ -157,7 +157,7 @@ class NestedDisconnectMutationInsideUpsertSpec extends FlatSpec with Matchers wi
          |}
       """,
         project,
-        errorCode = 3042
+        errorCode = "P2014"
       )
 
     //ifConnectorIsActive { dataResolver(project).countByTable("_ChildToParent").await should be(1) }
@@ -203,7 +203,7 @@ class NestedDisconnectMutationInsideUpsertSpec extends FlatSpec with Matchers wi
          |}
       """,
         project,
-        errorCode = 3042,
+        errorCode = "P2014",
         errorContains = "The change you are trying to make would violate the required relation 'ChildToParent' between Child and Parent"
       )
 
@@ -823,7 +823,7 @@ class NestedDisconnectMutationInsideUpsertSpec extends FlatSpec with Matchers wi
          |}
       """,
       project,
-      errorCode = 3041
+      errorCode = "P2017"
     )
   }
 
@@ -44,7 +44,7 @@ class NestedUpdateManyMutationInsideUpdateSpec extends FlatSpec with Matchers wi
          |}
       """.stripMargin,
         project,
-        errorCode = 0,
+        errorCode = "P2009",
         errorContains = """ Reason: 'childOpt.updateMany' Field 'updateMany' is not defined in the input model 'ChildUpdateOneWithoutParentOptInput'."""
       )
     }
@@ -367,7 +367,7 @@ class NestedUpdateMutationInsideUpdateSpec extends FlatSpec with Matchers with A
          |}
       """.stripMargin,
       project,
-      errorCode = 3039,
+      errorCode = "P2001",
       errorContains = "No Node for the model Todo with value DOES NOT EXIST for id found."
     )
 
@@ -433,7 +433,7 @@ class NestedUpdateMutationInsideUpdateSpec extends FlatSpec with Matchers with A
          |}
       """.stripMargin,
       project,
-      errorCode = 3039,
+      errorCode = "P2001",
       errorContains = "No Node for the model Todo with value 5beea4aa6183dd734b2dbd9b for id found."
     )
   }
@@ -499,7 +499,7 @@ class NestedUpdateMutationInsideUpdateSpec extends FlatSpec with Matchers with A
          |}
       """.stripMargin,
       project,
-      errorCode = 3040,
+      errorCode = "P2009",
       errorContains = "You provided a null value for the where clause on Todo."
     )
   }
@@ -943,7 +943,7 @@ class NestedUpdateMutationInsideUpdateSpec extends FlatSpec with Matchers with A
     server.queryThatMustFail(
       updateMutation,
       project,
-      errorCode = 3041,
+      errorCode = "P2017",
       errorContains =
         """The relation BelowToBottom has no node for the model Bottom connected to a Node for the model Below with the value 'other below' for the field 'nameBelow' on your mutation path."""
     )
@@ -1165,7 +1165,7 @@ class NestedUpdateMutationInsideUpdateSpec extends FlatSpec with Matchers with A
     server.queryThatMustFail(
       updateMutation,
       project,
-      errorCode = 3041,
+      errorCode = "P2017",
       errorContains = """The relation BottomToMiddle has no node for the model Middle connected to a Node for the model Bottom on your mutation path."""
     )
 
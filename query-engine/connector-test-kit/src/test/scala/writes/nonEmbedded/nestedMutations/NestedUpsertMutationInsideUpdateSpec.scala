@@ -533,7 +533,7 @@ class NestedUpsertMutationInsideUpdateSpec extends FlatSpec with Matchers with A
          |}
       """.stripMargin,
       project,
-      errorCode = 3010,
+      errorCode = "P2002",
       errorContains = "A unique constraint would be violated on Comment. Details: Field name = uniqueComment"
     )
   }
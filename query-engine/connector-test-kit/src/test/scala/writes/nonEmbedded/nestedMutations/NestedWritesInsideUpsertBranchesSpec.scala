@@ -0,0 +1,134 @@
+package writes.nonEmbedded.nestedMutations
+
+import org.scalatest.{FlatSpec, Matchers}
+import util.ConnectorCapability.JoinRelationLinksCapability
+import util._
+
+class NestedWritesInsideUpsertBranchesSpec extends FlatSpec with Matchers with ApiSpecBase {
+  override def runOnlyForCapabilities = Set(JoinRelationLinksCapability)
+
+  val project = SchemaDsl.fromStringV11() {
+    """
+      |model List {
+      |  id    String @id @default(cuid())
+      |  name  String @unique
+      |  todos Todo[]
+      |}
+      |
+      |model Todo {
+      |  id    String @id @default(cuid())
+      |  title String @unique
+      |  list  List
+      |  tags  Tag[]
+      |}
+      |
+      |model Tag {
+      |  id   String @id @default(cuid())
+      |  name String
+      |  todo Todo
+      |}
+    """
+  }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+  }
+
+  override def beforeEach(): Unit = database.truncateProjectTables(project)
+
+  "A root upsert" should "execute the nested writes of the create branch only if the record doesn't exist" in {
+    val result = upsertList("list", createTag = "created", updateTag = "updated")
+
+    result.toString should be("""{"data":{"upsertList":{"name":"list","todos":[{"title":"first","tags":[{"name":"created"}]}]}}}""")
+    tagCount should be(1)
+  }
+
+  "A root upsert" should "execute the nested writes of the update branch only if the record exists" in {
+    upsertList("list", createTag = "created", updateTag = "updated")
+    val result = upsertList("list", createTag = "created", updateTag = "updated")
+
+    result.toString should be(
+      """{"data":{"upsertList":{"name":"list","todos":[{"title":"first","tags":[{"name":"created"}]},{"title":"second","tags":[{"name":"updated"}]}]}}}""")
+    tagCount should be(2)
+  }
+
+  "A nested upsert" should "take the branch depending on whether the record is connected to the parent" in {
+    server.query("""mutation { createList(data: {name: "list", todos: {create: [{title: "existing"}]}}){ id } }""", project)
+
+    val result = server.query(
+      """
+        |mutation {
+        |  updateList(
+        |    where: {name: "list"}
+        |    data: {
+        |      todos: {
+        |        upsert: [
+        |          {
+        |            where: {title: "existing"}
+        |            create: {title: "never", tags: {create: [{name: "never"}]}}
+        |            update: {tags: {create: [{name: "on existing"}]}}
+        |          },
+        |          {
+        |            where: {title: "missing"}
+        |            create: {title: "new", tags: {create: [{name: "on new"}]}}
+        |            update: {tags: {create: [{name: "never"}]}}
+        |          }
+        |        ]
+        |      }
+        |    }
+        |  ){
+        |    todos { title tags { name } }
+        |  }
+        |}
+      """,
+      project
+    )
+
+    result.toString should be(
+      """{"data":{"updateList":{"todos":[{"title":"existing","tags":[{"name":"on existing"}]},{"title":"new","tags":[{"name":"on new"}]}]}}}""")
+    tagCount should be(2)
+  }
+
+  "A failing nested write inside an upsert branch" should "roll back the whole upsert" in {
+    server.query("""mutation { createList(data: {name: "list"}){ id } }""", project)
+
+    server.queryThatMustFail(
+      """
+        |mutation {
+        |  upsertList(
+        |    where: {name: "list"}
+        |    create: {name: "list"}
+        |    update: {name: "renamed", todos: {connect: [{title: "does not exist"}]}}
+        |  ){
+        |    name
+        |  }
+        |}
+      """,
+      project,
+      errorCode = "P2001"
+    )
+
+    server.query("""{ list(where: {name: "list"}) { name } }""", project).toString should be("""{"data":{"list":{"name":"list"}}}""")
+  }
+
+  private def upsertList(name: String, createTag: String, updateTag: String) = {
+    server.query(
+      s"""
+        |mutation {
+        |  upsertList(
+        |    where: {name: "$name"}
+        |    create: {name: "$name", todos: {create: [{title: "first", tags: {create: [{name: "$createTag"}]}}]}}
+        |    update: {todos: {create: [{title: "second", tags: {create: [{name: "$updateTag"}]}}]}}
+        |  ){
+        |    name
+        |    todos { title tags { name } }
+        |  }
+        |}
+      """,
+      project
+    )
+  }
+
+  private def tagCount: Int = server.query("{ tags { id } }", project).pathAsSeq("data.tags").size
+}
@@ -43,6 +43,10 @@ pub trait ScalarCompare {
     where
         T: Into<PrismaValue>;
 
+    fn search<T>(&self, val: T) -> Filter
+    where
+        T: Into<PrismaValue>;
+
     fn less_than<T>(&self, val: T) -> Filter
     where
         T: Into<PrismaValue>;
@@ -87,13 +91,13 @@ pub trait ScalarListCompare {
     where
         T: Into<PrismaValue>;
 
-    fn contains_every_element<T>(&self, filter: Vec<T>) -> Filter
+    fn contains_every_element<T>(&self, values: Vec<T>) -> Filter
     where
-        T: Into<Filter>;
+        T: Into<PrismaValue>;
 
-    fn contains_some_element<T>(&self, filter: Vec<T>) -> Filter
+    fn contains_some_element<T>(&self, values: Vec<T>) -> Filter
     where
-        T: Into<Filter>;
+        T: Into<PrismaValue>;
 
     fn contains_none(&self) -> Filter;
 }
@@ -14,7 +14,7 @@ impl RecordFinderInfo {
     pub fn for_id(model: ModelRef, value: &GraphqlId) -> Self {
         Self {
             model: model.name.clone(),
-            field: model.fields().id().name.clone(),
+            field: model.id_field_names().join("_"),
             value: PrismaValue::from(value.clone()),
         }
     }
@@ -32,21 +32,47 @@ impl fmt::Display for RecordFinderInfo {
 
 impl From<&RecordFinder> for RecordFinderInfo {
     fn from(ns: &RecordFinder) -> Self {
+        let field_names: Vec<String> = ns.fields().iter().map(|f| f.name.clone()).collect();
+
+        let value = match ns.single() {
+            Some((_, value)) => value.clone(),
+            None => {
+                let values: Vec<String> = ns.selectors.iter().map(|(_, value)| value.to_string()).collect();
+                PrismaValue::String(format!("({})", values.join(", ")))
+            }
+        };
+
         Self {
-            model: ns.field.model().name.clone(),
-            field: ns.field.name.clone(),
-            value: ns.value.clone(),
+            model: ns.model().name.clone(),
+            field: field_names.join("_"),
+            value,
         }
     }
 }
 
+/// The names of the model fields involved in a constraint violation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldNames(pub Vec<String>);
+
+impl fmt::Display for FieldNames {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({})", self.0.join(", "))
+    }
+}
+
 #[derive(Debug, Fail)]
 pub enum ConnectorError {
-    #[fail(display = "Unique constraint failed: {}", field_name)]
-    UniqueConstraintViolation { field_name: String },
+    #[fail(display = "Unique constraint failed on the fields {} of model {}", fields, model)]
+    UniqueConstraintViolation { model: String, fields: FieldNames },
 
-    #[fail(display = "Null constraint failed: {}", field_name)]
-    NullConstraintViolation { field_name: String },
+    #[fail(display = "Null constraint failed on the fields {} of model {}", fields, model)]
+    NullConstraintViolation { model: String, fields: FieldNames },
+
+    #[fail(
+        display = "Foreign key constraint failed on the fields {} of model {}",
+        fields, model
+    )]
+    ForeignKeyConstraintViolation { model: String, fields: FieldNames },
 
     #[fail(display = "Record does not exist.")]
     RecordDoesNotExist,
@@ -114,6 +140,9 @@ pub enum ConnectorError {
 
     #[fail(display = "Authentication failed for user '{}'", user)]
     AuthenticationFailed { user: String },
+
+    #[fail(display = "The transaction was already committed or rolled back.")]
+    TransactionAlreadyClosed,
 }
 
 impl From<DomainError> for ConnectorError {
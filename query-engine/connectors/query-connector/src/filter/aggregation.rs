@@ -0,0 +1,29 @@
+use super::ScalarCondition;
+use prisma_models::ScalarFieldRef;
+
+/// Filters on aggregated values of a group of records (`HAVING` semantics).
+#[derive(Debug, Clone)]
+pub enum AggregationFilter {
+    And(Vec<AggregationFilter>),
+    Or(Vec<AggregationFilter>),
+    Not(Vec<AggregationFilter>),
+    Count(ScalarCondition),
+    Sum(ScalarFieldRef, ScalarCondition),
+    Average(ScalarFieldRef, ScalarCondition),
+    Min(ScalarFieldRef, ScalarCondition),
+    Max(ScalarFieldRef, ScalarCondition),
+}
+
+impl AggregationFilter {
+    pub fn and(filters: Vec<AggregationFilter>) -> Self {
+        AggregationFilter::And(filters)
+    }
+
+    pub fn or(filters: Vec<AggregationFilter>) -> Self {
+        AggregationFilter::Or(filters)
+    }
+
+    pub fn not(filters: Vec<AggregationFilter>) -> Self {
+        AggregationFilter::Not(filters)
+    }
+}
@@ -4,11 +4,13 @@
 //! [ScalarCompare](/query-connector/trait.ScalarCompare.html) and
 //! [RelationCompare](/query-connector/trait.RelationCompare.html).
 
+mod aggregation;
 mod list;
 mod record_finder;
 mod relation;
 mod scalar;
 
+pub use aggregation::*;
 pub use list::*;
 pub use record_finder::*;
 pub use relation::*;
@@ -77,9 +79,21 @@ impl From<bool> for Filter {
 
 impl From<RecordFinder> for Filter {
     fn from(record_finder: RecordFinder) -> Self {
-        Filter::Scalar(ScalarFilter {
-            field: record_finder.field,
-            condition: ScalarCondition::Equals(record_finder.value),
-        })
+        let mut filters: Vec<Filter> = record_finder
+            .selectors
+            .into_iter()
+            .map(|(field, value)| {
+                Filter::Scalar(ScalarFilter {
+                    field,
+                    condition: ScalarCondition::Equals(value),
+                    mode: QueryMode::Default,
+                })
+            })
+            .collect();
+
+        match filters.len() {
+            1 => filters.pop().unwrap(),
+            _ => Filter::and(filters),
+        }
     }
 }
@@ -1,11 +1,12 @@
 use prisma_models::prelude::*;
 use std::sync::Arc;
 
-/// Designates a specific record to find by a field and a value that field should have.
+/// Designates a specific record to find by fields and the values those fields should have.
+/// Most finders select by a single unique field, compound finders select by all fields of a compound id
+/// or compound unique index.
 #[derive(Debug, Clone)]
 pub struct RecordFinder {
-    pub field: Arc<ScalarField>,
-    pub value: PrismaValue,
+    pub selectors: Vec<(Arc<ScalarField>, PrismaValue)>,
 }
 
 impl<T> From<(Arc<ScalarField>, T)> for RecordFinder
@@ -13,10 +14,7 @@ where
     T: Into<PrismaValue>,
 {
     fn from(tup: (Arc<ScalarField>, T)) -> RecordFinder {
-        RecordFinder {
-            field: tup.0,
-            value: tup.1.into(),
-        }
+        RecordFinder::new(tup.0, tup.1)
     }
 }
 
@@ -26,8 +24,44 @@ impl RecordFinder {
         T: Into<PrismaValue>,
     {
         Self {
-            field,
-            value: value.into(),
+            selectors: vec![(field, value.into())],
+        }
+    }
+
+    pub fn compound(selectors: Vec<(Arc<ScalarField>, PrismaValue)>) -> Self {
+        Self { selectors }
+    }
+
+    /// Finds the record with the given id, which is compound for models with a compound id.
+    pub fn for_id(model: &ModelRef, id: &GraphqlId) -> Self {
+        let selectors = model
+            .fields()
+            .id_fields()
+            .into_iter()
+            .zip(id.parts())
+            .map(|(field, part)| (field, PrismaValue::from(part)))
+            .collect();
+
+        Self { selectors }
+    }
+
+    pub fn model(&self) -> ModelRef {
+        self.selectors[0].0.model()
+    }
+
+    pub fn fields(&self) -> Vec<Arc<ScalarField>> {
+        self.selectors.iter().map(|(field, _)| Arc::clone(field)).collect()
+    }
+
+    pub fn is_compound(&self) -> bool {
+        self.selectors.len() > 1
+    }
+
+    /// The field and value of a finder selecting by a single field.
+    pub fn single(&self) -> Option<(&Arc<ScalarField>, &PrismaValue)> {
+        match self.selectors.as_slice() {
+            [(field, value)] => Some((field, value)),
+            _ => None,
         }
     }
 }
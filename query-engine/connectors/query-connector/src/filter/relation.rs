@@ -63,6 +63,7 @@ impl RelationCompare for Arc<RelationField> {
     ///             Filter::Scalar(ScalarFilter {
     ///                 field: scalar_field,
     ///                 condition: ScalarCondition::Equals(scalar_val),
+    ///                 ..
     ///             }) => {
     ///                 assert_eq!(String::from("name"), scalar_field.name);
     ///                 assert_eq!(PrismaValue::from("Blog"), scalar_val);
@@ -114,6 +115,7 @@ impl RelationCompare for Arc<RelationField> {
     ///             Filter::Scalar(ScalarFilter {
     ///                 field: scalar_field,
     ///                 condition: ScalarCondition::Equals(scalar_val),
+    ///                 ..
     ///             }) => {
     ///                 assert_eq!(String::from("name"), scalar_field.name);
     ///                 assert_eq!(PrismaValue::from("Blog"), scalar_val);
@@ -165,6 +167,7 @@ impl RelationCompare for Arc<RelationField> {
     ///             Filter::Scalar(ScalarFilter {
     ///                 field: scalar_field,
     ///                 condition: ScalarCondition::Equals(scalar_val),
+    ///                 ..
     ///             }) => {
     ///                 assert_eq!(String::from("name"), scalar_field.name);
     ///                 assert_eq!(PrismaValue::from("Blog"), scalar_val);
@@ -216,6 +219,7 @@ impl RelationCompare for Arc<RelationField> {
     ///             Filter::Scalar(ScalarFilter {
     ///                 field: scalar_field,
     ///                 condition: ScalarCondition::Equals(scalar_val),
+    ///                 ..
     ///             }) => {
     ///                 assert_eq!(String::from("name"), scalar_field.name);
     ///                 assert_eq!(PrismaValue::from("Blog"), scalar_val);
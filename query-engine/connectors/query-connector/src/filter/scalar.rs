@@ -7,6 +7,20 @@ use std::sync::Arc;
 pub struct ScalarFilter {
     pub field: Arc<ScalarField>,
     pub condition: ScalarCondition,
+    pub mode: QueryMode,
+}
+
+/// How string values are compared by a scalar filter.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum QueryMode {
+    Default,
+    Insensitive,
+}
+
+impl Default for QueryMode {
+    fn default() -> Self {
+        QueryMode::Default
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -19,6 +33,8 @@ pub enum ScalarCondition {
     NotStartsWith(PrismaValue),
     EndsWith(PrismaValue),
     NotEndsWith(PrismaValue),
+    /// Full-text search, backed by the full-text index of the database.
+    Search(PrismaValue),
     LessThan(PrismaValue),
     LessThanOrEquals(PrismaValue),
     GreaterThan(PrismaValue),
@@ -36,6 +52,7 @@ impl ScalarCompare for Arc<ScalarField> {
         Filter::from(ScalarFilter {
             field: Arc::clone(self),
             condition: ScalarCondition::In(val.map(|v| v.into_iter().map(|i| i.into()).collect())),
+            mode: QueryMode::Default,
         })
     }
 
@@ -47,6 +64,7 @@ impl ScalarCompare for Arc<ScalarField> {
         Filter::from(ScalarFilter {
             field: Arc::clone(self),
             condition: ScalarCondition::NotIn(val.map(|v| v.into_iter().map(|i| i.into()).collect())),
+            mode: QueryMode::Default,
         })
     }
 
@@ -66,7 +84,7 @@ impl ScalarCompare for Arc<ScalarField> {
     /// let filter = field.equals("foo");
     ///
     /// match filter {
-    ///     Filter::Scalar(ScalarFilter { field: field, condition: ScalarCondition::Equals(val) }) => {
+    ///     Filter::Scalar(ScalarFilter { field: field, condition: ScalarCondition::Equals(val), .. }) => {
     ///         assert_eq!(PrismaValue::from("foo"), val);
     ///         assert_eq!(String::from("name"), field.name);
     ///     }
@@ -80,6 +98,7 @@ impl ScalarCompare for Arc<ScalarField> {
         Filter::from(ScalarFilter {
             field: Arc::clone(self),
             condition: ScalarCondition::Equals(val.into()),
+            mode: QueryMode::Default,
         })
     }
 
@@ -99,7 +118,7 @@ impl ScalarCompare for Arc<ScalarField> {
     /// let filter = field.not_equals(false);
     ///
     /// match filter {
-    ///     Filter::Scalar(ScalarFilter { field: field, condition: ScalarCondition::NotEquals(val) }) => {
+    ///     Filter::Scalar(ScalarFilter { field: field, condition: ScalarCondition::NotEquals(val), .. }) => {
     ///         assert_eq!(PrismaValue::from(false), val);
     ///         assert_eq!(String::from("name"), field.name);
     ///     }
@@ -113,6 +132,7 @@ impl ScalarCompare for Arc<ScalarField> {
         Filter::from(ScalarFilter {
             field: Arc::clone(self),
             condition: ScalarCondition::NotEquals(val.into()),
+            mode: QueryMode::Default,
         })
     }
 
@@ -132,7 +152,7 @@ impl ScalarCompare for Arc<ScalarField> {
     /// let filter = field.contains("asdf");
     ///
     /// match filter {
-    ///     Filter::Scalar(ScalarFilter { field: field, condition: ScalarCondition::Contains(val) }) => {
+    ///     Filter::Scalar(ScalarFilter { field: field, condition: ScalarCondition::Contains(val), .. }) => {
     ///         assert_eq!(PrismaValue::from("asdf"), val);
     ///         assert_eq!(String::from("name"), field.name);
     ///     }
@@ -146,6 +166,7 @@ impl ScalarCompare for Arc<ScalarField> {
         Filter::from(ScalarFilter {
             field: Arc::clone(self),
             condition: ScalarCondition::Contains(val.into()),
+            mode: QueryMode::Default,
         })
     }
 
@@ -165,7 +186,7 @@ impl ScalarCompare for Arc<ScalarField> {
     /// let filter = field.not_contains("asdf");
     ///
     /// match filter {
-    ///     Filter::Scalar(ScalarFilter { field: field, condition: ScalarCondition::NotContains(val) }) => {
+    ///     Filter::Scalar(ScalarFilter { field: field, condition: ScalarCondition::NotContains(val), .. }) => {
     ///         assert_eq!(PrismaValue::from("asdf"), val);
     ///         assert_eq!(String::from("name"), field.name);
     ///     }
@@ -179,6 +200,7 @@ impl ScalarCompare for Arc<ScalarField> {
         Filter::from(ScalarFilter {
             field: Arc::clone(self),
             condition: ScalarCondition::NotContains(val.into()),
+            mode: QueryMode::Default,
         })
     }
 
@@ -198,7 +220,7 @@ impl ScalarCompare for Arc<ScalarField> {
     /// let filter = field.starts_with("qwert");
     ///
     /// match filter {
-    ///     Filter::Scalar(ScalarFilter { field: field, condition: ScalarCondition::StartsWith(val) }) => {
+    ///     Filter::Scalar(ScalarFilter { field: field, condition: ScalarCondition::StartsWith(val), .. }) => {
     ///         assert_eq!(PrismaValue::from("qwert"), val);
     ///         assert_eq!(String::from("name"), field.name);
     ///     }
@@ -212,6 +234,7 @@ impl ScalarCompare for Arc<ScalarField> {
         Filter::from(ScalarFilter {
             field: Arc::clone(self),
             condition: ScalarCondition::StartsWith(val.into()),
+            mode: QueryMode::Default,
         })
     }
 
@@ -231,7 +254,7 @@ impl ScalarCompare for Arc<ScalarField> {
     /// let filter = field.not_starts_with("qwert");
     ///
     /// match filter {
-    ///     Filter::Scalar(ScalarFilter { field: field, condition: ScalarCondition::NotStartsWith(val) }) => {
+    ///     Filter::Scalar(ScalarFilter { field: field, condition: ScalarCondition::NotStartsWith(val), .. }) => {
     ///         assert_eq!(PrismaValue::from("qwert"), val);
     ///         assert_eq!(String::from("name"), field.name);
     ///     }
@@ -245,6 +268,7 @@ impl ScalarCompare for Arc<ScalarField> {
         Filter::from(ScalarFilter {
             field: Arc::clone(self),
             condition: ScalarCondition::NotStartsWith(val.into()),
+            mode: QueryMode::Default,
         })
     }
 
@@ -264,7 +288,7 @@ impl ScalarCompare for Arc<ScalarField> {
     /// let filter = field.ends_with("musti");
     ///
     /// match filter {
-    ///     Filter::Scalar(ScalarFilter { field: field, condition: ScalarCondition::EndsWith(val) }) => {
+    ///     Filter::Scalar(ScalarFilter { field: field, condition: ScalarCondition::EndsWith(val), .. }) => {
     ///         assert_eq!(PrismaValue::from("musti"), val);
     ///         assert_eq!(String::from("name"), field.name);
     ///     }
@@ -278,6 +302,7 @@ impl ScalarCompare for Arc<ScalarField> {
         Filter::from(ScalarFilter {
             field: Arc::clone(self),
             condition: ScalarCondition::EndsWith(val.into()),
+            mode: QueryMode::Default,
         })
     }
 
@@ -297,7 +322,7 @@ impl ScalarCompare for Arc<ScalarField> {
     /// let filter = field.not_ends_with("naukio");
     ///
     /// match filter {
-    ///     Filter::Scalar(ScalarFilter { field: field, condition: ScalarCondition::NotEndsWith(val) }) => {
+    ///     Filter::Scalar(ScalarFilter { field: field, condition: ScalarCondition::NotEndsWith(val), .. }) => {
     ///         assert_eq!(PrismaValue::from("naukio"), val);
     ///         assert_eq!(String::from("name"), field.name);
     ///     }
@@ -311,6 +336,41 @@ impl ScalarCompare for Arc<ScalarField> {
         Filter::from(ScalarFilter {
             field: Arc::clone(self),
             condition: ScalarCondition::NotEndsWith(val.into()),
+            mode: QueryMode::Default,
+        })
+    }
+
+    /// Field matches the given full-text search query.
+    /// ```rust
+    /// # use query_connector::{*, filter::*};
+    /// # use prisma_models::*;
+    /// # use prisma_query::ast::*;
+    /// # use serde_json;
+    /// # use std::{fs::File, sync::Arc};
+    /// #
+    /// # let tmp: InternalDataModelTemplate = serde_json::from_reader(File::open("../sql-query-connector/test_schema.json").unwrap()).unwrap();
+    /// # let schema = tmp.build(String::from("test"));
+    /// # let model = schema.find_model("User").unwrap();
+    /// #
+    /// let field = model.fields().find_from_scalar("name").unwrap();
+    /// let filter = field.search("naukio");
+    ///
+    /// match filter {
+    ///     Filter::Scalar(ScalarFilter { field: field, condition: ScalarCondition::Search(val), .. }) => {
+    ///         assert_eq!(PrismaValue::from("naukio"), val);
+    ///         assert_eq!(String::from("name"), field.name);
+    ///     }
+    ///     _ => unreachable!()
+    /// }
+    /// ```
+    fn search<T>(&self, val: T) -> Filter
+    where
+        T: Into<PrismaValue>,
+    {
+        Filter::from(ScalarFilter {
+            field: Arc::clone(self),
+            condition: ScalarCondition::Search(val.into()),
+            mode: QueryMode::Default,
         })
     }
 
@@ -330,7 +390,7 @@ impl ScalarCompare for Arc<ScalarField> {
     /// let filter = field.less_than(10);
     ///
     /// match filter {
-    ///     Filter::Scalar(ScalarFilter { field: field, condition: ScalarCondition::LessThan(val) }) => {
+    ///     Filter::Scalar(ScalarFilter { field: field, condition: ScalarCondition::LessThan(val), .. }) => {
     ///         assert_eq!(PrismaValue::from(10), val);
     ///         assert_eq!(String::from("id"), field.name);
     ///     }
@@ -344,6 +404,7 @@ impl ScalarCompare for Arc<ScalarField> {
         Filter::from(ScalarFilter {
             field: Arc::clone(self),
             condition: ScalarCondition::LessThan(val.into()),
+            mode: QueryMode::Default,
         })
     }
 
@@ -363,7 +424,7 @@ impl ScalarCompare for Arc<ScalarField> {
     /// let filter = field.less_than_or_equals(10);
     ///
     /// match filter {
-    ///     Filter::Scalar(ScalarFilter { field: field, condition: ScalarCondition::LessThanOrEquals(val) }) => {
+    ///     Filter::Scalar(ScalarFilter { field: field, condition: ScalarCondition::LessThanOrEquals(val), .. }) => {
     ///         assert_eq!(PrismaValue::from(10), val);
     ///         assert_eq!(String::from("id"), field.name);
     ///     }
@@ -377,6 +438,7 @@ impl ScalarCompare for Arc<ScalarField> {
         Filter::from(ScalarFilter {
             field: Arc::clone(self),
             condition: ScalarCondition::LessThanOrEquals(val.into()),
+            mode: QueryMode::Default,
         })
     }
 
@@ -396,7 +458,7 @@ impl ScalarCompare for Arc<ScalarField> {
     /// let filter = field.greater_than(10);
     ///
     /// match filter {
-    ///     Filter::Scalar(ScalarFilter { field: field, condition: ScalarCondition::GreaterThan(val) }) => {
+    ///     Filter::Scalar(ScalarFilter { field: field, condition: ScalarCondition::GreaterThan(val), .. }) => {
     ///         assert_eq!(PrismaValue::from(10), val);
     ///         assert_eq!(String::from("id"), field.name);
     ///     }
@@ -410,6 +472,7 @@ impl ScalarCompare for Arc<ScalarField> {
         Filter::from(ScalarFilter {
             field: Arc::clone(self),
             condition: ScalarCondition::GreaterThan(val.into()),
+            mode: QueryMode::Default,
         })
     }
 
@@ -429,7 +492,7 @@ impl ScalarCompare for Arc<ScalarField> {
     /// let filter = field.greater_than_or_equals(10);
     ///
     /// match filter {
-    ///     Filter::Scalar(ScalarFilter { field: field, condition: ScalarCondition::GreaterThanOrEquals(val) }) => {
+    ///     Filter::Scalar(ScalarFilter { field: field, condition: ScalarCondition::GreaterThanOrEquals(val), .. }) => {
     ///         assert_eq!(PrismaValue::from(10), val);
     ///         assert_eq!(String::from("id"), field.name);
     ///     }
@@ -443,6 +506,7 @@ impl ScalarCompare for Arc<ScalarField> {
         Filter::from(ScalarFilter {
             field: Arc::clone(self),
             condition: ScalarCondition::GreaterThanOrEquals(val.into()),
+            mode: QueryMode::Default,
         })
     }
 }
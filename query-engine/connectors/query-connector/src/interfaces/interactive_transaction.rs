@@ -0,0 +1,23 @@
+use crate::{ManagedDatabaseReader, UnmanagedDatabaseWriter};
+use std::sync::Arc;
+
+/// Methods for connectors that are able to keep a transaction open across several executions.
+pub trait InteractiveTransactionCapable {
+    /// Starts a new transaction on a connection dedicated to it.
+    fn start_transaction(&self, db_name: String) -> crate::Result<OpenTransaction>;
+}
+
+/// Commits or rolls back an open transaction. Afterwards, the transaction can't be used anymore.
+pub trait TransactionHandle {
+    fn commit(&self) -> crate::Result<()>;
+
+    fn rollback(&self) -> crate::Result<()>;
+}
+
+/// A transaction that stays open until it is explicitly committed or rolled back.
+/// All reads and writes done through it are executed within the transaction.
+pub struct OpenTransaction {
+    pub reader: Arc<dyn ManagedDatabaseReader + Send + Sync + 'static>,
+    pub writer: Arc<dyn UnmanagedDatabaseWriter + Send + Sync + 'static>,
+    pub handle: Box<dyn TransactionHandle + Send + Sync + 'static>,
+}
@@ -1,4 +1,8 @@
-use crate::{filter::RecordFinder, query_arguments::QueryArguments};
+use crate::{
+    filter::{AggregationFilter, RecordFinder},
+    query_arguments::QueryArguments,
+    AggregationRow, AggregationSelection,
+};
 use prisma_models::prelude::*;
 use prisma_models::ScalarFieldRef;
 
@@ -38,6 +42,17 @@ pub trait ManagedDatabaseReader {
     /// Count the items in the model with the given arguments.
     fn count_by_model(&self, model: ModelRef, query_arguments: QueryArguments) -> crate::Result<usize>;
 
+    /// Aggregate the records of the model with the given arguments.
+    /// If `group_by` is not empty, one row per distinct group is returned, optionally filtered by `having`.
+    fn aggregate_records(
+        &self,
+        model: ModelRef,
+        query_arguments: QueryArguments,
+        selections: &[AggregationSelection],
+        group_by: &[ScalarFieldRef],
+        having: Option<AggregationFilter>,
+    ) -> crate::Result<Vec<AggregationRow>>;
+
     /// Count the items in the table.
     fn count_by_table(&self, database: &str, table: &str) -> crate::Result<usize>;
 }
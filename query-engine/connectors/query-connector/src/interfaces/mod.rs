@@ -9,7 +9,9 @@
 ///! Unmanaged query-connector interfaces take full responsibility of query execution.
 
 mod unmanaged_database_writer;
+mod interactive_transaction;
 mod managed_database_reader;
 
+pub use interactive_transaction::*;
 pub use managed_database_reader::*;
 pub use unmanaged_database_writer::*;
@@ -1,4 +1,8 @@
-use crate::{write_ast::RootWriteQuery, result_ast::WriteQueryResult};
+use crate::{
+    result_ast::WriteQueryResult,
+    write_ast::{NestedWriteQuery, RootWriteQuery},
+};
+use prisma_models::GraphqlId;
 use serde_json::Value;
 
 /// Methods for writing data.
@@ -9,4 +13,13 @@ pub trait UnmanagedDatabaseWriter {
     /// Executes the write query and all nested write queries, returning the result
     /// of the topmost write.
     fn execute(&self, db_name: String, write_query: RootWriteQuery) -> crate::Result<WriteQueryResult>;
+
+    /// Executes a single nested write query for the parent record with the given id. Nested writes of
+    /// the query are not executed, except for upserts, which execute the nested writes of the branch taken.
+    fn execute_nested(
+        &self,
+        db_name: String,
+        write_query: NestedWriteQuery,
+        parent_id: GraphqlId,
+    ) -> crate::Result<WriteQueryResult>;
 }
@@ -10,12 +10,12 @@ pub struct SkipAndLimit {
 #[derive(Debug, Default, Clone)]
 pub struct QueryArguments {
     pub skip: Option<i64>,
-    pub after: Option<GraphqlId>,
+    pub after: Option<RecordFinder>,
     pub first: Option<i64>,
-    pub before: Option<GraphqlId>,
+    pub before: Option<RecordFinder>,
     pub last: Option<i64>,
     pub filter: Option<Filter>,
-    pub order_by: Option<OrderBy>,
+    pub order_by: Vec<OrderBy>,
 }
 
 impl QueryArguments {
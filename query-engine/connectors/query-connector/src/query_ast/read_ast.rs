@@ -1,7 +1,10 @@
 //! Prisma read query AST
 
 use super::ModelExtractor;
-use crate::{filter::RecordFinder, QueryArguments};
+use crate::{
+    filter::{AggregationFilter, RecordFinder},
+    QueryArguments,
+};
 use prisma_models::prelude::*;
 use std::sync::Arc;
 
@@ -16,7 +19,7 @@ pub enum ReadQuery {
 impl ModelExtractor for ReadQuery {
     fn extract_model(&self) -> Option<ModelRef> {
         match self {
-            ReadQuery::RecordQuery(q) => q.record_finder.as_ref().map(|rf| rf.field.model()),
+            ReadQuery::RecordQuery(q) => q.record_finder.as_ref().map(|rf| rf.model()),
             ReadQuery::ManyRecordsQuery(q) => Some(Arc::clone(&q.model)),
             ReadQuery::RelatedRecordsQuery(q) => Some(q.parent_field.related_model()),
             ReadQuery::AggregateRecordsQuery(q) => Some(Arc::clone(&q.model)),
@@ -61,4 +64,56 @@ pub struct AggregateRecordsQuery {
     pub name: String,
     pub alias: Option<String>,
     pub model: ModelRef,
+    pub args: QueryArguments,
+    pub selectors: Vec<AggregationSelection>,
+    pub group_by: Vec<ScalarFieldRef>,
+    pub having: Option<AggregationFilter>,
+
+    /// Whether or not the query results in one row per group (`true`) or a single aggregated row (`false`).
+    pub is_grouped: bool,
+}
+
+/// A single selection of an aggregation query.
+/// Each selection maps to one or more values in an aggregation result row.
+#[derive(Debug, Clone)]
+pub enum AggregationSelection {
+    /// A grouping field, resolves to the value of the field for the group.
+    Field(ScalarFieldRef),
+
+    /// Number of records in the group.
+    Count,
+
+    /// Sum of the values of each field in the group.
+    Sum(Vec<ScalarFieldRef>),
+
+    /// Average of the values of each field in the group.
+    Average(Vec<ScalarFieldRef>),
+
+    /// Minimum value of each field in the group.
+    Min(Vec<ScalarFieldRef>),
+
+    /// Maximum value of each field in the group.
+    Max(Vec<ScalarFieldRef>),
+}
+
+impl AggregationSelection {
+    /// Type identifiers of the values this selection resolves to, in order.
+    pub fn type_identifiers(&self) -> Vec<TypeIdentifier> {
+        match self {
+            AggregationSelection::Field(field) => vec![field.type_identifier],
+            AggregationSelection::Count => vec![TypeIdentifier::Int],
+            AggregationSelection::Sum(fields) => fields
+                .iter()
+                .map(|f| match f.type_identifier {
+                    // Sums keep the type of the summed field, e.g. the precision of decimals.
+                    TypeIdentifier::Int => TypeIdentifier::Int,
+                    TypeIdentifier::Decimal => TypeIdentifier::Decimal,
+                    _ => TypeIdentifier::Float,
+                })
+                .collect(),
+            AggregationSelection::Average(fields) => fields.iter().map(|_| TypeIdentifier::Float).collect(),
+            AggregationSelection::Min(fields) => fields.iter().map(|f| f.type_identifier).collect(),
+            AggregationSelection::Max(fields) => fields.iter().map(|f| f.type_identifier).collect(),
+        }
+    }
 }
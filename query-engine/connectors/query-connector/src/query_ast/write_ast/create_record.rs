@@ -10,6 +10,16 @@ pub struct CreateRecord {
     pub nested_writes: NestedWriteQueries,
 }
 
+/// Creates several records of a model at once. Records created in bulk can't have nested writes.
+#[derive(Debug, Clone)]
+pub struct CreateManyRecords {
+    pub model: ModelRef,
+    pub args: Vec<PrismaArgs>,
+
+    /// Whether or not the ids of the created records are returned in addition to the count.
+    pub return_ids: bool,
+}
+
 #[derive(Debug, Clone)]
 pub struct NestedCreateRecord {
     pub relation_field: Arc<RelationField>,
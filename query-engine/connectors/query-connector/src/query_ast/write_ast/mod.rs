@@ -38,6 +38,7 @@ impl ModelExtractor for WriteQuery {
 #[derive(Debug, Clone)]
 pub enum RootWriteQuery {
     CreateRecord(Box<CreateRecord>),
+    CreateManyRecords(CreateManyRecords),
     UpdateRecord(Box<UpdateRecord>),
     DeleteRecord(DeleteRecord),
     UpsertRecord(Box<UpsertRecord>),
@@ -50,9 +51,10 @@ impl RootWriteQuery {
     pub fn extract_model(&self) -> Option<ModelRef> {
         match self {
             RootWriteQuery::CreateRecord(q) => Some(Arc::clone(&q.model)),
-            RootWriteQuery::UpdateRecord(q) => Some(q.where_.field.model()),
-            RootWriteQuery::DeleteRecord(q) => Some(q.where_.field.model()),
-            RootWriteQuery::UpsertRecord(q) => Some(q.where_.field.model()),
+            RootWriteQuery::CreateManyRecords(q) => Some(Arc::clone(&q.model)),
+            RootWriteQuery::UpdateRecord(q) => Some(q.where_.model()),
+            RootWriteQuery::DeleteRecord(q) => Some(q.where_.model()),
+            RootWriteQuery::UpsertRecord(q) => Some(q.where_.model()),
             RootWriteQuery::UpdateManyRecords(q) => Some(Arc::clone(&q.model)),
             RootWriteQuery::DeleteManyRecords(q) => Some(Arc::clone(&q.model)),
             _ => None,
@@ -1,6 +1,6 @@
 use crate::{QueryArguments, ScalarListValues};
 // use prisma_models::{GraphqlId, ManyRecords, PrismaValue, SingleRecord};
-use prisma_models::ManyRecords;
+use prisma_models::{ManyRecords, PrismaValue, ScalarFieldRef};
 
 // #[derive(Debug, Default)]
 // pub struct ReadQueryResult {
@@ -45,6 +45,7 @@ pub struct ReadQueryResult {
 pub enum ResultContent {
     RecordSelection(RecordSelection),
     Count(usize),
+    Aggregation(AggregationRecords),
 }
 
 #[derive(Debug, Default)]
@@ -64,6 +65,28 @@ pub struct RecordSelection {
     /// Required for result processing
     pub query_arguments: QueryArguments,
 
-    /// Name of the id field of the contained records.
-    pub id_field: String,
+    /// Names of the id fields of the contained records, more than one for compound ids.
+    pub id_fields: Vec<String>,
+}
+
+#[derive(Debug, Default)]
+pub struct AggregationRecords {
+    /// One row of aggregated values per group, or exactly one row if the query is not grouped.
+    pub rows: Vec<AggregationRow>,
+
+    /// Whether or not the rows are the result of a grouping.
+    pub is_grouped: bool,
+}
+
+/// A single row of an aggregation, holding the results in order of the query selections.
+pub type AggregationRow = Vec<AggregationResult>;
+
+#[derive(Debug, Clone)]
+pub enum AggregationResult {
+    Field(ScalarFieldRef, PrismaValue),
+    Count(usize),
+    Sum(ScalarFieldRef, PrismaValue),
+    Average(ScalarFieldRef, PrismaValue),
+    Min(ScalarFieldRef, PrismaValue),
+    Max(ScalarFieldRef, PrismaValue),
 }
@@ -5,6 +5,9 @@ use crate::{filter::RecordFinder, error::ConnectorError};
 pub enum Identifier {
     Id(GraphqlId),
     Count(usize),
+
+    /// Ids of all records affected by the write, in order.
+    Ids(Vec<GraphqlId>),
     Record(SingleRecord),
     None,
 }
@@ -50,13 +53,11 @@ impl WriteQueryResult {
     /// Attempts to convert a write query result into a RecordFinder required for dependent queries.
     /// Assumes ID field is used as dependent field (which is true for now in the current execution model).
     pub fn to_record_finder(&self, model: ModelRef) -> crate::Result<RecordFinder> {
-        let id_field = model.fields().id();
-
         match &self.identifier {
-            Identifier::Id(ref id) => Ok(RecordFinder::new(id_field, id)),
+            Identifier::Id(ref id) => Ok(RecordFinder::for_id(&model, id)),
             Identifier::Record(ref r) => r
-                .collect_id(&id_field.name)
-                .map(|id_val| RecordFinder::new(id_field, id_val))
+                .collect_id(&model.id_field_names())
+                .map(|id_val| RecordFinder::for_id(&model, &id_val))
                 .map_err(|err| err.into()),
 
             other => Err(ConnectorError::InternalConversionError(format!(
@@ -1,7 +1,7 @@
-use connector_interface::QueryArguments;
+use crate::{filter_conversion::record_finder_cond, ordering::Ordering};
+use connector_interface::{filter::RecordFinder, QueryArguments};
 use prisma_models::prelude::*;
 use prisma_query::ast::*;
-use std::sync::Arc;
 
 #[derive(Clone, Copy)]
 enum CursorType {
@@ -12,67 +12,87 @@ enum CursorType {
 pub struct CursorCondition;
 
 impl CursorCondition {
+    /// Builds a keyset condition for the cursor(s) of the query arguments. Cursors select the cursor
+    /// record by any of its unique fields.
+    ///
+    /// The condition respects all orderings, with the id field(s) as final tie-breaker.
+    /// For an ordering of `(a ASC, b DESC, id ASC)` and an after cursor `c`, the condition is:
+    /// `a > c.a OR (a = c.a AND b < c.b) OR (a = c.a AND b = c.b AND id > c.id)`.
     pub fn build(query_arguments: &QueryArguments, model: ModelRef) -> ConditionTree<'static> {
-        match (
-            query_arguments.before.as_ref(),
-            query_arguments.after.as_ref(),
-            query_arguments.order_by.as_ref(),
-        ) {
-            (None, None, _) => ConditionTree::NoCondition,
-            (before, after, order_by) => {
-                let field = match order_by {
-                    Some(order) => Arc::clone(&order.field),
-                    None => model.fields().id(),
-                };
+        match (query_arguments.before.as_ref(), query_arguments.after.as_ref()) {
+            (None, None) => ConditionTree::NoCondition,
+            (before, after) => {
+                let mut order_by: Vec<OrderBy> = query_arguments.order_by.clone();
 
-                let sort_order: SortOrder = order_by.map(|order| order.sort_order).unwrap_or(SortOrder::Ascending);
+                for id_field in model.fields().id_fields() {
+                    let ordered_by_id = order_by
+                        .iter()
+                        .any(|order| order.scalar_field().map(|f| f.name == id_field.name).unwrap_or(false));
 
-                let cursor_for = |cursor_type: CursorType, id: GraphqlId| {
-                    let model_id = model.fields().id();
-                    let where_condition = model_id.as_column().equals(id.clone());
+                    if !ordered_by_id {
+                        order_by.push(OrderBy::scalar(id_field, SortOrder::Ascending));
+                    }
+                }
 
-                    let select_query = Select::from_table(model.table())
-                        .column(field.as_column())
-                        .so_that(ConditionTree::single(where_condition));
+                let cursor_for = |cursor_type: CursorType, cursor: &RecordFinder| {
+                    // The value of the ordering for the cursor record. Orderings by the fields selecting the
+                    // cursor record use the given values, all others are looked up.
+                    let cursor_value = |order: &OrderBy| -> DatabaseValue<'static> {
+                        let selected_value = order.scalar_field().and_then(|field| {
+                            cursor
+                                .selectors
+                                .iter()
+                                .find(|(selector, _)| selector.name == field.name)
+                                .map(|(_, value)| value.clone())
+                        });
 
-                    let compare = match (cursor_type, sort_order) {
-                        (CursorType::Before, SortOrder::Ascending) => field
-                            .as_column()
-                            .equals(select_query.clone())
-                            .and(model_id.as_column().less_than(id))
-                            .or(field.as_column().less_than(select_query)),
-                        (CursorType::Before, SortOrder::Descending) => field
-                            .as_column()
-                            .equals(select_query.clone())
-                            .and(model_id.as_column().less_than(id))
-                            .or(field.as_column().greater_than(select_query)),
-                        (CursorType::After, SortOrder::Ascending) => field
-                            .as_column()
-                            .equals(select_query.clone())
-                            .and(model_id.as_column().greater_than(id))
-                            .or(field.as_column().greater_than(select_query)),
-                        (CursorType::After, SortOrder::Descending) => field
-                            .as_column()
-                            .equals(select_query.clone())
-                            .and(model_id.as_column().greater_than(id))
-                            .or(field.as_column().less_than(select_query)),
+                        match selected_value {
+                            Some(value) => value.into(),
+                            None => Select::from_table(model.table())
+                                .value(Ordering::expression(order))
+                                .so_that(record_finder_cond(cursor.clone()))
+                                .into(),
+                        }
                     };
 
-                    ConditionTree::single(compare)
+                    let compare = |order: &OrderBy| {
+                        let value = Ordering::expression(order);
+
+                        match (cursor_type, order.sort_order) {
+                            (CursorType::Before, SortOrder::Ascending) => value.less_than(cursor_value(order)),
+                            (CursorType::Before, SortOrder::Descending) => value.greater_than(cursor_value(order)),
+                            (CursorType::After, SortOrder::Ascending) => value.greater_than(cursor_value(order)),
+                            (CursorType::After, SortOrder::Descending) => value.less_than(cursor_value(order)),
+                        }
+                    };
+
+                    let mut alternatives: Vec<ConditionTree<'static>> = order_by
+                        .iter()
+                        .enumerate()
+                        .map(|(i, order)| {
+                            order_by[0..i]
+                                .iter()
+                                .rev()
+                                .fold(ConditionTree::single(compare(order)), |acc, prev| {
+                                    ConditionTree::and(Ordering::expression(prev).equals(cursor_value(prev)), acc)
+                                })
+                        })
+                        .collect();
+
+                    // There is always at least the id in the ordering.
+                    let last = alternatives.pop().unwrap();
+                    alternatives
+                        .into_iter()
+                        .rev()
+                        .fold(last, |acc, alternative| ConditionTree::or(alternative, acc))
                 };
 
                 let after_cursor = after
-                    .map(|id| {
-                        let id_val = id.clone();
-                        cursor_for(CursorType::After, id_val)
-                    })
+                    .map(|cursor| cursor_for(CursorType::After, cursor))
                     .unwrap_or(ConditionTree::NoCondition);
 
                 let before_cursor = before
-                    .map(|id| {
-                        let id_val = id.clone();
-                        cursor_for(CursorType::Before, id_val)
-                    })
+                    .map(|cursor| cursor_for(CursorType::Before, cursor))
                     .unwrap_or(ConditionTree::NoCondition);
 
                 ConditionTree::and(after_cursor, before_cursor)
@@ -19,6 +19,19 @@ pub trait SqlCapabilities {
     /// This we use to differentiate between databases with or without
     /// `ROW_NUMBER` function for related records pagination.
     type ManyRelatedRecordsBuilder: ManyRelatedRecordsQueryBuilder;
+
+    /// The maximum number of values that can be bound to a single statement.
+    const MAX_BIND_VALUES: usize;
+
+    /// The SQL dialect of the database, for the few conditions rendered differently per database.
+    const SQL_FAMILY: SqlFamily;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SqlFamily {
+    Postgres,
+    Mysql,
+    Sqlite,
 }
 
 /// A wrapper for relational databases due to trait restrictions. Implements the
@@ -1,4 +1,7 @@
-use crate::{query_builder::ManyRelatedRecordsWithUnionAll, FromSource, SqlCapabilities, Transaction, Transactional};
+use crate::{
+    query_builder::ManyRelatedRecordsWithUnionAll, DedicatedConnection, FromSource, SqlCapabilities, SqlFamily,
+    Transaction, TransactionConnection, Transactional,
+};
 use datamodel::Source;
 use prisma_query::{
     connector::{MysqlParams, Queryable},
@@ -25,6 +28,9 @@ impl FromSource for Mysql {
 
 impl SqlCapabilities for Mysql {
     type ManyRelatedRecordsBuilder = ManyRelatedRecordsWithUnionAll;
+
+    const MAX_BIND_VALUES: usize = 65535;
+    const SQL_FAMILY: SqlFamily = SqlFamily::Mysql;
 }
 
 impl Transactional for Mysql {
@@ -44,3 +50,9 @@ impl Transactional for Mysql {
         result
     }
 }
+
+impl DedicatedConnection for Mysql {
+    fn dedicated_connection(&self, _: &str) -> crate::Result<Box<dyn TransactionConnection + Send>> {
+        Ok(Box::new(self.pool.get()?))
+    }
+}
@@ -1,4 +1,7 @@
-use crate::{query_builder::ManyRelatedRecordsWithRowNumber, FromSource, SqlCapabilities, Transaction, Transactional};
+use crate::{
+    query_builder::ManyRelatedRecordsWithRowNumber, DedicatedConnection, FromSource, SqlCapabilities, SqlFamily,
+    Transaction, TransactionConnection, Transactional,
+};
 use datamodel::Source;
 use prisma_query::{
     connector::{PostgresParams, Queryable},
@@ -24,6 +27,9 @@ impl FromSource for PostgreSql {
 
 impl SqlCapabilities for PostgreSql {
     type ManyRelatedRecordsBuilder = ManyRelatedRecordsWithRowNumber;
+
+    const MAX_BIND_VALUES: usize = 32767;
+    const SQL_FAMILY: SqlFamily = SqlFamily::Postgres;
 }
 
 impl Transactional for PostgreSql {
@@ -42,3 +48,9 @@ impl Transactional for PostgreSql {
         result
     }
 }
+
+impl DedicatedConnection for PostgreSql {
+    fn dedicated_connection(&self, _: &str) -> crate::Result<Box<dyn TransactionConnection + Send>> {
+        Ok(Box::new(self.pool.get()?))
+    }
+}
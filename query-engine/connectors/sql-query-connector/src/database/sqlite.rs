@@ -1,4 +1,7 @@
-use crate::{query_builder::ManyRelatedRecordsWithRowNumber, FromSource, SqlCapabilities, Transaction, Transactional};
+use crate::{
+    query_builder::ManyRelatedRecordsWithRowNumber, DedicatedConnection, FromSource, SqlCapabilities, SqlFamily,
+    Transaction, TransactionConnection, Transactional,
+};
 use datamodel::Source;
 use prisma_query::{
     ast::ParameterizedValue,
@@ -8,6 +11,7 @@ use prisma_query::{
 use std::{collections::HashSet, convert::TryFrom};
 
 type Pool = r2d2::Pool<PrismaConnectionManager<SqliteConnectionManager>>;
+type PooledConnection = r2d2::PooledConnection<PrismaConnectionManager<SqliteConnectionManager>>;
 
 pub struct Sqlite {
     pool: Pool,
@@ -30,6 +34,35 @@ impl Sqlite {
     pub fn file_path(&self) -> &str {
         self.file_path.as_str()
     }
+
+    /// Attaches the database if necessary and enables foreign key checks.
+    fn prepare_connection(&self, conn: &mut PooledConnection, db: &str) -> crate::Result<()> {
+        let databases: HashSet<String> = conn
+            .query_raw("PRAGMA database_list", &[])?
+            .into_iter()
+            .map(|rr| {
+                let db_name = rr.into_iter().nth(1).unwrap();
+
+                db_name.into_string().unwrap()
+            })
+            .collect();
+
+        if !databases.contains(db) {
+            // This is basically hacked until we have a full rust stack with a migration engine.
+            // Currently, the scala tests use the JNA library to write to the database.
+            conn.execute_raw(
+                "ATTACH DATABASE ? AS ?",
+                &[
+                    ParameterizedValue::from(self.file_path.as_ref()),
+                    ParameterizedValue::from(db),
+                ],
+            )?;
+        }
+
+        conn.execute_raw("PRAGMA foreign_keys = ON", &[])?;
+
+        Ok(())
+    }
 }
 
 impl FromSource for Sqlite {
@@ -49,6 +82,9 @@ impl FromSource for Sqlite {
 
 impl SqlCapabilities for Sqlite {
     type ManyRelatedRecordsBuilder = ManyRelatedRecordsWithRowNumber;
+
+    const MAX_BIND_VALUES: usize = 999;
+    const SQL_FAMILY: SqlFamily = SqlFamily::Sqlite;
 }
 
 impl Transactional for Sqlite {
@@ -57,30 +93,7 @@ impl Transactional for Sqlite {
         F: FnOnce(&mut dyn Transaction) -> crate::Result<T>,
     {
         let mut conn = self.pool.get()?;
-
-        let databases: HashSet<String> = conn
-            .query_raw("PRAGMA database_list", &[])?
-            .into_iter()
-            .map(|rr| {
-                let db_name = rr.into_iter().nth(1).unwrap();
-
-                db_name.into_string().unwrap()
-            })
-            .collect();
-
-        if !databases.contains(db) {
-            // This is basically hacked until we have a full rust stack with a migration engine.
-            // Currently, the scala tests use the JNA library to write to the database.
-            conn.execute_raw(
-                "ATTACH DATABASE ? AS ?",
-                &[
-                    ParameterizedValue::from(self.file_path.as_ref()),
-                    ParameterizedValue::from(db),
-                ],
-            )?;
-        }
-
-        conn.execute_raw("PRAGMA foreign_keys = ON", &[])?;
+        self.prepare_connection(&mut conn, db)?;
 
         let result = {
             let mut tx = conn.start_transaction()?;
@@ -100,3 +113,12 @@ impl Transactional for Sqlite {
         result
     }
 }
+
+impl DedicatedConnection for Sqlite {
+    fn dedicated_connection(&self, db: &str) -> crate::Result<Box<dyn TransactionConnection + Send>> {
+        let mut conn = self.pool.get()?;
+        self.prepare_connection(&mut conn, db)?;
+
+        Ok(Box::new(conn))
+    }
+}
@@ -1,15 +1,21 @@
 use connector_interface::error::*;
 use failure::{Error, Fail};
-use prisma_models::prelude::DomainError;
+use prisma_models::prelude::{DomainError, ModelRef, RelationFieldRef};
 use std::string::FromUtf8Error;
 
 #[derive(Debug, Fail)]
 pub enum SqlError {
-    #[fail(display = "Unique constraint failed: {}", field_name)]
-    UniqueConstraintViolation { field_name: String },
+    #[fail(display = "Unique constraint failed on the fields {} of model {}", fields, model)]
+    UniqueConstraintViolation { model: String, fields: FieldNames },
 
-    #[fail(display = "Null constraint failed: {}", field_name)]
-    NullConstraintViolation { field_name: String },
+    #[fail(display = "Null constraint failed on the fields {} of model {}", fields, model)]
+    NullConstraintViolation { model: String, fields: FieldNames },
+
+    #[fail(
+        display = "Foreign key constraint failed on the fields {} of model {}",
+        fields, model
+    )]
+    ForeignKeyConstraintViolation { model: String, fields: FieldNames },
 
     #[fail(display = "Record does not exist.")]
     RecordDoesNotExist,
@@ -74,6 +80,9 @@ pub enum SqlError {
 
     #[fail(display = "Authentication failed for user '{}'", user)]
     AuthenticationFailed { user: String },
+
+    #[fail(display = "The transaction was already committed or rolled back.")]
+    TransactionAlreadyClosed,
 }
 
 impl From<tokio_postgres::error::Error> for SqlError {
@@ -85,10 +94,15 @@ impl From<tokio_postgres::error::Error> for SqlError {
 impl From<SqlError> for ConnectorError {
     fn from(sql: SqlError) -> Self {
         match sql {
-            SqlError::UniqueConstraintViolation { field_name } => {
-                ConnectorError::UniqueConstraintViolation { field_name }
+            SqlError::UniqueConstraintViolation { model, fields } => {
+                ConnectorError::UniqueConstraintViolation { model, fields }
+            }
+            SqlError::NullConstraintViolation { model, fields } => {
+                ConnectorError::NullConstraintViolation { model, fields }
+            }
+            SqlError::ForeignKeyConstraintViolation { model, fields } => {
+                ConnectorError::ForeignKeyConstraintViolation { model, fields }
             }
-            SqlError::NullConstraintViolation { field_name } => ConnectorError::NullConstraintViolation { field_name },
             SqlError::DatabaseDoesNotExist { db_name } => ConnectorError::DatabaseDoesNotExist { db_name },
             SqlError::DatabaseAccessDenied { db_name } => ConnectorError::DatabaseAccessDenied { db_name },
             SqlError::AuthenticationFailed { user } => ConnectorError::AuthenticationFailed { user },
@@ -125,8 +139,196 @@ impl From<SqlError> for ConnectorError {
             SqlError::ConversionError(e) => ConnectorError::ConversionError(e),
             SqlError::DatabaseCreationError(e) => ConnectorError::DatabaseCreationError(e),
             SqlError::QueryError(e) => ConnectorError::QueryError(e),
+            SqlError::TransactionAlreadyClosed => ConnectorError::TransactionAlreadyClosed,
+        }
+    }
+}
+
+impl SqlError {
+    /// Converts the error of a write to the given model. Unique, foreign key and null constraint violations
+    /// are parsed from the database specific error and mapped back to the model fields involved.
+    pub fn from_write_error(model: &ModelRef, error: prisma_query::error::Error) -> SqlError {
+        Self::from_violation(&model.name, error, |constraint| constraint_fields(model, constraint))
+    }
+
+    /// Converts the error of a write linking or unlinking records of the given relation field. Violations on
+    /// an inline relation column are mapped to the fields of the model holding the column, violations on a
+    /// relation table to the relation field itself.
+    pub fn from_relation_write_error(field: &RelationFieldRef, error: prisma_query::error::Error) -> SqlError {
+        match field.relation().inline_relation_column() {
+            Some(_) if field.relation_is_inlined_in_parent() => Self::from_write_error(&field.model(), error),
+            Some(_) => Self::from_write_error(&field.related_model(), error),
+            None => Self::from_violation(&field.model().name, error, |_| FieldNames(vec![field.name.clone()])),
+        }
+    }
+
+    fn from_violation<F>(model: &str, error: prisma_query::error::Error, fields: F) -> SqlError
+    where
+        F: Fn(&str) -> FieldNames,
+    {
+        let violation = match error {
+            prisma_query::error::Error::UniqueConstraintViolation { ref field_name } => {
+                Some(Violation::Unique(field_name.clone()))
+            }
+            prisma_query::error::Error::NullConstraintViolation { ref field_name } => {
+                Some(Violation::Null(field_name.clone()))
+            }
+            prisma_query::error::Error::QueryError(ref e) => Violation::parse(&format!("{}", e)),
+            _ => None,
+        };
+
+        let model = model.to_string();
+
+        match violation {
+            Some(Violation::Unique(constraint)) => SqlError::UniqueConstraintViolation {
+                model,
+                fields: fields(&constraint),
+            },
+            Some(Violation::Null(constraint)) => SqlError::NullConstraintViolation {
+                model,
+                fields: fields(&constraint),
+            },
+            Some(Violation::ForeignKey(constraint)) => SqlError::ForeignKeyConstraintViolation {
+                model,
+                fields: fields(&constraint),
+            },
+            None => SqlError::from(error),
+        }
+    }
+}
+
+/// A constraint violation as reported by the database. Holds the columns, the name of the
+/// violated constraint or index, or an empty string if the database doesn't tell.
+#[derive(Debug, PartialEq)]
+enum Violation {
+    Unique(String),
+    Null(String),
+    ForeignKey(String),
+}
+
+impl Violation {
+    /// Parses the error messages of Postgres, MySQL and SQLite.
+    fn parse(message: &str) -> Option<Violation> {
+        // Postgres 23505, 23503 and 23502. The detail names the columns, if available.
+        let detail_columns = || between(message, "Key (", ")=");
+
+        if let Some(constraint) = between(message, "violates unique constraint \"", "\"") {
+            return Some(Violation::Unique(detail_columns().unwrap_or(constraint)));
+        }
+
+        if let Some(constraint) = between(message, "violates foreign key constraint \"", "\"") {
+            return Some(Violation::ForeignKey(detail_columns().unwrap_or(constraint)));
+        }
+
+        if let Some(column) = between(message, "null value in column \"", "\"") {
+            return Some(Violation::Null(column));
+        }
+
+        // MySQL 1062, 1452 and 1048.
+        if message.contains("Duplicate entry ") {
+            if let Some(start) = message.rfind(" for key ") {
+                return Some(Violation::Unique(message[start + 9..].trim().to_string()));
+            }
+        }
+
+        if message.contains("a foreign key constraint fails") {
+            return Some(Violation::ForeignKey(
+                between(message, "FOREIGN KEY (", ")").unwrap_or_default(),
+            ));
+        }
+
+        if let Some(column) = between(message, "Column '", "' cannot be null") {
+            return Some(Violation::Null(column));
         }
+
+        // SQLite, which names the columns as `table.column`, except for foreign keys.
+        if let Some(columns) = after(message, "UNIQUE constraint failed: ") {
+            return Some(Violation::Unique(columns));
+        }
+
+        if let Some(columns) = after(message, "NOT NULL constraint failed: ") {
+            return Some(Violation::Null(columns));
+        }
+
+        if message.contains("FOREIGN KEY constraint failed") {
+            return Some(Violation::ForeignKey(String::new()));
+        }
+
+        None
+    }
+}
+
+fn between(message: &str, start: &str, end: &str) -> Option<String> {
+    let from = message.find(start)? + start.len();
+    let to = message[from..].find(end)? + from;
+
+    Some(message[from..to].to_string())
+}
+
+fn after(message: &str, start: &str) -> Option<String> {
+    let from = message.find(start)? + start.len();
+    let rest = &message[from..];
+
+    Some(rest.lines().next().unwrap_or(rest).trim().to_string())
+}
+
+/// Maps a list of columns (`email`, `"User"."email", "User"."name"`) or the name of a constraint or index
+/// (`User.email`, `Post_author_fkey`, `PRIMARY`) back to the names of the model fields it covers.
+/// Names that can't be mapped are returned as given.
+fn constraint_fields(model: &ModelRef, constraint: &str) -> FieldNames {
+    let table = model.db_name();
+
+    let names = constraint
+        .split(',')
+        .map(|name| name.trim().trim_matches(|c| c == '"' || c == '`' || c == '\''))
+        .filter(|name| !name.is_empty())
+        .flat_map(|name| {
+            if name == "PRIMARY" || name == format!("{}_pkey", table) {
+                return model.id_field_names();
+            }
+
+            // `table.column`, `"table"."column"` and the default index and constraint names.
+            let name = name.rsplit('.').next().unwrap().trim_matches('"');
+            let unprefixed = if name.starts_with(table) && name[table.len()..].starts_with('_') {
+                name[table.len() + 1..]
+                    .trim_end_matches("_fkey")
+                    .trim_end_matches("_key")
+            } else {
+                name
+            };
+
+            column_fields(model, name)
+                .or_else(|| column_fields(model, unprefixed))
+                .unwrap_or_else(|| vec![name.to_string()])
+        })
+        .collect();
+
+    FieldNames(names)
+}
+
+/// The field of a column, or the fields of the compound unique index whose column names joined by `_` match.
+fn column_fields(model: &ModelRef, column: &str) -> Option<Vec<String>> {
+    let fields = model.fields();
+
+    let scalar = fields.scalar().into_iter().find(|f| f.db_name() == column);
+    let relation = fields
+        .relation()
+        .into_iter()
+        .find(|f| f.relation_is_inlined_in_parent() && f.db_name() == column);
+
+    if let Some(field) = scalar {
+        return Some(vec![field.name.clone()]);
     }
+
+    if let Some(field) = relation {
+        return Some(vec![field.name.clone()]);
+    }
+
+    model
+        .compound_unique_fields()
+        .into_iter()
+        .find(|unique| unique.iter().map(|f| f.db_name()).collect::<Vec<_>>().join("_") == column)
+        .map(|unique| unique.iter().map(|f| f.name.clone()).collect())
 }
 
 impl From<prisma_query::error::Error> for SqlError {
@@ -139,13 +341,10 @@ impl From<prisma_query::error::Error> for SqlError {
             prisma_query::error::Error::ConnectTimeout => Self::ConnectionError(e.into()),
             prisma_query::error::Error::Timeout => Self::ConnectionError(e.into()),
 
-            prisma_query::error::Error::UniqueConstraintViolation { field_name } => {
-                Self::UniqueConstraintViolation { field_name }
-            }
-
-            prisma_query::error::Error::NullConstraintViolation { field_name } => {
-                Self::NullConstraintViolation { field_name }
-            }
+            // Without the model of the write, the violation can't be mapped to fields.
+            // Writes go through `from_write_error` or `from_relation_write_error` instead.
+            e @ prisma_query::error::Error::UniqueConstraintViolation { .. } => Self::QueryError(e.into()),
+            e @ prisma_query::error::Error::NullConstraintViolation { .. } => Self::QueryError(e.into()),
 
             prisma_query::error::Error::DatabaseDoesNotExist { db_name } => Self::DatabaseDoesNotExist { db_name },
             prisma_query::error::Error::DatabaseAccessDenied { db_name } => Self::DatabaseAccessDenied { db_name },
@@ -206,3 +405,70 @@ impl From<FromUtf8Error> for SqlError {
         SqlError::ColumnReadFailure(e.into())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Violation;
+
+    fn parse(message: &str) -> Option<Violation> {
+        Violation::parse(message)
+    }
+
+    #[test]
+    fn postgres_violations_must_be_parsed() {
+        assert_eq!(
+            parse("duplicate key value violates unique constraint \"User_email_key\"\nDETAIL: Key (email)=(a@b.c) already exists."),
+            Some(Violation::Unique("email".into()))
+        );
+        assert_eq!(
+            parse("duplicate key value violates unique constraint \"User_email_key\""),
+            Some(Violation::Unique("User_email_key".into()))
+        );
+        assert_eq!(
+            parse("insert or update on table \"Post\" violates foreign key constraint \"Post_author_fkey\"\nDETAIL: Key (author)=(1) is not present in table \"User\"."),
+            Some(Violation::ForeignKey("author".into()))
+        );
+        assert_eq!(
+            parse("null value in column \"name\" violates not-null constraint"),
+            Some(Violation::Null("name".into()))
+        );
+    }
+
+    #[test]
+    fn mysql_violations_must_be_parsed() {
+        assert_eq!(
+            parse("Duplicate entry 'a@b.c' for key 'email'"),
+            Some(Violation::Unique("'email'".into()))
+        );
+        assert_eq!(
+            parse("Cannot add or update a child row: a foreign key constraint fails (`db`.`Post`, CONSTRAINT `Post_ibfk_1` FOREIGN KEY (`author`) REFERENCES `User` (`id`))"),
+            Some(Violation::ForeignKey("`author`".into()))
+        );
+        assert_eq!(
+            parse("Column 'name' cannot be null"),
+            Some(Violation::Null("name".into()))
+        );
+    }
+
+    #[test]
+    fn sqlite_violations_must_be_parsed() {
+        assert_eq!(
+            parse("UNIQUE constraint failed: User.email, User.name"),
+            Some(Violation::Unique("User.email, User.name".into()))
+        );
+        assert_eq!(
+            parse("NOT NULL constraint failed: User.name"),
+            Some(Violation::Null("User.name".into()))
+        );
+        assert_eq!(
+            parse("FOREIGN KEY constraint failed"),
+            Some(Violation::ForeignKey(String::new()))
+        );
+    }
+
+    #[test]
+    fn other_errors_must_not_be_parsed_as_violations() {
+        assert_eq!(parse("no such table: User"), None);
+        assert_eq!(parse("relation \"User\" does not exist"), None);
+    }
+}
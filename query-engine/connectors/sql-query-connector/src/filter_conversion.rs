@@ -1,3 +1,4 @@
+use crate::SqlFamily;
 use connector_interface::filter::*;
 use prisma_models::prelude::*;
 use prisma_query::ast::*;
@@ -69,8 +70,9 @@ pub trait AliasedCondition {
     /// alias if provided, otherwise using the fully qualified path.
     ///
     /// Alias should be used only when nesting, making the top level queries
-    /// more explicit.
-    fn aliased_cond(self, alias: Option<Alias>) -> ConditionTree<'static>;
+    /// more explicit. The SQL family determines how case-insensitive
+    /// comparisons are rendered.
+    fn aliased_cond(self, alias: Option<Alias>, family: SqlFamily) -> ConditionTree<'static>;
 }
 
 trait AliasedSelect {
@@ -79,20 +81,20 @@ trait AliasedSelect {
     ///
     /// Alias should be used only when nesting, making the top level queries
     /// more explicit.
-    fn aliased_sel(self, alias: Option<Alias>) -> Select<'static>;
+    fn aliased_sel(self, alias: Option<Alias>, family: SqlFamily) -> Select<'static>;
 }
 
 impl AliasedCondition for Filter {
     /// Conversion from a `Filter` to a query condition tree. Aliased when in a nested `SELECT`.
-    fn aliased_cond(self, alias: Option<Alias>) -> ConditionTree<'static> {
+    fn aliased_cond(self, alias: Option<Alias>, family: SqlFamily) -> ConditionTree<'static> {
         match self {
             Filter::And(mut filters) => match filters.pop() {
                 None => ConditionTree::NoCondition,
                 Some(filter) => {
-                    let right = filter.aliased_cond(alias);
+                    let right = filter.aliased_cond(alias, family);
 
                     filters.into_iter().rev().fold(right, |acc, filter| {
-                        let left = filter.aliased_cond(alias);
+                        let left = filter.aliased_cond(alias, family);
                         ConditionTree::and(left, acc)
                     })
                 }
@@ -100,10 +102,10 @@ impl AliasedCondition for Filter {
             Filter::Or(mut filters) => match filters.pop() {
                 None => ConditionTree::NegativeCondition,
                 Some(filter) => {
-                    let right = filter.aliased_cond(alias);
+                    let right = filter.aliased_cond(alias, family);
 
                     filters.into_iter().rev().fold(right, |acc, filter| {
-                        let left = filter.aliased_cond(alias);
+                        let left = filter.aliased_cond(alias, family);
                         ConditionTree::or(left, acc)
                     })
                 }
@@ -111,17 +113,18 @@ impl AliasedCondition for Filter {
             Filter::Not(mut filters) => match filters.pop() {
                 None => ConditionTree::NoCondition,
                 Some(filter) => {
-                    let right = filter.aliased_cond(alias).not();
+                    let right = filter.aliased_cond(alias, family).not();
 
                     filters.into_iter().rev().fold(right, |acc, filter| {
-                        let left = filter.aliased_cond(alias).not();
+                        let left = filter.aliased_cond(alias, family).not();
                         ConditionTree::and(left, acc)
                     })
                 }
             },
-            Filter::Scalar(filter) => filter.aliased_cond(alias),
-            Filter::OneRelationIsNull(filter) => filter.aliased_cond(alias),
-            Filter::Relation(filter) => filter.aliased_cond(alias),
+            Filter::Scalar(filter) => filter.aliased_cond(alias, family),
+            Filter::ScalarList(filter) => filter.aliased_cond(alias, family),
+            Filter::OneRelationIsNull(filter) => filter.aliased_cond(alias, family),
+            Filter::Relation(filter) => filter.aliased_cond(alias, family),
             Filter::BoolFilter(b) => {
                 if b {
                     ConditionTree::NoCondition
@@ -136,48 +139,250 @@ impl AliasedCondition for Filter {
 
 impl AliasedCondition for ScalarFilter {
     /// Conversion from a `ScalarFilter` to a query condition tree. Aliased when in a nested `SELECT`.
-    fn aliased_cond(self, alias: Option<Alias>) -> ConditionTree<'static> {
+    fn aliased_cond(self, alias: Option<Alias>, family: SqlFamily) -> ConditionTree<'static> {
+        let column = match alias {
+            Some(ref alias) => self.field.as_column().table(alias.to_string(None)),
+            None => self.field.as_column(),
+        };
+
+        // The query mode is only offered by connectors whose pattern filters match case-insensitively,
+        // see `ConnectorCapability::InsensitiveFilters`, and is only accepted on pattern filters.
+        // Both modes render the same.
+        match self.condition {
+            ScalarCondition::Search(query) => convert_search_condition(&self.field, alias, query, family),
+            condition => ConditionTree::single(convert_scalar_condition(column, condition, family)),
+        }
+    }
+}
+
+impl AliasedCondition for ScalarListFilter {
+    /// Conversion from a `ScalarListFilter` to a query condition tree. Only lists stored in native arrays
+    /// can be filtered. The query builder has no array operators, so the schema only offers the emptiness
+    /// check, which compares with an empty array parameter.
+    fn aliased_cond(self, alias: Option<Alias>, _: SqlFamily) -> ConditionTree<'static> {
         let column = match alias {
             Some(ref alias) => self.field.as_column().table(alias.to_string(None)),
             None => self.field.as_column(),
         };
 
-        let condition = match self.condition {
-            ScalarCondition::Equals(PrismaValue::Null) => column.is_null(),
-            ScalarCondition::NotEquals(PrismaValue::Null) => column.is_not_null(),
-            ScalarCondition::Equals(value) => column.equals(value),
-            ScalarCondition::NotEquals(value) => column.not_equals(value),
-            ScalarCondition::Contains(value) => column.like(format!("{}", value)),
-            ScalarCondition::NotContains(value) => column.not_like(format!("{}", value)),
-            ScalarCondition::StartsWith(value) => column.begins_with(format!("{}", value)),
-            ScalarCondition::NotStartsWith(value) => column.not_begins_with(format!("{}", value)),
-            ScalarCondition::EndsWith(value) => column.ends_into(format!("{}", value)),
-            ScalarCondition::NotEndsWith(value) => column.not_ends_into(format!("{}", value)),
-            ScalarCondition::LessThan(value) => column.less_than(value),
-            ScalarCondition::LessThanOrEquals(value) => column.less_than_or_equals(value),
-            ScalarCondition::GreaterThan(value) => column.greater_than(value),
-            ScalarCondition::GreaterThanOrEquals(value) => column.greater_than_or_equals(value),
-            // We need to preserve the split first semantic for protobuf
-            ScalarCondition::In(Some(values)) => match values.split_first() {
-                Some((PrismaValue::Null, tail)) if tail.is_empty() => column.is_null(),
-                _ => column.in_selection(values),
+        match self.condition {
+            ScalarListCondition::ContainsNone => ConditionTree::or(
+                column.clone().is_null(),
+                column.equals(DatabaseValue::Parameterized(ParameterizedValue::Array(Vec::new()))),
+            ),
+            condition => unimplemented!("Scalar list condition {:?}", condition),
+        }
+    }
+}
+
+/// Conversion from a `RecordFinder` to a query condition tree. Record finders always compare
+/// case-sensitively, which renders the same for all SQL families.
+pub fn record_finder_cond(record_finder: RecordFinder) -> ConditionTree<'static> {
+    record_finder
+        .selectors
+        .into_iter()
+        .map(|(field, value)| match value {
+            PrismaValue::Null => ConditionTree::single(field.as_column().is_null()),
+            value => ConditionTree::single(field.as_column().equals(value)),
+        })
+        .fold(ConditionTree::NoCondition, |acc, condition| match acc {
+            ConditionTree::NoCondition => condition,
+            acc => ConditionTree::and(acc, condition),
+        })
+}
+
+/// Condition selecting the records with the given ids. Compound ids compare every id column:
+/// `(a = 1 AND b = 2) OR (a = 3 AND b = 4)`.
+pub fn ids_cond(model: &ModelRef, ids: &[&GraphqlId]) -> ConditionTree<'static> {
+    if !model.has_compound_id() {
+        return ConditionTree::single(model.fields().id().as_column().in_selection(ids.to_vec()));
+    }
+
+    ids.iter()
+        .map(|id| record_finder_cond(RecordFinder::for_id(model, id)))
+        .fold(ConditionTree::NegativeCondition, |acc, condition| match acc {
+            ConditionTree::NegativeCondition => condition,
+            acc => ConditionTree::or(acc, condition),
+        })
+}
+
+impl AliasedCondition for AggregationFilter {
+    /// Conversion from an `AggregationFilter` to a query condition tree. Aggregated columns always point
+    /// to the given alias, as aggregations are done over an aliased sub-select.
+    fn aliased_cond(self, alias: Option<Alias>, family: SqlFamily) -> ConditionTree<'static> {
+        let alias = alias.unwrap_or_default();
+        let column = |field: ScalarFieldRef| Column::from((alias.to_string(None), field.db_name().to_string()));
+
+        match self {
+            AggregationFilter::And(mut filters) => match filters.pop() {
+                None => ConditionTree::NoCondition,
+                Some(filter) => {
+                    let right = filter.aliased_cond(Some(alias), family);
+
+                    filters.into_iter().rev().fold(right, |acc, filter| {
+                        let left = filter.aliased_cond(Some(alias), family);
+                        ConditionTree::and(left, acc)
+                    })
+                }
             },
-            // We need to preserve the split first semantic for protobuf
-            ScalarCondition::NotIn(Some(values)) => match values.split_first() {
-                Some((PrismaValue::Null, tail)) if tail.is_empty() => column.is_not_null(),
-                _ => column.not_in_selection(values),
+            AggregationFilter::Or(mut filters) => match filters.pop() {
+                None => ConditionTree::NegativeCondition,
+                Some(filter) => {
+                    let right = filter.aliased_cond(Some(alias), family);
+
+                    filters.into_iter().rev().fold(right, |acc, filter| {
+                        let left = filter.aliased_cond(Some(alias), family);
+                        ConditionTree::or(left, acc)
+                    })
+                }
             },
-            ScalarCondition::In(None) => column.is_null(),
-            ScalarCondition::NotIn(None) => column.is_not_null(),
-        };
+            AggregationFilter::Not(mut filters) => match filters.pop() {
+                None => ConditionTree::NoCondition,
+                Some(filter) => {
+                    let right = filter.aliased_cond(Some(alias), family).not();
 
-        ConditionTree::single(condition)
+                    filters.into_iter().rev().fold(right, |acc, filter| {
+                        let left = filter.aliased_cond(Some(alias), family).not();
+                        ConditionTree::and(left, acc)
+                    })
+                }
+            },
+            AggregationFilter::Count(condition) => {
+                let expr = DatabaseValue::from(count(asterisk()));
+                ConditionTree::single(convert_scalar_condition(expr, condition, family))
+            }
+            AggregationFilter::Sum(field, condition) => {
+                let expr = DatabaseValue::from(sum(column(field)));
+                ConditionTree::single(convert_scalar_condition(expr, condition, family))
+            }
+            AggregationFilter::Average(field, condition) => {
+                let expr = DatabaseValue::from(avg(column(field)));
+                ConditionTree::single(convert_scalar_condition(expr, condition, family))
+            }
+            AggregationFilter::Min(field, condition) => {
+                let expr = DatabaseValue::from(min(column(field)));
+                ConditionTree::single(convert_scalar_condition(expr, condition, family))
+            }
+            AggregationFilter::Max(field, condition) => {
+                let expr = DatabaseValue::from(max(column(field)));
+                ConditionTree::single(convert_scalar_condition(expr, condition, family))
+            }
+        }
+    }
+}
+
+/// Converts a scalar condition into a comparison against the given expression.
+fn convert_scalar_condition<T>(comparable: T, condition: ScalarCondition, family: SqlFamily) -> Compare<'static>
+where
+    T: Comparable<'static>,
+{
+    match condition {
+        ScalarCondition::Equals(PrismaValue::Null) => comparable.is_null(),
+        ScalarCondition::NotEquals(PrismaValue::Null) => comparable.is_not_null(),
+        ScalarCondition::Equals(value) => comparable.equals(value),
+        ScalarCondition::NotEquals(value) => comparable.not_equals(value),
+        ScalarCondition::Contains(value) => comparable.like(escape_pattern(value, family)),
+        ScalarCondition::NotContains(value) => comparable.not_like(escape_pattern(value, family)),
+        ScalarCondition::StartsWith(value) => comparable.begins_with(escape_pattern(value, family)),
+        ScalarCondition::NotStartsWith(value) => comparable.not_begins_with(escape_pattern(value, family)),
+        ScalarCondition::EndsWith(value) => comparable.ends_into(escape_pattern(value, family)),
+        ScalarCondition::NotEndsWith(value) => comparable.not_ends_into(escape_pattern(value, family)),
+        ScalarCondition::Search(_) => unreachable!("Searches are converted with the full-text index of the field."),
+        ScalarCondition::LessThan(value) => comparable.less_than(value),
+        ScalarCondition::LessThanOrEquals(value) => comparable.less_than_or_equals(value),
+        ScalarCondition::GreaterThan(value) => comparable.greater_than(value),
+        ScalarCondition::GreaterThanOrEquals(value) => comparable.greater_than_or_equals(value),
+        // We need to preserve the split first semantic for protobuf
+        ScalarCondition::In(Some(values)) => match values.split_first() {
+            Some((PrismaValue::Null, tail)) if tail.is_empty() => comparable.is_null(),
+            _ => comparable.in_selection(values),
+        },
+        // We need to preserve the split first semantic for protobuf
+        ScalarCondition::NotIn(Some(values)) => match values.split_first() {
+            Some((PrismaValue::Null, tail)) if tail.is_empty() => comparable.is_not_null(),
+            _ => comparable.not_in_selection(values),
+        },
+        ScalarCondition::In(None) => comparable.is_null(),
+        ScalarCondition::NotIn(None) => comparable.is_not_null(),
+    }
+}
+
+/// Converts a full-text search into a lookup of the matching rows in the FTS5 table of the full-text index
+/// covering the field, which is named after the index. Searches are only offered on SQLite, see
+/// `ConnectorCapability::FullTextSearch`, and on fields covered by a full-text index, the others never match.
+fn convert_search_condition(
+    field: &ScalarFieldRef,
+    alias: Option<Alias>,
+    query: PrismaValue,
+    family: SqlFamily,
+) -> ConditionTree<'static> {
+    let index = match field.fulltext_index {
+        Some(ref index) if family == SqlFamily::Sqlite => index,
+        _ => return ConditionTree::NegativeCondition,
+    };
+
+    // A search without words matches nothing.
+    let fts5_query = match fts5_query(field.db_name(), query) {
+        Some(fts5_query) => fts5_query,
+        None => return ConditionTree::NegativeCondition,
+    };
+
+    let model = field.model();
+
+    // Comparing the hidden column named after the FTS5 table matches the whole table, the column filter
+    // restricts the query to the searched field.
+    let matching_rows = Select::from_table((model.internal_data_model().db_name.clone(), index.name.clone()))
+        .column("rowid")
+        .so_that(Column::from(index.name.clone()).equals(fts5_query));
+
+    let rowid: Column<'static> = match alias {
+        Some(ref alias) => Column::from("rowid").table(alias.to_string(None)),
+        None => Column::from((
+            (model.internal_data_model().db_name.clone(), model.db_name().to_string()),
+            "rowid",
+        )),
+    };
+
+    ConditionTree::single(rowid.in_selection(matching_rows))
+}
+
+/// Renders the words of the search as an FTS5 query on the given column, if there are any. Every word must
+/// match. Quoting the words keeps the FTS5 query syntax out of user input.
+fn fts5_query(column: &str, query: PrismaValue) -> Option<String> {
+    let column = format!("{{\"{}\"}}", column.replace('"', "\"\""));
+    let terms: Vec<String> = format!("{}", query)
+        .split_whitespace()
+        .map(|term| format!("{} : \"{}\"", column, term.replace('"', "\"\"")))
+        .collect();
+
+    if terms.is_empty() {
+        None
+    } else {
+        Some(terms.join(" AND "))
+    }
+}
+
+/// Escapes the pattern metacharacters of the value for a `LIKE` comparison, which adds the wildcards itself.
+/// Postgres and MySQL escape with a backslash by default. SQLite has no default escape character, so its
+/// metacharacters can't be escaped in a `LIKE` without `ESCAPE` clause and match as wildcards.
+fn escape_pattern(value: PrismaValue, family: SqlFamily) -> String {
+    let value = format!("{}", value);
+
+    match family {
+        SqlFamily::Sqlite => value,
+        _ => value
+            .chars()
+            .map(|c| match c {
+                '\\' | '%' | '_' => format!("\\{}", c),
+                c => c.to_string(),
+            })
+            .collect(),
     }
 }
 
 impl AliasedCondition for RelationFilter {
     /// Conversion from a `RelationFilter` to a query condition tree. Aliased when in a nested `SELECT`.
-    fn aliased_cond(self, alias: Option<Alias>) -> ConditionTree<'static> {
+    fn aliased_cond(self, alias: Option<Alias>, family: SqlFamily) -> ConditionTree<'static> {
         let id = self.field.model().id_column();
 
         let column = match alias {
@@ -186,7 +391,7 @@ impl AliasedCondition for RelationFilter {
         };
 
         let condition = self.condition.clone();
-        let sub_select = self.aliased_sel(alias.map(|a| a.inc(AliasMode::Table)));
+        let sub_select = self.aliased_sel(alias.map(|a| a.inc(AliasMode::Table)), family);
 
         let comparison = match condition {
             RelationCondition::EveryRelatedRecord => column.not_in_selection(sub_select),
@@ -201,7 +406,7 @@ impl AliasedCondition for RelationFilter {
 
 impl AliasedSelect for RelationFilter {
     /// The subselect part of the `RelationFilter` `ConditionTree`.
-    fn aliased_sel(self, alias: Option<Alias>) -> Select<'static> {
+    fn aliased_sel(self, alias: Option<Alias>, family: SqlFamily) -> Select<'static> {
         let alias = alias.unwrap_or(Alias::default());
         let condition = self.condition.clone();
         let relation = self.field.relation();
@@ -231,7 +436,7 @@ impl AliasedSelect for RelationFilter {
         match compacted {
             Filter::Relation(filter) => {
                 let sub_condition = filter.condition.clone();
-                let sub_select = filter.aliased_sel(Some(alias.inc(AliasMode::Table)));
+                let sub_select = filter.aliased_sel(Some(alias.inc(AliasMode::Table)), family);
 
                 let tree: ConditionTree<'static> = match sub_condition {
                     RelationCondition::EveryRelatedRecord => other_column.not_in_selection(sub_select),
@@ -248,7 +453,7 @@ impl AliasedSelect for RelationFilter {
                     .so_that(conditions)
             }
             nested_filter => {
-                let tree = nested_filter.aliased_cond(Some(alias.flip(AliasMode::Join)));
+                let tree = nested_filter.aliased_cond(Some(alias.flip(AliasMode::Join)), family);
 
                 let id_column = self
                     .field
@@ -276,7 +481,7 @@ impl AliasedSelect for RelationFilter {
 
 impl AliasedCondition for OneRelationIsNullFilter {
     /// Conversion from a `OneRelationIsNullFilter` to a query condition tree. Aliased when in a nested `SELECT`.
-    fn aliased_cond(self, alias: Option<Alias>) -> ConditionTree<'static> {
+    fn aliased_cond(self, alias: Option<Alias>, _: SqlFamily) -> ConditionTree<'static> {
         let alias = alias.map(|a| a.to_string(None));
 
         let condition = if self.field.relation_is_inlined_in_parent() {
@@ -305,3 +510,40 @@ impl AliasedCondition for OneRelationIsNullFilter {
         ConditionTree::single(condition)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{escape_pattern, fts5_query};
+    use crate::SqlFamily;
+    use prisma_models::PrismaValue;
+
+    #[test]
+    fn escapes_like_metacharacters_with_a_backslash() {
+        let value = PrismaValue::String(String::from(r"50%_off\now"));
+
+        assert_eq!(r"50\%\_off\\now", escape_pattern(value.clone(), SqlFamily::Postgres));
+        assert_eq!(r"50\%\_off\\now", escape_pattern(value, SqlFamily::Mysql));
+    }
+
+    #[test]
+    fn keeps_patterns_on_sqlite_as_they_are() {
+        let value = PrismaValue::String(String::from("50%_off"));
+
+        assert_eq!("50%_off", escape_pattern(value, SqlFamily::Sqlite));
+    }
+
+    #[test]
+    fn restricts_every_word_of_a_search_to_the_column() {
+        let query = PrismaValue::String(String::from(r#"rust  "engine" OR"#));
+
+        assert_eq!(
+            Some(r#"{"title"} : "rust" AND {"title"} : """engine""" AND {"title"} : "OR""#.to_string()),
+            fts5_query("title", query)
+        );
+    }
+
+    #[test]
+    fn searches_without_words_have_no_query() {
+        assert_eq!(None, fts5_query("title", PrismaValue::String(String::from("  "))));
+    }
+}
@@ -1,3 +1,4 @@
+use crate::filter_conversion::{Alias, AliasMode};
 use prisma_models::prelude::*;
 use prisma_query::ast::*;
 
@@ -7,77 +8,176 @@ pub struct Ordering;
 
 /// Tooling for generating orderings for different query types.
 impl Ordering {
-    pub fn for_model(model: ModelRef, order_by: Option<&OrderBy>, reverse: bool) -> OrderVec<'static> {
+    pub fn for_model(model: ModelRef, order_by: &[OrderBy], reverse: bool) -> OrderVec<'static> {
         Self::by_fields(
-            order_by.map(|oby| oby.field.as_column()),
-            model.fields().id().as_column(),
-            order_by,
+            order_by
+                .iter()
+                .map(|oby| (Self::expression(oby), oby.sort_order))
+                .collect(),
+            model.id_columns().into_iter().map(DatabaseValue::from).collect(),
             reverse,
         )
     }
 
-    pub fn internal<C>(second_field: C, order_by: Option<&OrderBy>, reverse: bool) -> OrderVec<'static>
+    pub fn internal<C>(second_field: C, order_by: &[OrderBy], reverse: bool) -> OrderVec<'static>
     where
         C: Into<Column<'static>>,
     {
         Self::by_fields(
-            order_by.map(|oby| oby.field.as_column()),
-            second_field.into(),
-            order_by,
+            order_by
+                .iter()
+                .map(|oby| (Self::expression(oby), oby.sort_order))
+                .collect(),
+            vec![second_field.into().into()],
             reverse,
         )
     }
 
+    /// Orders by columns of an aliased sub-select. Orderings are expected to be selected
+    /// in the sub-select as named by `Ordering::column_name`.
     pub fn aliased_internal(
         alias: &str,
         secondary_alias: &str,
         secondary_field: &str,
-        order_by: Option<&OrderBy>,
+        order_by: &[OrderBy],
         reverse: bool,
     ) -> OrderVec<'static> {
         Self::by_fields(
-            order_by.map(|oby| (alias.to_string(), oby.field.db_name().to_string()).into()),
-            (secondary_alias.to_string(), secondary_field.to_string()).into(),
-            order_by,
+            order_by
+                .iter()
+                .enumerate()
+                .map(|(i, oby)| {
+                    let column: Column<'static> = (alias.to_string(), Self::column_name(oby, i)).into();
+                    (column.into(), oby.sort_order)
+                })
+                .collect(),
+            vec![Column::from((secondary_alias.to_string(), secondary_field.to_string())).into()],
             reverse,
         )
     }
 
-    fn by_fields(
-        first_column: Option<Column<'static>>,
-        second_column: Column<'static>,
-        order_by: Option<&OrderBy>,
-        reverse: bool,
-    ) -> OrderVec<'static> {
-        let default_order = order_by
-            .as_ref()
-            .map(|order| order.sort_order)
-            .unwrap_or(SortOrder::Ascending);
-
-        match first_column {
-            Some(first) => {
-                if first != second_column {
-                    match (default_order, reverse) {
-                        (SortOrder::Ascending, true) => vec![first.descend(), second_column.descend()],
-                        (SortOrder::Descending, true) => vec![first.ascend(), second_column.descend()],
-                        (SortOrder::Ascending, false) => vec![first.ascend(), second_column.ascend()],
-                        (SortOrder::Descending, false) => vec![first.descend(), second_column.ascend()],
-                    }
-                } else {
-                    match (default_order, reverse) {
-                        (SortOrder::Ascending, true) => vec![second_column.descend()],
-                        (SortOrder::Descending, true) => vec![second_column.ascend()],
-                        (SortOrder::Ascending, false) => vec![second_column.ascend()],
-                        (SortOrder::Descending, false) => vec![second_column.descend()],
+    /// Orders by the selected columns of the query itself, without referring to any table. Orderings
+    /// are expected to be selected as named by `Ordering::column_name`, see `Ordering::select_columns`.
+    pub fn selected_internal(secondary_field: &str, order_by: &[OrderBy], reverse: bool) -> OrderVec<'static> {
+        Self::by_fields(
+            order_by
+                .iter()
+                .enumerate()
+                .map(|(i, oby)| (Column::from(Self::column_name(oby, i)).into(), oby.sort_order))
+                .collect(),
+            vec![Column::from(secondary_field.to_string()).into()],
+            reverse,
+        )
+    }
+
+    /// Adds the values of all orderings to the selection of the given query, named as by `Ordering::column_name`,
+    /// which allows ordering aliased sub-selects and unions by the selected columns.
+    pub fn select_columns(
+        query: Select<'static>,
+        selected_fields: &SelectedFields,
+        order_by: &[OrderBy],
+    ) -> Select<'static> {
+        order_by
+            .iter()
+            .enumerate()
+            .fold(query, |query, (i, order_by)| match order_by.scalar_field() {
+                Some(field) => {
+                    let column = field.as_column();
+
+                    if selected_fields.columns().contains(&column) {
+                        query
+                    } else {
+                        query.column(column)
                     }
                 }
+                None => query.value(Self::expression(order_by).alias(Self::column_name(order_by, i))),
+            })
+    }
+
+    /// The name of the `index`th ordering when selected as a column. Scalar fields of the
+    /// ordered model keep their name, all other orderings are selected under a generated name.
+    pub fn column_name(order_by: &OrderBy, index: usize) -> String {
+        match order_by.scalar_field() {
+            Some(field) => field.db_name().to_string(),
+            None => format!("__OrderBy{}__", index),
+        }
+    }
+
+    /// The value to order by, relative to the unaliased table of the ordered model.
+    ///
+    /// Orderings on related records are resolved with correlated sub-selects, e.g. for `author_name`:
+    /// `(SELECT j1.name FROM Post AS t1 INNER JOIN User AS j1 ON j1.id = t1.author WHERE t1.id = Post.id)`.
+    pub fn expression(order_by: &OrderBy) -> DatabaseValue<'static> {
+        Self::expression_at(&order_by.path, &order_by.target, None)
+    }
+
+    fn expression_at(
+        path: &[RelationFieldRef],
+        target: &OrderByTarget,
+        alias: Option<Alias>,
+    ) -> DatabaseValue<'static> {
+        let table_alias = alias.map(|a| a.to_string(Some(AliasMode::Join)));
+        let next_alias = alias.unwrap_or_default().inc(AliasMode::Join);
+        let relation_table_alias = next_alias.to_string(Some(AliasMode::Table));
+
+        match (path.split_first(), target) {
+            (Some((rf, rest)), _) => {
+                let related_alias = next_alias.to_string(Some(AliasMode::Join));
+                let related_id = rf.related_model().id_column().table(related_alias.clone());
+                let parent_id = rf.model().id_column().opt_table(table_alias);
+
+                let join = rf
+                    .related_model()
+                    .table()
+                    .alias(related_alias)
+                    .on(related_id.equals(rf.opposite_column().table(relation_table_alias.clone())));
+
+                Select::from_table(rf.relation().relation_table().alias(relation_table_alias.clone()))
+                    .value(Self::expression_at(rest, target, Some(next_alias)))
+                    .inner_join(join)
+                    .so_that(rf.relation_column().table(relation_table_alias).equals(parent_id))
+                    .into()
             }
-            _ => match (default_order, reverse) {
-                (SortOrder::Ascending, true) => vec![second_column.descend()],
-                (SortOrder::Descending, true) => vec![second_column.ascend()],
-                (SortOrder::Ascending, false) => vec![second_column.ascend()],
-                (SortOrder::Descending, false) => vec![second_column.descend()],
-            },
+            (None, OrderByTarget::Field(field)) => field.as_column().opt_table(table_alias).into(),
+            (None, OrderByTarget::RelationCount(rf)) => {
+                let parent_id = rf.model().id_column().opt_table(table_alias);
+
+                Select::from_table(rf.relation().relation_table().alias(relation_table_alias.clone()))
+                    .value(count(asterisk()))
+                    .so_that(rf.relation_column().table(relation_table_alias).equals(parent_id))
+                    .into()
+            }
+        }
+    }
+
+    /// Orders by the given values in order of appearance. The tie-breaker columns (e.g. all columns
+    /// of a compound id) guarantee a stable ordering, unless they are already part of the ordering.
+    fn by_fields(
+        values: Vec<(DatabaseValue<'static>, SortOrder)>,
+        tie_breakers: Vec<DatabaseValue<'static>>,
+        reverse: bool,
+    ) -> OrderVec<'static> {
+        let order = |value: DatabaseValue<'static>, sort_order: SortOrder| match (sort_order, reverse) {
+            (SortOrder::Ascending, true) => value.descend(),
+            (SortOrder::Descending, true) => value.ascend(),
+            (SortOrder::Ascending, false) => value.ascend(),
+            (SortOrder::Descending, false) => value.descend(),
+        };
+
+        let missing: Vec<DatabaseValue<'static>> = tie_breakers
+            .into_iter()
+            .filter(|column| values.iter().all(|(value, _)| value != column))
+            .collect();
+
+        let mut result: OrderVec<'static> = values
+            .into_iter()
+            .map(|(value, sort_order)| order(value, sort_order))
+            .collect();
+
+        for column in missing {
+            result.push(order(column, SortOrder::Ascending));
         }
+
+        result
     }
 }
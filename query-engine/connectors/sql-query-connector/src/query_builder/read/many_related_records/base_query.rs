@@ -1,4 +1,4 @@
-use crate::{cursor_condition::CursorCondition, filter_conversion::AliasedCondition};
+use crate::{cursor_condition::CursorCondition, filter_conversion::AliasedCondition, SqlFamily};
 use connector_interface::{QueryArguments, SkipAndLimit};
 use prisma_models::prelude::*;
 use prisma_query::ast::{Aliasable, Comparable, ConditionTree, Joinable, Select};
@@ -9,7 +9,7 @@ pub struct ManyRelatedRecordsBaseQuery<'a> {
     pub selected_fields: &'a SelectedFields,
     pub from_record_ids: &'a [GraphqlId],
     pub query: Select<'static>,
-    pub order_by: Option<OrderBy>,
+    pub order_by: Vec<OrderBy>,
     pub is_reverse_order: bool,
     pub condition: ConditionTree<'static>,
     pub cursor: ConditionTree<'static>,
@@ -23,6 +23,7 @@ impl<'a> ManyRelatedRecordsBaseQuery<'a> {
         from_record_ids: &'a [GraphqlId],
         query_arguments: QueryArguments,
         selected_fields: &'a SelectedFields,
+        family: SqlFamily,
     ) -> ManyRelatedRecordsBaseQuery<'a> {
         let cursor = CursorCondition::build(&query_arguments, from_field.related_model());
         let window_limits = query_arguments.window_limits();
@@ -30,7 +31,7 @@ impl<'a> ManyRelatedRecordsBaseQuery<'a> {
 
         let condition = query_arguments
             .filter
-            .map(|f| f.aliased_cond(None))
+            .map(|f| f.aliased_cond(None, family))
             .unwrap_or(ConditionTree::NoCondition);
 
         let opposite_column = from_field.opposite_column().table(Relation::TABLE_ALIAS);
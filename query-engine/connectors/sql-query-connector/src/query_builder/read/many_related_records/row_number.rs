@@ -1,7 +1,7 @@
 use super::*;
 use crate::ordering::Ordering;
 use prisma_models::prelude::*;
-use prisma_query::ast::{row_number, Aliasable, Comparable, Conjuctive, Function, Select, Table};
+use prisma_query::ast::{row_number, Comparable, Conjuctive, Function, Select, Table};
 
 pub struct ManyRelatedRecordsWithRowNumber;
 
@@ -15,21 +15,14 @@ impl ManyRelatedRecordsQueryBuilder for ManyRelatedRecordsWithRowNumber {
             .and(base.condition)
             .and(base.cursor);
 
-        let mut base_query = base.query.so_that(conditions);
-
-        if let Some(order_by) = base.order_by.as_ref() {
-            let column = order_by.field.as_column();
-
-            if !base.selected_fields.columns().contains(&column) {
-                base_query = base_query.column(order_by.field.as_column());
-            }
-        }
+        // The orderings must be available to the row number function of the outer query.
+        let base_query = Ordering::select_columns(base.query.so_that(conditions), base.selected_fields, &base.order_by);
 
         let order_columns = Ordering::aliased_internal(
             Self::BASE_TABLE_ALIAS,
             Self::BASE_TABLE_ALIAS,
             SelectedFields::RELATED_MODEL_ALIAS,
-            base.order_by.as_ref(),
+            &base.order_by,
             base.is_reverse_order,
         );
 
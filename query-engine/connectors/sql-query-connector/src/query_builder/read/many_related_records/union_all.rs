@@ -15,21 +15,25 @@ impl ManyRelatedRecordsQueryBuilder for ManyRelatedRecordsWithUnionAll {
             ids
         };
 
-        let order_columns = Ordering::internal(
+        // Each part of the union is ordered by its selected columns, as the tables of the parts
+        // can't be referenced from the ordering of the union.
+        let order_columns = Ordering::selected_internal(
             SelectedFields::RELATED_MODEL_ALIAS,
-            base.order_by.as_ref(),
+            &base.order_by,
             base.is_reverse_order,
         );
 
         let base_condition = base.condition.and(base.cursor);
         let from_field = base.from_field;
 
+        let query = Ordering::select_columns(base.query, base.selected_fields, &base.order_by);
+
         let base_query = match base.skip_and_limit {
             SkipAndLimit {
                 skip,
                 limit: Some(limit),
-            } => base.query.limit(limit).offset(skip),
-            SkipAndLimit { skip, limit: None } => base.query.offset(skip),
+            } => query.limit(limit).offset(skip),
+            SkipAndLimit { skip, limit: None } => query.offset(skip),
         };
 
         let base_query = order_columns.into_iter().fold(base_query, |acc, ord| acc.order_by(ord));
@@ -1,9 +1,14 @@
 mod many_related_records;
 
-use crate::{cursor_condition::CursorCondition, filter_conversion::AliasedCondition, ordering::Ordering};
+use crate::{
+    cursor_condition::CursorCondition,
+    filter_conversion::{record_finder_cond, Alias, AliasedCondition},
+    ordering::Ordering,
+    SqlFamily,
+};
 use connector_interface::{
-    filter::{Filter, RecordFinder},
-    QueryArguments,
+    filter::{AggregationFilter, RecordFinder},
+    AggregationSelection, QueryArguments,
 };
 use prisma_models::prelude::*;
 use prisma_query::ast::*;
@@ -15,17 +20,9 @@ pub trait SelectDefinition {
     fn into_select(self, _: ModelRef) -> Select<'static>;
 }
 
-impl SelectDefinition for Filter {
-    fn into_select(self, model: ModelRef) -> Select<'static> {
-        let args = QueryArguments::from(self);
-        args.into_select(model)
-    }
-}
-
 impl SelectDefinition for RecordFinder {
     fn into_select(self, model: ModelRef) -> Select<'static> {
-        let args = QueryArguments::from(self);
-        args.into_select(model)
+        Select::from_table(model.table()).so_that(record_finder_cond(self))
     }
 }
 
@@ -41,15 +38,41 @@ impl SelectDefinition for Select<'static> {
     }
 }
 
-impl SelectDefinition for QueryArguments {
-    fn into_select(self, model: ModelRef) -> Select<'static> {
-        let cursor: ConditionTree = CursorCondition::build(&self, Arc::clone(&model));
-        let order_by = self.order_by;
-        let ordering = Ordering::for_model(Arc::clone(&model), order_by.as_ref(), self.last.is_some());
+pub struct ReadQueryBuilder;
 
-        let filter: ConditionTree = self
+impl ReadQueryBuilder {
+    pub fn get_records<T>(model: ModelRef, selected_fields: &SelectedFields, query: T) -> Select<'static>
+    where
+        T: SelectDefinition,
+    {
+        selected_fields
+            .columns()
+            .into_iter()
+            .fold(query.into_select(model), |acc, col| acc.column(col.clone()))
+    }
+
+    /// Selects the records matching the query arguments. Filters are rendered for the given SQL family.
+    pub fn get_many_records(
+        model: ModelRef,
+        selected_fields: &SelectedFields,
+        query_arguments: QueryArguments,
+        family: SqlFamily,
+    ) -> Select<'static> {
+        let query = Self::select_by_arguments(Arc::clone(&model), query_arguments, family);
+        Self::get_records(model, selected_fields, query)
+    }
+
+    fn select_by_arguments(model: ModelRef, query_arguments: QueryArguments, family: SqlFamily) -> Select<'static> {
+        let cursor: ConditionTree = CursorCondition::build(&query_arguments, Arc::clone(&model));
+        let ordering = Ordering::for_model(
+            Arc::clone(&model),
+            &query_arguments.order_by,
+            query_arguments.last.is_some(),
+        );
+
+        let filter: ConditionTree = query_arguments
             .filter
-            .map(|f| f.aliased_cond(None))
+            .map(|f| f.aliased_cond(None, family))
             .unwrap_or(ConditionTree::NoCondition);
 
         let conditions = match (filter, cursor) {
@@ -58,9 +81,9 @@ impl SelectDefinition for QueryArguments {
             (filter, cursor) => ConditionTree::and(filter, cursor),
         };
 
-        let (skip, limit) = match self.last.or(self.first) {
-            Some(c) => (self.skip.unwrap_or(0), Some(c + 1)), // +1 to see if there's more data
-            None => (self.skip.unwrap_or(0), None),
+        let (skip, limit) = match query_arguments.last.or(query_arguments.first) {
+            Some(c) => (query_arguments.skip.unwrap_or(0), Some(c + 1)), // +1 to see if there's more data
+            None => (query_arguments.skip.unwrap_or(0), None),
         };
 
         let select_ast = Select::from_table(model.table())
@@ -74,20 +97,6 @@ impl SelectDefinition for QueryArguments {
             None => select_ast,
         }
     }
-}
-
-pub struct ReadQueryBuilder;
-
-impl ReadQueryBuilder {
-    pub fn get_records<T>(model: ModelRef, selected_fields: &SelectedFields, query: T) -> Select<'static>
-    where
-        T: SelectDefinition,
-    {
-        selected_fields
-            .columns()
-            .into_iter()
-            .fold(query.into_select(model), |acc, col| acc.column(col.clone()))
-    }
 
     pub fn get_scalar_list_values_by_record_ids(
         list_field: ScalarFieldRef,
@@ -104,19 +113,120 @@ impl ReadQueryBuilder {
             .so_that(vhere)
     }
 
-    pub fn count_by_model(model: ModelRef, query_arguments: QueryArguments) -> Select<'static> {
-        let id_field = model.fields().id();
+    pub fn count_by_model(model: ModelRef, query_arguments: QueryArguments, family: SqlFamily) -> Select<'static> {
+        // Id columns are never null, counting the first one counts all records.
+        let id_field = model.fields().id_fields().remove(0);
 
         let mut selected_fields = SelectedFields::default();
         selected_fields.add_scalar(id_field.clone());
 
-        let base_query = Self::get_records(model, &selected_fields, query_arguments);
+        let base_query = Self::get_many_records(model, &selected_fields, query_arguments, family);
         let table = Table::from(base_query).alias("sub");
         let column = Column::from(("sub", id_field.db_name().to_string()));
 
         Select::from_table(table).value(count(column))
     }
 
+    /// Aggregates the records selected by the query arguments. The aggregation is done over
+    /// a sub-select of the required columns, which allows pagination to be applied before aggregating.
+    pub fn aggregate(
+        model: ModelRef,
+        selections: &[AggregationSelection],
+        group_by: &[ScalarFieldRef],
+        having: Option<AggregationFilter>,
+        query_arguments: QueryArguments,
+        family: SqlFamily,
+    ) -> Select<'static> {
+        let alias = Alias::default();
+        let column = |field: &ScalarFieldRef| Column::from((alias.to_string(None), field.db_name().to_string()));
+
+        // Columns required by the aggregation. Each column may only appear once in the sub-select.
+        let mut fields: Vec<ScalarFieldRef> = model.fields().id_fields();
+
+        for selection in selections {
+            match selection {
+                AggregationSelection::Field(field) => fields.push(Arc::clone(field)),
+                AggregationSelection::Count => (),
+                AggregationSelection::Sum(selected)
+                | AggregationSelection::Average(selected)
+                | AggregationSelection::Min(selected)
+                | AggregationSelection::Max(selected) => fields.extend(selected.iter().cloned()),
+            }
+        }
+
+        fields.extend(group_by.iter().cloned());
+        fields.sort_by(|a, b| a.name.cmp(&b.name));
+        fields.dedup_by(|a, b| a.name == b.name);
+
+        let selected_fields = SelectedFields::from(fields);
+
+        // `last` takes the groups from the end, the reader restores the ascending group order.
+        let descending = !group_by.is_empty() && query_arguments.first.is_none() && query_arguments.last.is_some();
+
+        // Grouped queries paginate over the groups, ungrouped queries over the records to aggregate.
+        let (base_query, skip, limit) = if group_by.is_empty() {
+            let limit = query_arguments.first.or(query_arguments.last);
+            let base = Self::get_many_records(Arc::clone(&model), &selected_fields, query_arguments, family);
+
+            // The pagination of the base query fetches one record more than requested, which must not be aggregated.
+            let base = match limit {
+                Some(limit) => base.limit(limit as usize),
+                None => base,
+            };
+
+            (base, None, None)
+        } else {
+            let filter_args = QueryArguments {
+                filter: query_arguments.filter,
+                ..Default::default()
+            };
+
+            let base = Self::get_many_records(Arc::clone(&model), &selected_fields, filter_args, family);
+            (
+                base,
+                query_arguments.skip,
+                query_arguments.first.or(query_arguments.last),
+            )
+        };
+
+        let table = Table::from(base_query).alias(alias.to_string(None));
+        let select = Select::from_table(table);
+
+        let select = selections.iter().fold(select, |acc, selection| match selection {
+            AggregationSelection::Field(field) => acc.column(column(field)),
+            AggregationSelection::Count => acc.value(count(asterisk())),
+            AggregationSelection::Sum(fields) => fields.iter().fold(acc, |acc, f| acc.value(sum(column(f)))),
+            AggregationSelection::Average(fields) => fields.iter().fold(acc, |acc, f| acc.value(avg(column(f)))),
+            AggregationSelection::Min(fields) => fields.iter().fold(acc, |acc, f| acc.value(min(column(f)))),
+            AggregationSelection::Max(fields) => fields.iter().fold(acc, |acc, f| acc.value(max(column(f)))),
+        });
+
+        let select = group_by.iter().fold(select, |acc, field| acc.group_by(column(field)));
+        let select = match having {
+            Some(having) => select.having(having.aliased_cond(Some(alias), family)),
+            None => select,
+        };
+
+        // Stable group order for pagination.
+        let select = group_by.iter().fold(select, |acc, field| {
+            if descending {
+                acc.order_by(column(field).descend())
+            } else {
+                acc.order_by(column(field).ascend())
+            }
+        });
+
+        let select = match skip {
+            Some(skip) => select.offset(skip as usize),
+            None => select,
+        };
+
+        match limit {
+            Some(limit) => select.limit(limit as usize),
+            None => select,
+        }
+    }
+
     pub fn count_by_table(database: &str, table: &str) -> Select<'static> {
         Select::from_table((database.to_string(), table.to_string())).value(count(asterisk()))
     }
@@ -1,27 +1,15 @@
-use crate::error::SqlError;
+use crate::{error::SqlError, filter_conversion::ids_cond};
 use prisma_models::prelude::*;
 use prisma_query::ast::*;
-use std::convert::TryFrom;
+use std::{cmp, convert::TryFrom};
 
 pub struct WriteQueryBuilder;
 
 impl WriteQueryBuilder {
     const PARAMETER_LIMIT: usize = 10000;
 
-    pub fn create_record(model: ModelRef, mut args: PrismaArgs) -> (Insert<'static>, Option<GraphqlId>) {
-        let id_field = model.fields().id();
-
-        let return_id = match args.get_field_value(&id_field.name) {
-            _ if id_field.is_auto_generated => None,
-            Some(PrismaValue::Null) | None => {
-                let id = model.generate_id();
-                args.insert(id_field.name.as_str(), id.clone());
-                Some(id)
-            }
-            Some(prisma_value) => Some(
-                GraphqlId::try_from(prisma_value).expect("Could not convert prisma value to graphqlid"),
-            ),
-        };
+    pub fn create_record(model: ModelRef, mut args: PrismaArgs) -> crate::Result<(Insert<'static>, Option<GraphqlId>)> {
+        let return_id = Self::prepare_id(&model, &mut args)?;
 
         let fields: Vec<&Field> = model
             .fields()
@@ -40,7 +28,107 @@ impl WriteQueryBuilder {
             .into_iter()
             .fold(base, |acc, (name, value)| acc.value(name.into_owned(), value));
 
-        (Insert::from(insert).returning(vec![id_field.as_column()]), return_id)
+        Ok((Insert::from(insert).returning(model.id_columns()), return_id))
+    }
+
+    /// Creates multi-row inserts for the given records. Records setting the same fields are inserted
+    /// together, in chunks small enough to not exceed `max_bind_values` per insert.
+    /// Returns the inserts, each with the positions of its records in `args` and returning the id columns,
+    /// and the id of each record, unless the id is generated by the database.
+    pub fn create_many_records(
+        model: ModelRef,
+        args: Vec<PrismaArgs>,
+        max_bind_values: usize,
+    ) -> crate::Result<(Vec<(Insert<'static>, Vec<usize>)>, Vec<Option<GraphqlId>>)> {
+        let mut ids = Vec::with_capacity(args.len());
+        let mut groups: Vec<(Vec<String>, Vec<(usize, Vec<PrismaValue>)>)> = Vec::new();
+
+        for (position, mut record_args) in args.into_iter().enumerate() {
+            ids.push(Self::prepare_id(&model, &mut record_args)?);
+
+            let fields: Vec<&Field> = model
+                .fields()
+                .all
+                .iter()
+                .filter(|field| record_args.has_arg_for(&field.name()))
+                .collect();
+
+            let columns: Vec<String> = fields.iter().map(|field| field.db_name().into_owned()).collect();
+            let values: Vec<PrismaValue> = fields
+                .iter()
+                .map(|field| record_args.take_field_value(field.name()).unwrap())
+                .collect();
+
+            match groups.iter_mut().find(|(group_columns, _)| group_columns == &columns) {
+                Some((_, rows)) => rows.push((position, values)),
+                None => groups.push((columns, vec![(position, values)])),
+            }
+        }
+
+        let inserts = groups
+            .into_iter()
+            .flat_map(|(columns, rows)| -> Vec<(Insert<'static>, Vec<usize>)> {
+                if columns.is_empty() {
+                    // Records without any values can't be part of a multi-row insert.
+                    rows.iter()
+                        .map(|(position, _)| {
+                            let insert = Insert::from(Insert::single_into(model.table()));
+                            (insert.returning(model.id_columns()), vec![*position])
+                        })
+                        .collect()
+                } else {
+                    let rows_per_insert = cmp::max(1, max_bind_values / columns.len());
+
+                    rows.chunks(rows_per_insert)
+                        .map(|chunk| {
+                            let insert = Insert::multi_into(model.table(), columns.clone());
+                            let insert = chunk.iter().fold(insert, |acc, (_, row)| acc.values(row.clone()));
+                            let positions = chunk.iter().map(|(position, _)| *position).collect();
+
+                            (Insert::from(insert).returning(model.id_columns()), positions)
+                        })
+                        .collect()
+                }
+            })
+            .collect();
+
+        Ok((inserts, ids))
+    }
+
+    /// Sets a generated id on the arguments if the id is neither given nor generated by the database.
+    /// Returns the id, unless it is generated by the database. Compound ids are never generated, their
+    /// fields are required on create.
+    fn prepare_id(model: &ModelRef, args: &mut PrismaArgs) -> crate::Result<Option<GraphqlId>> {
+        if model.has_compound_id() {
+            let parts = model
+                .fields()
+                .id_fields()
+                .iter()
+                .map(|field| match args.get_field_value(&field.name) {
+                    Some(PrismaValue::Null) | None => Err(SqlError::FieldCannotBeNull {
+                        field: field.name.clone(),
+                    }),
+                    Some(value) => Ok(GraphqlId::try_from(value)?),
+                })
+                .collect::<crate::Result<Vec<GraphqlId>>>()?;
+
+            return Ok(Some(GraphqlId::Compound(parts)));
+        }
+
+        let id_field = model.fields().id();
+
+        match args.get_field_value(&id_field.name) {
+            _ if id_field.is_auto_generated => Ok(None),
+            Some(PrismaValue::Null) | None => {
+                let id = model.generate_id().ok_or_else(|| SqlError::FieldCannotBeNull {
+                    field: id_field.name.clone(),
+                })?;
+
+                args.insert(id_field.name.as_str(), id.clone());
+                Ok(Some(id))
+            }
+            Some(prisma_value) => Ok(Some(GraphqlId::try_from(prisma_value)?)),
+        }
     }
 
     pub fn create_relation(field: RelationFieldRef, parent_id: &GraphqlId, child_id: &GraphqlId) -> Query<'static> {
@@ -136,34 +224,79 @@ impl WriteQueryBuilder {
                 });
             }
 
-            query = query.set(field.db_name().to_string(), value.clone());
+            // Values pushed next to a `set` are appended to the new list. Pushes to the stored list are
+            // written separately, see `append_list_values`.
+            let value = match (value, args.pushes.get(name)) {
+                (value, Some(pushed)) => {
+                    let mut values = PrismaListValue::try_from(value.clone()).ok().and_then(|l| l).unwrap_or_default();
+                    values.extend(pushed.iter().cloned());
+
+                    PrismaValue::List(Some(values))
+                }
+                (value, _) => value.clone(),
+            };
+
+            query = query.set(field.db_name().to_string(), value);
         }
 
         let result: Vec<Update> = ids
             .chunks(Self::PARAMETER_LIMIT)
             .into_iter()
             .map(|ids| {
-                query
-                    .clone()
-                    .so_that(fields.id().as_column().in_selection(ids.to_vec()))
+                query.clone().so_that(ids_cond(&model, ids))
             })
             .collect();
 
         Ok(result)
     }
 
+    /// Lists stored in native arrays with values pushed to the stored list. Lists that are also set in
+    /// the update are left out, their pushed values are part of the new list.
+    pub fn pushed_lists(model: &ModelRef, args: &PrismaArgs) -> Vec<ScalarFieldRef> {
+        args.pushes
+            .keys()
+            .filter(|name| !args.args.contains_key(*name))
+            .map(|name| model.fields().find_from_scalar(name).unwrap())
+            .collect()
+    }
+
+    /// Appends the pushed values to the stored `lists` of a record, which are given in the same order in
+    /// `current`. The query builder can't concatenate arrays, so the lists are written back as a whole.
+    pub fn append_list_values(
+        model: ModelRef,
+        id: &GraphqlId,
+        args: &PrismaArgs,
+        lists: &[ScalarFieldRef],
+        current: Vec<PrismaValue>,
+    ) -> Option<Update<'static>> {
+        if lists.is_empty() {
+            return None;
+        }
+
+        let query = lists
+            .iter()
+            .zip(current.into_iter())
+            .fold(Update::table(model.table()), |query, (field, value)| {
+                let mut values = PrismaListValue::try_from(value).ok().and_then(|l| l).unwrap_or_default();
+                values.extend(args.pushes[&field.name].iter().cloned());
+
+                query.set(field.db_name().to_string(), PrismaValue::List(Some(values)))
+            });
+
+        Some(query.so_that(ids_cond(&model, &[id])))
+    }
+
     pub fn delete_many(model: ModelRef, ids: &[&GraphqlId]) -> Vec<Delete<'static>> {
         let mut deletes = Vec::new();
 
         for chunk in ids.chunks(Self::PARAMETER_LIMIT).into_iter() {
-            for lf in model.fields().scalar_list() {
+            for lf in model.fields().scalar_list().into_iter().filter(|lf| !lf.is_embedded_list()) {
                 let scalar_list_table = lf.scalar_list_table();
                 let condition = scalar_list_table.node_id_column().in_selection(chunk.to_vec());
                 deletes.push(Delete::from_table(scalar_list_table.table()).so_that(condition));
             }
 
-            let condition = model.fields().id().as_column().in_selection(chunk.to_vec());
-            deletes.push(Delete::from_table(model.table()).so_that(condition));
+            deletes.push(Delete::from_table(model.table()).so_that(ids_cond(&model, chunk)));
         }
 
         deletes
@@ -225,6 +358,7 @@ impl WriteQueryBuilder {
                     .fields()
                     .scalar_list()
                     .iter()
+                    .filter(|slf| !slf.is_embedded_list())
                     .map(|slf| slf.scalar_list_table().table())
                     .collect();
 
@@ -1,14 +1,20 @@
 use crate::error::SqlError;
 use prisma_models::prelude::*;
 use prisma_query::ast::*;
+use std::sync::Arc;
 
-/// Checks to be executed when deleting data.
+/// Checks and referential actions to be executed when deleting data.
+///
+/// The `onDelete` actions of the relations are emulated here instead of
+/// relying on the foreign keys, so they behave the same on databases that
+/// don't enforce them.
 pub struct DeleteActions;
 
 impl DeleteActions {
-    /// A model can be required in another model, preventing the deletion.
+    /// A model can be required in another model, or the relation can
+    /// restrict the deletion of the related records, preventing the deletion.
     /// Therefore we must check if any other model in the data model sets
-    /// `is_required` for this record.
+    /// `is_required` for this record without cascading, or restricts it.
     ///
     /// The closure is called with a `SELECT` statement to be executed in the
     /// connector, giving the connector the possibility to return an optional
@@ -18,7 +24,15 @@ impl DeleteActions {
     where
         F: FnMut(Select) -> crate::Result<Option<GraphqlId>>,
     {
-        for rf in model.internal_data_model().fields_requiring_model(model) {
+        for rf in Self::referencing_fields(&model) {
+            let on_delete = rf.on_delete();
+            let is_unlinked_to_null = on_delete.is_unlink() && rf.unlink_value(on_delete).is_null();
+            let is_required = rf.is_required && !rf.is_list && is_unlinked_to_null;
+
+            if !(is_required || on_delete.is_restrict()) {
+                continue;
+            }
+
             let relation = rf.relation();
 
             let condition = rf
@@ -41,4 +55,64 @@ impl DeleteActions {
 
         Ok(())
     }
+
+    /// Selects the ids of the records to be deleted together with the given
+    /// records through relations with `onDelete: CASCADE`, together with the
+    /// model of the selected records.
+    pub fn cascades(model: &ModelRef, ids: &[&GraphqlId]) -> Vec<(ModelRef, Select<'static>)> {
+        Self::referencing_fields(model)
+            .into_iter()
+            .filter(|rf| rf.on_delete().is_cascade())
+            .map(|rf| {
+                let condition = rf
+                    .opposite_column()
+                    .in_selection(ids.to_vec())
+                    .and(rf.relation_column().is_not_null());
+
+                let select = Select::from_table(rf.relation().relation_table())
+                    .column(rf.relation_column())
+                    .so_that(condition);
+
+                (rf.model(), select)
+            })
+            .collect()
+    }
+
+    /// Removes the links of other records to the given records through
+    /// relations with `onDelete: SET_NULL`, the default for relations, or
+    /// `onDelete: SET_DEFAULT`, which links them to the default value of the
+    /// relation field instead. Returns the relation field each query unlinks.
+    pub fn unlink(model: &ModelRef, ids: &[&GraphqlId]) -> Vec<(RelationFieldRef, Query<'static>)> {
+        Self::referencing_fields(model)
+            .into_iter()
+            .filter(|rf| rf.on_delete().is_unlink())
+            .filter_map(|rf| {
+                let relation = rf.relation();
+                let condition = rf.opposite_column().in_selection(ids.to_vec());
+
+                let query: Query<'static> = match relation.inline_relation_column() {
+                    // The link is removed with the deleted row itself.
+                    Some(_) if !rf.relation_is_inlined_in_parent() => return None,
+                    Some(column) => Update::table(relation.relation_table())
+                        .set(column.name.to_string(), rf.unlink_value(rf.on_delete()))
+                        .so_that(condition)
+                        .into(),
+                    None => Delete::from_table(relation.relation_table()).so_that(condition).into(),
+                };
+
+                Some((rf, query))
+            })
+            .collect()
+    }
+
+    /// All relation fields pointing to the given model.
+    pub(crate) fn referencing_fields(model: &ModelRef) -> Vec<RelationFieldRef> {
+        model
+            .internal_data_model()
+            .relation_fields()
+            .iter()
+            .filter(|rf| &rf.related_model() == model)
+            .map(|rf| Arc::clone(rf))
+            .collect()
+    }
 }
@@ -1,7 +1,9 @@
 mod builder;
 mod delete_actions;
 mod nested_actions;
+mod update_actions;
 
 pub use builder::*;
 pub use delete_actions::*;
 pub use nested_actions::*;
+pub use update_actions::*;
@@ -0,0 +1,95 @@
+use super::DeleteActions;
+use crate::error::SqlError;
+use prisma_models::prelude::*;
+use prisma_query::ast::*;
+
+/// Checks and referential actions to be executed when updating the id of a
+/// record.
+///
+/// Like the `onDelete` actions, the `onUpdate` actions of the relations are
+/// emulated here, so they behave the same on databases that don't enforce
+/// foreign keys.
+pub struct UpdateActions;
+
+impl UpdateActions {
+    /// Relations restricting updates, which is the default, prevent changing
+    /// the id of a record as long as other records are linked to it.
+    ///
+    /// The closure is called with a `SELECT` statement to be executed in the
+    /// connector, returning a linked record if there is one.
+    pub fn check_relation_violations<F>(model: &ModelRef, id: &GraphqlId, mut f: F) -> crate::Result<()>
+    where
+        F: FnMut(Select) -> crate::Result<Option<GraphqlId>>,
+    {
+        for rf in Self::referencing_fields(model) {
+            if !rf.on_update().is_restrict() {
+                continue;
+            }
+
+            let relation = rf.relation();
+
+            let condition = rf.opposite_column().equals(id).and(rf.relation_column().is_not_null());
+
+            let select = Select::from_table(relation.relation_table())
+                .column(rf.opposite_column())
+                .so_that(condition);
+
+            if let Some(_) = f(select)? {
+                return Err(SqlError::RelationViolation {
+                    relation_name: relation.name.clone(),
+                    model_a_name: relation.model_a().name.clone(),
+                    model_b_name: relation.model_b().name.clone(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Links the records linked to the record with the previous id to the
+    /// updated id through relations with `onUpdate: CASCADE`, and unlinks them
+    /// through relations with `onUpdate: SET_NULL` or `onUpdate: SET_DEFAULT`.
+    /// Returns the relation field each query links.
+    pub fn relink(
+        model: &ModelRef,
+        previous_id: &GraphqlId,
+        next_id: &GraphqlId,
+    ) -> Vec<(RelationFieldRef, Query<'static>)> {
+        Self::referencing_fields(model)
+            .into_iter()
+            .filter_map(|rf| {
+                let relation = rf.relation();
+                let column = rf.opposite_column();
+                let condition = column.clone().equals(previous_id);
+                let on_update = rf.on_update();
+
+                let query: Query<'static> = match relation.inline_relation_column() {
+                    _ if on_update.is_cascade() => Update::table(relation.relation_table())
+                        .set(column.name.to_string(), next_id)
+                        .so_that(condition)
+                        .into(),
+                    Some(_) if on_update.is_unlink() => Update::table(relation.relation_table())
+                        .set(column.name.to_string(), rf.unlink_value(on_update))
+                        .so_that(condition)
+                        .into(),
+                    None if on_update.is_unlink() => {
+                        Delete::from_table(relation.relation_table()).so_that(condition).into()
+                    }
+                    _ => return None,
+                };
+
+                Some((rf, query))
+            })
+            .collect()
+    }
+
+    /// All relation fields of records linking to the id of the given model.
+    /// Inline relations with the link in the table of the given model don't
+    /// point to its id.
+    fn referencing_fields(model: &ModelRef) -> Vec<RelationFieldRef> {
+        DeleteActions::referencing_fields(model)
+            .into_iter()
+            .filter(|rf| rf.relation().inline_relation_column().is_none() || rf.relation_is_inlined_in_parent())
+            .collect()
+    }
+}
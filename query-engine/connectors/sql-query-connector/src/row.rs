@@ -1,4 +1,5 @@
 use crate::error::SqlError;
+use bigdecimal::BigDecimal;
 use chrono::{DateTime, Utc};
 use prisma_models::{EnumValue, GraphqlId, PrismaValue, Record, TypeIdentifier};
 use prisma_query::{
@@ -33,99 +34,17 @@ impl ToSqlRow for ResultRow {
         let row_width = idents.len();
 
         for (i, p_value) in self.into_iter().enumerate().take(row_width) {
-            let pv = match idents[i] {
-                TypeIdentifier::GraphQLID | TypeIdentifier::Relation => match p_value {
-                    ParameterizedValue::Null => PrismaValue::Null,
-                    ParameterizedValue::Text(s) => {
-                        let id = Uuid::parse_str(s.borrow())
-                            .map(|uuid| GraphqlId::UUID(uuid))
-                            .unwrap_or_else(|_| GraphqlId::String(s.into_owned()));
-
-                        PrismaValue::GraphqlId(id)
-                    }
-                    ParameterizedValue::Integer(i) => PrismaValue::GraphqlId(GraphqlId::Int(i as usize)),
-                    ParameterizedValue::Uuid(u) => PrismaValue::GraphqlId(GraphqlId::UUID(u)),
-                    _ => {
-                        let error =
-                            io::Error::new(io::ErrorKind::InvalidData, "ID value not stored as string, int or uuid");
-                        return Err(SqlError::ConversionError(error.into()));
-                    }
-                },
-                TypeIdentifier::Boolean => match p_value {
-                    ParameterizedValue::Null => PrismaValue::Null,
-                    ParameterizedValue::Integer(i) => PrismaValue::Boolean(i != 0),
-                    ParameterizedValue::Boolean(b) => PrismaValue::Boolean(b),
-                    _ => {
-                        let error = io::Error::new(io::ErrorKind::InvalidData, "Bool value not stored as bool or int");
-                        return Err(SqlError::ConversionError(error.into()));
-                    }
-                },
-                TypeIdentifier::Enum => match p_value {
-                    ParameterizedValue::Null => PrismaValue::Null,
-                    ParameterizedValue::Text(cow) => PrismaValue::Enum(EnumValue::from(cow.into_owned())),
-                    _ => {
-                        let error = io::Error::new(io::ErrorKind::InvalidData, "Enum value not stored as text");
-                        return Err(SqlError::ConversionError(error.into()));
-                    }
-                },
-                TypeIdentifier::Json => match p_value {
-                    ParameterizedValue::Null => PrismaValue::Null,
-                    ParameterizedValue::Text(json) => PrismaValue::Json(serde_json::from_str(json.borrow())?),
-                    ParameterizedValue::Json(json) => PrismaValue::Json(json),
-                    _ => {
-                        let error = io::Error::new(io::ErrorKind::InvalidData, "Json value not stored as text or json");
-                        return Err(SqlError::ConversionError(error.into()));
-                    }
-                },
-                TypeIdentifier::UUID => match p_value {
-                    ParameterizedValue::Null => PrismaValue::Null,
-                    ParameterizedValue::Text(uuid) => PrismaValue::Uuid(Uuid::parse_str(&uuid)?),
-                    ParameterizedValue::Uuid(uuid) => PrismaValue::Uuid(uuid),
-                    _ => {
-                        let error = io::Error::new(io::ErrorKind::InvalidData, "Uuid value not stored as text or uuid");
-                        return Err(SqlError::ConversionError(error.into()));
-                    }
-                },
-                TypeIdentifier::DateTime => match p_value {
-                    ParameterizedValue::Null => PrismaValue::Null,
-                    ParameterizedValue::DateTime(dt) => PrismaValue::DateTime(dt),
-                    ParameterizedValue::Integer(ts) => {
-                        let nsecs = ((ts % 1000) * 1_000_000) as u32;
-                        let secs = (ts / 1000) as i64;
-                        let naive = chrono::NaiveDateTime::from_timestamp(secs, nsecs);
-                        let datetime: DateTime<Utc> = DateTime::from_utc(naive, Utc);
-
-                        PrismaValue::DateTime(datetime)
-                    }
-                    ParameterizedValue::Text(dt_string) => {
-                        let dt = DateTime::parse_from_rfc3339(dt_string.borrow())
-                            .or_else(|_| DateTime::parse_from_rfc2822(dt_string.borrow()))
-                            .expect(&format!("Could not parse stored DateTime string: {}", dt_string));
-
-                        PrismaValue::DateTime(dt.with_timezone(&Utc))
-                    }
-                    _ => {
-                        let error = io::Error::new(
-                            io::ErrorKind::InvalidData,
-                            "DateTime value not stored as datetime, int or text",
-                        );
-                        return Err(SqlError::ConversionError(error.into()));
-                    }
-                },
-                TypeIdentifier::Float => match p_value {
-                    ParameterizedValue::Null => PrismaValue::Null,
-                    ParameterizedValue::Real(f) => PrismaValue::Float(f),
-                    ParameterizedValue::Integer(i) => PrismaValue::Float(i as f64),
-                    ParameterizedValue::Text(s) => PrismaValue::Float(s.parse().unwrap()),
-                    _ => {
-                        let error = io::Error::new(
-                            io::ErrorKind::InvalidData,
-                            "Float value not stored as float, int or text",
-                        );
-                        return Err(SqlError::ConversionError(error.into()));
-                    }
-                },
-                _ => PrismaValue::from(p_value),
+            let pv = match p_value {
+                // Scalar lists stored in native arrays hold values of the field type.
+                ParameterizedValue::Array(values) => {
+                    let values = values
+                        .into_iter()
+                        .map(|value| convert_value(value, idents[i]))
+                        .collect::<crate::Result<Vec<PrismaValue>>>()?;
+
+                    PrismaValue::List(Some(values))
+                }
+                p_value => convert_value(p_value, idents[i])?,
             };
 
             row.values.push(pv);
@@ -135,6 +54,163 @@ impl ToSqlRow for ResultRow {
     }
 }
 
+/// Converts a single value of a row into the type of the field it was read for.
+fn convert_value(p_value: ParameterizedValue, ident: TypeIdentifier) -> crate::Result<PrismaValue> {
+    let pv = match ident {
+        TypeIdentifier::GraphQLID | TypeIdentifier::Relation => match p_value {
+            ParameterizedValue::Null => PrismaValue::Null,
+            ParameterizedValue::Text(s) => {
+                let id = Uuid::parse_str(s.borrow())
+                    .map(|uuid| GraphqlId::UUID(uuid))
+                    .unwrap_or_else(|_| GraphqlId::String(s.into_owned()));
+
+                PrismaValue::GraphqlId(id)
+            }
+            ParameterizedValue::Integer(i) => PrismaValue::GraphqlId(GraphqlId::Int(i as usize)),
+            ParameterizedValue::Uuid(u) => PrismaValue::GraphqlId(GraphqlId::UUID(u)),
+            _ => {
+                let error = io::Error::new(io::ErrorKind::InvalidData, "ID value not stored as string, int or uuid");
+                return Err(SqlError::ConversionError(error.into()));
+            }
+        },
+        TypeIdentifier::Boolean => match p_value {
+            ParameterizedValue::Null => PrismaValue::Null,
+            ParameterizedValue::Integer(i) => PrismaValue::Boolean(i != 0),
+            ParameterizedValue::Boolean(b) => PrismaValue::Boolean(b),
+            _ => {
+                let error = io::Error::new(io::ErrorKind::InvalidData, "Bool value not stored as bool or int");
+                return Err(SqlError::ConversionError(error.into()));
+            }
+        },
+        TypeIdentifier::Enum => match p_value {
+            ParameterizedValue::Null => PrismaValue::Null,
+            ParameterizedValue::Text(cow) => PrismaValue::Enum(EnumValue::from(cow.into_owned())),
+            _ => {
+                let error = io::Error::new(io::ErrorKind::InvalidData, "Enum value not stored as text");
+                return Err(SqlError::ConversionError(error.into()));
+            }
+        },
+        TypeIdentifier::Json => match p_value {
+            ParameterizedValue::Null => PrismaValue::Null,
+            ParameterizedValue::Text(json) => PrismaValue::Json(serde_json::from_str(json.borrow())?),
+            ParameterizedValue::Json(json) => PrismaValue::Json(json),
+            _ => {
+                let error = io::Error::new(io::ErrorKind::InvalidData, "Json value not stored as text or json");
+                return Err(SqlError::ConversionError(error.into()));
+            }
+        },
+        TypeIdentifier::UUID => match p_value {
+            ParameterizedValue::Null => PrismaValue::Null,
+            ParameterizedValue::Text(uuid) => PrismaValue::Uuid(Uuid::parse_str(&uuid)?),
+            ParameterizedValue::Uuid(uuid) => PrismaValue::Uuid(uuid),
+            _ => {
+                let error = io::Error::new(io::ErrorKind::InvalidData, "Uuid value not stored as text or uuid");
+                return Err(SqlError::ConversionError(error.into()));
+            }
+        },
+        TypeIdentifier::DateTime => match p_value {
+            ParameterizedValue::Null => PrismaValue::Null,
+            ParameterizedValue::DateTime(dt) => PrismaValue::DateTime(dt),
+            ParameterizedValue::Integer(ts) => {
+                let nsecs = ((ts % 1000) * 1_000_000) as u32;
+                let secs = (ts / 1000) as i64;
+                let naive = chrono::NaiveDateTime::from_timestamp(secs, nsecs);
+                let datetime: DateTime<Utc> = DateTime::from_utc(naive, Utc);
+
+                PrismaValue::DateTime(datetime)
+            }
+            ParameterizedValue::Text(dt_string) => {
+                let dt = DateTime::parse_from_rfc3339(dt_string.borrow())
+                    .or_else(|_| DateTime::parse_from_rfc2822(dt_string.borrow()))
+                    .expect(&format!("Could not parse stored DateTime string: {}", dt_string));
+
+                PrismaValue::DateTime(dt.with_timezone(&Utc))
+            }
+            _ => {
+                let error = io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "DateTime value not stored as datetime, int or text",
+                );
+                return Err(SqlError::ConversionError(error.into()));
+            }
+        },
+        TypeIdentifier::Int => match p_value {
+            ParameterizedValue::Null => PrismaValue::Null,
+            ParameterizedValue::Integer(i) => PrismaValue::Int(i),
+            // Sums of integers are returned as numeric values by Postgres and MySQL.
+            ParameterizedValue::Real(f) => PrismaValue::Int(f as i64),
+            ParameterizedValue::Text(s) => PrismaValue::Int(parse_int(s.borrow())?),
+            _ => {
+                let error = io::Error::new(io::ErrorKind::InvalidData, "Int value not stored as int, float or text");
+                return Err(SqlError::ConversionError(error.into()));
+            }
+        },
+        TypeIdentifier::Float => match p_value {
+            ParameterizedValue::Null => PrismaValue::Null,
+            ParameterizedValue::Real(f) => PrismaValue::Float(f),
+            ParameterizedValue::Integer(i) => PrismaValue::Float(i as f64),
+            ParameterizedValue::Text(s) => PrismaValue::Float(s.parse().unwrap()),
+            _ => {
+                let error = io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "Float value not stored as float, int or text",
+                );
+                return Err(SqlError::ConversionError(error.into()));
+            }
+        },
+        TypeIdentifier::Decimal => match p_value {
+            ParameterizedValue::Null => PrismaValue::Null,
+            ParameterizedValue::Text(s) => PrismaValue::Decimal(parse_decimal(s.borrow())?),
+            ParameterizedValue::Integer(i) => PrismaValue::Decimal(BigDecimal::from(i)),
+            // SQLite has no exact numeric storage and returns the float it stored, its shortest
+            // representation is the closest to what was written.
+            ParameterizedValue::Real(f) => PrismaValue::Decimal(parse_decimal(&f.to_string())?),
+            _ => {
+                let error = io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "Decimal value not stored as text, int or float",
+                );
+                return Err(SqlError::ConversionError(error.into()));
+            }
+        },
+        TypeIdentifier::Bytes => match p_value {
+            ParameterizedValue::Null => PrismaValue::Null,
+            ParameterizedValue::Text(s) => {
+                let bytes = base64::decode(s.as_bytes()).map_err(|err| {
+                    let error = io::Error::new(io::ErrorKind::InvalidData, format!("Invalid bytes value: {}", err));
+                    SqlError::ConversionError(error.into())
+                })?;
+
+                PrismaValue::Bytes(bytes)
+            }
+            _ => {
+                let error = io::Error::new(io::ErrorKind::InvalidData, "Bytes value not stored as text");
+                return Err(SqlError::ConversionError(error.into()));
+            }
+        },
+        _ => PrismaValue::from(p_value),
+    };
+
+    Ok(pv)
+}
+
+fn parse_int(s: &str) -> crate::Result<i64> {
+    // Numeric sums may be rendered with a fractional part, e.g. `42.0000`.
+    let integral = s.split('.').next().unwrap_or(s);
+
+    integral.parse().map_err(|_| {
+        let error = io::Error::new(io::ErrorKind::InvalidData, format!("Invalid integer value: {}", s));
+        SqlError::ConversionError(error.into())
+    })
+}
+
+fn parse_decimal(s: &str) -> crate::Result<BigDecimal> {
+    s.parse().map_err(|_| {
+        let error = io::Error::new(io::ErrorKind::InvalidData, format!("Invalid decimal value: {}", s));
+        SqlError::ConversionError(error.into())
+    })
+}
+
 #[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum SqlId {
     String(String),
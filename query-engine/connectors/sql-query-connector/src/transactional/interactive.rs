@@ -0,0 +1,264 @@
+use crate::{
+    database::{SqlCapabilities, SqlDatabase, SqlFamily},
+    error::SqlError,
+    Transaction, Transactional,
+};
+use connector_interface::{InteractiveTransactionCapable, OpenTransaction, TransactionHandle};
+use prisma_query::connector::Queryable;
+use r2d2::{ManageConnection, PooledConnection};
+use std::{
+    marker::PhantomData,
+    sync::{Arc, Mutex},
+};
+
+/// Databases able to hand out a connection for the exclusive use of an interactive transaction.
+pub trait DedicatedConnection {
+    fn dedicated_connection(&self, db: &str) -> crate::Result<Box<dyn TransactionConnection + Send>>;
+}
+
+/// A connection held by an interactive transaction for its whole lifetime.
+pub trait TransactionConnection {
+    fn transaction(&mut self) -> &mut dyn Transaction;
+}
+
+impl<M> TransactionConnection for PooledConnection<M>
+where
+    M: ManageConnection,
+    M::Connection: Queryable,
+{
+    fn transaction(&mut self) -> &mut dyn Transaction {
+        &mut **self
+    }
+}
+
+/// The connection of an open interactive transaction. `None` after the transaction ended.
+///
+/// A transaction that was neither committed nor rolled back when its last reference is dropped
+/// (e.g. because the request holding it panicked) is rolled back, before the connection goes back to the pool.
+struct OpenConnection(Mutex<Option<Box<dyn TransactionConnection + Send>>>);
+
+impl Drop for OpenConnection {
+    fn drop(&mut self) {
+        let connection = match self.0.get_mut() {
+            Ok(connection) => connection,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+
+        if let Some(mut connection) = connection.take() {
+            if let Err(err) = connection.transaction().execute_raw("ROLLBACK", &[]) {
+                log::warn!("Failed to roll back the dropped transaction: {}", err);
+            }
+        }
+    }
+}
+
+/// A transaction spanning several executions. The transaction is started on a dedicated
+/// connection, which is returned to the pool after committing or rolling back.
+///
+/// As opposed to the regular database executors, `with_transaction` runs the given closure
+/// within the open transaction and neither commits nor rolls back.
+pub struct InteractiveTransaction<T>
+where
+    T: SqlCapabilities,
+{
+    connection: Arc<OpenConnection>,
+    capabilities: PhantomData<T>,
+}
+
+impl<T> Clone for InteractiveTransaction<T>
+where
+    T: SqlCapabilities,
+{
+    fn clone(&self) -> Self {
+        Self {
+            connection: Arc::clone(&self.connection),
+            capabilities: PhantomData,
+        }
+    }
+}
+
+impl<T> InteractiveTransaction<T>
+where
+    T: SqlCapabilities,
+{
+    pub fn begin(mut connection: Box<dyn TransactionConnection + Send>) -> crate::Result<Self> {
+        connection.transaction().execute_raw("BEGIN", &[])?;
+
+        Ok(Self {
+            connection: Arc::new(OpenConnection(Mutex::new(Some(connection)))),
+            capabilities: PhantomData,
+        })
+    }
+
+    /// Ends the transaction with the given statement and releases the connection.
+    ///
+    /// If ending the transaction fails, it is rolled back before the connection is released, so that
+    /// no connection goes back to the pool with a transaction still open. Connections that can't even
+    /// roll back are most likely broken and fail the health check of the pool on their next checkout.
+    fn end(&self, statement: &str) -> crate::Result<()> {
+        let mut guard = self.connection.0.lock().unwrap();
+        let connection = guard.as_mut().ok_or(SqlError::TransactionAlreadyClosed)?;
+
+        let result = connection.transaction().execute_raw(statement, &[]);
+
+        if result.is_err() && statement != "ROLLBACK" {
+            if let Err(err) = connection.transaction().execute_raw("ROLLBACK", &[]) {
+                log::warn!(
+                    "Failed to roll back the transaction after {} failed: {}",
+                    statement,
+                    err
+                );
+            }
+        }
+
+        guard.take();
+        result?;
+
+        Ok(())
+    }
+}
+
+impl<T> SqlCapabilities for InteractiveTransaction<T>
+where
+    T: SqlCapabilities,
+{
+    type ManyRelatedRecordsBuilder = T::ManyRelatedRecordsBuilder;
+
+    const MAX_BIND_VALUES: usize = T::MAX_BIND_VALUES;
+    const SQL_FAMILY: SqlFamily = T::SQL_FAMILY;
+}
+
+impl<T> Transactional for InteractiveTransaction<T>
+where
+    T: SqlCapabilities,
+{
+    fn with_transaction<F, R>(&self, _: &str, f: F) -> crate::Result<R>
+    where
+        F: FnOnce(&mut dyn Transaction) -> crate::Result<R>,
+    {
+        let mut guard = self.connection.0.lock().unwrap();
+
+        match guard.as_mut() {
+            Some(connection) => f(connection.transaction()),
+            None => Err(SqlError::TransactionAlreadyClosed),
+        }
+    }
+}
+
+impl<T> TransactionHandle for InteractiveTransaction<T>
+where
+    T: SqlCapabilities,
+{
+    fn commit(&self) -> connector_interface::Result<()> {
+        Ok(self.end("COMMIT")?)
+    }
+
+    fn rollback(&self) -> connector_interface::Result<()> {
+        Ok(self.end("ROLLBACK")?)
+    }
+}
+
+impl<T> InteractiveTransactionCapable for SqlDatabase<T>
+where
+    T: Transactional + SqlCapabilities + DedicatedConnection + Send + Sync + 'static,
+{
+    fn start_transaction(&self, db_name: String) -> connector_interface::Result<OpenTransaction> {
+        let connection = self.executor.dedicated_connection(&db_name)?;
+        let transaction: InteractiveTransaction<T> = InteractiveTransaction::begin(connection)?;
+        let database = Arc::new(SqlDatabase::new(transaction.clone()));
+
+        Ok(OpenTransaction {
+            reader: database.clone(),
+            writer: database,
+            handle: Box::new(transaction),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::Sqlite;
+    use prisma_query::ast::ParameterizedValue;
+    use std::env;
+    use uuid::Uuid;
+
+    fn count_items(db: &Sqlite) -> i64 {
+        db.with_transaction("interactive", |conn| {
+            let result_set = conn.query_raw("SELECT COUNT(*) FROM Item", &[])?;
+            let count = result_set.into_iter().next().and_then(|row| row.into_iter().next());
+
+            Ok(match count {
+                Some(ParameterizedValue::Integer(count)) => count,
+                other => panic!("Unexpected count: {:?}", other),
+            })
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn dropping_an_open_transaction_rolls_it_back() {
+        let file_path = env::temp_dir().join(format!("interactive-{}.db", Uuid::new_v4()));
+        let db = Sqlite::new(file_path.to_str().unwrap().to_owned(), 1, false).unwrap();
+
+        db.with_transaction("interactive", |conn| {
+            conn.execute_raw("CREATE TABLE Item (id INTEGER PRIMARY KEY)", &[])?;
+            Ok(())
+        })
+        .unwrap();
+
+        let transaction: InteractiveTransaction<Sqlite> =
+            InteractiveTransaction::begin(db.dedicated_connection("interactive").unwrap()).unwrap();
+
+        let clone = transaction.clone();
+        clone
+            .with_transaction("interactive", |conn| {
+                conn.execute_raw("INSERT INTO Item (id) VALUES (1)", &[])?;
+                Ok(())
+            })
+            .unwrap();
+
+        // The connection is shared by all clones, dropping one of them keeps the transaction open.
+        drop(clone);
+        transaction
+            .with_transaction("interactive", |conn| {
+                conn.execute_raw("INSERT INTO Item (id) VALUES (2)", &[])?;
+                Ok(())
+            })
+            .unwrap();
+
+        drop(transaction);
+
+        // The pool holds a single connection, which is only available again after the rollback.
+        assert_eq!(0, count_items(&db));
+        let _ = std::fs::remove_file(file_path);
+    }
+
+    #[test]
+    fn dropping_a_committed_transaction_keeps_its_changes() {
+        let file_path = env::temp_dir().join(format!("interactive-{}.db", Uuid::new_v4()));
+        let db = Sqlite::new(file_path.to_str().unwrap().to_owned(), 1, false).unwrap();
+
+        db.with_transaction("interactive", |conn| {
+            conn.execute_raw("CREATE TABLE Item (id INTEGER PRIMARY KEY)", &[])?;
+            Ok(())
+        })
+        .unwrap();
+
+        let transaction: InteractiveTransaction<Sqlite> =
+            InteractiveTransaction::begin(db.dedicated_connection("interactive").unwrap()).unwrap();
+
+        transaction
+            .with_transaction("interactive", |conn| {
+                conn.execute_raw("INSERT INTO Item (id) VALUES (1)", &[])?;
+                Ok(())
+            })
+            .unwrap();
+
+        transaction.commit().unwrap();
+        drop(transaction);
+
+        assert_eq!(1, count_items(&db));
+        let _ = std::fs::remove_file(file_path);
+    }
+}
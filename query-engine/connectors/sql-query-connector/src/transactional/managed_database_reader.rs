@@ -5,10 +5,15 @@ use crate::{
     Transactional,
 };
 
-use connector_interface::{self, error::ConnectorError, filter::RecordFinder, *};
+use connector_interface::{
+    self,
+    error::ConnectorError,
+    filter::{AggregationFilter, RecordFinder},
+    *,
+};
 use itertools::Itertools;
 use prisma_models::*;
-use std::convert::TryFrom;
+use std::{convert::TryFrom, sync::Arc};
 
 struct ScalarListElement {
     record_id: GraphqlId,
@@ -24,8 +29,8 @@ where
         record_finder: &RecordFinder,
         selected_fields: &SelectedFields,
     ) -> connector_interface::Result<Option<SingleRecord>> {
-        let db_name = &record_finder.field.model().internal_data_model().db_name;
-        let query = ReadQueryBuilder::get_records(record_finder.field.model(), selected_fields, record_finder);
+        let db_name = &record_finder.model().internal_data_model().db_name;
+        let query = ReadQueryBuilder::get_records(record_finder.model(), selected_fields, record_finder);
         let field_names = selected_fields.names();
         let idents = selected_fields.type_identifiers();
 
@@ -54,7 +59,7 @@ where
         let db_name = &model.internal_data_model().db_name;
         let field_names = selected_fields.names();
         let idents = selected_fields.type_identifiers();
-        let query = ReadQueryBuilder::get_records(model, selected_fields, query_arguments);
+        let query = ReadQueryBuilder::get_many_records(model, selected_fields, query_arguments, T::SQL_FAMILY);
 
         let records = self
             .executor
@@ -79,7 +84,13 @@ where
 
         let query = {
             let is_with_pagination = query_arguments.is_with_pagination();
-            let base = ManyRelatedRecordsBaseQuery::new(from_field, from_record_ids, query_arguments, selected_fields);
+            let base = ManyRelatedRecordsBaseQuery::new(
+                from_field,
+                from_record_ids,
+                query_arguments,
+                selected_fields,
+                T::SQL_FAMILY,
+            );
 
             if is_with_pagination {
                 T::ManyRelatedRecordsBuilder::with_pagination(base)
@@ -114,7 +125,7 @@ where
 
     fn count_by_model(&self, model: ModelRef, query_arguments: QueryArguments) -> connector_interface::Result<usize> {
         let db_name = &model.internal_data_model().db_name;
-        let query = ReadQueryBuilder::count_by_model(model, query_arguments);
+        let query = ReadQueryBuilder::count_by_model(model, query_arguments, T::SQL_FAMILY);
 
         let result = self
             .executor
@@ -124,6 +135,74 @@ where
         Ok(result)
     }
 
+    fn aggregate_records(
+        &self,
+        model: ModelRef,
+        query_arguments: QueryArguments,
+        selections: &[AggregationSelection],
+        group_by: &[ScalarFieldRef],
+        having: Option<AggregationFilter>,
+    ) -> connector_interface::Result<Vec<AggregationRow>> {
+        let db_name = &model.internal_data_model().db_name;
+        let idents: Vec<TypeIdentifier> = selections.iter().flat_map(|s| s.type_identifiers()).collect();
+        let reverse = !group_by.is_empty() && query_arguments.first.is_none() && query_arguments.last.is_some();
+        let query = ReadQueryBuilder::aggregate(model, selections, group_by, having, query_arguments, T::SQL_FAMILY);
+
+        let rows = self
+            .executor
+            .with_transaction(db_name, |conn| conn.filter(query.into(), idents.as_slice()))?;
+
+        let mut rows = rows
+            .into_iter()
+            .map(|row| {
+                let mut values = row.values.into_iter();
+                let mut results = Vec::with_capacity(selections.len());
+
+                for selection in selections {
+                    let mut next = || values.next().ok_or(ConnectorError::ColumnDoesNotExist);
+
+                    match selection {
+                        AggregationSelection::Field(field) => {
+                            results.push(AggregationResult::Field(Arc::clone(field), next()?))
+                        }
+                        AggregationSelection::Count => {
+                            results.push(AggregationResult::Count(i64::try_from(next()?)? as usize))
+                        }
+                        AggregationSelection::Sum(fields) => {
+                            for field in fields {
+                                results.push(AggregationResult::Sum(Arc::clone(field), next()?));
+                            }
+                        }
+                        AggregationSelection::Average(fields) => {
+                            for field in fields {
+                                results.push(AggregationResult::Average(Arc::clone(field), next()?));
+                            }
+                        }
+                        AggregationSelection::Min(fields) => {
+                            for field in fields {
+                                results.push(AggregationResult::Min(Arc::clone(field), next()?));
+                            }
+                        }
+                        AggregationSelection::Max(fields) => {
+                            for field in fields {
+                                results.push(AggregationResult::Max(Arc::clone(field), next()?));
+                            }
+                        }
+                    }
+                }
+
+                Ok(results)
+            })
+            .collect::<connector_interface::Result<Vec<AggregationRow>>>()?;
+
+        // Groups selected with `last` are fetched in descending order.
+        if reverse {
+            rows.reverse();
+        }
+
+        Ok(rows)
+    }
+
     fn count_by_table(&self, database: &str, table: &str) -> connector_interface::Result<usize> {
         let query = ReadQueryBuilder::count_by_table(database, table);
 
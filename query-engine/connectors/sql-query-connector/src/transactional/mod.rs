@@ -1,19 +1,21 @@
+mod interactive;
 mod managed_database_reader;
 mod unmanaged_database_writer;
 
+pub use interactive::*;
 pub use managed_database_reader::*;
 pub use unmanaged_database_writer::*;
 
-use crate::{error::*, query_builder::ReadQueryBuilder, AliasedCondition, RawQuery, SqlRow, ToSqlRow};
+use crate::{
+    error::*, filter_conversion::record_finder_cond, query_builder::ReadQueryBuilder, AliasedCondition, RawQuery,
+    SqlFamily, SqlRow, ToSqlRow,
+};
 use connector_interface::{
     error::RecordFinderInfo,
     filter::{Filter, RecordFinder},
 };
 use prisma_models::*;
-use prisma_query::{
-    ast::*,
-    connector::{self, Queryable},
-};
+use prisma_query::{ast::*, connector::Queryable};
 use serde_json::{Map, Number, Value};
 use std::{convert::TryFrom, sync::Arc};
 
@@ -23,7 +25,7 @@ pub trait Transactional {
         F: FnOnce(&mut dyn Transaction) -> crate::Result<T>;
 }
 
-impl<'t> Transaction for connector::Transaction<'t> {}
+impl<Q> Transaction for Q where Q: Queryable {}
 
 pub trait Transaction: Queryable {
     fn filter(&mut self, q: Query, idents: &[TypeIdentifier]) -> crate::Result<Vec<SqlRow>> {
@@ -65,7 +67,7 @@ pub trait Transaction: Queryable {
     fn find_record(&mut self, record_finder: &RecordFinder) -> crate::Result<SingleRecord> {
         use SqlError::*;
 
-        let model = record_finder.field.model();
+        let model = record_finder.model();
         let selected_fields = SelectedFields::from(Arc::clone(&model));
         let select = ReadQueryBuilder::get_records(model, &selected_fields, record_finder);
         let idents = selected_fields.type_identifiers();
@@ -96,13 +98,14 @@ pub trait Transaction: Queryable {
         Ok(i64::try_from(id)?)
     }
 
-    /// Read the first column from the first row as an `GraphqlId`.
+    /// Read the id of the first row as an `GraphqlId`.
     fn find_id(&mut self, record_finder: &RecordFinder) -> crate::Result<GraphqlId> {
-        let model = record_finder.field.model();
-        let filter = Filter::from(record_finder.clone());
+        let model = record_finder.model();
+        let selected_fields = SelectedFields::id(Arc::clone(&model));
+        let select = ReadQueryBuilder::get_records(Arc::clone(&model), &selected_fields, record_finder);
 
         let id = self
-            .filter_ids(model, filter)?
+            .select_model_ids(&model, select)?
             .into_iter()
             .next()
             .ok_or_else(|| SqlError::RecordNotFoundForWhere(RecordFinderInfo::from(record_finder)))?;
@@ -110,13 +113,26 @@ pub trait Transaction: Queryable {
         Ok(id)
     }
 
-    /// Read the all columns as an `GraphqlId`
-    fn filter_ids(&mut self, model: ModelRef, filter: Filter) -> crate::Result<Vec<GraphqlId>> {
-        let select = Select::from_table(model.table())
-            .column(model.fields().id().as_column())
-            .so_that(filter.aliased_cond(None));
+    /// Read the ids of all records matching the filter.
+    fn filter_ids(&mut self, model: ModelRef, filter: Filter, family: SqlFamily) -> crate::Result<Vec<GraphqlId>> {
+        let select = model
+            .id_columns()
+            .into_iter()
+            .fold(Select::from_table(model.table()), |acc, column| acc.column(column))
+            .so_that(filter.aliased_cond(None, family));
 
-        self.select_ids(select)
+        self.select_model_ids(&model, select)
+    }
+
+    /// Read the id columns of the model from all rows. Compound ids are selected with one column per id field.
+    fn select_model_ids(&mut self, model: &ModelRef, select: Select) -> crate::Result<Vec<GraphqlId>> {
+        let id_fields = model.id_field_names();
+        let idents: Vec<TypeIdentifier> = id_fields.iter().map(|_| TypeIdentifier::GraphQLID).collect();
+
+        self.filter(select.into(), idents.as_slice())?
+            .into_iter()
+            .map(|row| Ok(Record::from(row).collect_id(&id_fields, &id_fields)?))
+            .collect()
     }
 
     fn select_ids(&mut self, select: Select) -> crate::Result<Vec<GraphqlId>> {
@@ -140,10 +156,10 @@ pub trait Transaction: Queryable {
         parent_id: &GraphqlId,
         selector: &Option<RecordFinder>,
     ) -> crate::Result<GraphqlId> {
-        let ids = self.filter_ids_by_parents(
+        let ids = self.select_ids_by_parents(
             Arc::clone(&parent_field),
             vec![parent_id],
-            selector.clone().map(Filter::from),
+            selector.clone().map(record_finder_cond),
         )?;
 
         let id = ids.into_iter().next().ok_or_else(|| SqlError::RecordsNotConnected {
@@ -164,6 +180,19 @@ pub trait Transaction: Queryable {
         parent_field: RelationFieldRef,
         parent_ids: Vec<&GraphqlId>,
         selector: Option<Filter>,
+        family: SqlFamily,
+    ) -> crate::Result<Vec<GraphqlId>> {
+        let condition = selector.map(|filter| filter.aliased_cond(None, family));
+        self.select_ids_by_parents(parent_field, parent_ids, condition)
+    }
+
+    /// Find all children record id's with the given parent id's, optionally
+    /// restricted by an extra condition on the children.
+    fn select_ids_by_parents(
+        &mut self,
+        parent_field: RelationFieldRef,
+        parent_ids: Vec<&GraphqlId>,
+        condition: Option<ConditionTree<'static>>,
     ) -> crate::Result<Vec<GraphqlId>> {
         let related_model = parent_field.related_model();
         let relation = parent_field.relation();
@@ -181,11 +210,8 @@ pub trait Transaction: Queryable {
             .to_string()
             .in_selection(subselect);
 
-        let conditions = match selector {
-            Some(into_cond) => {
-                let filter: Filter = into_cond.into();
-                conditions.and(filter.aliased_cond(None))
-            }
+        let conditions = match condition {
+            Some(condition) => conditions.and(condition),
             None => conditions.into(),
         };
 
@@ -4,7 +4,6 @@ use crate::{
     Transaction,
 };
 use prisma_models::{GraphqlId, ModelRef, PrismaArgs, PrismaListValue, RelationFieldRef};
-use prisma_query::error::Error as QueryError;
 use std::sync::Arc;
 
 /// Creates a new root record and any associated list records to the database.
@@ -17,34 +16,11 @@ pub fn execute<S>(
 where
     S: AsRef<str>,
 {
-    let (insert, returned_id) = WriteQueryBuilder::create_record(Arc::clone(&model), non_list_args.clone());
+    let (insert, returned_id) = WriteQueryBuilder::create_record(Arc::clone(&model), non_list_args.clone())?;
 
-    let last_id = match conn.insert(insert) {
-        Ok(id) => id,
-        Err(QueryError::UniqueConstraintViolation { field_name }) => {
-            if field_name == "PRIMARY" {
-                return Err(SqlError::UniqueConstraintViolation {
-                    field_name: format!("{}.{}", model.name, model.fields().id().name),
-                });
-            } else {
-                return Err(SqlError::UniqueConstraintViolation {
-                    field_name: format!("{}.{}", model.name, field_name),
-                });
-            }
-        }
-        Err(QueryError::NullConstraintViolation { field_name }) => {
-            if field_name == "PRIMARY" {
-                return Err(SqlError::NullConstraintViolation {
-                    field_name: format!("{}.{}", model.name, model.fields().id().name),
-                });
-            } else {
-                return Err(SqlError::NullConstraintViolation {
-                    field_name: format!("{}.{}", model.name, field_name),
-                });
-            }
-        }
-        Err(e) => return Err(SqlError::from(e)),
-    };
+    let last_id = conn
+        .insert(insert)
+        .map_err(|err| SqlError::from_write_error(&model, err))?;
 
     let id = match returned_id {
         Some(id) => id,
@@ -56,7 +32,8 @@ where
         let table = field.scalar_list_table();
 
         if let Some(insert) = WriteQueryBuilder::create_scalar_list_value(table.table(), &list_value, &id) {
-            conn.insert(insert)?;
+            conn.insert(insert)
+                .map_err(|err| SqlError::from_write_error(&model, err))?;
         }
     }
 
@@ -82,7 +59,8 @@ where
     };
 
     if let Some(query) = actions.parent_removal(parent_id) {
-        conn.execute(query)?;
+        conn.execute(query)
+            .map_err(|err| SqlError::from_relation_write_error(&relation_field, err))?;
     }
 
     let related_field = relation_field.related_field();
@@ -94,9 +72,10 @@ where
         execute(conn, relation_field.related_model(), &prisma_args, list_args)
     } else {
         let id = execute(conn, relation_field.related_model(), non_list_args, list_args)?;
-        let relation_query = WriteQueryBuilder::create_relation(relation_field, parent_id, &id);
+        let relation_query = WriteQueryBuilder::create_relation(Arc::clone(&relation_field), parent_id, &id);
 
-        conn.execute(relation_query)?;
+        conn.execute(relation_query)
+            .map_err(|err| SqlError::from_relation_write_error(&relation_field, err))?;
 
         Ok(id)
     }
@@ -0,0 +1,72 @@
+use super::create;
+use crate::{error::SqlError, query_builder::WriteQueryBuilder, SqlFamily, ToSqlRow, Transaction};
+use prisma_models::{GraphqlId, ModelRef, PrismaArgs, PrismaListValue, Record, TypeIdentifier};
+use prisma_query::connector::ResultSet;
+use std::sync::Arc;
+
+/// Creates the given records in as few statements as the bind value limit of the database allows.
+/// Returns the ids of the created records if `return_ids` is set.
+pub fn execute(
+    conn: &mut dyn Transaction,
+    model: ModelRef,
+    args: &[PrismaArgs],
+    return_ids: bool,
+    max_bind_values: usize,
+    family: SqlFamily,
+) -> crate::Result<Option<Vec<GraphqlId>>> {
+    // Only Postgres returns the ids generated by the database for every row of a multi-row insert,
+    // on the other databases the records are inserted one by one instead.
+    let is_auto_generated = model.fields().id_fields().iter().any(|field| field.is_auto_generated);
+
+    if return_ids && is_auto_generated && family != SqlFamily::Postgres {
+        let no_list_args: &[(String, PrismaListValue)] = &[];
+
+        let ids = args
+            .iter()
+            .map(|record_args| create::execute(conn, Arc::clone(&model), record_args, no_list_args))
+            .collect::<crate::Result<Vec<GraphqlId>>>()?;
+
+        return Ok(Some(ids));
+    }
+
+    let (inserts, mut ids) =
+        WriteQueryBuilder::create_many_records(Arc::clone(&model), args.to_vec(), max_bind_values)?;
+
+    for (insert, positions) in inserts {
+        if return_ids && is_auto_generated {
+            let result_set = conn
+                .query(insert.into())
+                .map_err(|err| SqlError::from_write_error(&model, err))?;
+
+            for (position, id) in positions.into_iter().zip(returned_ids(&model, result_set)?) {
+                ids[position] = Some(id);
+            }
+        } else {
+            conn.insert(insert)
+                .map_err(|err| SqlError::from_write_error(&model, err))?;
+        }
+    }
+
+    if return_ids {
+        ids.into_iter()
+            .map(|id| id.ok_or(SqlError::RecordDoesNotExist))
+            .collect::<crate::Result<Vec<GraphqlId>>>()
+            .map(Some)
+    } else {
+        Ok(None)
+    }
+}
+
+/// Reads the ids from the rows returned by an insert returning the id columns of the model.
+fn returned_ids(model: &ModelRef, result_set: ResultSet) -> crate::Result<Vec<GraphqlId>> {
+    let id_fields = model.id_field_names();
+    let idents: Vec<TypeIdentifier> = id_fields.iter().map(|_| TypeIdentifier::GraphQLID).collect();
+
+    result_set
+        .into_iter()
+        .map(|row| {
+            let record = Record::from(row.to_sql_row(&idents)?);
+            Ok(record.collect_id(&id_fields, &id_fields)?)
+        })
+        .collect()
+}
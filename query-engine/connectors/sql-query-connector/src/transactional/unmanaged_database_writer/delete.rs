@@ -1,8 +1,5 @@
-use crate::{
-    error::SqlError,
-    query_builder::{DeleteActions, NestedActions, WriteQueryBuilder},
-    Transaction,
-};
+use super::delete_actions;
+use crate::{error::SqlError, query_builder::NestedActions, Transaction};
 use connector_interface::{error::RecordFinderInfo, filter::RecordFinder};
 use prisma_models::{GraphqlId, RelationFieldRef, SingleRecord};
 use std::sync::Arc;
@@ -12,18 +9,11 @@ use std::sync::Arc;
 ///
 /// Will return the deleted record if the delete was successful.
 pub fn execute(conn: &mut dyn Transaction, record_finder: &RecordFinder) -> crate::Result<SingleRecord> {
-    let model = record_finder.field.model();
+    let model = record_finder.model();
     let record = conn.find_record(record_finder)?;
-    let id = record.collect_id(&model.fields().id().name).unwrap();
+    let id = record.collect_id(&model.id_field_names()).unwrap();
 
-    DeleteActions::check_relation_violations(Arc::clone(&model), &[&id], |select| {
-        let ids = conn.select_ids(select)?;
-        Ok(ids.into_iter().next())
-    })?;
-
-    for delete in WriteQueryBuilder::delete_many(model, &[&id]) {
-        conn.delete(delete)?;
-    }
+    delete_actions::execute(conn, model, &[&id])?;
 
     Ok(record)
 }
@@ -77,16 +67,7 @@ pub fn execute_nested(
         check(ids.into_iter().next().is_some())?;
     }
 
-    let related_model = relation_field.related_model();
-
-    DeleteActions::check_relation_violations(related_model, &[&child_id; 1], |select| {
-        let ids = conn.select_ids(select)?;
-        Ok(ids.into_iter().next())
-    })?;
-
-    for delete in WriteQueryBuilder::delete_many(relation_field.related_model(), &[&child_id]) {
-        conn.delete(delete)?;
-    }
+    delete_actions::execute(conn, relation_field.related_model(), &[&child_id])?;
 
     Ok(())
 }
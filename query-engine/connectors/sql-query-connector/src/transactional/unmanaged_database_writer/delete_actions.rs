@@ -0,0 +1,63 @@
+use crate::{
+    error::SqlError,
+    query_builder::{DeleteActions, WriteQueryBuilder},
+    Transaction,
+};
+use prisma_models::{GraphqlId, ModelRef};
+use std::{collections::HashSet, sync::Arc};
+
+/// Deletes the records with the given ids, applying the `onDelete` actions
+/// of all relations pointing to the model.
+///
+/// Restricting relations and required relations without a cascade cause a
+/// relation violation, cascading relations delete the related records
+/// first and all other relations are unlinked from the deleted records.
+pub fn execute(conn: &mut dyn Transaction, model: ModelRef, ids: &[&GraphqlId]) -> crate::Result<()> {
+    let mut deleted = HashSet::new();
+
+    for id in ids {
+        deleted.insert((model.name.clone(), (*id).clone()));
+    }
+
+    execute_cascading(conn, model, ids, &mut deleted)
+}
+
+/// Deletes the records and cascades the deletion. Records already being deleted,
+/// identified by model name and id, are skipped, so that cycles in the data or
+/// between the models terminate.
+fn execute_cascading(
+    conn: &mut dyn Transaction,
+    model: ModelRef,
+    ids: &[&GraphqlId],
+    deleted: &mut HashSet<(String, GraphqlId)>,
+) -> crate::Result<()> {
+    DeleteActions::check_relation_violations(Arc::clone(&model), ids, |select| {
+        let ids = conn.select_ids(select)?;
+        Ok(ids.into_iter().next())
+    })?;
+
+    for (related_model, select) in DeleteActions::cascades(&model, ids) {
+        let related_ids: Vec<GraphqlId> = conn
+            .select_ids(select)?
+            .into_iter()
+            .filter(|id| deleted.insert((related_model.name.clone(), id.clone())))
+            .collect();
+
+        if !related_ids.is_empty() {
+            let related_ids: Vec<&GraphqlId> = related_ids.iter().collect();
+            execute_cascading(conn, related_model, related_ids.as_slice(), deleted)?;
+        }
+    }
+
+    for (relation_field, query) in DeleteActions::unlink(&model, ids) {
+        conn.execute(query)
+            .map_err(|err| SqlError::from_relation_write_error(&relation_field, err))?;
+    }
+
+    for delete in WriteQueryBuilder::delete_many(Arc::clone(&model), ids) {
+        conn.delete(delete)
+            .map_err(|err| SqlError::from_write_error(&model, err))?;
+    }
+
+    Ok(())
+}
@@ -1,7 +1,5 @@
-use crate::{
-    query_builder::{DeleteActions, WriteQueryBuilder},
-    Transaction,
-};
+use super::delete_actions;
+use crate::{SqlFamily, Transaction};
 use connector_interface::filter::Filter;
 use prisma_models::{GraphqlId, ModelRef, RelationFieldRef};
 use std::sync::Arc;
@@ -10,8 +8,13 @@ use std::sync::Arc;
 /// any relations will cause an error.
 ///
 /// Will return the number records deleted.
-pub fn execute(conn: &mut dyn Transaction, model: ModelRef, filter: &Filter) -> crate::Result<usize> {
-    let ids = conn.filter_ids(Arc::clone(&model), filter.clone())?;
+pub fn execute(
+    conn: &mut dyn Transaction,
+    model: ModelRef,
+    filter: &Filter,
+    family: SqlFamily,
+) -> crate::Result<usize> {
+    let ids = conn.filter_ids(Arc::clone(&model), filter.clone(), family)?;
     let ids: Vec<&GraphqlId> = ids.iter().map(|id| &*id).collect();
     let count = ids.len();
 
@@ -19,14 +22,7 @@ pub fn execute(conn: &mut dyn Transaction, model: ModelRef, filter: &Filter) ->
         return Ok(count);
     }
 
-    DeleteActions::check_relation_violations(Arc::clone(&model), ids.as_slice(), |select| {
-        let ids = conn.select_ids(select)?;
-        Ok(ids.into_iter().next())
-    })?;
-
-    for delete in WriteQueryBuilder::delete_many(model, ids.as_slice()) {
-        conn.delete(delete)?;
-    }
+    delete_actions::execute(conn, model, ids.as_slice())?;
 
     Ok(count)
 }
@@ -39,8 +35,9 @@ pub fn execute_nested(
     parent_id: &GraphqlId,
     filter: &Option<Filter>,
     relation_field: RelationFieldRef,
+    family: SqlFamily,
 ) -> crate::Result<usize> {
-    let ids = conn.filter_ids_by_parents(Arc::clone(&relation_field), vec![parent_id], filter.clone())?;
+    let ids = conn.filter_ids_by_parents(Arc::clone(&relation_field), vec![parent_id], filter.clone(), family)?;
 
     let count = ids.len();
 
@@ -49,16 +46,7 @@ pub fn execute_nested(
     }
 
     let ids: Vec<&GraphqlId> = ids.iter().map(|id| &*id).collect();
-    let model = relation_field.model();
-
-    DeleteActions::check_relation_violations(model, ids.as_slice(), |select| {
-        let ids = conn.select_ids(select)?;
-        Ok(ids.into_iter().next())
-    })?;
-
-    for delete in WriteQueryBuilder::delete_many(relation_field.related_model(), ids.as_slice()) {
-        conn.delete(delete)?;
-    }
+    delete_actions::execute(conn, relation_field.related_model(), ids.as_slice())?;
 
     Ok(count)
 }
@@ -1,5 +1,7 @@
 mod create;
+mod create_many;
 mod delete;
+mod delete_actions;
 mod delete_many;
 mod nested;
 mod relation;
@@ -7,12 +9,13 @@ mod update;
 mod update_many;
 
 use crate::{
-    database::{SqlCapabilities, SqlDatabase},
+    database::{SqlCapabilities, SqlDatabase, SqlFamily},
     error::SqlError,
     query_builder::WriteQueryBuilder,
     RawQuery, Transaction, Transactional,
 };
 use connector_interface::{self, result_ast::*, write_ast::*, UnmanagedDatabaseWriter};
+use prisma_models::GraphqlId;
 use serde_json::Value;
 use std::sync::Arc;
 
@@ -22,9 +25,13 @@ where
 {
     fn execute(&self, db_name: String, write_query: RootWriteQuery) -> connector_interface::Result<WriteQueryResult> {
         let result = self.executor.with_transaction(&db_name, |conn| {
-            fn create(conn: &mut dyn Transaction, cn: &CreateRecord) -> crate::Result<WriteQueryResult> {
+            fn create(
+                conn: &mut dyn Transaction,
+                cn: &CreateRecord,
+                family: SqlFamily,
+            ) -> crate::Result<WriteQueryResult> {
                 let parent_id = create::execute(conn, Arc::clone(&cn.model), &cn.non_list_args, &cn.list_args)?;
-                nested::execute(conn, &cn.nested_writes, &parent_id)?;
+                nested::execute(conn, &cn.nested_writes, &parent_id, family)?;
 
                 Ok(WriteQueryResult {
                     identifier: Identifier::Id(parent_id),
@@ -32,9 +39,13 @@ where
                 })
             }
 
-            fn update(conn: &mut dyn Transaction, un: &UpdateRecord) -> crate::Result<WriteQueryResult> {
+            fn update(
+                conn: &mut dyn Transaction,
+                un: &UpdateRecord,
+                family: SqlFamily,
+            ) -> crate::Result<WriteQueryResult> {
                 let parent_id = update::execute(conn, &un.where_, &un.non_list_args, &un.list_args)?;
-                nested::execute(conn, &un.nested_writes, &parent_id)?;
+                nested::execute(conn, &un.nested_writes, &parent_id, family)?;
 
                 Ok(WriteQueryResult {
                     identifier: Identifier::Id(parent_id),
@@ -43,13 +54,33 @@ where
             }
 
             match write_query {
-                RootWriteQuery::CreateRecord(ref cn) => Ok(create(conn, cn)?),
-                RootWriteQuery::UpdateRecord(ref un) => Ok(update(conn, un)?),
+                RootWriteQuery::CreateRecord(ref cn) => Ok(create(conn, cn, T::SQL_FAMILY)?),
+                RootWriteQuery::UpdateRecord(ref un) => Ok(update(conn, un, T::SQL_FAMILY)?),
                 RootWriteQuery::UpsertRecord(ref ups) => match conn.find_id(&ups.where_) {
-                    Err(_e @ SqlError::RecordNotFoundForWhere { .. }) => Ok(create(conn, &ups.create)?),
+                    Err(_e @ SqlError::RecordNotFoundForWhere { .. }) => Ok(create(conn, &ups.create, T::SQL_FAMILY)?),
                     Err(e) => Err(e.into()),
-                    Ok(_) => Ok(update(conn, &ups.update)?),
+                    Ok(_) => Ok(update(conn, &ups.update, T::SQL_FAMILY)?),
                 },
+                RootWriteQuery::CreateManyRecords(ref cms) => {
+                    let ids = create_many::execute(
+                        conn,
+                        Arc::clone(&cms.model),
+                        &cms.args,
+                        cms.return_ids,
+                        T::MAX_BIND_VALUES,
+                        T::SQL_FAMILY,
+                    )?;
+
+                    let identifier = match ids {
+                        Some(ids) => Identifier::Ids(ids),
+                        None => Identifier::Count(cms.args.len()),
+                    };
+
+                    Ok(WriteQueryResult {
+                        identifier,
+                        typ: WriteQueryResultType::Many,
+                    })
+                }
                 RootWriteQuery::UpdateManyRecords(ref uns) => {
                     let count = update_many::execute(
                         conn,
@@ -57,6 +88,7 @@ where
                         &uns.filter,
                         &uns.non_list_args,
                         &uns.list_args,
+                        T::SQL_FAMILY,
                     )?;
 
                     Ok(WriteQueryResult {
@@ -73,7 +105,7 @@ where
                     })
                 }
                 RootWriteQuery::DeleteManyRecords(ref dns) => {
-                    let count = delete_many::execute(conn, Arc::clone(&dns.model), &dns.filter)?;
+                    let count = delete_many::execute(conn, Arc::clone(&dns.model), &dns.filter, T::SQL_FAMILY)?;
 
                     Ok(WriteQueryResult {
                         identifier: Identifier::Count(count),
@@ -95,6 +127,19 @@ where
         Ok(result)
     }
 
+    fn execute_nested(
+        &self,
+        db_name: String,
+        write_query: NestedWriteQuery,
+        parent_id: GraphqlId,
+    ) -> connector_interface::Result<WriteQueryResult> {
+        let result = self.executor.with_transaction(&db_name, |conn| {
+            nested::execute_single(conn, &write_query, &parent_id, T::SQL_FAMILY)
+        })?;
+
+        Ok(result)
+    }
+
     fn execute_raw(&self, db_name: String, query: String) -> connector_interface::Result<Value> {
         let result = self
             .executor
@@ -1,84 +1,58 @@
 use super::{create, delete, delete_many, relation, update, update_many};
-use crate::{error::SqlError, Transaction};
-use connector_interface::write_ast::*;
+use crate::{error::SqlError, SqlFamily, Transaction};
+use connector_interface::{result_ast::*, write_ast::*};
 use prisma_models::GraphqlId;
 use std::sync::Arc;
 
+/// Executes all given nested writes for the parent, including the nested writes of the nested writes.
 pub fn execute(
     conn: &mut dyn Transaction,
     nested_write_writes: &NestedWriteQueries,
     parent_id: &GraphqlId,
+    family: SqlFamily,
 ) -> crate::Result<()> {
-    fn create(conn: &mut dyn Transaction, parent_id: &GraphqlId, cn: &NestedCreateRecord) -> crate::Result<()> {
-        let parent_id = create::execute_nested(
-            conn,
-            parent_id,
-            cn,
-            Arc::clone(&cn.relation_field),
-            &cn.non_list_args,
-            &cn.list_args,
-        )?;
-
-        execute(conn, &cn.nested_writes, &parent_id)?;
-
-        Ok(())
+    fn create(
+        conn: &mut dyn Transaction,
+        parent_id: &GraphqlId,
+        cn: &NestedCreateRecord,
+        family: SqlFamily,
+    ) -> crate::Result<GraphqlId> {
+        let parent_id = create_one(conn, parent_id, cn)?;
+        execute(conn, &cn.nested_writes, &parent_id, family)?;
+
+        Ok(parent_id)
     }
 
-    fn update(conn: &mut dyn Transaction, parent_id: &GraphqlId, un: &NestedUpdateRecord) -> crate::Result<()> {
-        let parent_id = update::execute_nested(
-            conn,
-            parent_id,
-            &un.where_,
-            Arc::clone(&un.relation_field),
-            &un.non_list_args,
-            &un.list_args,
-        )?;
+    fn update(
+        conn: &mut dyn Transaction,
+        parent_id: &GraphqlId,
+        un: &NestedUpdateRecord,
+        family: SqlFamily,
+    ) -> crate::Result<GraphqlId> {
+        let parent_id = update_one(conn, parent_id, un)?;
+        execute(conn, &un.nested_writes, &parent_id, family)?;
 
-        execute(conn, &un.nested_writes, &parent_id)?;
-
-        Ok(())
+        Ok(parent_id)
     }
 
     for create_record in nested_write_writes.creates.iter() {
-        create(conn, parent_id, create_record)?;
+        create(conn, parent_id, create_record, family)?;
     }
 
     for update_record in nested_write_writes.updates.iter() {
-        update(conn, parent_id, update_record)?;
+        update(conn, parent_id, update_record, family)?;
     }
 
     for upsert_record in nested_write_writes.upserts.iter() {
-        let id_opt = conn.find_id_by_parent(
-            Arc::clone(&upsert_record.relation_field),
-            parent_id,
-            &upsert_record.where_,
-        );
-
-        match id_opt {
-            Ok(_) => update(conn, parent_id, &upsert_record.update)?,
-            Err(_e @ SqlError::RecordsNotConnected { .. }) => create(conn, parent_id, &upsert_record.create)?,
-            Err(e) => return Err(e),
-        }
+        upsert(conn, parent_id, upsert_record, family)?;
     }
 
     for delete_record in nested_write_writes.deletes.iter() {
-        delete::execute_nested(
-            conn,
-            parent_id,
-            delete_record,
-            &delete_record.where_,
-            Arc::clone(&delete_record.relation_field),
-        )?;
+        delete_one(conn, parent_id, delete_record)?;
     }
 
     for connect in nested_write_writes.connects.iter() {
-        relation::connect(
-            conn,
-            &parent_id,
-            connect,
-            &connect.where_,
-            Arc::clone(&connect.relation_field),
-        )?;
+        connect_one(conn, parent_id, connect)?;
     }
 
     for set in nested_write_writes.sets.iter() {
@@ -90,24 +64,145 @@ pub fn execute(
     }
 
     for update_many in nested_write_writes.update_manys.iter() {
-        update_many::execute_nested(
-            conn,
-            &parent_id,
-            &update_many.filter,
-            Arc::clone(&update_many.relation_field),
-            &update_many.non_list_args,
-            &update_many.list_args,
-        )?;
+        update_many_one(conn, parent_id, update_many, family)?;
     }
 
     for delete_many in nested_write_writes.delete_manys.iter() {
-        delete_many::execute_nested(
-            conn,
-            &parent_id,
-            &delete_many.filter,
-            Arc::clone(&delete_many.relation_field),
-        )?;
+        delete_many_one(conn, parent_id, delete_many, family)?;
     }
 
     Ok(())
 }
+
+/// Executes a single nested write for the parent. Nested writes of the given write are not executed,
+/// with the exception of upserts, which execute the nested writes of the branch taken.
+pub fn execute_single(
+    conn: &mut dyn Transaction,
+    write_query: &NestedWriteQuery,
+    parent_id: &GraphqlId,
+    family: SqlFamily,
+) -> crate::Result<WriteQueryResult> {
+    let result = |identifier, typ| WriteQueryResult { identifier, typ };
+
+    match write_query {
+        NestedWriteQuery::CreateRecord(ref cn) => Ok(result(
+            Identifier::Id(create_one(conn, parent_id, cn)?),
+            WriteQueryResultType::Create,
+        )),
+        NestedWriteQuery::UpdateRecord(ref un) => Ok(result(
+            Identifier::Id(update_one(conn, parent_id, un)?),
+            WriteQueryResultType::Update,
+        )),
+        NestedWriteQuery::UpsertRecord(ref ups) => Ok(result(
+            Identifier::Id(upsert(conn, parent_id, ups, family)?),
+            WriteQueryResultType::Update,
+        )),
+        NestedWriteQuery::DeleteRecord(ref dn) => {
+            delete_one(conn, parent_id, dn)?;
+            Ok(result(Identifier::None, WriteQueryResultType::Delete))
+        }
+        NestedWriteQuery::Connect(ref cn) => {
+            connect_one(conn, parent_id, cn)?;
+            Ok(result(Identifier::None, WriteQueryResultType::Unit))
+        }
+        NestedWriteQuery::Disconnect(ref dn) => {
+            relation::disconnect(conn, parent_id, dn, &dn.where_)?;
+            Ok(result(Identifier::None, WriteQueryResultType::Unit))
+        }
+        NestedWriteQuery::Set(ref sn) => {
+            relation::set(conn, parent_id, sn, &sn.wheres, Arc::clone(&sn.relation_field))?;
+            Ok(result(Identifier::None, WriteQueryResultType::Unit))
+        }
+        NestedWriteQuery::UpdateManyRecords(ref uns) => Ok(result(
+            Identifier::Count(update_many_one(conn, parent_id, uns, family)?),
+            WriteQueryResultType::Many,
+        )),
+        NestedWriteQuery::DeleteManyRecords(ref dns) => Ok(result(
+            Identifier::Count(delete_many_one(conn, parent_id, dns, family)?),
+            WriteQueryResultType::Many,
+        )),
+    }
+}
+
+fn create_one(conn: &mut dyn Transaction, parent_id: &GraphqlId, cn: &NestedCreateRecord) -> crate::Result<GraphqlId> {
+    create::execute_nested(
+        conn,
+        parent_id,
+        cn,
+        Arc::clone(&cn.relation_field),
+        &cn.non_list_args,
+        &cn.list_args,
+    )
+}
+
+fn update_one(conn: &mut dyn Transaction, parent_id: &GraphqlId, un: &NestedUpdateRecord) -> crate::Result<GraphqlId> {
+    update::execute_nested(
+        conn,
+        parent_id,
+        &un.where_,
+        Arc::clone(&un.relation_field),
+        &un.non_list_args,
+        &un.list_args,
+    )
+}
+
+/// Updates the record if it is connected to the parent, creates it otherwise.
+/// The nested writes of the branch taken are executed as well.
+fn upsert(
+    conn: &mut dyn Transaction,
+    parent_id: &GraphqlId,
+    ups: &NestedUpsertRecord,
+    family: SqlFamily,
+) -> crate::Result<GraphqlId> {
+    let id_opt = conn.find_id_by_parent(Arc::clone(&ups.relation_field), parent_id, &ups.where_);
+
+    let id = match id_opt {
+        Ok(_) => {
+            let id = update_one(conn, parent_id, &ups.update)?;
+            execute(conn, &ups.update.nested_writes, &id, family)?;
+            id
+        }
+        Err(_e @ SqlError::RecordsNotConnected { .. }) => {
+            let id = create_one(conn, parent_id, &ups.create)?;
+            execute(conn, &ups.create.nested_writes, &id, family)?;
+            id
+        }
+        Err(e) => return Err(e),
+    };
+
+    Ok(id)
+}
+
+fn delete_one(conn: &mut dyn Transaction, parent_id: &GraphqlId, dn: &NestedDeleteRecord) -> crate::Result<()> {
+    delete::execute_nested(conn, parent_id, dn, &dn.where_, Arc::clone(&dn.relation_field))
+}
+
+fn connect_one(conn: &mut dyn Transaction, parent_id: &GraphqlId, cn: &NestedConnect) -> crate::Result<()> {
+    relation::connect(conn, parent_id, cn, &cn.where_, Arc::clone(&cn.relation_field))
+}
+
+fn update_many_one(
+    conn: &mut dyn Transaction,
+    parent_id: &GraphqlId,
+    uns: &NestedUpdateManyRecords,
+    family: SqlFamily,
+) -> crate::Result<usize> {
+    update_many::execute_nested(
+        conn,
+        parent_id,
+        &uns.filter,
+        Arc::clone(&uns.relation_field),
+        &uns.non_list_args,
+        &uns.list_args,
+        family,
+    )
+}
+
+fn delete_many_one(
+    conn: &mut dyn Transaction,
+    parent_id: &GraphqlId,
+    dns: &NestedDeleteManyRecords,
+    family: SqlFamily,
+) -> crate::Result<usize> {
+    delete_many::execute_nested(conn, parent_id, &dns.filter, Arc::clone(&dns.relation_field), family)
+}
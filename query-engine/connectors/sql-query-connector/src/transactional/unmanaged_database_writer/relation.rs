@@ -1,4 +1,5 @@
 use crate::{
+    error::SqlError,
     query_builder::{NestedActions, WriteQueryBuilder},
     Transaction,
 };
@@ -38,17 +39,18 @@ pub fn connect(
     }
 
     let child_id = conn.find_id(record_finder)?;
+    let relation_error = |err| SqlError::from_relation_write_error(&relation_field, err);
 
     if let Some(query) = actions.parent_removal(parent_id) {
-        conn.execute(query)?;
+        conn.execute(query).map_err(relation_error)?;
     }
 
     if let Some(query) = actions.child_removal(&child_id) {
-        conn.execute(query)?;
+        conn.execute(query).map_err(relation_error)?;
     }
 
-    let relation_query = WriteQueryBuilder::create_relation(relation_field, parent_id, &child_id);
-    conn.execute(relation_query)?;
+    let relation_query = WriteQueryBuilder::create_relation(Arc::clone(&relation_field), parent_id, &child_id);
+    conn.execute(relation_query).map_err(relation_error)?;
 
     Ok(())
 }
@@ -75,6 +77,9 @@ pub fn disconnect(
         check(ids.into_iter().next().is_some())?
     }
 
+    let relation_field = actions.relation_field();
+    let relation_error = |err| SqlError::from_relation_write_error(&relation_field, err);
+
     match record_finder {
         None => {
             let (select, check) = actions.ensure_parent_is_connected(parent_id);
@@ -82,7 +87,8 @@ pub fn disconnect(
             let ids = conn.select_ids(select)?;
             check(ids.into_iter().next().is_some())?;
 
-            conn.execute(actions.removal_by_parent(parent_id))?;
+            conn.execute(actions.removal_by_parent(parent_id))
+                .map_err(relation_error)?;
         }
         Some(ref selector) => {
             let child_id = conn.find_id(selector)?;
@@ -91,7 +97,8 @@ pub fn disconnect(
             let ids = conn.select_ids(select)?;
             check(ids.into_iter().next().is_some())?;
 
-            conn.execute(actions.removal_by_parent_and_child(parent_id, &child_id))?;
+            conn.execute(actions.removal_by_parent_and_child(parent_id, &child_id))
+                .map_err(relation_error)?;
         }
     }
 
@@ -112,17 +119,21 @@ pub fn set(
         check(ids.into_iter().next().is_some())?
     }
 
-    conn.execute(actions.removal_by_parent(parent_id))?;
+    let relation_error = |err| SqlError::from_relation_write_error(&relation_field, err);
+
+    conn.execute(actions.removal_by_parent(parent_id))
+        .map_err(relation_error)?;
 
     for selector in record_finders {
         let child_id = conn.find_id(selector)?;
 
         if !relation_field.is_list {
-            conn.execute(actions.removal_by_child(&child_id))?;
+            conn.execute(actions.removal_by_child(&child_id))
+                .map_err(relation_error)?;
         }
 
         let relation_query = WriteQueryBuilder::create_relation(Arc::clone(&relation_field), parent_id, &child_id);
-        conn.execute(relation_query)?;
+        conn.execute(relation_query).map_err(relation_error)?;
     }
 
     Ok(())
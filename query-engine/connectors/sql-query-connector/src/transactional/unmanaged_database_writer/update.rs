@@ -1,9 +1,19 @@
-use crate::{query_builder::WriteQueryBuilder, Transaction};
+use crate::{
+    error::SqlError,
+    filter_conversion::ids_cond,
+    query_builder::{UpdateActions, WriteQueryBuilder},
+    Transaction,
+};
 use connector_interface::filter::RecordFinder;
-use prisma_models::{GraphqlId, ModelRef, PrismaArgs, PrismaListValue, RelationFieldRef};
-use std::sync::Arc;
+use prisma_models::{GraphqlId, ModelRef, PrismaArgs, PrismaListValue, RelationFieldRef, TypeIdentifier};
+use prisma_query::ast::Select;
+use std::{convert::TryFrom, sync::Arc};
 
 /// Updates one record and any associated list record in the database.
+///
+/// If the update changes the id of the record, the `onUpdate` actions of all
+/// relations pointing to the model are applied. Returns the id of the record
+/// after the update.
 pub fn execute<S>(
     conn: &mut dyn Transaction,
     record_finder: &RecordFinder,
@@ -13,18 +23,55 @@ pub fn execute<S>(
 where
     S: AsRef<str>,
 {
-    let model = record_finder.field.model();
+    let model = record_finder.model();
     let id = conn.find_id(record_finder)?;
+    let next_id = updated_id(&model, non_list_args)?.filter(|next_id| next_id != &id);
+
+    if next_id.is_some() {
+        UpdateActions::check_relation_violations(&model, &id, |select| {
+            let ids = conn.select_ids(select)?;
+            Ok(ids.into_iter().next())
+        })?;
+    }
 
     if let Some(update) = WriteQueryBuilder::update_one(Arc::clone(&model), &id, non_list_args)? {
-        conn.update(update)?;
+        conn.update(update)
+            .map_err(|err| SqlError::from_write_error(&model, err))?;
     }
 
+    let id = match next_id {
+        Some(next_id) => {
+            for (relation_field, query) in UpdateActions::relink(&model, &id, &next_id) {
+                conn.execute(query)
+                    .map_err(|err| SqlError::from_relation_write_error(&relation_field, err))?;
+            }
+
+            next_id
+        }
+        None => id,
+    };
+
+    append_list_values(conn, &[id.clone()], Arc::clone(&model), non_list_args)?;
     update_list_args(conn, &[id.clone()], Arc::clone(&model), list_args)?;
 
     Ok(id)
 }
 
+/// The id the update sets for the record, if any. Models with a compound id
+/// can't be related, so only single ids are read.
+fn updated_id(model: &ModelRef, args: &PrismaArgs) -> crate::Result<Option<GraphqlId>> {
+    let id_fields = model.fields().id_fields();
+
+    if id_fields.len() != 1 {
+        return Ok(None);
+    }
+
+    match args.get_field_value(&id_fields[0].name) {
+        Some(value) => Ok(Some(GraphqlId::try_from(value)?)),
+        None => Ok(None),
+    }
+}
+
 /// Updates a nested item related to the parent, including any associated
 /// list values.
 pub fn execute_nested<S>(
@@ -43,11 +90,47 @@ where
     };
 
     let id = conn.find_id_by_parent(Arc::clone(&relation_field), parent_id, record_finder)?;
-    let record_finder = RecordFinder::from((relation_field.related_model().fields().id(), id));
+    let record_finder = RecordFinder::for_id(&relation_field.related_model(), &id);
 
     execute(conn, &record_finder, non_list_args, list_args)
 }
 
+/// Appends the values pushed to lists stored in native arrays to the lists
+/// of the given records, which are read and written back one record at a
+/// time.
+pub fn append_list_values(
+    conn: &mut dyn Transaction,
+    ids: &[GraphqlId],
+    model: ModelRef,
+    args: &PrismaArgs,
+) -> crate::Result<()> {
+    let lists = WriteQueryBuilder::pushed_lists(&model, args);
+
+    if lists.is_empty() {
+        return Ok(());
+    }
+
+    let idents: Vec<TypeIdentifier> = lists.iter().map(|field| field.type_identifier).collect();
+
+    for id in ids {
+        let select = lists
+            .iter()
+            .fold(Select::from_table(model.table()), |acc, field| {
+                acc.column(field.as_column())
+            })
+            .so_that(ids_cond(&model, &[id]));
+
+        let current = conn.find(select, idents.as_slice())?.values;
+
+        if let Some(update) = WriteQueryBuilder::append_list_values(Arc::clone(&model), id, args, &lists, current) {
+            conn.update(update)
+                .map_err(|err| SqlError::from_write_error(&model, err))?;
+        }
+    }
+
+    Ok(())
+}
+
 /// Updates list args related to the given records.
 pub fn update_list_args<S>(
     conn: &mut dyn Transaction,
@@ -64,11 +147,13 @@ where
         let (deletes, inserts) = WriteQueryBuilder::update_scalar_list_values(&table, &list_value, ids.to_vec());
 
         for delete in deletes {
-            conn.delete(delete)?;
+            conn.delete(delete)
+                .map_err(|err| SqlError::from_write_error(&model, err))?;
         }
 
         for insert in inserts {
-            conn.insert(insert)?;
+            conn.insert(insert)
+                .map_err(|err| SqlError::from_write_error(&model, err))?;
         }
     }
 
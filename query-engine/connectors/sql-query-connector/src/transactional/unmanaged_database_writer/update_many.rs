@@ -1,5 +1,5 @@
 use super::update;
-use crate::{query_builder::WriteQueryBuilder, Transaction};
+use crate::{error::SqlError, query_builder::WriteQueryBuilder, SqlFamily, Transaction};
 use connector_interface::filter::Filter;
 use prisma_models::{GraphqlId, ModelRef, PrismaArgs, PrismaListValue, RelationFieldRef};
 use std::sync::Arc;
@@ -14,11 +14,12 @@ pub fn execute<S>(
     filter: &Filter,
     non_list_args: &PrismaArgs,
     list_args: &[(S, PrismaListValue)],
+    family: SqlFamily,
 ) -> crate::Result<usize>
 where
     S: AsRef<str>,
 {
-    let ids = conn.filter_ids(Arc::clone(&model), filter.clone())?;
+    let ids = conn.filter_ids(Arc::clone(&model), filter.clone(), family)?;
     let count = ids.len();
 
     if count == 0 {
@@ -31,9 +32,11 @@ where
     };
 
     for update in updates {
-        conn.update(update)?;
+        conn.update(update)
+            .map_err(|err| SqlError::from_write_error(&model, err))?;
     }
 
+    update::append_list_values(conn, ids.as_slice(), Arc::clone(&model), non_list_args)?;
     update::update_list_args(conn, ids.as_slice(), Arc::clone(&model), list_args)?;
 
     Ok(count)
@@ -48,11 +51,12 @@ pub fn execute_nested<S>(
     relation_field: RelationFieldRef,
     non_list_args: &PrismaArgs,
     list_args: &[(S, PrismaListValue)],
+    family: SqlFamily,
 ) -> crate::Result<usize>
 where
     S: AsRef<str>,
 {
-    let ids = conn.filter_ids_by_parents(Arc::clone(&relation_field), vec![parent_id], filter.clone())?;
+    let ids = conn.filter_ids_by_parents(Arc::clone(&relation_field), vec![parent_id], filter.clone(), family)?;
 
     let count = ids.len();
 
@@ -60,15 +64,18 @@ where
         return Ok(count);
     }
 
+    let model = relation_field.related_model();
     let updates = {
         let ids: Vec<&GraphqlId> = ids.iter().map(|id| &*id).collect();
-        WriteQueryBuilder::update_many(relation_field.related_model(), ids.as_slice(), non_list_args)?
+        WriteQueryBuilder::update_many(Arc::clone(&model), ids.as_slice(), non_list_args)?
     };
 
     for update in updates {
-        conn.update(update)?;
+        conn.update(update)
+            .map_err(|err| SqlError::from_write_error(&model, err))?;
     }
 
+    update::append_list_values(conn, ids.as_slice(), Arc::clone(&model), non_list_args)?;
     update::update_list_args(conn, ids.as_slice(), relation_field.model(), list_args)?;
 
     Ok(count)
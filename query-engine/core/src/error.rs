@@ -2,6 +2,8 @@ use crate::query_builders::QueryValidationError;
 use connector::error::ConnectorError;
 use failure::Fail;
 use prisma_models::DomainError;
+use serde_json::{json, Value};
+use std::fmt;
 
 // TODO: Cleanup unused errors after refactorings.
 #[derive(Debug, Fail)]
@@ -45,3 +47,149 @@ impl From<QueryValidationError> for CoreError {
         CoreError::QueryValidationError(e)
     }
 }
+
+impl CoreError {
+    /// The stable code identifying the kind of this error for clients.
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            CoreError::ConnectorError(e) => match e {
+                ConnectorError::UniqueConstraintViolation { .. } => ErrorCode::UniqueConstraintViolation,
+                ConnectorError::NullConstraintViolation { .. } => ErrorCode::NullConstraintViolation,
+                ConnectorError::ForeignKeyConstraintViolation { .. } => ErrorCode::ForeignKeyConstraintViolation,
+                ConnectorError::FieldCannotBeNull { .. } => ErrorCode::NullConstraintViolation,
+                ConnectorError::RecordDoesNotExist => ErrorCode::RecordNotFound,
+                ConnectorError::RecordNotFoundForWhere(_) => ErrorCode::RecordNotFound,
+                ConnectorError::RelationViolation { .. } => ErrorCode::RelationViolation,
+                ConnectorError::RecordsNotConnected { .. } => ErrorCode::RecordsNotConnected,
+                ConnectorError::ConnectionError(_) => ErrorCode::ConnectionError,
+                ConnectorError::DatabaseDoesNotExist { .. } => ErrorCode::DatabaseDoesNotExist,
+                ConnectorError::DatabaseAccessDenied { .. } => ErrorCode::DatabaseAccessDenied,
+                ConnectorError::AuthenticationFailed { .. } => ErrorCode::AuthenticationFailed,
+                ConnectorError::TransactionAlreadyClosed => ErrorCode::TransactionError,
+                _ => ErrorCode::InternalError,
+            },
+            CoreError::QueryValidationError(_) => ErrorCode::QueryValidationError,
+            CoreError::LegacyQueryValidationError(_) => ErrorCode::QueryValidationError,
+            CoreError::UnsupportedFeatureError(_) => ErrorCode::UnsupportedFeature,
+            _ => ErrorCode::InternalError,
+        }
+    }
+
+    /// Additional, code specific information about this error, always a JSON object.
+    pub fn meta(&self) -> Value {
+        match self {
+            CoreError::ConnectorError(e) => match e {
+                ConnectorError::UniqueConstraintViolation { model, fields }
+                | ConnectorError::NullConstraintViolation { model, fields }
+                | ConnectorError::ForeignKeyConstraintViolation { model, fields } => {
+                    json!({ "model": model, "fields": fields.0 })
+                }
+                ConnectorError::FieldCannotBeNull { field } => json!({ "fields": [field] }),
+                ConnectorError::RecordNotFoundForWhere(info) => json!({
+                    "model": info.model,
+                    "field": info.field,
+                    "value": info.value.to_string(),
+                }),
+                ConnectorError::RelationViolation {
+                    relation_name,
+                    model_a_name,
+                    model_b_name,
+                } => json!({
+                    "relationName": relation_name,
+                    "modelAName": model_a_name,
+                    "modelBName": model_b_name,
+                }),
+                ConnectorError::RecordsNotConnected {
+                    relation_name,
+                    parent_name,
+                    child_name,
+                    ..
+                } => json!({
+                    "relationName": relation_name,
+                    "parentName": parent_name,
+                    "childName": child_name,
+                }),
+                ConnectorError::DatabaseDoesNotExist { db_name } => json!({ "databaseName": db_name }),
+                ConnectorError::DatabaseAccessDenied { db_name } => json!({ "databaseName": db_name }),
+                ConnectorError::AuthenticationFailed { user } => json!({ "user": user }),
+                _ => json!({}),
+            },
+            CoreError::QueryValidationError(e) => json!({ "path": e.path() }),
+            _ => json!({}),
+        }
+    }
+}
+
+/// Stable, machine-readable codes for the errors returned to clients.
+/// Codes are part of the API: they must never change or be reused, new kinds of errors get new codes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ErrorCode {
+    /// The credentials for the database server are invalid.
+    AuthenticationFailed,
+
+    /// The database server can't be reached or the connection failed.
+    ConnectionError,
+
+    /// The database in the connection string doesn't exist on the server.
+    DatabaseDoesNotExist,
+
+    /// The user in the connection string has no access to the database.
+    DatabaseAccessDenied,
+
+    /// A record required by the query was not found.
+    RecordNotFound,
+
+    /// A write would violate a unique constraint.
+    UniqueConstraintViolation,
+
+    /// A write would reference a record that doesn't exist.
+    ForeignKeyConstraintViolation,
+
+    /// The query is invalid against the query schema.
+    QueryValidationError,
+
+    /// A write would set a required field to null.
+    NullConstraintViolation,
+
+    /// A write would violate a required relation.
+    RelationViolation,
+
+    /// Records on a nested write path are not connected.
+    RecordsNotConnected,
+
+    /// The query uses a feature that is not supported.
+    UnsupportedFeature,
+
+    /// Starting, committing or rolling back a transaction failed.
+    TransactionError,
+
+    /// Any other error. Clients can only rely on the message.
+    InternalError,
+}
+
+impl ErrorCode {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ErrorCode::AuthenticationFailed => "P1000",
+            ErrorCode::ConnectionError => "P1001",
+            ErrorCode::DatabaseDoesNotExist => "P1003",
+            ErrorCode::DatabaseAccessDenied => "P1010",
+            ErrorCode::RecordNotFound => "P2001",
+            ErrorCode::UniqueConstraintViolation => "P2002",
+            ErrorCode::ForeignKeyConstraintViolation => "P2003",
+            ErrorCode::QueryValidationError => "P2009",
+            ErrorCode::NullConstraintViolation => "P2011",
+            ErrorCode::RelationViolation => "P2014",
+            ErrorCode::RecordsNotConnected => "P2017",
+            ErrorCode::UnsupportedFeature => "P2026",
+            ErrorCode::TransactionError => "P2028",
+            ErrorCode::InternalError => "P5000",
+        }
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
@@ -0,0 +1,202 @@
+use super::{ReadQueryExecutor, WriteQueryExecutor};
+use crate::{
+    query_graph::{Edge, EdgeType, QueryGraph},
+    CoreError, CoreResult, ResultPair, WriteQueryResultWrapper,
+};
+use connector::{
+    filter::RecordFinder, Identifier, ModelExtractor, Query, ReadQuery, ReadQueryResult, ResultContent, WriteQuery,
+    WriteQueryResult,
+};
+use prisma_models::{GraphqlId, ModelRef};
+
+/// Executes query graphs, node by node, with the primitive operations of the connector.
+pub struct QueryInterpreter<'a> {
+    pub read_executor: &'a ReadQueryExecutor,
+    pub write_executor: &'a WriteQueryExecutor,
+}
+
+enum NodeResult {
+    Read(ReadQueryResult),
+    Write(WriteQueryResultWrapper),
+    NestedWrite(WriteQueryResult),
+}
+
+struct ExecutedNode {
+    result: NodeResult,
+
+    /// Model of the executed query, if the query carries one.
+    model: Option<ModelRef>,
+}
+
+impl ExecutedNode {
+    /// The id of the record written or read by the node.
+    fn id(&self) -> CoreResult<GraphqlId> {
+        match &self.result {
+            NodeResult::Write(wrapper) => Self::id_from_write_result(&wrapper.result),
+            NodeResult::NestedWrite(result) => Self::id_from_write_result(result),
+            NodeResult::Read(result) => match &result.content {
+                ResultContent::RecordSelection(selection) => match selection.scalars.records.first() {
+                    Some(record) => Ok(record.collect_id(&selection.scalars.field_names, &selection.id_fields)?),
+                    None => Err(CoreError::ConversionError(format!(
+                        "Query '{}' did not return a record to depend on.",
+                        result.name
+                    ))),
+                },
+                _ => Err(CoreError::ConversionError(format!(
+                    "Result of query '{}' can't be depended on.",
+                    result.name
+                ))),
+            },
+        }
+    }
+
+    /// Whether the node read at least one record.
+    fn has_record(&self) -> CoreResult<bool> {
+        match &self.result {
+            NodeResult::Read(ReadQueryResult {
+                content: ResultContent::RecordSelection(selection),
+                ..
+            }) => Ok(!selection.scalars.records.is_empty()),
+            _ => Err(CoreError::ConversionError(
+                "Only the result of a record read can be the condition of another query.".into(),
+            )),
+        }
+    }
+
+    fn id_from_write_result(result: &WriteQueryResult) -> CoreResult<GraphqlId> {
+        match result.identifier {
+            Identifier::Id(ref id) => Ok(id.clone()),
+            ref other => Err(CoreError::ConversionError(format!(
+                "Write result {:?} does not contain the id of a record.",
+                other
+            ))),
+        }
+    }
+
+    fn record_finder(&self) -> CoreResult<RecordFinder> {
+        let model = self
+            .model
+            .clone()
+            .ok_or_else(|| CoreError::ConversionError("Model required for dependent query execution".into()))?;
+
+        match &self.result {
+            NodeResult::Write(wrapper) => Ok(wrapper.result.to_record_finder(model)?),
+            _ => Ok(RecordFinder::for_id(&model, &self.id()?)),
+        }
+    }
+}
+
+impl<'a> QueryInterpreter<'a> {
+    /// Executes all nodes of the graph and returns the result of the first executed result node.
+    /// Nodes are skipped if a condition on them doesn't hold or if all parents they take a result from were skipped.
+    pub fn interpret(&self, mut graph: QueryGraph) -> CoreResult<ResultPair> {
+        let mut results: Vec<Option<ExecutedNode>> = (0..graph.node_count()).map(|_| None).collect();
+        let mut skipped = vec![false; graph.node_count()];
+
+        for node in graph.execution_order()? {
+            let query = graph.take_query(node).unwrap();
+            let model = query.extract_model();
+            let edges = graph.incoming_edges(node);
+
+            if Self::must_skip(&edges, &results, &skipped)? {
+                skipped[node.index()] = true;
+                continue;
+            }
+
+            let parents: Vec<(EdgeType, &ExecutedNode)> = edges
+                .into_iter()
+                .filter_map(|edge| results[edge.parent.index()].as_ref().map(|parent| (edge.typ, parent)))
+                .collect();
+
+            let result = self.execute_node(query, &parents)?;
+            results[node.index()] = Some(ExecutedNode { result, model });
+        }
+
+        let (nodes, typ) = graph
+            .result_nodes()
+            .cloned()
+            .ok_or_else(|| CoreError::ConversionError("Query graph without result node.".into()))?;
+
+        let executed = nodes
+            .into_iter()
+            .filter_map(|node| results[node.index()].take())
+            .next()
+            .map(|executed| executed.result);
+
+        match executed {
+            Some(NodeResult::Read(result)) => Ok(ResultPair::Read(result, typ)),
+            Some(NodeResult::Write(result)) => Ok(ResultPair::Write(result, typ)),
+            Some(NodeResult::NestedWrite(_)) => Err(CoreError::ConversionError(
+                "Result of a nested write can't be serialized.".into(),
+            )),
+            None => Err(CoreError::ConversionError(
+                "No result node of the query graph was executed.".into(),
+            )),
+        }
+    }
+
+    /// A node is skipped if the condition of a condition edge doesn't hold, if a parent of a condition edge
+    /// was skipped, or if all parents of the node that it takes a result from were skipped.
+    /// Execution order edges don't cause a node to be skipped.
+    fn must_skip(edges: &[Edge], results: &[Option<ExecutedNode>], skipped: &[bool]) -> CoreResult<bool> {
+        for edge in edges.iter().filter(|edge| edge.typ.is_condition()) {
+            let exists = match &results[edge.parent.index()] {
+                Some(parent) => parent.has_record()?,
+                None => return Ok(true),
+            };
+
+            if exists != (edge.typ == EdgeType::IfExists) {
+                return Ok(true);
+            }
+        }
+
+        for typ in &[EdgeType::ParentId, EdgeType::RecordFinder] {
+            let mut parents = edges.iter().filter(|edge| edge.typ == *typ).peekable();
+
+            if parents.peek().is_some() && parents.all(|edge| skipped[edge.parent.index()]) {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
+    fn execute_node(&self, query: Query, parents: &[(EdgeType, &ExecutedNode)]) -> CoreResult<NodeResult> {
+        match query {
+            Query::Read(mut read) => {
+                let mut parent_ids = vec![];
+
+                for (typ, parent) in parents {
+                    match (typ, &mut read) {
+                        (EdgeType::RecordFinder, ReadQuery::RecordQuery(rq)) => {
+                            rq.record_finder = Some(parent.record_finder()?)
+                        }
+                        (EdgeType::RecordFinder, _) => {
+                            return Err(CoreError::UnsupportedFeatureError(
+                                "Only single record reads can depend on the result of another query.".into(),
+                            ))
+                        }
+                        (EdgeType::ParentId, _) => parent_ids.push(parent.id()?),
+                        _ => (),
+                    }
+                }
+
+                Ok(NodeResult::Read(self.read_executor.execute(read, &parent_ids)?))
+            }
+
+            Query::Write(WriteQuery::Nested(nested)) => {
+                let parent = parents
+                    .iter()
+                    .find(|(typ, _)| *typ == EdgeType::ParentId)
+                    .map(|(_, parent)| parent)
+                    .ok_or_else(|| CoreError::ConversionError("Nested write without parent.".into()))?;
+
+                Ok(NodeResult::NestedWrite(
+                    self.write_executor.execute_nested(nested, parent.id()?)?,
+                ))
+            }
+
+            Query::Write(write) => Ok(NodeResult::Write(self.write_executor.execute(write)?)),
+        }
+    }
+}
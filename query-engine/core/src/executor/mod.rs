@@ -1,22 +1,32 @@
+mod interpreter;
+mod plan;
 mod read;
 mod write;
 
+pub use connector::TransactionHandle;
+pub use interpreter::QueryInterpreter;
 pub use read::ReadQueryExecutor;
 pub use write::WriteQueryExecutor;
 
 use crate::{
     query_builders::QueryBuilder,
     query_document::QueryDocument,
+    query_graph::QueryGraph,
     response_ir::{Response, ResultIrBuilder},
-    CoreError, CoreResult, QueryPair, QuerySchemaRef, ResultPair, ResultResolutionStrategy,
+    CoreError, CoreResult, QueryPair, QuerySchemaRef, ResultPair,
 };
-use connector::{ModelExtractor, Query, ReadQuery};
+use connector::InteractiveTransactionCapable;
+use plan::{PlanStep, QueryPlan};
+use std::sync::Arc;
 
 /// Central query executor and main entry point into the query core.
 pub struct QueryExecutor {
     primary_connector: &'static str,
     read_executor: ReadQueryExecutor,
     write_executor: WriteQueryExecutor,
+
+    /// Starts interactive transactions. Not present for executors already bound to a transaction.
+    transactions: Option<Arc<dyn InteractiveTransactionCapable + Send + Sync + 'static>>,
 }
 
 // Todo:
@@ -25,11 +35,17 @@ pub struct QueryExecutor {
 // - ReadQueryResult should probably just be QueryResult
 // - This is all temporary code until the larger query execution overhaul.
 impl QueryExecutor {
-    pub fn new(primary_connector: &'static str, read_executor: ReadQueryExecutor, write_executor: WriteQueryExecutor) -> Self {
+    pub fn new(
+        primary_connector: &'static str,
+        read_executor: ReadQueryExecutor,
+        write_executor: WriteQueryExecutor,
+        transactions: Option<Arc<dyn InteractiveTransactionCapable + Send + Sync + 'static>>,
+    ) -> Self {
         QueryExecutor {
             primary_connector,
             read_executor,
             write_executor,
+            transactions,
         }
     }
 
@@ -45,10 +61,10 @@ impl QueryExecutor {
         let queries = QueryBuilder::new(query_schema).build(query_doc)?;
 
         // 2. Build query plan
-        // ...
+        let plan = QueryPlan::new(queries);
 
         // 3. Execute query plan
-        let results: Vec<ResultPair> = self.execute_queries(queries)?;
+        let results: Vec<ResultPair> = self.execute_plan(plan)?;
 
         // 4. Build IR response / Parse results into IR response
         Ok(results
@@ -57,48 +73,95 @@ impl QueryExecutor {
             .build())
     }
 
-    fn execute_queries(&self, queries: Vec<QueryPair>) -> CoreResult<Vec<ResultPair>> {
-        queries.into_iter().map(|query| self.execute_query(query)).collect()
+    /// Executes the steps of the plan in order and returns the results in the order of the query document.
+    fn execute_plan(&self, plan: QueryPlan) -> CoreResult<Vec<ResultPair>> {
+        let mut results: Vec<Option<ResultPair>> = (0..plan.query_count).map(|_| None).collect();
+
+        for step in plan.steps {
+            match step {
+                PlanStep::Single(index, query) => results[index] = Some(self.execute_query(query)?),
+                PlanStep::FindOneBatch(batch) => {
+                    let (indices, queries, types): (Vec<_>, Vec<_>, Vec<_>) = batch.into_iter().fold(
+                        (vec![], vec![], vec![]),
+                        |(mut indices, mut queries, mut types), (index, query, typ)| {
+                            indices.push(index);
+                            queries.push(query);
+                            types.push(typ);
+
+                            (indices, queries, types)
+                        },
+                    );
+
+                    let query_results = self.read_executor.read_one_batch(queries)?;
+
+                    for ((index, query_result), typ) in indices.into_iter().zip(query_results).zip(types) {
+                        results[index] = Some(ResultPair::Read(query_result, typ));
+                    }
+                }
+            }
+        }
+
+        Ok(results.into_iter().map(|result| result.unwrap()).collect())
     }
 
+    /// Executes a query, including nested writes and dependent queries, as a query graph.
     fn execute_query(&self, query: QueryPair) -> CoreResult<ResultPair> {
-        let (query, strategy) = query;
-        let model_opt = query.extract_model();
-        match query {
-            Query::Read(read) => {
-                let query_result = self.read_executor.execute(read, &[])?;
-
-                Ok(match strategy {
-                    ResultResolutionStrategy::Serialize(typ) => ResultPair::Read(query_result, typ),
-                    ResultResolutionStrategy::Dependent(_) => unimplemented!(), // Dependent query exec. from read is not supported in this execution model.
-                })
-            }
+        let graph = QueryGraph::from(query);
 
-            Query::Write(write) => {
-                let query_result = self.write_executor.execute(write)?;
-
-                match strategy {
-                    ResultResolutionStrategy::Serialize(typ) => Ok(ResultPair::Write(query_result, typ)),
-                    ResultResolutionStrategy::Dependent(dependent_pair) => match model_opt {
-                        Some(model) => match *dependent_pair {
-                            (Query::Read(ReadQuery::RecordQuery(mut rq)), strategy) => {
-                                // Inject required information into the query and execute
-                                rq.record_finder = Some(query_result.result.to_record_finder(model)?);
-
-                                let dependent_pair = (Query::Read(ReadQuery::RecordQuery(rq)), strategy);
-                                self.execute_query(dependent_pair)
-                            }
-                            _ => unreachable!(), // Invariant for now
-                        },
-                        None => Err(CoreError::ConversionError(
-                            "Model required for dependent query execution".into(),
-                        )),
-                    },
+        // Writes of the graph are executed one by one and need to share a transaction to be rolled back together.
+        // Executors bound to an interactive transaction leave that to the owner of the transaction.
+        if self.transactions.is_some() && graph.write_count() > 1 {
+            let (executor, handle) = self.start_transaction()?;
+
+            match executor.interpreter().interpret(graph) {
+                Ok(result) => {
+                    handle.commit()?;
+                    Ok(result)
+                }
+                Err(err) => {
+                    // The original error is what the client needs to see, a failed rollback is only logged.
+                    if let Err(rollback_err) = handle.rollback() {
+                        log::warn!("Rolling back the transaction failed: {}", rollback_err);
+                    }
+
+                    Err(err)
                 }
             }
+        } else {
+            self.interpreter().interpret(graph)
+        }
+    }
+
+    fn interpreter(&self) -> QueryInterpreter<'_> {
+        QueryInterpreter {
+            read_executor: &self.read_executor,
+            write_executor: &self.write_executor,
         }
     }
 
+    /// Starts an interactive transaction. All queries run with the returned executor are executed
+    /// within the transaction, which is committed or rolled back with the returned handle.
+    pub fn start_transaction(&self) -> CoreResult<(QueryExecutor, Box<dyn TransactionHandle + Send + Sync + 'static>)> {
+        let transactions = self.transactions.as_ref().ok_or_else(|| {
+            CoreError::UnsupportedFeatureError("Starting a transaction within a transaction is not supported.".into())
+        })?;
+
+        let transaction = transactions.start_transaction(self.db_name())?;
+
+        let read_executor = ReadQueryExecutor {
+            data_resolver: transaction.reader,
+        };
+
+        let write_executor = WriteQueryExecutor {
+            db_name: self.db_name(),
+            write_executor: transaction.writer,
+        };
+
+        let executor = QueryExecutor::new(self.primary_connector, read_executor, write_executor, None);
+
+        Ok((executor, transaction.handle))
+    }
+
     /// Returns db name used in the executor.
     pub fn db_name(&self) -> String {
         self.write_executor.db_name.clone()
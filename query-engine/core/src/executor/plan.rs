@@ -0,0 +1,94 @@
+use crate::{OutputTypeRef, QueryPair, ResultResolutionStrategy};
+use connector::{Query, ReadQuery, RecordQuery};
+use std::collections::HashMap;
+
+/// Execution plan for the queries of a query document.
+/// Every step holds the position of its queries in the document, so results can be returned in document order.
+pub struct QueryPlan {
+    pub steps: Vec<PlanStep>,
+
+    /// Total number of queries planned.
+    pub query_count: usize,
+}
+
+pub enum PlanStep {
+    /// A query executed on its own.
+    Single(usize, QueryPair),
+
+    /// `findOne` reads on the same model by the same field, resolved with a single `IN (...)` read.
+    FindOneBatch(Vec<(usize, RecordQuery, OutputTypeRef)>),
+}
+
+impl QueryPlan {
+    /// Merges compatible `findOne` reads into batches, all other queries are planned as-is.
+    /// Reads are never batched across writes, to keep the read-after-write order of the document.
+    pub fn new(queries: Vec<QueryPair>) -> Self {
+        let query_count = queries.len();
+        let mut steps: Vec<PlanStep> = vec![];
+        let mut batches: HashMap<(String, String), usize> = HashMap::new();
+
+        for (index, pair) in queries.into_iter().enumerate() {
+            match pair {
+                (Query::Read(ReadQuery::RecordQuery(query)), ResultResolutionStrategy::Serialize(typ)) => {
+                    let key = query
+                        .record_finder
+                        .as_ref()
+                        .and_then(|finder| finder.single())
+                        .map(|(field, _)| (field.model().name.clone(), field.name.clone()));
+
+                    match key {
+                        Some(key) => {
+                            let step_index = *batches.entry(key).or_insert_with(|| {
+                                steps.push(PlanStep::FindOneBatch(vec![]));
+                                steps.len() - 1
+                            });
+
+                            if let PlanStep::FindOneBatch(ref mut batch) = steps[step_index] {
+                                batch.push((index, query, typ));
+                            }
+                        }
+                        None => steps.push(PlanStep::Single(
+                            index,
+                            (
+                                Query::Read(ReadQuery::RecordQuery(query)),
+                                ResultResolutionStrategy::Serialize(typ),
+                            ),
+                        )),
+                    }
+                }
+                pair => {
+                    if let Query::Write(_) = pair.0 {
+                        batches.clear();
+                    }
+
+                    steps.push(PlanStep::Single(index, pair));
+                }
+            }
+        }
+
+        // Batches of a single read don't gain anything over executing the read on its own.
+        let steps = steps
+            .into_iter()
+            .map(|step| match step {
+                PlanStep::FindOneBatch(mut batch) => {
+                    if batch.len() == 1 {
+                        let (index, query, typ) = batch.pop().unwrap();
+
+                        PlanStep::Single(
+                            index,
+                            (
+                                Query::Read(ReadQuery::RecordQuery(query)),
+                                ResultResolutionStrategy::Serialize(typ),
+                            ),
+                        )
+                    } else {
+                        PlanStep::FindOneBatch(batch)
+                    }
+                }
+                step => step,
+            })
+            .collect();
+
+        QueryPlan { steps, query_count }
+    }
+}
@@ -1,7 +1,9 @@
-use crate::CoreResult;
-use connector::{self, query_ast::*, result_ast::*, ManagedDatabaseReader, ScalarListValues, QueryArguments};
-use prisma_models::{GraphqlId, ScalarField, SelectedFields};
-use std::sync::Arc;
+use crate::{CoreError, CoreResult};
+use connector::{
+    self, query_ast::*, result_ast::*, ManagedDatabaseReader, QueryArguments, ScalarCompare, ScalarListValues,
+};
+use prisma_models::{GraphqlId, PrismaValue, ScalarField, SelectedField, SelectedFields, SingleRecord};
+use std::{convert::TryFrom, sync::Arc};
 
 pub struct ReadQueryExecutor {
     pub data_resolver: Arc<dyn ManagedDatabaseReader + Send + Sync + 'static>,
@@ -24,12 +26,104 @@ impl ReadQueryExecutor {
             .data_resolver
             .get_single_record(query.record_finder.as_ref().unwrap(), &selected_fields)?;
 
-        let model = Arc::clone(&query.record_finder.unwrap().field.model());
-        let id_field = model.fields().id().name.clone();
+        self.resolve_single_record(query, scalars, &selected_fields)
+    }
+
+    /// Queries a batch of single records, all found by the same field of the same model,
+    /// with one read. Results are returned in the order of the given queries.
+    ///
+    /// Records are matched to the queries by comparing the values in memory. The database may consider
+    /// values equal that aren't equal in memory though, e.g. strings under a case-insensitive collation.
+    /// Only if the read returned records that match none of the queries exactly, the queries without an
+    /// exact match are executed on their own. Otherwise these queries have no record.
+    pub fn read_one_batch(&self, queries: Vec<RecordQuery>) -> CoreResult<Vec<ReadQueryResult>> {
+        // The plan only batches queries selecting by the same single field, see `QueryPlan::new`.
+        let values = queries
+            .iter()
+            .map(|query| {
+                query
+                    .record_finder
+                    .as_ref()
+                    .and_then(|finder| finder.single())
+                    .map(|(_, value)| value.clone())
+                    .ok_or_else(|| {
+                        CoreError::ConversionError(format!(
+                            "Query '{}' doesn't select a record by a single field and can't be batched.",
+                            query.name
+                        ))
+                    })
+            })
+            .collect::<CoreResult<Vec<PrismaValue>>>()?;
+
+        let finder_field = match queries
+            .first()
+            .and_then(|q| q.record_finder.as_ref())
+            .and_then(|f| f.single())
+        {
+            Some((field, _)) => Arc::clone(field),
+            None => return Ok(vec![]),
+        };
+        let model = finder_field.model();
+
+        let mut selected_fields = Self::merge_selected_fields(queries.iter().map(|q| &q.selected_fields));
+        if selected_fields.scalar.iter().all(|f| f.field.name != finder_field.name) {
+            selected_fields.add_scalar(Arc::clone(&finder_field));
+        }
+
+        let selected_fields = Self::inject_required_fields(selected_fields);
+        let records = self.data_resolver.get_many_records(
+            model,
+            QueryArguments::from(finder_field.is_in(Some(values.clone()))),
+            &selected_fields,
+        )?;
+
+        let matches: Vec<Option<usize>> = values
+            .iter()
+            .map(|value| {
+                records.records.iter().position(|record| {
+                    record
+                        .get_field_value(&records.field_names, &finder_field.name)
+                        .map(|v| Self::values_match(v, value))
+                        .unwrap_or(false)
+                })
+            })
+            .collect();
+
+        let has_unmatched_records =
+            (0..records.records.len()).any(|position| !matches.iter().any(|m| *m == Some(position)));
+
+        queries
+            .into_iter()
+            .zip(matches)
+            .map(|(query, position)| match position {
+                Some(position) => {
+                    let record = SingleRecord::new(records.records[position].clone(), records.field_names.clone());
+                    let query_fields = Self::inject_required_fields(query.selected_fields.clone());
+
+                    self.resolve_single_record(query, Some(record), &query_fields)
+                }
+                None if has_unmatched_records => self.read_one(query),
+                None => {
+                    let query_fields = Self::inject_required_fields(query.selected_fields.clone());
+                    self.resolve_single_record(query, None, &query_fields)
+                }
+            })
+            .collect()
+    }
+
+    /// Resolves scalar lists and nested queries of a single record query and builds its result.
+    fn resolve_single_record(
+        &self,
+        query: RecordQuery,
+        scalars: Option<SingleRecord>,
+        selected_fields: &SelectedFields,
+    ) -> CoreResult<ReadQueryResult> {
+        let model = query.record_finder.as_ref().unwrap().model();
+        let id_fields = model.id_field_names();
 
         match scalars {
             Some(record) => {
-                let ids = vec![record.collect_id(&id_field)?];
+                let ids = vec![record.collect_id(&id_fields)?];
                 let list_fields = selected_fields.scalar_lists();
                 let lists = self.resolve_scalar_list_fields(ids.clone(), list_fields)?;
                 let nested: Vec<ReadQueryResult> = query
@@ -46,7 +140,7 @@ impl ReadQueryExecutor {
                         scalars: record.into(),
                         nested,
                         lists,
-                        id_field,
+                        id_fields,
                         ..Default::default()
                     }),
                 })
@@ -56,7 +150,7 @@ impl ReadQueryExecutor {
                 alias: query.alias,
                 content: ResultContent::RecordSelection(RecordSelection {
                     fields: query.selection_order,
-                    id_field,
+                    id_fields,
                     ..Default::default()
                 }),
             }),
@@ -71,8 +165,8 @@ impl ReadQueryExecutor {
                 .get_many_records(Arc::clone(&query.model), query.args.clone(), &selected_fields)?;
 
         let model = Arc::clone(&query.model);
-        let id_field = model.fields().id().name.clone();
-        let ids = scalars.collect_ids(&id_field)?;
+        let id_fields = model.id_field_names();
+        let ids = scalars.collect_ids(&id_fields)?;
         let list_fields = selected_fields.scalar_lists();
         let lists = self.resolve_scalar_list_fields(ids.clone(), list_fields)?;
         let nested: Vec<ReadQueryResult> = query
@@ -90,7 +184,7 @@ impl ReadQueryExecutor {
                 scalars,
                 nested,
                 lists,
-                id_field,
+                id_fields,
             }),
         })
     }
@@ -106,8 +200,8 @@ impl ReadQueryExecutor {
         )?;
 
         let model = Arc::clone(&query.parent_field.related_model());
-        let id_field = model.fields().id().name.clone();
-        let ids = scalars.collect_ids(&id_field)?;
+        let id_fields = model.id_field_names();
+        let ids = scalars.collect_ids(&id_fields)?;
         let list_fields = selected_fields.scalar_lists();
         let lists = self.resolve_scalar_list_fields(ids.clone(), list_fields)?;
         let nested: Vec<ReadQueryResult> = query
@@ -125,18 +219,28 @@ impl ReadQueryExecutor {
                 scalars,
                 nested,
                 lists,
-                id_field,
+                id_fields,
             }),
         })
     }
 
+    /// Aggregates a set of records, optionally grouped by a set of fields.
     pub fn aggregate(&self, query: AggregateRecordsQuery) -> CoreResult<ReadQueryResult> {
-        let result = self.data_resolver.count_by_model(query.model, QueryArguments::default())?;
+        let rows = self.data_resolver.aggregate_records(
+            query.model,
+            query.args,
+            &query.selectors,
+            &query.group_by,
+            query.having,
+        )?;
 
         Ok(ReadQueryResult {
             name: query.name,
             alias: query.alias,
-            content: ResultContent::Count(result),
+            content: ResultContent::Aggregation(AggregationRecords {
+                rows,
+                is_grouped: query.is_grouped,
+            }),
         })
     }
 
@@ -161,19 +265,63 @@ impl ReadQueryExecutor {
         }
     }
 
+    /// Merges the selections of several queries on the same model into one selection
+    /// containing every field selected by any of the queries.
+    fn merge_selected_fields<'a, I>(selections: I) -> SelectedFields
+    where
+        I: Iterator<Item = &'a SelectedFields>,
+    {
+        let mut fields: Vec<SelectedField> = vec![];
+
+        for selection in selections {
+            for sf in selection.scalar.iter() {
+                let is_present = fields.iter().any(|f| match f {
+                    SelectedField::Scalar(existing) => existing.field.name == sf.field.name,
+                    _ => false,
+                });
+
+                if !is_present {
+                    fields.push(SelectedField::Scalar(sf.clone()));
+                }
+            }
+
+            for rf in selection.relation.iter() {
+                let is_present = fields.iter().any(|f| match f {
+                    SelectedField::Relation(existing) => existing.field.name == rf.field.name,
+                    _ => false,
+                });
+
+                if !is_present {
+                    fields.push(SelectedField::Relation(rf.clone()));
+                }
+            }
+        }
+
+        SelectedFields::new(fields, None)
+    }
+
+    /// Values read from the database don't necessarily have the same representation as the values
+    /// given in the query, e.g. ids are read as `GraphqlId`, but may be given as plain strings.
+    fn values_match(a: &PrismaValue, b: &PrismaValue) -> bool {
+        match (GraphqlId::try_from(a), GraphqlId::try_from(b)) {
+            (Ok(a), Ok(b)) => a == b,
+            _ => a == b,
+        }
+    }
+
     /// Injects fields required for querying, if they're not already in the selection set.
     /// Currently, required fields for every query are:
-    /// - ID field
+    /// - ID field(s)
     fn inject_required_fields(mut selected_fields: SelectedFields) -> SelectedFields {
-        let id_field = selected_fields.model().fields().id();
-
-        if selected_fields
-            .scalar
-            .iter()
-            .find(|f| f.field.name == id_field.name)
-            .is_none()
-        {
-            selected_fields.add_scalar(id_field);
+        for id_field in selected_fields.model().fields().id_fields() {
+            if selected_fields
+                .scalar
+                .iter()
+                .find(|f| f.field.name == id_field.name)
+                .is_none()
+            {
+                selected_fields.add_scalar(id_field);
+            }
         }
 
         selected_fields
@@ -1,5 +1,6 @@
 use crate::{CoreError, CoreResult, WriteQueryResultWrapper};
-use connector::{UnmanagedDatabaseWriter, WriteQuery};
+use connector::{NestedWriteQuery, UnmanagedDatabaseWriter, WriteQuery, WriteQueryResult};
+use prisma_models::GraphqlId;
 use std::sync::Arc;
 
 /// A small wrapper around running WriteQueries
@@ -22,4 +23,11 @@ impl WriteQueryExecutor {
             )),
         }
     }
+
+    /// Executes a single nested write for the parent record with the given id.
+    pub fn execute_nested(&self, write_query: NestedWriteQuery, parent_id: GraphqlId) -> CoreResult<WriteQueryResult> {
+        self.write_executor
+            .execute_nested(self.db_name.clone(), write_query, parent_id)
+            .map_err(|err| err.into())
+    }
 }
@@ -14,6 +14,7 @@ mod error;
 pub mod executor;
 pub mod query_builders;
 pub mod query_document;
+pub mod query_graph;
 pub mod response_ir;
 pub mod schema;
 
@@ -61,6 +61,31 @@ impl QueryValidationError {
         }
     }
 
+    /// The names of the objects, fields and arguments leading to the invalid input,
+    /// e.g. `["Query", "findOneUser", "where", "id"]`.
+    pub fn path(&self) -> Vec<String> {
+        let mut path = vec![];
+        let mut current = self;
+
+        loop {
+            match current {
+                QueryValidationError::ObjectValidationError {
+                    object_name: name,
+                    inner,
+                }
+                | QueryValidationError::FieldValidationError {
+                    field_name: name,
+                    inner,
+                }
+                | QueryValidationError::ArgumentValidationError { argument: name, inner } => {
+                    path.push(name.clone());
+                    current = &**inner;
+                }
+                _ => return path,
+            }
+        }
+    }
+
     fn ident(s: String, size: usize) -> String {
         format!("{}↳ {}", " ".repeat(size), s)
     }
@@ -1,11 +1,15 @@
-use crate::query_builders::{ParsedInputValue, QueryBuilderResult};
-use connector::{filter::Filter, RelationCompare, ScalarCompare};
-use prisma_models::{Field, ModelRef, PrismaListValue, PrismaValue};
+use crate::query_builders::{ParsedInputValue, QueryBuilderResult, QueryValidationError};
+use connector::{
+    filter::{AggregationFilter, Filter, QueryMode, ScalarCondition, ScalarFilter},
+    RelationCompare, ScalarCompare, ScalarListCompare,
+};
+use prisma_models::{Field, ModelRef, PrismaListValue, PrismaValue, TypeIdentifier};
 use std::{collections::BTreeMap, convert::TryFrom, convert::TryInto};
 
 lazy_static! {
     /// Filter operations in descending order of how they should be checked.
     static ref FILTER_OPERATIONS: Vec<FilterOp> = vec![
+        FilterOp::IsEmpty,
         FilterOp::NotIn,
         FilterOp::NotContains,
         FilterOp::NotStartsWith,
@@ -19,6 +23,8 @@ lazy_static! {
         FilterOp::Contains,
         FilterOp::StartsWith,
         FilterOp::EndsWith,
+        FilterOp::Search,
+        FilterOp::Mode,
         FilterOp::Some,
         FilterOp::None,
         FilterOp::Every,
@@ -44,6 +50,9 @@ enum FilterOp {
     NotStartsWith,
     EndsWith,
     NotEndsWith,
+    IsEmpty,
+    Search,
+    Mode,
     Some,
     None,
     Every,
@@ -64,6 +73,19 @@ impl FilterOp {
             .copied()
     }
 
+    /// Whether the operation matches a pattern, which is the only kind of filter a query mode applies to.
+    pub fn is_pattern(self) -> bool {
+        match self {
+            FilterOp::Contains
+            | FilterOp::NotContains
+            | FilterOp::StartsWith
+            | FilterOp::NotStartsWith
+            | FilterOp::EndsWith
+            | FilterOp::NotEndsWith => true,
+            _ => false,
+        }
+    }
+
     pub fn suffix(self) -> &'static str {
         match self {
             FilterOp::In => "_in",
@@ -79,6 +101,9 @@ impl FilterOp {
             FilterOp::NotStartsWith => "_not_starts_with",
             FilterOp::EndsWith => "_ends_with",
             FilterOp::NotEndsWith => "_not_ends_with",
+            FilterOp::IsEmpty => "_is_empty",
+            FilterOp::Search => "_search",
+            FilterOp::Mode => "_mode",
             FilterOp::Some => "_some",
             FilterOp::None => "_none",
             FilterOp::Every => "_every",
@@ -90,14 +115,26 @@ impl FilterOp {
     }
 }
 
-pub fn extract_filter(value_map: BTreeMap<String, ParsedInputValue>, model: &ModelRef) -> QueryBuilderResult<Filter> {
+pub fn extract_filter(
+    mut value_map: BTreeMap<String, ParsedInputValue>,
+    model: &ModelRef,
+) -> QueryBuilderResult<Filter> {
+    let modes = extract_modes(&mut value_map, model)?;
     let filters = value_map
         .into_iter()
         .map(|(key, value): (String, ParsedInputValue)| {
-            let op = FilterOp::find_op(key.as_str()).unwrap();
+            let (field, op) = match key.as_str() {
+                "AND" => (None, FilterOp::NestedAnd),
+                "OR" => (None, FilterOp::NestedOr),
+                "NOT" => (None, FilterOp::NestedNot),
+                _ => {
+                    let (field, op) = parse_filter_key(model, &key)?;
+                    (Some(field), op)
+                }
+            };
 
-            match op {
-                op if (op == FilterOp::NestedAnd || op == FilterOp::NestedOr || op == FilterOp::NestedNot) => {
+            match (op, field) {
+                (op, None) => {
                     let value: QueryBuilderResult<Vec<Filter>> = match value {
                         ParsedInputValue::List(values) => values
                             .into_iter()
@@ -105,7 +142,7 @@ pub fn extract_filter(value_map: BTreeMap<String, ParsedInputValue>, model: &Mod
                             .collect(),
 
                         ParsedInputValue::Map(map) => extract_filter(map, model).map(|res| vec![res]),
-                        _ => unreachable!(),
+                        _ => Err(invalid_filter(&key)),
                     };
 
                     value.map(|value| match op {
@@ -115,15 +152,33 @@ pub fn extract_filter(value_map: BTreeMap<String, ParsedInputValue>, model: &Mod
                         _ => unreachable!(),
                     })
                 }
-                op => {
-                    let op_name: &'static str = op.suffix();
-                    let field_name = key.trim_end_matches(op_name);
-                    let field = model.fields().find_from_all(&field_name).unwrap();
-
+                (op, Some(field)) => {
                     match field {
                         Field::Scalar(s) => {
                             let value: PrismaValue = value.try_into()?;
-                            Ok(match op {
+
+                            if s.is_list {
+                                let filter = match op {
+                                    FilterOp::IsEmpty if value == PrismaValue::Boolean(false) => {
+                                        Filter::not(vec![s.contains_none()])
+                                    }
+                                    FilterOp::IsEmpty => s.contains_none(),
+                                    _ => return Err(invalid_filter(&key)),
+                                };
+
+                                return Ok(filter);
+                            }
+
+                            let mode = modes.get(&s.name).cloned().unwrap_or_default();
+
+                            if mode == QueryMode::Insensitive && !op.is_pattern() {
+                                return Err(QueryValidationError::AssertionError(format!(
+                                    "Filter '{}' can't be used with query mode 'insensitive', which only applies to pattern filters.",
+                                    key
+                                )));
+                            }
+
+                            let filter = match op {
                                 FilterOp::In => s.is_in(PrismaListValue::try_from(value)?),
                                 FilterOp::NotIn => s.not_in(PrismaListValue::try_from(value)?),
                                 FilterOp::Not => s.not_equals(value),
@@ -137,29 +192,38 @@ pub fn extract_filter(value_map: BTreeMap<String, ParsedInputValue>, model: &Mod
                                 FilterOp::NotStartsWith => s.not_starts_with(value),
                                 FilterOp::EndsWith => s.ends_with(value),
                                 FilterOp::NotEndsWith => s.not_ends_with(value),
+                                FilterOp::Search if s.fulltext_index.is_none() => {
+                                    return Err(QueryValidationError::AssertionError(format!(
+                                        "Field '{}' can't be searched, as it isn't covered by a full-text index.",
+                                        s.name
+                                    )))
+                                }
+                                FilterOp::Search => s.search(value),
                                 FilterOp::Field => s.equals(value),
-                                _ => unreachable!(),
-                            })
+                                _ => return Err(invalid_filter(&key)),
+                            };
+
+                            Ok(with_mode(filter, mode))
                         }
                         Field::Relation(r) => {
                             let value: Option<BTreeMap<String, ParsedInputValue>> = value.try_into()?;
 
-                            Ok(match (op, value) {
+                            match (op, value) {
                                 (FilterOp::Some, Some(value)) => {
-                                    r.at_least_one_related(extract_filter(value, &r.related_model())?)
+                                    Ok(r.at_least_one_related(extract_filter(value, &r.related_model())?))
                                 }
                                 (FilterOp::None, Some(value)) => {
-                                    r.no_related(extract_filter(value, &r.related_model())?)
+                                    Ok(r.no_related(extract_filter(value, &r.related_model())?))
                                 }
                                 (FilterOp::Every, Some(value)) => {
-                                    r.every_related(extract_filter(value, &r.related_model())?)
+                                    Ok(r.every_related(extract_filter(value, &r.related_model())?))
                                 }
                                 (FilterOp::Field, Some(value)) => {
-                                    r.to_one_related(extract_filter(value, &r.related_model())?)
+                                    Ok(r.to_one_related(extract_filter(value, &r.related_model())?))
                                 }
-                                (FilterOp::Field, None) => r.one_relation_is_null(),
-                                _ => unreachable!(),
-                            })
+                                (FilterOp::Field, None) => Ok(r.one_relation_is_null()),
+                                _ => Err(invalid_filter(&key)),
+                            }
                         }
                     }
                 }
@@ -169,3 +233,174 @@ pub fn extract_filter(value_map: BTreeMap<String, ParsedInputValue>, model: &Mod
 
     Ok(Filter::and(filters))
 }
+
+/// Splits a filter key into the filtered field and the operation. Keys naming a field are equality filters
+/// on that field. Otherwise the operation is the longest suffix following the name of a field, e.g.
+/// `is_not_contains` on a field called `is_not` is a `_contains` filter.
+fn parse_filter_key<'a>(model: &'a ModelRef, key: &str) -> QueryBuilderResult<(&'a Field, FilterOp)> {
+    if let Ok(field) = model.fields().find_from_all(key) {
+        return Ok((field, FilterOp::Field));
+    }
+
+    FILTER_OPERATIONS
+        .iter()
+        .filter(|op| key.ends_with(op.suffix()))
+        .filter_map(|op| {
+            let field = model
+                .fields()
+                .find_from_all(&key[..key.len() - op.suffix().len()])
+                .ok()?;
+            Some((field, *op))
+        })
+        .max_by_key(|(_, op)| op.suffix().len())
+        .ok_or_else(|| invalid_filter(key))
+}
+
+fn invalid_filter(key: &str) -> QueryValidationError {
+    QueryValidationError::AssertionError(format!("Invalid filter '{}'", key))
+}
+
+/// Removes the `<field>_mode` entries from the filter map and returns the query mode per field name.
+/// Only string fields have a query mode, modes for other fields, including relation fields, are rejected.
+fn extract_modes(
+    value_map: &mut BTreeMap<String, ParsedInputValue>,
+    model: &ModelRef,
+) -> QueryBuilderResult<BTreeMap<String, QueryMode>> {
+    let suffix = FilterOp::Mode.suffix();
+    let mode_keys: Vec<(String, &Field)> = value_map
+        .keys()
+        .filter(|key| key.ends_with(suffix) && model.fields().find_from_all(key).is_err())
+        .filter_map(|key| {
+            let field = model.fields().find_from_all(&key[..key.len() - suffix.len()]).ok()?;
+            Some((key.clone(), field))
+        })
+        .collect();
+
+    let mut modes = BTreeMap::new();
+
+    for (key, field) in mode_keys {
+        let has_mode = match field {
+            Field::Scalar(s) => match s.type_identifier {
+                TypeIdentifier::String | TypeIdentifier::UUID | TypeIdentifier::GraphQLID => !s.is_list,
+                _ => false,
+            },
+            Field::Relation(_) => false,
+        };
+
+        if !has_mode {
+            return Err(QueryValidationError::AssertionError(format!(
+                "Invalid filter '{}', a query mode can only be set for String fields.",
+                key
+            )));
+        }
+
+        let value: PrismaValue = value_map.remove(&key).unwrap().try_into()?;
+        let mode = match value {
+            PrismaValue::Null => QueryMode::Default,
+            PrismaValue::Enum(ref e) if e.as_string() == "default" => QueryMode::Default,
+            PrismaValue::Enum(ref e) if e.as_string() == "insensitive" => QueryMode::Insensitive,
+            _ => {
+                return Err(QueryValidationError::AssertionError(format!(
+                    "Invalid query mode '{}' for filter '{}'",
+                    value, key
+                )))
+            }
+        };
+
+        modes.insert(field.name().to_owned(), mode);
+    }
+
+    Ok(modes)
+}
+
+fn with_mode(filter: Filter, mode: QueryMode) -> Filter {
+    match filter {
+        Filter::Scalar(scalar_filter) => Filter::Scalar(ScalarFilter { mode, ..scalar_filter }),
+        filter => filter,
+    }
+}
+
+/// Extracts a filter on aggregated values of groups (the "having" argument of group by queries).
+/// Keys are of the shape `_count<op>` or `_<function>_<field><op>`, e.g. `_sum_age_gt`.
+pub fn extract_having_filter(
+    value_map: BTreeMap<String, ParsedInputValue>,
+    model: &ModelRef,
+) -> QueryBuilderResult<AggregationFilter> {
+    let filters = value_map
+        .into_iter()
+        .map(|(key, value): (String, ParsedInputValue)| {
+            let op = FilterOp::find_op(key.as_str()).ok_or_else(|| invalid_aggregation_filter(&key))?;
+
+            match op {
+                op if (op == FilterOp::NestedAnd || op == FilterOp::NestedOr || op == FilterOp::NestedNot) => {
+                    let value: QueryBuilderResult<Vec<AggregationFilter>> = match value {
+                        ParsedInputValue::List(values) => values
+                            .into_iter()
+                            .map(|val| extract_having_filter(val.try_into()?, model))
+                            .collect(),
+
+                        ParsedInputValue::Map(map) => extract_having_filter(map, model).map(|res| vec![res]),
+                        _ => Err(invalid_aggregation_filter(&key)),
+                    };
+
+                    value.map(|value| match op {
+                        FilterOp::NestedAnd => AggregationFilter::and(value),
+                        FilterOp::NestedOr => AggregationFilter::or(value),
+                        FilterOp::NestedNot => AggregationFilter::not(value),
+                        _ => unreachable!(),
+                    })
+                }
+                op => {
+                    let aggregation = key.trim_end_matches(op.suffix());
+                    let condition = aggregation_condition(op, value.try_into()?)?;
+
+                    if aggregation == "_count" {
+                        return Ok(AggregationFilter::Count(condition));
+                    }
+
+                    // All other aggregations are of the shape `_<function>_<field>`.
+                    let mut parts = aggregation.splitn(3, '_');
+                    let (function, field_name) = match (parts.next(), parts.next(), parts.next()) {
+                        (Some(""), Some(function), Some(field_name)) => (function, field_name),
+                        _ => return Err(invalid_aggregation_filter(&key)),
+                    };
+
+                    let field = model.fields().find_from_scalar(field_name)?;
+
+                    match function {
+                        "sum" => Ok(AggregationFilter::Sum(field, condition)),
+                        "avg" => Ok(AggregationFilter::Average(field, condition)),
+                        "min" => Ok(AggregationFilter::Min(field, condition)),
+                        "max" => Ok(AggregationFilter::Max(field, condition)),
+                        _ => Err(invalid_aggregation_filter(&key)),
+                    }
+                }
+            }
+        })
+        .collect::<QueryBuilderResult<Vec<AggregationFilter>>>()?;
+
+    Ok(AggregationFilter::and(filters))
+}
+
+fn invalid_aggregation_filter(key: &str) -> QueryValidationError {
+    QueryValidationError::AssertionError(format!("Invalid aggregation filter '{}'", key))
+}
+
+fn aggregation_condition(op: FilterOp, value: PrismaValue) -> QueryBuilderResult<ScalarCondition> {
+    Ok(match op {
+        FilterOp::In => ScalarCondition::In(PrismaListValue::try_from(value)?),
+        FilterOp::NotIn => ScalarCondition::NotIn(PrismaListValue::try_from(value)?),
+        FilterOp::Not => ScalarCondition::NotEquals(value),
+        FilterOp::Lt => ScalarCondition::LessThan(value),
+        FilterOp::Lte => ScalarCondition::LessThanOrEquals(value),
+        FilterOp::Gt => ScalarCondition::GreaterThan(value),
+        FilterOp::Gte => ScalarCondition::GreaterThanOrEquals(value),
+        FilterOp::Field => ScalarCondition::Equals(value),
+        op => {
+            return Err(QueryValidationError::AssertionError(format!(
+                "Filter operation '{}' is not supported on aggregations",
+                op.suffix()
+            )))
+        }
+    })
+}
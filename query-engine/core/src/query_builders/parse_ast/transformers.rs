@@ -124,6 +124,30 @@ impl TryInto<Option<OrderBy>> for ParsedInputValue {
     }
 }
 
+impl TryInto<Vec<OrderBy>> for ParsedInputValue {
+    type Error = QueryValidationError;
+
+    fn try_into(self) -> QueryBuilderResult<Vec<OrderBy>> {
+        match self {
+            ParsedInputValue::List(values) => values
+                .into_iter()
+                .map(|value| {
+                    let order_by: Option<OrderBy> = value.try_into()?;
+
+                    order_by.ok_or_else(|| {
+                        QueryValidationError::AssertionError("Order by values in a list must not be null.".into())
+                    })
+                })
+                .collect(),
+
+            value => {
+                let order_by: Option<OrderBy> = value.try_into()?;
+                Ok(order_by.into_iter().collect())
+            }
+        }
+    }
+}
+
 impl TryInto<Option<f64>> for ParsedInputValue {
     type Error = QueryValidationError;
 
@@ -4,6 +4,7 @@ use crate::{
     ModelOperation, ObjectTypeStrongRef, OperationTag, OutputTypeRef, QueryPair, QuerySchemaRef,
     ResultResolutionStrategy, ScalarType,
 };
+use bigdecimal::BigDecimal;
 use chrono::prelude::*;
 use connector::Query;
 use prisma_models::{GraphqlId, ModelRef, PrismaValue};
@@ -92,6 +93,9 @@ impl QueryBuilder {
                 parsed_field,
                 Arc::clone(&operation.model),
             )),
+            OperationTag::GroupBy(_) => ReadQueryBuilder::AggregateRecordsBuilder(
+                AggregateRecordsBuilder::new_grouped(parsed_field, Arc::clone(&operation.model)),
+            ),
             _ => unreachable!(),
         };
 
@@ -139,6 +143,23 @@ impl QueryBuilder {
                         (write_query, result_strategy)
                     }
 
+                    OperationTag::CreateMany(ref result_strategy) => {
+                        let result_strategy = self.resolve_result_strategy(
+                            &parsed_field,
+                            &field.field_type,
+                            result_strategy,
+                            &field_operation.model,
+                        )?;
+
+                        let write_query = WriteQueryBuilder::CreateManyBuilder(CreateManyBuilder::new(
+                            parsed_field,
+                            Arc::clone(&field_operation.model),
+                        ))
+                        .build()?;
+
+                        (write_query, result_strategy)
+                    }
+
                     OperationTag::UpdateOne(ref result_strategy) => {
                         let result_strategy = self.resolve_result_strategy(
                             &parsed_field,
@@ -415,6 +436,11 @@ impl QueryBuilder {
             // The optional handling above guarantees that if we hit a Null here, a required value is missing.
             (QueryValue::Null, _)                           => Err(QueryValidationError::RequiredValueNotSetError),
 
+            // Strings of variables are enum values where an enum is expected, and plain strings otherwise.
+            (QueryValue::VariableString(s), InputType::Enum(_))
+            | (QueryValue::VariableString(s), InputType::Scalar(ScalarType::Enum(_))) => self.parse_input_value(QueryValue::Enum(s.clone()), input_type),
+            (QueryValue::VariableString(s), InputType::Scalar(_)) => self.parse_input_value(QueryValue::String(s.clone()), input_type),
+
             // Scalar and enum handling.
             (_, InputType::Scalar(scalar))                  => self.parse_scalar(value, &scalar).map(ParsedInputValue::Single),
             (QueryValue::Enum(_), InputType::Enum(et))      => self.parse_scalar(value, &ScalarType::Enum(Arc::clone(et))).map(ParsedInputValue::Single), // todo
@@ -436,6 +462,10 @@ impl QueryBuilder {
             (QueryValue::String(s), ScalarType::DateTime) => Self::parse_datetime(s.as_str()).map(PrismaValue::DateTime),
             (QueryValue::String(s), ScalarType::Json)     => Self::parse_json(s.as_str()).map(PrismaValue::Json),
             (QueryValue::String(s), ScalarType::UUID)     => Self::parse_uuid(s.as_str()).map(PrismaValue::Uuid),
+            (QueryValue::String(s), ScalarType::Decimal)  => Self::parse_decimal(s.as_str()).map(PrismaValue::Decimal),
+            (QueryValue::String(s), ScalarType::Bytes)    => Self::parse_bytes(s.as_str()).map(PrismaValue::Bytes),
+            (QueryValue::Int(i), ScalarType::Decimal)     => Ok(PrismaValue::Decimal(BigDecimal::from(i))),
+            (QueryValue::Float(f), ScalarType::Decimal)   => Self::parse_decimal(&f.to_string()).map(PrismaValue::Decimal),
             (QueryValue::Int(i), ScalarType::Float)       => Ok(PrismaValue::Float(i as f64)),
             (QueryValue::Int(i), ScalarType::Int)         => Ok(PrismaValue::Int(i)),
             (QueryValue::Float(f), ScalarType::Float)     => Ok(PrismaValue::Float(f)),
@@ -475,6 +505,16 @@ impl QueryBuilder {
         Uuid::parse_str(s).map_err(|err| QueryValidationError::ValueParseError(format!("Invalid UUID: {}", err)))
     }
 
+    /// Decimals are given as strings to keep their precision, floats are parsed from their shortest representation.
+    fn parse_decimal(s: &str) -> QueryBuilderResult<BigDecimal> {
+        s.parse()
+            .map_err(|err| QueryValidationError::ValueParseError(format!("Invalid Decimal: {}", err)))
+    }
+
+    fn parse_bytes(s: &str) -> QueryBuilderResult<Vec<u8>> {
+        base64::decode(s).map_err(|err| QueryValidationError::ValueParseError(format!("Invalid base64 Bytes: {}", err)))
+    }
+
     fn parse_list(&self, values: Vec<QueryValue>, value_type: &InputType) -> QueryBuilderResult<Vec<ParsedInputValue>> {
         values
             .into_iter()
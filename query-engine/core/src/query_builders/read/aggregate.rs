@@ -1,28 +1,151 @@
-use crate::query_builders::{Builder, ParsedField, QueryBuilderResult};
-use connector::read_ast::{AggregateRecordsQuery, ReadQuery};
-use prisma_models::ModelRef;
+use crate::query_builders::{
+    extract_having_filter, utils, ArgumentListLookup, Builder, ParsedField, ParsedInputMap, ParsedInputValue,
+    QueryBuilderResult, QueryValidationError,
+};
+use connector::read_ast::{AggregateRecordsQuery, AggregationSelection, ReadQuery};
+use prisma_models::{ModelRef, PrismaValue, ScalarFieldRef};
+use std::convert::TryInto;
 
 pub struct AggregateRecordsBuilder {
     field: ParsedField,
     model: ModelRef,
+    is_grouped: bool,
 }
 
 impl AggregateRecordsBuilder {
     pub fn new(field: ParsedField, model: ModelRef) -> Self {
-        Self { field, model }
+        Self {
+            field,
+            model,
+            is_grouped: false,
+        }
+    }
+
+    /// Builder for aggregations that result in one row per group (`groupBy`).
+    pub fn new_grouped(field: ParsedField, model: ModelRef) -> Self {
+        Self {
+            field,
+            model,
+            is_grouped: true,
+        }
+    }
+
+    /// Aggregation functions of grouped queries are prefixed with an underscore.
+    fn function_name<'a>(&self, name: &'a str) -> Option<&'a str> {
+        match (self.is_grouped, name.starts_with('_')) {
+            (true, true) => Some(&name[1..]),
+            (true, false) => None,
+            (false, _) => Some(name),
+        }
+    }
+
+    fn collect_group_by(&self, value: ParsedInputValue) -> QueryBuilderResult<Vec<ScalarFieldRef>> {
+        let values: Vec<ParsedInputValue> = value.try_into()?;
+
+        values
+            .into_iter()
+            .map(|value| {
+                let value: PrismaValue = value.try_into()?;
+                let name = match value {
+                    PrismaValue::Enum(ev) => ev.name,
+                    PrismaValue::String(s) => s,
+                    v => {
+                        return Err(QueryValidationError::AssertionError(format!(
+                            "Expected a field name to group by, got: {:?}",
+                            v
+                        )))
+                    }
+                };
+
+                self.model.fields().find_from_scalar(&name).map_err(|err| err.into())
+            })
+            .collect()
+    }
+
+    fn collect_selectors(
+        &self,
+        sub_selections: Vec<ParsedField>,
+        group_by: &[ScalarFieldRef],
+    ) -> QueryBuilderResult<Vec<AggregationSelection>> {
+        sub_selections
+            .into_iter()
+            .map(|selection| {
+                let fields = || -> QueryBuilderResult<Vec<ScalarFieldRef>> {
+                    selection
+                        .sub_selections
+                        .clone()
+                        .map(|obj| obj.fields)
+                        .unwrap_or_else(Vec::new)
+                        .into_iter()
+                        .map(|f| self.model.fields().find_from_scalar(&f.name).map_err(|err| err.into()))
+                        .collect()
+                };
+
+                match self.function_name(&selection.name) {
+                    Some("count") => Ok(AggregationSelection::Count),
+                    Some("sum") => Ok(AggregationSelection::Sum(fields()?)),
+                    Some("avg") => Ok(AggregationSelection::Average(fields()?)),
+                    Some("min") => Ok(AggregationSelection::Min(fields()?)),
+                    Some("max") => Ok(AggregationSelection::Max(fields()?)),
+                    _ => {
+                        let field = self.model.fields().find_from_scalar(&selection.name)?;
+
+                        if group_by.iter().any(|f| f.name == field.name) {
+                            Ok(AggregationSelection::Field(field))
+                        } else {
+                            Err(QueryValidationError::AssertionError(format!(
+                                "Field '{}' can only be selected if it is part of the 'by' argument",
+                                field.name
+                            )))
+                        }
+                    }
+                }
+            })
+            .collect()
     }
 }
 
 impl Builder<ReadQuery> for AggregateRecordsBuilder {
     fn build(self) -> QueryBuilderResult<ReadQuery> {
-        let name = self.field.name;
-        let alias = self.field.alias;
-        let model = self.model;
+        let mut arguments = self.field.arguments.clone();
+
+        let group_by = match arguments.lookup("by") {
+            Some(arg) => self.collect_group_by(arg.value)?,
+            None => vec![],
+        };
+
+        let having = match arguments.lookup("having") {
+            Some(arg) => {
+                let value: Option<ParsedInputMap> = arg.value.try_into()?;
+
+                match value {
+                    Some(map) => Some(extract_having_filter(map, &self.model)?),
+                    None => None,
+                }
+            }
+            None => None,
+        };
+
+        let args = utils::extract_query_args(arguments, &self.model)?;
+
+        if self.is_grouped && args.first.is_some() && args.last.is_some() {
+            return Err(QueryValidationError::AssertionError(
+                "Groups can either be paginated with 'first' or with 'last', not both".to_owned(),
+            ));
+        }
+
+        let sub_selections = self.field.sub_selections.clone().unwrap().fields;
+        let selectors = self.collect_selectors(sub_selections, &group_by)?;
 
         Ok(ReadQuery::AggregateRecordsQuery(AggregateRecordsQuery {
-            name,
-            alias,
-            model,
+            name: self.field.name,
+            alias: self.field.alias,
+            model: self.model,
+            args,
+            selectors,
+            group_by,
+            having,
+            is_grouped: self.is_grouped,
         }))
     }
 }
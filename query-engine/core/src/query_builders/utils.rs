@@ -1,5 +1,8 @@
 use super::*;
-use crate::query_builders::{ParsedArgument, ParsedInputValue, QueryBuilderResult};
+use crate::{
+    query_builders::{ParsedArgument, ParsedInputValue, QueryBuilderResult},
+    schema::compound_field_name,
+};
 use connector::{filter::RecordFinder, QueryArguments};
 use prisma_models::{ModelRef, PrismaValue};
 use std::convert::TryInto;
@@ -21,21 +24,71 @@ pub fn extract_record_finder(parsed_value: ParsedInputValue, model: &ModelRef) -
         )))
     } else {
         let field_selector: (String, ParsedInputValue) = values.into_iter().next().unwrap();
-        let model_field = model.fields().find_from_scalar(&field_selector.0).unwrap();
 
-        match field_selector.1.try_into()? {
-            PrismaValue::Null => Err(QueryValidationError::AssertionError(format!(
-                "You provided a null value for the where clause on {}. Please provide a non null value.",
-                &model.name
-            ))),
-            x => Ok(RecordFinder {
-                field: model_field,
-                value: x,
-            }),
+        match model.fields().find_from_scalar(&field_selector.0) {
+            Ok(model_field) => Ok(RecordFinder::new(model_field, selector_value(field_selector.1, model)?)),
+            Err(_) => extract_compound_selector(&field_selector.0, field_selector.1, model),
         }
     }
 }
 
+/// Extracts a record finder from a compound selector, e.g. `email_tenantId: { email: "a", tenantId: 1 }`.
+fn extract_compound_selector(
+    name: &str,
+    parsed_value: ParsedInputValue,
+    model: &ModelRef,
+) -> QueryBuilderResult<RecordFinder> {
+    let mut values: ParsedInputMap = parsed_value.try_into()?;
+
+    let fields = model
+        .compound_unique_fields()
+        .into_iter()
+        .find(|fields| compound_field_name(fields) == name)
+        .ok_or_else(|| {
+            QueryValidationError::AssertionError(format!(
+                "'{}' is neither a unique field nor a compound unique selector of model {}.",
+                name, model.name
+            ))
+        })?;
+
+    let selectors = fields
+        .into_iter()
+        .map(|field| {
+            let value = values.remove(&field.name).ok_or_else(|| {
+                QueryValidationError::AssertionError(format!(
+                    "The compound selector '{}' requires a value for field '{}'.",
+                    name, field.name
+                ))
+            })?;
+
+            Ok((field, selector_value(value, model)?))
+        })
+        .collect::<QueryBuilderResult<Vec<_>>>()?;
+
+    Ok(RecordFinder::compound(selectors))
+}
+
+fn selector_value(parsed_value: ParsedInputValue, model: &ModelRef) -> QueryBuilderResult<PrismaValue> {
+    match parsed_value.try_into()? {
+        PrismaValue::Null => Err(QueryValidationError::AssertionError(format!(
+            "You provided a null value for the where clause on {}. Please provide a non null value.",
+            &model.name
+        ))),
+        x => Ok(x),
+    }
+}
+
+/// Extracts a cursor from the given parsed input. Cursors select a record by any of its unique fields,
+/// just like record finders.
+fn extract_cursor(parsed_value: ParsedInputValue, model: &ModelRef) -> QueryBuilderResult<Option<RecordFinder>> {
+    let value: Option<ParsedInputMap> = parsed_value.try_into()?;
+
+    match value {
+        Some(map) => Ok(Some(extract_record_finder(ParsedInputValue::Map(map), model)?)),
+        None => Ok(None),
+    }
+}
+
 /// Expects the caller to know that it is structurally guaranteed that query arguments can be extracted,
 /// e.g. that the query schema guarantees that required fields are present.
 /// Errors occur if conversions fail unexpectedly.
@@ -58,11 +111,11 @@ pub fn extract_query_args(arguments: Vec<ParsedArgument>, model: &ModelRef) -> Q
                         ..res
                     }),
                     "after" => Ok(QueryArguments {
-                        after: arg.value.try_into()?,
+                        after: extract_cursor(arg.value, model)?,
                         ..res
                     }),
                     "before" => Ok(QueryArguments {
-                        before: arg.value.try_into()?,
+                        before: extract_cursor(arg.value, model)?,
                         ..res
                     }),
                     "orderBy" => Ok(QueryArguments {
@@ -0,0 +1,52 @@
+use super::*;
+use crate::query_builders::{Builder, ParsedField, ParsedInputMap, ParsedInputValue, QueryBuilderResult};
+use connector::write_ast::*;
+use prisma_models::{ModelRef, PrismaArgs};
+use std::convert::TryInto;
+use std::sync::Arc;
+
+pub struct CreateManyBuilder {
+    field: ParsedField,
+    model: ModelRef,
+}
+
+impl CreateManyBuilder {
+    pub fn new(field: ParsedField, model: ModelRef) -> Self {
+        Self { field, model }
+    }
+}
+
+impl Builder<WriteQuery> for CreateManyBuilder {
+    fn build(mut self) -> QueryBuilderResult<WriteQuery> {
+        let data_argument = self.field.arguments.lookup("data").unwrap();
+        let data_list: Vec<ParsedInputValue> = data_argument.value.try_into()?;
+
+        let args = data_list
+            .into_iter()
+            .map(|value| {
+                let data_map: ParsedInputMap = value.try_into()?;
+                let create_args = WriteArguments::from(&self.model, data_map, true)?;
+                let mut non_list_args = create_args.non_list;
+
+                non_list_args.add_datetimes(Arc::clone(&self.model));
+                Ok(non_list_args)
+            })
+            .collect::<QueryBuilderResult<Vec<PrismaArgs>>>()?;
+
+        // Collecting the ids of the created records can be more expensive, so it's only done if they're selected.
+        let return_ids = self
+            .field
+            .sub_selections
+            .as_ref()
+            .map(|selections| selections.fields.iter().any(|f| f.name == "ids"))
+            .unwrap_or(false);
+
+        let create_many = RootWriteQuery::CreateManyRecords(CreateManyRecords {
+            model: self.model,
+            args,
+            return_ids,
+        });
+
+        Ok(WriteQuery::Root(self.field.name, self.field.alias, create_many))
+    }
+}
@@ -1,4 +1,5 @@
 mod create;
+mod create_many;
 mod delete;
 mod delete_many;
 mod nested;
@@ -8,6 +9,7 @@ mod upsert;
 mod write_arguments;
 
 pub use create::*;
+pub use create_many::*;
 pub use delete::*;
 pub use delete_many::*;
 pub use nested::*;
@@ -21,6 +23,7 @@ use connector::write_ast::WriteQuery;
 
 pub enum WriteQueryBuilder {
     CreateBuilder(CreateBuilder),
+    CreateManyBuilder(CreateManyBuilder),
     UpdateBuilder(UpdateBuilder),
     DeleteBuilder(DeleteBuilder),
     UpsertBuilder(UpsertBuilder),
@@ -32,6 +35,7 @@ impl Builder<WriteQuery> for WriteQueryBuilder {
     fn build(self) -> QueryBuilderResult<WriteQuery> {
         match self {
             WriteQueryBuilder::CreateBuilder(b) => b.build(),
+            WriteQueryBuilder::CreateManyBuilder(b) => b.build(),
             WriteQueryBuilder::UpdateBuilder(b) => b.build(),
             WriteQueryBuilder::DeleteBuilder(b) => b.build(),
             WriteQueryBuilder::UpsertBuilder(b) => b.build(),
@@ -23,6 +23,26 @@ impl WriteArguments {
             |mut args, (k, v): (String, ParsedInputValue)| {
                 let field = model.fields().find_from_all(&k).unwrap();
                 match field {
+                    // Lists stored in native arrays are written with the record itself.
+                    Field::Scalar(sf) if sf.is_embedded_list() => {
+                        let vals: ParsedInputMap = v.try_into()?;
+
+                        for (operation, value) in vals {
+                            let value: PrismaValue = value.try_into()?;
+                            let list_value: PrismaListValue = value.try_into()?;
+
+                            match operation.as_str() {
+                                "push" => args.non_list.push(sf.name.clone(), list_value.unwrap_or_default()),
+                                _ => match list_value {
+                                    Some(values) => {
+                                        args.non_list.insert(sf.name.clone(), PrismaValue::List(Some(values)))
+                                    }
+                                    None => args.non_list.insert(sf.name.clone(), PrismaValue::Null),
+                                },
+                            }
+                        }
+                    }
+
                     Field::Scalar(sf) if sf.is_list => {
                         let vals: ParsedInputMap = v.try_into()?;
                         let set_value: PrismaValue =
@@ -45,7 +45,7 @@ pub struct Selection {
     pub sub_selections: Vec<Selection>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum QueryValue {
     Int(i64),
     Float(f64),
@@ -53,6 +53,8 @@ pub enum QueryValue {
     Boolean(bool),
     Null,
     Enum(String),
+    /// A string of a JSON variable. JSON has no enum values, so where an enum is expected, it stands for one.
+    VariableString(String),
     List(Vec<QueryValue>),
     Object(BTreeMap<String, QueryValue>),
 }
@@ -0,0 +1,199 @@
+use super::{EdgeType, NodeRef, QueryGraph};
+use crate::{QueryPair, ResultResolutionStrategy};
+use connector::{read_ast::*, write_ast::*, Query, QueryArguments};
+use prisma_models::{ModelRef, SelectedFields};
+use std::{mem, sync::Arc};
+
+impl From<QueryPair> for QueryGraph {
+    fn from(pair: QueryPair) -> Self {
+        let mut graph = QueryGraph::new();
+        graph.add_query_pair(pair);
+        graph
+    }
+}
+
+impl QueryGraph {
+    /// Adds the query and, if the result of the query is resolved by another query, the dependent query.
+    /// Returns the nodes that may hold the result of the given query, see `add_query`.
+    fn add_query_pair(&mut self, pair: QueryPair) -> Vec<NodeRef> {
+        let (query, strategy) = pair;
+        let first_index = self.node_count();
+        let nodes = self.add_query(query);
+
+        match strategy {
+            ResultResolutionStrategy::Serialize(typ) => self.set_result_nodes(nodes.clone(), typ),
+            ResultResolutionStrategy::Dependent(dependent_pair) => {
+                let last_index = self.node_count();
+
+                for dependent in self.add_query_pair(*dependent_pair) {
+                    for node in nodes.iter() {
+                        self.create_edge(*node, dependent, EdgeType::RecordFinder);
+                    }
+
+                    // The dependent query must see the effects of the nested writes of the query as well.
+                    for index in first_index..last_index {
+                        if !nodes.contains(&NodeRef(index)) {
+                            self.create_edge(NodeRef(index), dependent, EdgeType::ExecutionOrder);
+                        }
+                    }
+                }
+            }
+        };
+
+        nodes
+    }
+
+    /// Adds a query to the graph. Nested writes of root creates and updates are split into nodes of their own.
+    /// Returns the nodes holding the result of the query: the node of the query itself, or for upserts
+    /// the create and the update branch, of which only one is executed.
+    fn add_query(&mut self, query: Query) -> Vec<NodeRef> {
+        match query {
+            Query::Write(WriteQuery::Root(name, alias, RootWriteQuery::UpsertRecord(upsert))) => {
+                let UpsertRecord { where_, create, update } = *upsert;
+                let model = Arc::clone(&create.model);
+
+                let check = self.create_node(Query::Read(ReadQuery::RecordQuery(RecordQuery {
+                    name: name.clone(),
+                    alias: alias.clone(),
+                    record_finder: Some(where_),
+                    selected_fields: Self::id_selection(&model),
+                    nested: vec![],
+                    selection_order: vec![],
+                })));
+
+                let create = RootWriteQuery::CreateRecord(Box::new(create));
+                let create_nodes = self.add_query(Query::Write(WriteQuery::Root(name.clone(), alias.clone(), create)));
+
+                let update = RootWriteQuery::UpdateRecord(Box::new(update));
+                let update_nodes = self.add_query(Query::Write(WriteQuery::Root(name, alias, update)));
+
+                self.create_edge(check, create_nodes[0], EdgeType::IfNotExists);
+                self.create_edge(check, update_nodes[0], EdgeType::IfExists);
+
+                vec![create_nodes[0], update_nodes[0]]
+            }
+            Query::Write(WriteQuery::Root(name, alias, mut root)) => {
+                let nested_writes = match root {
+                    RootWriteQuery::CreateRecord(ref mut q) => Some(Self::take_nested_writes(&mut q.nested_writes)),
+                    RootWriteQuery::UpdateRecord(ref mut q) => Some(Self::take_nested_writes(&mut q.nested_writes)),
+                    _ => None,
+                };
+
+                let node = self.create_node(Query::Write(WriteQuery::Root(name, alias, root)));
+
+                if let Some(nested_writes) = nested_writes {
+                    self.add_nested_writes(node, nested_writes);
+                }
+
+                vec![node]
+            }
+            query => vec![self.create_node(query)],
+        }
+    }
+
+    /// Adds a node per nested write, depending on the id of the record written by the parent.
+    /// Upserts are split into a read of the related record and a create and an update branch,
+    /// which are executed depending on the result of the read.
+    fn add_nested_writes(&mut self, parent: NodeRef, nested_writes: NestedWriteQueries) {
+        let NestedWriteQueries {
+            creates,
+            updates,
+            upserts,
+            deletes,
+            connects,
+            disconnects,
+            sets,
+            update_manys,
+            delete_manys,
+        } = nested_writes;
+
+        // Nested writes are added in the order the connector executed them in, which is kept by the graph.
+        let leading = creates
+            .into_iter()
+            .map(|q| NestedWriteQuery::CreateRecord(Box::new(q)))
+            .chain(updates.into_iter().map(|q| NestedWriteQuery::UpdateRecord(Box::new(q))));
+
+        for query in leading {
+            self.add_nested_write(parent, query);
+        }
+
+        for upsert in upserts {
+            self.add_nested_upsert(parent, upsert);
+        }
+
+        let trailing = deletes
+            .into_iter()
+            .map(NestedWriteQuery::DeleteRecord)
+            .chain(connects.into_iter().map(NestedWriteQuery::Connect))
+            .chain(sets.into_iter().map(NestedWriteQuery::Set))
+            .chain(disconnects.into_iter().map(NestedWriteQuery::Disconnect))
+            .chain(update_manys.into_iter().map(NestedWriteQuery::UpdateManyRecords))
+            .chain(delete_manys.into_iter().map(NestedWriteQuery::DeleteManyRecords));
+
+        for query in trailing {
+            self.add_nested_write(parent, query);
+        }
+    }
+
+    /// Adds a read of the record connected to the parent, followed by the update branch of the upsert
+    /// if the record exists and the create branch otherwise.
+    fn add_nested_upsert(&mut self, parent: NodeRef, upsert: NestedUpsertRecord) {
+        let NestedUpsertRecord {
+            relation_field,
+            where_,
+            create,
+            update,
+        } = upsert;
+
+        let check = self.create_node(Query::Read(ReadQuery::RelatedRecordsQuery(RelatedRecordsQuery {
+            name: relation_field.name.clone(),
+            alias: None,
+            parent_field: Arc::clone(&relation_field),
+            args: where_.map(QueryArguments::from).unwrap_or_default(),
+            selected_fields: Self::id_selection(&relation_field.related_model()),
+            nested: vec![],
+            selection_order: vec![],
+        })));
+
+        self.create_edge(parent, check, EdgeType::ParentId);
+
+        let create = self.add_nested_write(parent, NestedWriteQuery::CreateRecord(Box::new(create)));
+        let update = self.add_nested_write(parent, NestedWriteQuery::UpdateRecord(Box::new(update)));
+
+        self.create_edge(check, create, EdgeType::IfNotExists);
+        self.create_edge(check, update, EdgeType::IfExists);
+    }
+
+    /// Adds a single nested write and its own nested writes. Returns the node of the nested write.
+    fn add_nested_write(&mut self, parent: NodeRef, mut query: NestedWriteQuery) -> NodeRef {
+        let nested_writes = match query {
+            NestedWriteQuery::CreateRecord(ref mut q) => Some(Self::take_nested_writes(&mut q.nested_writes)),
+            NestedWriteQuery::UpdateRecord(ref mut q) => Some(Self::take_nested_writes(&mut q.nested_writes)),
+            _ => None,
+        };
+
+        let node = self.create_node(Query::Write(WriteQuery::Nested(query)));
+        self.create_edge(parent, node, EdgeType::ParentId);
+
+        if let Some(nested_writes) = nested_writes {
+            self.add_nested_writes(node, nested_writes);
+        }
+
+        node
+    }
+
+    /// Selects only the id of the records of the given model.
+    fn id_selection(model: &ModelRef) -> SelectedFields {
+        let mut selected_fields = SelectedFields::default();
+
+        for field in model.fields().id_fields() {
+            selected_fields.add_scalar(field);
+        }
+
+        selected_fields
+    }
+
+    fn take_nested_writes(nested_writes: &mut NestedWriteQueries) -> NestedWriteQueries {
+        mem::replace(nested_writes, NestedWriteQueries::default())
+    }
+}
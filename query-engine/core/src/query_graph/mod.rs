@@ -0,0 +1,157 @@
+mod builder;
+
+use crate::{CoreError, CoreResult, OutputTypeRef};
+use connector::Query;
+use std::collections::BTreeSet;
+
+/// Reference to a node of a query graph.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NodeRef(usize);
+
+impl NodeRef {
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
+
+/// Describes how the result of the parent node of an edge is used by the child node.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EdgeType {
+    /// The child is a nested write on the record written by the parent and requires its id.
+    ParentId,
+
+    /// The child is a read of the record resulting from the parent. The parent result is
+    /// injected as record finder into the child.
+    RecordFinder,
+
+    /// The child must be executed after the parent, without using the result of the parent.
+    ExecutionOrder,
+
+    /// The child is only executed if the parent read found a record, e.g. the update branch of an upsert.
+    IfExists,
+
+    /// The child is only executed if the parent read found no record, e.g. the create branch of an upsert.
+    IfNotExists,
+}
+
+impl EdgeType {
+    /// Whether the child of the edge is only executed depending on the result of the parent.
+    pub fn is_condition(self) -> bool {
+        match self {
+            EdgeType::IfExists | EdgeType::IfNotExists => true,
+            _ => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Edge {
+    pub parent: NodeRef,
+    pub child: NodeRef,
+    pub typ: EdgeType,
+}
+
+/// Graph of reads and writes with data dependencies between them.
+///
+/// Every node is a query that a connector can execute on its own. Nested writes are split into
+/// their own nodes, which depend on the id of the record written by their parent node.
+/// The query executor orchestrates the execution order and pipes results between nodes.
+///
+/// Nodes behind a condition edge are skipped if the condition doesn't hold, as are all nodes that only
+/// depend on the results of skipped nodes. Upserts are split into a read and two conditional branches this way.
+#[derive(Debug, Default)]
+pub struct QueryGraph {
+    nodes: Vec<Option<Query>>,
+    edges: Vec<Edge>,
+
+    /// The nodes that may hold the result of the graph and the type to serialize the result into.
+    /// The result is taken from the first of the nodes that is executed.
+    result_nodes: Option<(Vec<NodeRef>, OutputTypeRef)>,
+}
+
+impl QueryGraph {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn create_node(&mut self, query: Query) -> NodeRef {
+        self.nodes.push(Some(query));
+        NodeRef(self.nodes.len() - 1)
+    }
+
+    pub fn create_edge(&mut self, parent: NodeRef, child: NodeRef, typ: EdgeType) {
+        self.edges.push(Edge { parent, child, typ });
+    }
+
+    pub fn set_result_nodes(&mut self, nodes: Vec<NodeRef>, typ: OutputTypeRef) {
+        self.result_nodes = Some((nodes, typ));
+    }
+
+    pub fn result_nodes(&self) -> Option<&(Vec<NodeRef>, OutputTypeRef)> {
+        self.result_nodes.as_ref()
+    }
+
+    /// Edges pointing to the given node, in order of creation.
+    pub fn incoming_edges(&self, node: NodeRef) -> Vec<Edge> {
+        self.edges.iter().filter(|edge| edge.child == node).cloned().collect()
+    }
+
+    /// Takes the query out of the node, leaving the node in place.
+    /// Returns `None` if the query has already been taken.
+    pub fn take_query(&mut self, node: NodeRef) -> Option<Query> {
+        self.nodes.get_mut(node.0).and_then(|query| query.take())
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Number of write queries in the graph.
+    pub fn write_count(&self) -> usize {
+        self.nodes
+            .iter()
+            .filter(|query| match query {
+                Some(Query::Write(_)) => true,
+                _ => false,
+            })
+            .count()
+    }
+
+    /// Orders the nodes so that every node comes after all of its parents.
+    /// Nodes without dependencies between them keep their order of creation.
+    pub fn execution_order(&self) -> CoreResult<Vec<NodeRef>> {
+        let mut parent_counts: Vec<usize> = vec![0; self.nodes.len()];
+
+        for edge in self.edges.iter() {
+            parent_counts[edge.child.0] += 1;
+        }
+
+        let mut ready: BTreeSet<NodeRef> = (0..self.nodes.len())
+            .filter(|i| parent_counts[*i] == 0)
+            .map(NodeRef)
+            .collect();
+
+        let mut order = Vec::with_capacity(self.nodes.len());
+
+        while let Some(node) = ready.iter().next().cloned() {
+            ready.remove(&node);
+            order.push(node);
+
+            for edge in self.edges.iter().filter(|edge| edge.parent == node) {
+                parent_counts[edge.child.0] -= 1;
+
+                if parent_counts[edge.child.0] == 0 {
+                    ready.insert(edge.child);
+                }
+            }
+        }
+
+        if order.len() != self.nodes.len() {
+            return Err(CoreError::ConversionError(
+                "Query graph contains a cycle and can't be executed.".into(),
+            ));
+        }
+
+        Ok(order)
+    }
+}
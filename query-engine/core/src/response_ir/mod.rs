@@ -16,9 +16,10 @@ pub use read::*;
 pub use utils::*;
 pub use write::*;
 
-use crate::{OutputType, ResultPair};
+use crate::{CoreError, ErrorCode, OutputType, ResultPair};
 use indexmap::IndexMap;
 use prisma_models::PrismaValue;
+use serde_json::Value;
 use std::{borrow::Borrow, sync::Arc};
 
 /// A `key -> value` map to an IR item
@@ -35,7 +36,38 @@ pub type ItemRef = Arc<Item>;
 #[derive(Debug)]
 pub enum Response {
     Data(String, Item),
-    Error(String),
+    Error(ResponseError),
+}
+
+/// An error as returned to clients: a stable code to match on,
+/// a human readable message and additional information depending on the code.
+#[derive(Debug, Clone)]
+pub struct ResponseError {
+    pub code: ErrorCode,
+    pub message: String,
+
+    /// Always a JSON object, empty if there is no additional information.
+    pub meta: Value,
+}
+
+impl ResponseError {
+    pub fn new<T: Into<String>>(code: ErrorCode, message: T) -> Self {
+        Self {
+            code,
+            message: message.into(),
+            meta: Value::Object(Default::default()),
+        }
+    }
+}
+
+impl From<CoreError> for ResponseError {
+    fn from(e: CoreError) -> Self {
+        Self {
+            code: e.code(),
+            meta: e.meta(),
+            message: format!("{}", e),
+        }
+    }
 }
 
 /// An IR item that either expands to a subtype or leaf-record.
@@ -78,7 +110,7 @@ impl ResultIrBuilder {
 
                         match serialized {
                             Ok(result) => vec.push(Response::Data(name, result)),
-                            Err(err) => vec.push(Response::Error(format!("{}", err))),
+                            Err(err) => vec.push(Response::Error(err.into())),
                         };
                     }
 
@@ -105,7 +137,7 @@ impl ResultIrBuilder {
                                 vec.push(Response::Data(name, result));
                             }
 
-                            Err(err) => vec.push(Response::Error(format!("{}", err))),
+                            Err(err) => vec.push(Response::Error(err.into())),
                         };
                     }
                 };
@@ -1,6 +1,8 @@
 use super::*;
-use crate::{CoreError, CoreResult, IntoArc, ObjectTypeStrongRef, OutputType, OutputTypeRef, ScalarType};
-use connector::{ReadQueryResult, RecordSelection, ResultContent, ScalarListValues};
+use crate::{CoreError, CoreResult, FieldRef, IntoArc, ObjectTypeStrongRef, OutputType, OutputTypeRef, ScalarType};
+use connector::{
+    AggregationRecords, AggregationResult, ReadQueryResult, RecordSelection, ResultContent, ScalarListValues,
+};
 use indexmap::IndexMap;
 use prisma_models::{GraphqlId, PrismaValue};
 use std::{borrow::Borrow, collections::HashMap, convert::TryFrom};
@@ -48,9 +50,81 @@ pub fn serialize_read(
 
             Ok(result)
         }
+        ResultContent::Aggregation(aggregation) => serialize_aggregation(aggregation, typ),
     }
 }
 
+/// Serializes aggregation rows into a single object (plain aggregation) or a list of objects (grouped aggregation).
+/// Aggregated values are coerced into the types defined on the (nested) output objects.
+fn serialize_aggregation(aggregation: AggregationRecords, typ: &OutputTypeRef) -> CoreResult<CheckedItemsWithParents> {
+    let object_type = typ.as_object_type().ok_or_else(|| {
+        CoreError::SerializationError(format!(
+            "Attempted to serialize aggregation with non-object compatible type '{:?}'",
+            typ
+        ))
+    })?;
+
+    let prefix = if aggregation.is_grouped { "_" } else { "" };
+    let mut items = Vec::with_capacity(aggregation.rows.len());
+
+    for row in aggregation.rows {
+        let mut map = Map::new();
+
+        for result in row {
+            let (key, field, value) = match result {
+                AggregationResult::Field(field, value) => {
+                    let output_field = find_aggregation_field(&object_type, &field.name)?;
+                    map.insert(field.name.clone(), serialize_scalar(value, &output_field.field_type)?);
+                    continue;
+                }
+                AggregationResult::Count(count) => {
+                    map.insert(format!("{}count", prefix), Item::Value(PrismaValue::Int(count as i64)));
+                    continue;
+                }
+                AggregationResult::Sum(field, value) => (format!("{}sum", prefix), field, value),
+                AggregationResult::Average(field, value) => (format!("{}avg", prefix), field, value),
+                AggregationResult::Min(field, value) => (format!("{}min", prefix), field, value),
+                AggregationResult::Max(field, value) => (format!("{}max", prefix), field, value),
+            };
+
+            let nested_type = find_aggregation_field(&object_type, &key)?
+                .field_type
+                .as_object_type()
+                .ok_or_else(|| {
+                    CoreError::SerializationError(format!("Aggregation field '{}' is not an object", key))
+                })?;
+
+            let output_field = find_aggregation_field(&nested_type, &field.name)?;
+            let item = serialize_scalar(value, &output_field.field_type)?;
+
+            if let Item::Map(ref mut inner) = map.entry(key).or_insert_with(|| Item::Map(Map::new())) {
+                inner.insert(field.name.clone(), item);
+            }
+        }
+
+        items.push(Item::Map(map));
+    }
+
+    let mut result = CheckedItemsWithParents::new();
+
+    if aggregation.is_grouped {
+        result.insert(None, Item::List(items));
+    } else if let Some(item) = items.pop() {
+        result.insert(None, item);
+    }
+
+    Ok(result)
+}
+
+fn find_aggregation_field(object_type: &ObjectTypeStrongRef, name: &str) -> CoreResult<FieldRef> {
+    object_type.find_field(name).ok_or_else(|| {
+        CoreError::SerializationError(format!(
+            "Field '{}' not found on aggregation type '{}'",
+            name, object_type.name
+        ))
+    })
+}
+
 fn serialize_record_selection(
     name: String,
     alias: Option<String>,
@@ -151,7 +225,7 @@ fn serialize_objects(mut result: RecordSelection, typ: ObjectTypeStrongRef) -> C
     // Write all fields, nested and list fields unordered into a map, afterwards order all into the final order.
     // If nothing is written to the object, write null instead.
     for record in result.scalars.records {
-        let record_id = Some(record.collect_id(&scalar_field_names, &result.id_field)?);
+        let record_id = Some(record.collect_id(&scalar_field_names, &result.id_fields)?);
 
         if !object_mapping.contains_key(&record.parent_id) {
             object_mapping.insert(record.parent_id.clone(), vec![]);
@@ -165,6 +239,9 @@ fn serialize_objects(mut result: RecordSelection, typ: ObjectTypeStrongRef) -> C
             let field = typ.find_field(field_name).unwrap();
             if !field.field_type.is_object() && !field.field_type.is_list() {
                 object.insert(field_name.to_owned(), serialize_scalar(val, &field.field_type)?);
+            } else if !field.field_type.is_object() {
+                // Scalar lists stored in native arrays are read with the record.
+                object.insert(field_name.to_owned(), serialize_scalar_list(val, &field.field_type)?);
             }
         }
 
@@ -295,6 +372,31 @@ fn process_scalar_lists(
     Ok(list_mapping)
 }
 
+/// Serializes a scalar list read with the record. Lists that are not set are serialized as empty lists.
+fn serialize_scalar_list(value: PrismaValue, typ: &OutputTypeRef) -> CoreResult<Item> {
+    let list_type = match typ.borrow() {
+        OutputType::List(inner) => inner,
+        other => {
+            return Err(CoreError::SerializationError(format!(
+                "Attempted to serialize scalar list with non-scalar-list compatible type '{:?}'",
+                other
+            )))
+        }
+    };
+
+    let values = match value {
+        PrismaValue::List(values) => values.unwrap_or_default(),
+        _ => vec![],
+    };
+
+    let items = values
+        .into_iter()
+        .map(|val| serialize_scalar(val, list_type))
+        .collect::<CoreResult<Vec<_>>>()?;
+
+    Ok(Item::List(items))
+}
+
 fn serialize_scalar(value: PrismaValue, typ: &OutputTypeRef) -> CoreResult<Item> {
     match (&value, typ.borrow()) {
         (PrismaValue::Null, OutputType::Opt(_)) => Ok(Item::Value(PrismaValue::Null)),
@@ -335,6 +437,9 @@ fn serialize_scalar(value: PrismaValue, typ: &OutputTypeRef) -> CoreResult<Item>
                 (ScalarType::Float, PrismaValue::Float(f)) => PrismaValue::Float(f),
                 (ScalarType::Float, PrismaValue::Int(i)) => PrismaValue::Float(i as f64),
 
+                (ScalarType::Decimal, PrismaValue::Decimal(d)) => PrismaValue::Decimal(d),
+                (ScalarType::Decimal, PrismaValue::Int(i)) => PrismaValue::Decimal(i.into()),
+
                 (ScalarType::Enum(ref et), PrismaValue::Enum(ref ev)) => match et.value_for(&ev.name) {
                     Some(_) => PrismaValue::Enum(ev.clone()),
                     None => {
@@ -350,6 +455,7 @@ fn serialize_scalar(value: PrismaValue, typ: &OutputTypeRef) -> CoreResult<Item>
                 (ScalarType::DateTime, PrismaValue::DateTime(dt)) => PrismaValue::DateTime(dt),
                 (ScalarType::Json, PrismaValue::Json(j)) => PrismaValue::Json(j),
                 (ScalarType::UUID, PrismaValue::Uuid(u)) => PrismaValue::Uuid(u),
+                (ScalarType::Bytes, PrismaValue::Bytes(b)) => PrismaValue::Bytes(b),
 
                 (st, pv) => {
                     return Err(CoreError::SerializationError(format!(
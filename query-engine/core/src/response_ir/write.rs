@@ -12,6 +12,15 @@ pub fn serialize_write(result: WriteQueryResult, typ: &OutputTypeRef) -> CoreRes
             map.insert("count".into(), Item::Value(PrismaValue::Int(c as i64)));
             Ok(Item::Map(map))
         }
+        Identifier::Ids(ids) => {
+            let mut map: IndexMap<String, Item> = IndexMap::new();
+            let count = ids.len();
+            let ids = ids.into_iter().map(|id| Item::Value(PrismaValue::from(id))).collect();
+
+            map.insert("count".into(), Item::Value(PrismaValue::Int(count as i64)));
+            map.insert("ids".into(), Item::List(ids));
+            Ok(Item::Map(map))
+        }
         Identifier::Record(r) => {
             let obj = typ.as_object_type().unwrap();
             let mut map: IndexMap<String, Item> = IndexMap::new();
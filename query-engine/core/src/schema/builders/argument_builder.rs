@@ -40,6 +40,17 @@ impl<'a> ArgumentBuilder<'a> {
         }
     }
 
+    /// Builds "data" argument intended for the create many field, a list of records to create.
+    pub fn create_many_arguments(&self, model: ModelRef) -> Vec<Argument> {
+        let input_object_type = self.input_type_builder.into_arc().create_many_input_type(model);
+
+        vec![argument(
+            "data",
+            InputType::list(InputType::object(input_object_type)),
+            None,
+        )]
+    }
+
     /// Builds "where" (unique) argument intended for the delete field.
     pub fn delete_arguments(&self, model: ModelRef) -> Option<Vec<Argument>> {
         self.where_unique_argument(model).map(|arg| vec![arg])
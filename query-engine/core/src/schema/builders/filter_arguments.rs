@@ -68,10 +68,12 @@ pub fn get_field_filters<'a>(field: &Field) -> Vec<&'a FilterArgument> {
             TypeIdentifier::String => vec![&args.base, &args.inclusion, &args.alphanumeric, &args.string],
             TypeIdentifier::Int => vec![&args.base, &args.inclusion, &args.alphanumeric],
             TypeIdentifier::Float => vec![&args.base, &args.inclusion, &args.alphanumeric],
+            TypeIdentifier::Decimal => vec![&args.base, &args.inclusion, &args.alphanumeric],
             TypeIdentifier::Boolean => vec![&args.base],
             TypeIdentifier::Enum => vec![&args.base, &args.inclusion],
             TypeIdentifier::DateTime => vec![&args.base, &args.inclusion, &args.alphanumeric],
             TypeIdentifier::Json => vec![],
+            TypeIdentifier::Bytes => vec![&args.base, &args.inclusion],
             TypeIdentifier::Relation => vec![&args.one_relation],
         };
 
@@ -82,3 +84,14 @@ pub fn get_field_filters<'a>(field: &Field) -> Vec<&'a FilterArgument> {
             .collect()
     }
 }
+
+/// Filters applicable to aggregated values.
+pub fn aggregation_filters<'a>() -> Vec<&'a FilterArgument> {
+    let args = &FILTER_ARGUMENTS;
+
+    vec![&args.base, &args.inclusion, &args.alphanumeric]
+        .into_iter()
+        .map(|l| l.iter().collect::<Vec<&'a FilterArgument>>())
+        .flatten()
+        .collect()
+}
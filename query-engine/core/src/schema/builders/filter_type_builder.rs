@@ -1,5 +1,7 @@
 use super::*;
-use prisma_models::{Field as ModelField, ModelRef, RelationFieldRef, ScalarFieldRef};
+use prisma_models::{
+    EnumType, EnumValue, Field as ModelField, ModelRef, RelationFieldRef, ScalarFieldRef, TypeIdentifier,
+};
 use std::sync::Arc;
 
 /// Filter object and scalar filter object type builder.
@@ -29,6 +31,61 @@ impl<'a> FilterObjectTypeBuilder<'a> {
         }
     }
 
+    /// Builds the object type selecting a single record by one of its unique fields (e.g. UserWhereUniqueInput).
+    pub fn where_unique_object_type(&self, model: ModelRef) -> InputObjectTypeRef {
+        let name = format!("{}WhereUniqueInput", model.name);
+        return_cached!(self.get_cache(), &name);
+
+        let input_object = Arc::new(init_input_object_type(name.clone()));
+        self.cache(name, Arc::clone(&input_object));
+
+        let unique_fields: Vec<ScalarFieldRef> = model
+            .fields()
+            .scalar()
+            .iter()
+            .filter(|f| (f.is_unique() && !f.is_hidden) || f.is_id())
+            .map(|f| Arc::clone(f))
+            .collect();
+
+        let mut fields: Vec<InputField> = unique_fields
+            .into_iter()
+            .map(|f| input_field(f.name.clone(), self.map_optional_input_type(f), None))
+            .collect();
+
+        // Compound ids and unique indexes are selected by all of their fields at once,
+        // e.g. `email_tenantId: { email, tenantId }`.
+        for compound_fields in model.compound_unique_fields() {
+            let name = compound_field_name(&compound_fields);
+            let compound_object = self.compound_field_object_type(&model, compound_fields);
+
+            fields.push(input_field(
+                name,
+                InputType::opt(InputType::object(compound_object)),
+                None,
+            ));
+        }
+
+        input_object.set_fields(fields);
+        Arc::downgrade(&input_object)
+    }
+
+    /// Builds the object type holding all fields of a compound selector (e.g. MembershipTenantUserCompoundUniqueInput).
+    fn compound_field_object_type(&self, model: &ModelRef, fields: Vec<ScalarFieldRef>) -> InputObjectTypeRef {
+        let field_names: Vec<String> = fields.iter().map(|f| capitalize(&f.name)).collect();
+        let name = format!("{}{}CompoundUniqueInput", model.name, field_names.join(""));
+        return_cached!(self.get_cache(), &name);
+
+        let input_fields = fields
+            .into_iter()
+            .map(|f| input_field(f.name.clone(), self.map_required_input_type(f), None))
+            .collect();
+
+        let input_object = Arc::new(input_object_type(name.clone(), input_fields));
+        self.cache(name, Arc::clone(&input_object));
+
+        Arc::downgrade(&input_object)
+    }
+
     pub fn scalar_filter_object_type(&self, model: ModelRef) -> InputObjectTypeRef {
         let object_name = format!("{}ScalarWhereInput", model.name);
         return_cached!(self.get_cache(), &object_name);
@@ -64,6 +121,68 @@ impl<'a> FilterObjectTypeBuilder<'a> {
         weak_ref
     }
 
+    /// Builds the filter object type for aggregated values of groups (e.g. UserScalarWhereWithAggregatesInput).
+    /// Aggregations are prefixed with an underscore, e.g. `_count_gt` or `_sum_age_lt`.
+    pub fn having_filter_object_type(&self, model: ModelRef) -> InputObjectTypeRef {
+        let object_name = format!("{}ScalarWhereWithAggregatesInput", model.name);
+        return_cached!(self.get_cache(), &object_name);
+
+        let input_object = Arc::new(init_input_object_type(object_name.clone()));
+        self.cache(object_name, Arc::clone(&input_object));
+
+        let weak_ref = Arc::downgrade(&input_object);
+        let mut input_fields = vec![
+            input_field(
+                "AND",
+                InputType::opt(InputType::list(InputType::object(Weak::clone(&weak_ref)))),
+                None,
+            ),
+            input_field(
+                "OR",
+                InputType::opt(InputType::list(InputType::object(Weak::clone(&weak_ref)))),
+                None,
+            ),
+            input_field(
+                "NOT",
+                InputType::opt(InputType::list(InputType::object(Weak::clone(&weak_ref)))),
+                None,
+            ),
+        ];
+
+        let mut count_fields = aggregation_filters()
+            .into_iter()
+            .map(|arg| aggregation_input_field(format!("_count{}", arg.suffix), InputType::int(), arg.is_list))
+            .collect();
+
+        input_fields.append(&mut count_fields);
+
+        for sf in aggregation_numeric_fields(&model) {
+            for (function, typ) in vec![
+                ("sum", self.map_required_input_type(Arc::clone(&sf))),
+                ("avg", InputType::float()),
+            ] {
+                for arg in aggregation_filters() {
+                    let name = format!("_{}_{}{}", function, sf.name, arg.suffix);
+                    input_fields.push(aggregation_input_field(name, typ.clone(), arg.is_list));
+                }
+            }
+        }
+
+        for sf in aggregation_comparable_fields(&model) {
+            for function in vec!["min", "max"] {
+                for arg in aggregation_filters() {
+                    let name = format!("_{}_{}{}", function, sf.name, arg.suffix);
+                    let typ = self.map_required_input_type(Arc::clone(&sf));
+
+                    input_fields.push(aggregation_input_field(name, typ, arg.is_list));
+                }
+            }
+        }
+
+        input_object.set_fields(input_fields);
+        weak_ref
+    }
+
     pub fn filter_object_type(&self, model: ModelRef) -> InputObjectTypeRef {
         if self.capabilities.has(ConnectorCapability::MongoJoinRelationLinks) {
             self.build_mongo_filter_object(model)
@@ -127,7 +246,8 @@ impl<'a> FilterObjectTypeBuilder<'a> {
     }
 
     fn map_input_field(&self, field: ScalarFieldRef) -> Vec<InputField> {
-        get_field_filters(&ModelField::Scalar(Arc::clone(&field))) // wip: take a look at required signatures
+        // wip: take a look at required signatures
+        let mut input_fields: Vec<InputField> = get_field_filters(&ModelField::Scalar(Arc::clone(&field)))
             .into_iter()
             .map(|arg| {
                 let field_name = format!("{}{}", field.name, arg.suffix);
@@ -139,7 +259,39 @@ impl<'a> FilterObjectTypeBuilder<'a> {
                     input_field(field_name, InputType::opt(mapped), None)
                 }
             })
-            .collect()
+            .collect();
+
+        // The mode applies to the pattern filters of the field within the same filter object,
+        // e.g. `name_mode: insensitive`, and is only offered by connectors that can match case-insensitively.
+        let has_insensitive_filters = self.capabilities.has(ConnectorCapability::InsensitiveFilters);
+
+        match field.type_identifier {
+            TypeIdentifier::String | TypeIdentifier::UUID | TypeIdentifier::GraphQLID
+                if !field.is_list && has_insensitive_filters =>
+            {
+                let field_name = format!("{}_mode", field.name);
+                input_fields.push(input_field(field_name, InputType::opt(query_mode_enum().into()), None));
+            }
+            _ => (),
+        };
+
+        // Lists stored in native arrays can be checked for emptiness. Filtering by their elements needs
+        // array operators, which the query builder doesn't offer.
+        if field.is_embedded_list() {
+            input_fields.push(input_field(
+                format!("{}_is_empty", field.name),
+                InputType::opt(InputType::boolean()),
+                None,
+            ));
+        }
+
+        // Searching is only offered on fields covered by a full-text index, as the databases rely on it.
+        if field.fulltext_index.is_some() && self.capabilities.has(ConnectorCapability::FullTextSearch) {
+            let field_name = format!("{}_search", field.name);
+            input_fields.push(input_field(field_name, InputType::opt(InputType::string()), None));
+        }
+
+        input_fields
     }
 
     /// Maps relations to (filter) input fields.
@@ -165,3 +317,22 @@ impl<'a> FilterObjectTypeBuilder<'a> {
         }
     }
 }
+
+fn aggregation_input_field(name: String, typ: InputType, is_list: bool) -> InputField {
+    if is_list {
+        input_field(name, InputType::opt(InputType::list(typ)), None)
+    } else {
+        input_field(name, InputType::opt(typ), None)
+    }
+}
+
+/// Builds the enum selecting how string filters compare values.
+fn query_mode_enum() -> EnumType {
+    enum_type(
+        "QueryMode",
+        vec![
+            EnumValue::string("default", "default".into()),
+            EnumValue::string("insensitive", "insensitive".into()),
+        ],
+    )
+}
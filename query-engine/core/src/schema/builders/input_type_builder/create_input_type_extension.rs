@@ -25,7 +25,40 @@ pub trait CreateInputTypeBuilderExtension<'a>: InputTypeBuilderBase<'a> {
             .filter(|f| !f.is_hidden && Self::do_filter(&f))
             .collect();
 
-        let mut fields = self.scalar_input_fields(
+        let mut fields = self.scalar_input_fields_for_create(&model, scalar_fields);
+
+        // Compute input fields for relational fields.
+        let mut relational_fields = self.relation_input_fields_create(Arc::clone(&model), parent_field.as_ref());
+        fields.append(&mut relational_fields);
+
+        input_object.set_fields(fields);
+        Arc::downgrade(&input_object)
+    }
+
+    /// Builds the input type for records created with createMany (<x>CreateManyInput).
+    /// Only non-list scalar fields can be set, as records created in bulk can't have nested writes.
+    fn create_many_input_type(&self, model: ModelRef) -> InputObjectTypeRef {
+        let name = format!("{}CreateManyInput", model.name);
+        return_cached!(self.get_cache(), &name);
+
+        let scalar_fields: Vec<ScalarFieldRef> = model
+            .fields()
+            .scalar_non_list()
+            .into_iter()
+            .filter(|f| !f.is_hidden && Self::do_filter(&f))
+            .collect();
+
+        let fields = self.scalar_input_fields_for_create(&model, scalar_fields);
+        let input_object = Arc::new(input_object_type(name.clone(), fields));
+
+        self.cache(name, Arc::clone(&input_object));
+        Arc::downgrade(&input_object)
+    }
+
+    /// Computes the input fields for scalar fields of create input types.
+    #[rustfmt::skip]
+    fn scalar_input_fields_for_create(&self, model: &ModelRef, scalar_fields: Vec<ScalarFieldRef>) -> Vec<InputField> {
+        self.scalar_input_fields(
             model.name.clone(),
             "Create",
             scalar_fields,
@@ -53,14 +86,7 @@ pub trait CreateInputTypeBuilderExtension<'a>: InputTypeBuilderBase<'a> {
                 }
             },
             true
-        );
-
-        // Compute input fields for relational fields.
-        let mut relational_fields = self.relation_input_fields_create(Arc::clone(&model), parent_field.as_ref());
-        fields.append(&mut relational_fields);
-
-        input_object.set_fields(fields);
-        Arc::downgrade(&input_object)
+        )
     }
 
     /// For create input types only. Compute input fields for relational fields.
@@ -44,7 +44,14 @@ pub trait InputTypeBuilderBase<'a>: CachedBuilder<InputObjectType> + InputBuilde
                 let input_object = match self.get_cache().get(&set_name) {
                     Some(t) => t,
                     None => {
-                        let set_fields = vec![input_field("set", self.map_optional_input_type(f), None)];
+                        let mut set_fields =
+                            vec![input_field("set", self.map_optional_input_type(Arc::clone(&f)), None)];
+
+                        // Lists stored in native arrays can be appended to on update.
+                        if f.is_embedded_list() && input_object_name == "Update" {
+                            set_fields.push(input_field("push", self.map_optional_input_type(f), None));
+                        }
+
                         let input_object = Arc::new(input_object_type(set_name.clone(), set_fields));
                         self.cache(set_name, Arc::clone(&input_object));
                         Arc::downgrade(&input_object)
@@ -89,27 +96,7 @@ pub trait InputTypeBuilderBase<'a>: CachedBuilder<InputObjectType> + InputBuilde
     }
 
     fn where_unique_object_type(&self, model: ModelRef) -> InputObjectTypeRef {
-        let name = format!("{}WhereUniqueInput", model.name);
-        return_cached!(self.get_cache(), &name);
-
-        let input_object = Arc::new(init_input_object_type(name.clone()));
-        self.cache(name, Arc::clone(&input_object));
-
-        let unique_fields: Vec<ScalarFieldRef> = model
-            .fields()
-            .scalar()
-            .iter()
-            .filter(|f| (f.is_unique() && !f.is_hidden) || f.is_id())
-            .map(|f| Arc::clone(f))
-            .collect();
-
-        let fields: Vec<InputField> = unique_fields
-            .into_iter()
-            .map(|f| input_field(f.name.clone(), self.map_optional_input_type(f), None))
-            .collect();
-
-        input_object.set_fields(fields);
-        Arc::downgrade(&input_object)
+        self.get_filter_object_builder().where_unique_object_type(model)
     }
 
     fn get_filter_object_builder(&self) -> Arc<FilterObjectTypeBuilder<'a>>;
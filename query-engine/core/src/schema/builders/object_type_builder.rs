@@ -1,6 +1,7 @@
 use super::*;
 use prisma_models::{
-    EnumType, EnumValue, Field as ModelField, InternalDataModelRef, ModelRef, ScalarField, SortOrder, TypeIdentifier,
+    EnumType, EnumValue, Field as ModelField, InternalDataModelRef, ModelRef, OrderBy, ScalarField, ScalarFieldRef,
+    SortOrder, TypeIdentifier,
 };
 use std::sync::Arc;
 
@@ -110,6 +111,7 @@ impl<'a> ObjectTypeBuilder<'a> {
             ModelField::Scalar(sf) => match sf.type_identifier {
                 TypeIdentifier::String => OutputType::string(),
                 TypeIdentifier::Float => OutputType::float(),
+                TypeIdentifier::Decimal => OutputType::decimal(),
                 TypeIdentifier::Boolean => OutputType::boolean(),
                 TypeIdentifier::Enum => Self::map_enum_field(sf).into(),
                 TypeIdentifier::Json => OutputType::json(),
@@ -117,6 +119,7 @@ impl<'a> ObjectTypeBuilder<'a> {
                 TypeIdentifier::GraphQLID => OutputType::id(),
                 TypeIdentifier::UUID => OutputType::uuid(),
                 TypeIdentifier::Int => OutputType::int(),
+                TypeIdentifier::Bytes => OutputType::bytes(),
                 TypeIdentifier::Relation => unreachable!(), // Scalar fields can't have a Relation type identifier.
             },
         };
@@ -145,15 +148,20 @@ impl<'a> ObjectTypeBuilder<'a> {
     }
 
     /// Builds "many records where" arguments solely based on the given model.
+    /// The cursors (`after` and `before`) select the cursor record by any of its unique fields.
     pub fn many_records_arguments(&self, model: &ModelRef) -> Vec<Argument> {
-        let id_field = model.fields().id();
-        let id_input_type = self.map_optional_input_type(id_field);
+        let cursor_object = self
+            .filter_object_type_builder
+            .into_arc()
+            .where_unique_object_type(Arc::clone(model));
+        let cursor_input_type = InputType::opt(InputType::object(cursor_object));
+
         vec![
             self.where_argument(&model),
             self.order_by_argument(&model),
             argument("skip", InputType::opt(InputType::int()), None),
-            argument("after", id_input_type.clone(), None),
-            argument("before", id_input_type, None),
+            argument("after", cursor_input_type.clone(), None),
+            argument("before", cursor_input_type, None),
             argument("first", InputType::opt(InputType::int()), None),
             argument("last", InputType::opt(InputType::int()), None),
         ]
@@ -169,33 +177,60 @@ impl<'a> ObjectTypeBuilder<'a> {
         argument("where", InputType::opt(InputType::object(where_object)), None)
     }
 
-    // Builds "orderBy" argument.
+    /// Builds "orderBy" argument. Accepts a list of orderings, applied in order of appearance.
+    /// Besides the scalar fields of the model, records can be ordered by the scalar fields of records
+    /// related via to-one relations (e.g. `author_name_ASC`) and by the number of records related
+    /// via to-many relations (e.g. `posts_count_DESC`).
     pub fn order_by_argument(&self, model: &ModelRef) -> Argument {
-        let enum_values: Vec<EnumValue> = model
+        let scalar_orderings = model
             .fields()
             .scalar_non_list()
-            .iter()
-            .map(|f| {
-                vec![
-                    EnumValue::order_by(
-                        format!("{}_{}", f.name, SortOrder::Ascending.abbreviated()),
-                        Arc::clone(f),
-                        SortOrder::Ascending,
-                    ),
-                    EnumValue::order_by(
-                        format!("{}_{}", f.name, SortOrder::Descending.abbreviated()),
-                        Arc::clone(f),
-                        SortOrder::Descending,
-                    ),
-                ]
+            .into_iter()
+            .map(|f| OrderBy::scalar(f, SortOrder::Ascending));
+
+        let relation_orderings = model
+            .fields()
+            .relation()
+            .into_iter()
+            .filter(|rf| !rf.is_hidden && !rf.related_model().is_embedded)
+            .map(|rf| {
+                if rf.is_list {
+                    vec![OrderBy::relation_count(vec![], rf, SortOrder::Ascending)]
+                } else {
+                    rf.related_model()
+                        .fields()
+                        .scalar_non_list()
+                        .into_iter()
+                        .map(|f| OrderBy::related_scalar(vec![Arc::clone(&rf)], f, SortOrder::Ascending))
+                        .collect()
+                }
             })
-            .flatten()
-            .collect();
+            .flatten();
+
+        let mut enum_values: Vec<EnumValue> = vec![];
+
+        for ordering in scalar_orderings.chain(relation_orderings) {
+            for sort_order in &[SortOrder::Ascending, SortOrder::Descending] {
+                let name = format!("{}_{}", ordering.name(), sort_order.abbreviated());
+
+                // Scalar fields take precedence over relation orderings with the same name.
+                if enum_values.iter().any(|value| value.name == name) {
+                    continue;
+                }
+
+                let order_by = OrderBy {
+                    sort_order: *sort_order,
+                    ..ordering.clone()
+                };
+
+                enum_values.push(EnumValue::ordering(name, order_by));
+            }
+        }
 
         let enum_name = format!("{}OrderByInput", model.name);
         let enum_type = enum_type(enum_name, enum_values);
 
-        argument("orderBy", InputType::opt(enum_type.into()), None)
+        argument("orderBy", InputType::opt(InputType::list(enum_type.into())), None)
     }
 
     pub fn map_enum_field(scalar_field: &Arc<ScalarField>) -> EnumType {
@@ -224,17 +259,209 @@ impl<'a> ObjectTypeBuilder<'a> {
         Arc::downgrade(&object_type)
     }
 
+    /// Builds the output type of create many mutations. The ids are only collected if selected.
+    pub fn create_many_payload_object_type(&self) -> ObjectTypeRef {
+        return_cached!(self.get_cache(), "CreateManyPayload");
+
+        let object_type = Arc::new(object_type(
+            "CreateManyPayload",
+            vec![
+                field("count", vec![], OutputType::int(), None),
+                field("ids", vec![], OutputType::list(OutputType::id()), None),
+            ],
+            None,
+        ));
+
+        self.cache("CreateManyPayload".into(), Arc::clone(&object_type));
+        Arc::downgrade(&object_type)
+    }
+
     /// Builds aggregation object type for given model (e.g. AggregateUser).
     pub fn aggregation_object_type(&self, model: &ModelRef) -> ObjectTypeRef {
         let name = format!("Aggregate{}", capitalize(&model.name));
         return_cached!(self.get_cache(), &name);
 
         let object = ObjectTypeStrongRef::new(init_object_type(&name, Some(ModelRef::clone(model))));
-        let fields = vec![field("count", vec![], OutputType::int(), None)];
+        let mut fields = vec![field("count", vec![], OutputType::int(), None)];
+
+        fields.append(&mut self.aggregation_function_fields(model, ""));
+        object.set_fields(fields);
+        self.cache(name, ObjectTypeStrongRef::clone(&object));
+
+        ObjectTypeStrongRef::downgrade(&object)
+    }
+
+    /// Builds group by object type for given model (e.g. UserGroupByOutputType).
+    /// Contains the grouping fields and the aggregations of each group, which are
+    /// prefixed with an underscore to prevent clashes with the model fields.
+    pub fn group_by_object_type(&self, model: &ModelRef) -> ObjectTypeRef {
+        let name = format!("{}GroupByOutputType", capitalize(&model.name));
+        return_cached!(self.get_cache(), &name);
+
+        let object = ObjectTypeStrongRef::new(init_object_type(&name, Some(ModelRef::clone(model))));
+        let mut fields: Vec<Field> = model
+            .fields()
+            .scalar_non_list()
+            .into_iter()
+            .filter(|f| !f.is_hidden)
+            .map(|f| {
+                let field_type = self.map_output_type(&ModelField::Scalar(Arc::clone(&f)));
+                let field_type = match field_type {
+                    OutputType::Opt(_) => field_type,
+                    other => OutputType::opt(other),
+                };
+
+                field(f.name.clone(), vec![], field_type, None)
+            })
+            .collect();
+
+        fields.push(field("_count", vec![], OutputType::int(), None));
+        fields.append(&mut self.aggregation_function_fields(model, "_"));
 
         object.set_fields(fields);
         self.cache(name, ObjectTypeStrongRef::clone(&object));
 
         ObjectTypeStrongRef::downgrade(&object)
     }
+
+    /// Builds the "sum", "avg", "min" and "max" fields for the given model, if the model has fields to aggregate.
+    fn aggregation_function_fields(&self, model: &ModelRef, prefix: &str) -> Vec<Field> {
+        let numeric_fields = aggregation_numeric_fields(model);
+        let comparable_fields = aggregation_comparable_fields(model);
+        let mut fields = vec![];
+
+        let sum_object = self.aggregate_function_object_type(model, "Sum", &numeric_fields, |sf| {
+            self.map_output_type(&ModelField::Scalar(Arc::clone(sf)))
+        });
+
+        let avg_object = self.aggregate_function_object_type(model, "Avg", &numeric_fields, |_| OutputType::float());
+
+        let min_object = self.aggregate_function_object_type(model, "Min", &comparable_fields, |sf| {
+            self.map_output_type(&ModelField::Scalar(Arc::clone(sf)))
+        });
+
+        let max_object = self.aggregate_function_object_type(model, "Max", &comparable_fields, |sf| {
+            self.map_output_type(&ModelField::Scalar(Arc::clone(sf)))
+        });
+
+        let objects = vec![
+            ("sum", sum_object),
+            ("avg", avg_object),
+            ("min", min_object),
+            ("max", max_object),
+        ];
+
+        for (name, object) in objects {
+            if let Some(object) = object {
+                fields.push(field(
+                    format!("{}{}", prefix, name),
+                    vec![],
+                    OutputType::opt(OutputType::object(object)),
+                    None,
+                ));
+            }
+        }
+
+        fields
+    }
+
+    /// Builds an object type holding the results of a single aggregation function (e.g. UserSumAggregateOutputType).
+    /// Returns None if there are no fields to aggregate.
+    fn aggregate_function_object_type<F>(
+        &self,
+        model: &ModelRef,
+        function_name: &str,
+        fields: &[ScalarFieldRef],
+        map_type: F,
+    ) -> Option<ObjectTypeRef>
+    where
+        F: Fn(&ScalarFieldRef) -> OutputType,
+    {
+        if fields.is_empty() {
+            return None;
+        }
+
+        let name = format!("{}{}AggregateOutputType", capitalize(&model.name), function_name);
+        if let Some(cached) = self.get_cache().get(&name) {
+            return Some(cached);
+        }
+
+        let object = ObjectTypeStrongRef::new(init_object_type(&name, Some(ModelRef::clone(model))));
+        let fields = fields
+            .iter()
+            .map(|sf| {
+                let field_type = match map_type(sf) {
+                    OutputType::Opt(inner) => OutputType::Opt(inner),
+                    other => OutputType::opt(other),
+                };
+
+                field(sf.name.clone(), vec![], field_type, None)
+            })
+            .collect();
+
+        object.set_fields(fields);
+        self.cache(name, ObjectTypeStrongRef::clone(&object));
+
+        Some(ObjectTypeStrongRef::downgrade(&object))
+    }
+
+    /// Builds the enum of all groupable fields of the given model (e.g. UserScalarFieldEnum).
+    pub fn scalar_field_enum_type(&self, model: &ModelRef) -> EnumType {
+        let values = model
+            .fields()
+            .scalar_non_list()
+            .into_iter()
+            .filter(|f| !f.is_hidden)
+            .map(|f| EnumValue::string(f.name.clone(), f.name.clone()))
+            .collect();
+
+        enum_type(format!("{}ScalarFieldEnum", capitalize(&model.name)), values)
+    }
+
+    /// Builds "groupBy" arguments for the given model.
+    pub fn group_by_arguments(&self, model: &ModelRef) -> Vec<Argument> {
+        let having_object = self
+            .filter_object_type_builder
+            .into_arc()
+            .having_filter_object_type(Arc::clone(model));
+
+        let by_type: InputType = self.scalar_field_enum_type(model).into();
+
+        vec![
+            self.where_argument(&model),
+            argument("by", InputType::list(by_type), None),
+            argument("having", InputType::opt(InputType::object(having_object)), None),
+            argument("skip", InputType::opt(InputType::int()), None),
+            argument("first", InputType::opt(InputType::int()), None),
+            argument("last", InputType::opt(InputType::int()), None),
+        ]
+    }
+}
+
+/// Fields of the model that can be summed and averaged.
+pub fn aggregation_numeric_fields(model: &ModelRef) -> Vec<ScalarFieldRef> {
+    model
+        .fields()
+        .scalar_non_list()
+        .into_iter()
+        .filter(|f| !f.is_hidden)
+        .filter(|f| match f.type_identifier {
+            TypeIdentifier::Int | TypeIdentifier::Float | TypeIdentifier::Decimal => true,
+            _ => false,
+        })
+        .collect()
+}
+
+/// Fields of the model that have a minimum and maximum.
+pub fn aggregation_comparable_fields(model: &ModelRef) -> Vec<ScalarFieldRef> {
+    model
+        .fields()
+        .scalar_non_list()
+        .into_iter()
+        .filter(|f| !f.is_hidden)
+        .filter(|f| match f.type_identifier {
+            TypeIdentifier::Int | TypeIdentifier::Float | TypeIdentifier::Decimal | TypeIdentifier::DateTime => true,
+            _ => false,
+        })
+        .collect()
 }
@@ -136,6 +136,7 @@ impl<'a> QuerySchemaBuilder<'a> {
                 let mut vec = vec![
                     self.all_items_field(Arc::clone(&m)),
                     self.aggregation_field(Arc::clone(&m)),
+                    self.group_by_field(Arc::clone(&m)),
                 ];
 
                 append_opt(&mut vec, self.single_item_field(Arc::clone(&m)));
@@ -155,7 +156,10 @@ impl<'a> QuerySchemaBuilder<'a> {
         let fields = non_embedded_models
             .into_iter()
             .map(|model| {
-                let mut vec = vec![self.create_item_field(Arc::clone(&model))];
+                let mut vec = vec![
+                    self.create_item_field(Arc::clone(&model)),
+                    self.create_many_field(Arc::clone(&model)),
+                ];
 
                 append_opt(&mut vec, self.delete_item_field(Arc::clone(&model)));
                 append_opt(&mut vec, self.update_item_field(Arc::clone(&model)));
@@ -230,7 +234,7 @@ impl<'a> QuerySchemaBuilder<'a> {
 
         field(
             field_name,
-            vec![],
+            self.object_type_builder.many_records_arguments(&model),
             OutputType::object(self.object_type_builder.aggregation_object_type(&model)),
             Some(ModelOperation::new(
                 Arc::clone(&model),
@@ -239,6 +243,23 @@ impl<'a> QuerySchemaBuilder<'a> {
         )
     }
 
+    /// Builds a "group by" query field (e.g. "groupByUser") for given model.
+    fn group_by_field(&self, model: ModelRef) -> Field {
+        let field_name = format!("groupBy{}", model.name.clone()); // Has no legacy counterpart.
+
+        field(
+            field_name,
+            self.object_type_builder.group_by_arguments(&model),
+            OutputType::list(OutputType::object(
+                self.object_type_builder.group_by_object_type(&model),
+            )),
+            Some(ModelOperation::new(
+                Arc::clone(&model),
+                OperationTag::GroupBy(Box::new(OperationTag::CoerceResultToOutputType)),
+            )),
+        )
+    }
+
     /// Builds a create mutation field (e.g. createUser) for given model.
     fn create_item_field(&self, model: ModelRef) -> Field {
         let args = self
@@ -262,6 +283,25 @@ impl<'a> QuerySchemaBuilder<'a> {
         )
     }
 
+    /// Builds a create many mutation field (e.g. createManyUsers) for given model.
+    fn create_many_field(&self, model: ModelRef) -> Field {
+        let arguments = self.argument_builder.create_many_arguments(Arc::clone(&model));
+        let field_name = self.pluralize_internal(
+            format!("createMany{}", pluralize(model.name.clone())),
+            format!("createMany{}", model.name.clone()),
+        );
+
+        field(
+            field_name,
+            arguments,
+            OutputType::object(self.object_type_builder.create_many_payload_object_type()),
+            Some(ModelOperation::new(
+                Arc::clone(&model),
+                OperationTag::CreateMany(Box::new(OperationTag::CoerceResultToOutputType)),
+            )),
+        )
+    }
+
     /// Builds a delete mutation field (e.g. deleteUser) for given model.
     fn delete_item_field(&self, model: ModelRef) -> Option<Field> {
         self.argument_builder.delete_arguments(Arc::clone(&model)).map(|args| {
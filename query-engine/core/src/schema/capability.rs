@@ -43,6 +43,14 @@ pub enum ConnectorCapability {
     MongoJoinRelationLinks,
     RelationLinkList,
     RelationLinkTable,
+
+    /// String pattern filters of the connector match case-insensitively, which makes the `mode` of
+    /// string filters available.
+    InsensitiveFilters,
+
+    /// The connector can search the full-text indices of the database, which makes the `search` filter
+    /// available on fields covered by one.
+    FullTextSearch,
 }
 
 #[derive(Debug, PartialEq)]
@@ -198,12 +198,14 @@ pub enum OperationTag {
 
     /// Write operations with associated result operations.
     CreateOne(Box<OperationTag>),
+    CreateMany(Box<OperationTag>),
     UpdateOne(Box<OperationTag>),
     UpdateMany(Box<OperationTag>),
     DeleteOne(Box<OperationTag>),
     DeleteMany(Box<OperationTag>),
     UpsertOne(Box<OperationTag>),
     Aggregate(Box<OperationTag>),
+    GroupBy(Box<OperationTag>),
 
     /// Marks an operation to write the result of the previous query directly
     /// as map shaped as the defined output type of a query.
@@ -217,12 +219,14 @@ impl fmt::Display for OperationTag {
             OperationTag::FindOne => "findOne",
             OperationTag::FindMany => "findMany",
             OperationTag::CreateOne(_) => "createOne",
+            OperationTag::CreateMany(_) => "createMany",
             OperationTag::UpdateOne(_) => "updateOne",
             OperationTag::UpdateMany(_) => "updateMany",
             OperationTag::DeleteOne(_) => "deleteOne",
             OperationTag::DeleteMany(_) => "deleteMany",
             OperationTag::UpsertOne(_) => "upsertOne",
             OperationTag::Aggregate(_) => "aggregate",
+            OperationTag::GroupBy(_) => "groupBy",
             OperationTag::CoerceResultToOutputType => unreachable!(), // Only top-level ops are reached.
         };
 
@@ -311,6 +315,14 @@ impl InputType {
         InputType::Scalar(ScalarType::Float)
     }
 
+    pub fn decimal() -> InputType {
+        InputType::Scalar(ScalarType::Decimal)
+    }
+
+    pub fn bytes() -> InputType {
+        InputType::Scalar(ScalarType::Bytes)
+    }
+
     pub fn boolean() -> InputType {
         InputType::Scalar(ScalarType::Boolean)
     }
@@ -366,6 +378,14 @@ impl OutputType {
         OutputType::Scalar(ScalarType::Float)
     }
 
+    pub fn decimal() -> OutputType {
+        OutputType::Scalar(ScalarType::Decimal)
+    }
+
+    pub fn bytes() -> OutputType {
+        OutputType::Scalar(ScalarType::Bytes)
+    }
+
     pub fn boolean() -> OutputType {
         OutputType::Scalar(ScalarType::Boolean)
     }
@@ -420,12 +440,14 @@ pub enum ScalarType {
     String,
     Int,
     Float,
+    Decimal,
     Boolean,
     Enum(EnumTypeRef),
     DateTime,
     Json,
     UUID,
     ID,
+    Bytes,
 }
 
 impl From<EnumType> for OutputType {
@@ -1,6 +1,6 @@
 use super::*;
 use once_cell::sync::OnceCell;
-use prisma_models::{EnumType, EnumValue, ModelRef, PrismaValue};
+use prisma_models::{EnumType, EnumValue, ModelRef, PrismaValue, ScalarFieldRef};
 use std::sync::Arc;
 
 /// Object type initializer for cases where only the name is known, and fields are computed later.
@@ -134,6 +134,12 @@ where
     format!("{}{}", first_char.to_uppercase(), s[1..].to_owned())
 }
 
+/// The name of a compound selector over the given fields, e.g. `email_tenantId`.
+pub fn compound_field_name(fields: &[ScalarFieldRef]) -> String {
+    let names: Vec<&str> = fields.iter().map(|f| f.name.as_str()).collect();
+    names.join("_")
+}
+
 /// Appends an option of type T to a vector over T if the option is Some.
 pub fn append_opt<T>(vec: &mut Vec<T>, opt: Option<T>) {
     opt.into_iter().for_each(|t| vec.push(t));
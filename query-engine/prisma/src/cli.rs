@@ -1,6 +1,6 @@
 use crate::{
     data_model_loader::{load_configuration, load_data_model_components},
-    dmmf, PrismaResult,
+    dmmf, exec_loader, PrismaResult,
 };
 use clap::ArgMatches;
 use core::{
@@ -67,7 +67,10 @@ impl CliCommand {
 
         // temporary code duplication
         let internal_data_model = template.build("".into());
-        let capabilities = SupportedCapabilities::empty();
+        let capabilities = match v2components.data_sources.first() {
+            Some(data_source) => exec_loader::capabilities(&**data_source),
+            None => SupportedCapabilities::empty(),
+        };
 
         let schema_builder = QuerySchemaBuilder::new(&internal_data_model, &capabilities, build_mode);
         let query_schema: QuerySchemaRef = Arc::new(schema_builder.build());
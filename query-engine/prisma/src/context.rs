@@ -1,9 +1,9 @@
 use std::sync::Arc;
 
-use core::{BuildMode, QueryExecutor, QuerySchemaBuilder, QuerySchemaRef, SupportedCapabilities};
+use core::{BuildMode, QueryExecutor, QuerySchemaBuilder, QuerySchemaRef};
 use prisma_models::InternalDataModelRef;
 
-use crate::{data_model_loader::*, exec_loader, PrismaError, PrismaResult};
+use crate::{data_model_loader::*, exec_loader, transactions::TransactionRegistry, PrismaError, PrismaResult};
 
 /// Prisma request context containing all immutable state of the process.
 /// There is usually only one context initialized per process.
@@ -21,6 +21,10 @@ pub struct PrismaContext {
     /// Central query executor.
     #[debug_stub = "#QueryExecutor#"]
     executor: QueryExecutor,
+
+    /// Interactive transactions currently open.
+    #[debug_stub = "#TransactionRegistry#"]
+    transactions: TransactionRegistry,
 }
 
 impl PrismaContext {
@@ -51,7 +55,7 @@ impl PrismaContext {
 
         // Construct query schema
         let build_mode = if legacy { BuildMode::Legacy } else { BuildMode::Modern };
-        let capabilities = SupportedCapabilities::empty(); // todo connector capabilities.
+        let capabilities = exec_loader::capabilities(&**data_source);
         let schema_builder = QuerySchemaBuilder::new(&internal_data_model, &capabilities, build_mode);
         let query_schema: QuerySchemaRef = Arc::new(schema_builder.build());
 
@@ -60,6 +64,7 @@ impl PrismaContext {
             query_schema,
             dm,
             executor,
+            transactions: TransactionRegistry::new(),
         })
     }
 
@@ -79,4 +84,7 @@ impl PrismaContext {
         &self.executor
     }
 
+    pub fn transactions(&self) -> &TransactionRegistry {
+        &self.transactions
+    }
 }
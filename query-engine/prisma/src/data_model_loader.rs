@@ -73,7 +73,8 @@ pub fn load_data_model_components() -> PrismaResult<(DatamodelV2Components, Inte
     // Load data model in order of precedence.
     match load_datamodel_v2()? {
         Some(v2components) => {
-            let template = DatamodelConverter::convert(&v2components.datamodel);
+            let template = DatamodelConverter::convert(&v2components.datamodel)
+                .map_err(|err| PrismaError::ConfigurationError(format!("{}", err)))?;
             Ok((v2components, template))
         }
         None => {
@@ -69,10 +69,12 @@ impl<'a> DMMFTypeRenderer<'a> {
                     ScalarType::Int => "Int",
                     ScalarType::Boolean => "Boolean",
                     ScalarType::Float => "Float",
+                    ScalarType::Decimal => "Decimal",
                     ScalarType::DateTime => "DateTime",
                     ScalarType::Json => "DateTime",
                     ScalarType::ID => "ID",
                     ScalarType::UUID => "UUID",
+                    ScalarType::Bytes => "Bytes",
                     ScalarType::Enum(_) => unreachable!(), // Handled separately above.
                 };
 
@@ -142,10 +144,12 @@ impl<'a> DMMFTypeRenderer<'a> {
                     ScalarType::Int => "Int",
                     ScalarType::Boolean => "Boolean",
                     ScalarType::Float => "Float",
+                    ScalarType::Decimal => "Decimal",
                     ScalarType::DateTime => "DateTime",
                     ScalarType::Json => "DateTime",
                     ScalarType::ID => "ID",
                     ScalarType::UUID => "UUID",
+                    ScalarType::Bytes => "Bytes",
                     ScalarType::Enum(_) => unreachable!(), // Handled separately above.
                 };
 
@@ -1,9 +1,9 @@
 use crate::utilities;
-use core::{response_ir, CoreError};
+use core::{response_ir, CoreError, ErrorCode};
 use datamodel::error::ErrorCollection;
 use failure::{Error, Fail};
 use graphql_parser::query::ParseError as GqlParseError;
-use serde_json;
+use serde_json::{self, json};
 
 #[cfg(feature = "sql")]
 use sql_query_connector::SqlError;
@@ -13,6 +13,10 @@ pub enum PrismaError {
     #[fail(display = "{}", _0)]
     QueryValidationError(String),
 
+    /// An invalid variable value, with the path to the value inside of the variable, e.g. `$ids[1]`.
+    #[fail(display = "{}", message)]
+    VariableValidationError { path: String, message: String },
+
     #[fail(display = "{}", _0)]
     SerializationError(String),
 
@@ -40,6 +44,9 @@ pub enum PrismaError {
 
     #[fail(display = "Error in data model: {}", _0)]
     DatamodelError(ErrorCollection),
+
+    #[fail(display = "{}", _0)]
+    TransactionError(String),
 }
 
 impl From<ErrorCollection> for PrismaError {
@@ -51,8 +58,28 @@ impl From<ErrorCollection> for PrismaError {
 /// Helps to handle gracefully handle errors as a response.
 impl Into<response_ir::Response> for PrismaError {
     fn into(self) -> response_ir::Response {
-        let error_msg = format!("{}", self);
-        response_ir::Response::Error(error_msg)
+        response_ir::Response::Error(self.into())
+    }
+}
+
+impl From<PrismaError> for response_ir::ResponseError {
+    fn from(e: PrismaError) -> Self {
+        let code = match e {
+            PrismaError::CoreError(e) => return e.into(),
+            PrismaError::VariableValidationError { path, message } => {
+                return response_ir::ResponseError {
+                    code: ErrorCode::QueryValidationError,
+                    message,
+                    meta: json!({ "path": [path] }),
+                }
+            }
+            PrismaError::QueryValidationError(_) | PrismaError::JsonDecodeError(_) => ErrorCode::QueryValidationError,
+            PrismaError::UnsupportedFeatureError(_, _) => ErrorCode::UnsupportedFeature,
+            PrismaError::TransactionError(_) => ErrorCode::TransactionError,
+            _ => ErrorCode::InternalError,
+        };
+
+        response_ir::ResponseError::new(code, format!("{}", e))
     }
 }
 
@@ -1,5 +1,8 @@
 use crate::{PrismaError, PrismaResult};
-use core::executor::{QueryExecutor, ReadQueryExecutor, WriteQueryExecutor};
+use core::{
+    executor::{QueryExecutor, ReadQueryExecutor, WriteQueryExecutor},
+    ConnectorCapability, SupportedCapabilities,
+};
 use datamodel::{
     configuration::{MYSQL_SOURCE_NAME, POSTGRES_SOURCE_NAME, SQLITE_SOURCE_NAME},
     Source,
@@ -28,6 +31,27 @@ pub fn load(source: &dyn Source) -> PrismaResult<QueryExecutor> {
     }
 }
 
+/// The capabilities of the connector of the source, which decide on the parts of the query schema
+/// that depend on the database.
+pub fn capabilities(source: &dyn Source) -> SupportedCapabilities {
+    match source.connector_type() {
+        // `LIKE` ignores the case on SQLite and with the default collations of MySQL, there's no
+        // case-insensitive comparison for Postgres in the query builder yet. The same goes for
+        // full-text searches, which the query builder can only express as lookups in the FTS5 tables
+        // of SQLite.
+        SQLITE_SOURCE_NAME => SupportedCapabilities {
+            capabilities: vec![
+                ConnectorCapability::InsensitiveFilters,
+                ConnectorCapability::FullTextSearch,
+            ],
+        },
+        MYSQL_SOURCE_NAME => SupportedCapabilities {
+            capabilities: vec![ConnectorCapability::InsensitiveFilters],
+        },
+        _ => SupportedCapabilities::empty(),
+    }
+}
+
 #[cfg(feature = "sql")]
 fn sqlite(source: &dyn Source) -> PrismaResult<QueryExecutor> {
     trace!("Loading SQLite connector...");
@@ -86,7 +110,7 @@ fn mysql(source: &dyn Source) -> PrismaResult<QueryExecutor> {
 #[cfg(feature = "sql")]
 fn sql_executor<T>(primary_connector: &'static str, db_name: String, connector: SqlDatabase<T>) -> QueryExecutor
 where
-    T: Transactional + SqlCapabilities + Send + Sync + 'static,
+    T: Transactional + SqlCapabilities + DedicatedConnection + Send + Sync + 'static,
 {
     let arc = Arc::new(connector);
     let read_exec: ReadQueryExecutor = ReadQueryExecutor {
@@ -95,8 +119,8 @@ where
 
     let write_exec: WriteQueryExecutor = WriteQueryExecutor {
         db_name,
-        write_executor: arc,
+        write_executor: arc.clone(),
     };
 
-    QueryExecutor::new(primary_connector, read_exec, write_exec)
+    QueryExecutor::new(primary_connector, read_exec, write_exec, Some(arc))
 }
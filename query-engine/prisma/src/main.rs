@@ -16,15 +16,18 @@ mod exec_loader;
 mod request_handlers;
 mod serializers;
 mod server;
+mod stdio;
+mod transactions;
 mod utilities;
 
 use clap::{App as ClapApp, Arg, SubCommand};
 use cli::*;
 use error::*;
-use logger::Logger;
+use logger::{LogOutput, Logger};
 use request_handlers::{PrismaRequest, RequestHandler};
 use server::HttpServer;
 use std::{env, error::Error, process};
+use stdio::StdioServer;
 
 pub type PrismaResult<T> = Result<T, PrismaError>;
 
@@ -46,6 +49,13 @@ fn main() -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
                 .takes_value(false)
                 .required(false),
         )
+        .arg(
+            Arg::with_name("stdio")
+                .long("stdio")
+                .help("Serves newline-delimited JSON-RPC requests on stdin and stdout instead of HTTP.")
+                .takes_value(false)
+                .required(false),
+        )
         .arg(
             Arg::with_name("version")
                 .long("version")
@@ -96,6 +106,24 @@ fn main() -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
                 process::exit(1);
             }
         }
+    } else if matches.is_present("stdio") {
+        let _logger = Logger::build_with_output("prisma", LogOutput::Stderr); // keep in scope, stdout is for responses
+        let legacy = matches.is_present("legacy");
+
+        let server = match StdioServer::new(legacy) {
+            Ok(server) => server,
+            Err(err) => {
+                info!("Encountered error during initialization:");
+                StdioServer::write_error(&err);
+                process::exit(1);
+            }
+        };
+
+        // Requests already got their responses, stdout might be gone. The error only goes to the log.
+        if let Err(err) = server.serve() {
+            error!("Encountered error while serving requests: {}", err);
+            process::exit(1);
+        };
     } else {
         let _logger = Logger::build("prisma"); // keep in scope
 
@@ -1,8 +1,11 @@
-use super::protocol_adapter::GraphQLProtocolAdapter;
-use crate::{context::PrismaContext, serializers::json, PrismaRequest, PrismaResult, RequestHandler};
-use core::response_ir;
+use super::{introspection::answer_introspection, protocol_adapter::GraphQLProtocolAdapter};
+use crate::{context::PrismaContext, serializers::json, PrismaError, PrismaRequest, PrismaResult, RequestHandler};
+use core::{
+    query_document::{Operation, QueryDocument},
+    response_ir, ErrorCode, QueryExecutor,
+};
 use graphql_parser as gql;
-use serde::{Deserialize, Serialize};
+use serde::{de, Deserialize, Deserializer, Serialize};
 use serde_json::Value;
 use std::{collections::HashMap, sync::Arc};
 
@@ -11,38 +14,218 @@ use std::{collections::HashMap, sync::Arc};
 pub struct GraphQlBody {
     query: String,
     operation_name: Option<String>,
-    variables: HashMap<String, String>,
+
+    #[serde(default)]
+    variables: HashMap<String, Value>,
+}
+
+/// A request body is either a single GraphQL body or a batch of bodies.
+/// Batches are executed in order and can optionally be executed within a single transaction.
+///
+/// The variant is chosen by the shape of the JSON: an array is a batch of bodies, an object with a
+/// `batch` key a `BatchBody` and any other object a single body. Deserialization errors are reported
+/// for the chosen variant, instead of the generic error of an untagged enum.
+#[derive(Clone, Debug)]
+pub enum GraphQlRequestBody {
+    Single(GraphQlBody),
+    Multi(Vec<GraphQlBody>),
+    Batch(BatchBody),
+}
+
+impl<'de> Deserialize<'de> for GraphQlRequestBody {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = Value::deserialize(deserializer)?;
+
+        let body = match value {
+            Value::Array(_) => serde_json::from_value(value).map(GraphQlRequestBody::Multi),
+            Value::Object(ref map) if map.contains_key("batch") => {
+                serde_json::from_value(value).map(GraphQlRequestBody::Batch)
+            }
+            value => serde_json::from_value(value).map(GraphQlRequestBody::Single),
+        };
+
+        body.map_err(de::Error::custom)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchBody {
+    batch: Vec<GraphQlBody>,
+
+    #[serde(default)]
+    is_transaction: bool,
 }
 
 pub struct GraphQlRequestHandler;
 
 #[allow(unused_variables)]
 impl RequestHandler for GraphQlRequestHandler {
-    type Body = GraphQlBody;
+    type Body = GraphQlRequestBody;
 
     fn handle<S: Into<PrismaRequest<Self::Body>>>(&self, req: S, ctx: &PrismaContext) -> Value {
-        let responses = match handle_graphql_query(req.into(), ctx) {
-            Ok(responses) => responses,
-            Err(err) => vec![err.into()],
+        let req: PrismaRequest<GraphQlRequestBody> = req.into();
+        let in_transaction = req.transaction_id.is_some();
+
+        let tx_executor;
+        let executor: &QueryExecutor = match req.transaction_id {
+            Some(ref id) => match ctx.transactions().executor(id) {
+                Ok(executor) => {
+                    tx_executor = executor;
+                    &*tx_executor
+                }
+                Err(err) => return json::serialize(vec![err.into()]),
+            },
+            None => ctx.executor(),
         };
 
-        json::serialize(responses)
+        match req.body {
+            GraphQlRequestBody::Single(body) => serialize(handle_graphql_query(body, executor, ctx)),
+            GraphQlRequestBody::Multi(bodies) => handle_batch(bodies, executor, ctx),
+
+            // A request already running in an interactive transaction is transactional as a whole.
+            GraphQlRequestBody::Batch(body) => {
+                if body.is_transaction && !in_transaction {
+                    handle_transactional_batch(body.batch, executor, ctx)
+                } else {
+                    handle_batch(body.batch, executor, ctx)
+                }
+            }
+        }
     }
 }
 
+fn serialize(result: PrismaResult<Vec<response_ir::Response>>) -> Value {
+    let responses = match result {
+        Ok(responses) => responses,
+        Err(err) => vec![err.into()],
+    };
+
+    json::serialize(responses)
+}
+
+/// Executes all bodies independently of each other.
+fn handle_batch(bodies: Vec<GraphQlBody>, executor: &QueryExecutor, ctx: &PrismaContext) -> Value {
+    let responses = bodies
+        .into_iter()
+        .map(|body| serialize(handle_graphql_query(body, executor, ctx)))
+        .collect();
+
+    Value::Array(responses)
+}
+
+/// Executes all bodies within one transaction. Execution stops at the first failing body and the
+/// transaction is rolled back: the failing body is answered with its error, all other bodies with a
+/// transaction error pointing to it. If the transaction itself can't be started or committed, the batch
+/// is answered with a single error instead of an array.
+fn handle_transactional_batch(bodies: Vec<GraphQlBody>, executor: &QueryExecutor, ctx: &PrismaContext) -> Value {
+    let (tx_executor, handle) = match executor.start_transaction() {
+        Ok(transaction) => transaction,
+        Err(err) => return serialize(Err(err.into())),
+    };
+
+    let count = bodies.len();
+    let mut results = Vec::with_capacity(count);
+
+    for body in bodies {
+        match handle_graphql_query(body, &tx_executor, ctx) {
+            Ok(responses) => results.push(responses),
+            Err(err) => {
+                if let Err(rollback_err) = handle.rollback() {
+                    warn!("Rolling back batch transaction failed: {}", rollback_err);
+                }
+
+                return rolled_back_batch(count, results.len(), err);
+            }
+        }
+    }
+
+    if let Err(err) = handle.commit() {
+        return serialize(Err(PrismaError::CoreError(err.into())));
+    }
+
+    Value::Array(results.into_iter().map(json::serialize).collect())
+}
+
+/// Responses for a transactional batch of `count` bodies that was rolled back after the body at `failed` failed.
+pub(super) fn rolled_back_batch(count: usize, failed: usize, error: PrismaError) -> Value {
+    let mut error = Some(error);
+
+    let responses = (0..count)
+        .map(|index| {
+            if index == failed {
+                if let Some(error) = error.take() {
+                    return serialize(Err(error));
+                }
+            }
+
+            let message = if index < failed {
+                format!("Rolled back because request {} of the batch failed.", failed)
+            } else {
+                format!("Not executed because request {} of the batch failed.", failed)
+            };
+
+            let error = response_ir::ResponseError::new(ErrorCode::TransactionError, message);
+            json::serialize(vec![response_ir::Response::Error(error)])
+        })
+        .collect();
+
+    Value::Array(responses)
+}
+
 fn handle_graphql_query(
-    req: PrismaRequest<GraphQlBody>,
+    body: GraphQlBody,
+    executor: &QueryExecutor,
     ctx: &PrismaContext,
 ) -> PrismaResult<Vec<response_ir::Response>> {
-    debug!("Incoming GQL query: {:?}", &req.body.query);
+    debug!("Incoming GQL query: {:?}", &body.query);
+
+    let gql_doc = gql::parse_query(&body.query)?;
+    let query_doc = GraphQLProtocolAdapter::convert(gql_doc, body.operation_name, &body.variables)?;
+    let keys = response_keys(&query_doc);
+    let (query_doc, introspection, typenames) = answer_introspection(query_doc, ctx.query_schema())?;
 
-    let gql_doc = gql::parse_query(&req.body.query)?;
-    let query_doc = GraphQLProtocolAdapter::convert(gql_doc, req.body.operation_name)?;
+    // Documents that only introspect the schema don't need to be executed.
+    let mut responses = if query_doc.operations.is_empty() {
+        vec![]
+    } else {
+        executor
+            .execute(query_doc, Arc::clone(ctx.query_schema()))
+            .map_err(|err| {
+                debug!("{}", err);
+                PrismaError::from(err)
+            })?
+    };
 
-    ctx.executor()
-        .execute(query_doc, Arc::clone(ctx.query_schema()))
-        .map_err(|err| {
-            debug!("{}", err);
-            err.into()
+    typenames.inject(&mut responses);
+    responses.extend(introspection);
+    order_responses(&keys, &mut responses);
+
+    Ok(responses)
+}
+
+/// The response keys (alias or name) of the root selections of the document, in the order they were selected.
+pub(super) fn response_keys(query_doc: &QueryDocument) -> Vec<String> {
+    query_doc
+        .operations
+        .iter()
+        .flat_map(|operation| match operation {
+            Operation::Read(op) => op.selections.iter(),
+            Operation::Write(op) => op.selections.iter(),
         })
+        .map(|selection| selection.alias.as_ref().unwrap_or(&selection.name).clone())
+        .collect()
+}
+
+/// Sorts the responses into the order of their keys in the document. Introspection fields are answered
+/// apart from the executed fields, but the data of a response lists the fields in the order they were selected.
+/// Errors keep their relative order after the data.
+pub(super) fn order_responses(keys: &[String], responses: &mut Vec<response_ir::Response>) {
+    responses.sort_by_key(|response| match response {
+        response_ir::Response::Data(key, _) => keys.iter().position(|k| k == key).unwrap_or(keys.len()),
+        response_ir::Response::Error(_) => keys.len(),
+    });
 }
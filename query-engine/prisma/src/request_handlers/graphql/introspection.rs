@@ -0,0 +1,615 @@
+use crate::{PrismaError, PrismaResult};
+use core::{
+    query_document::*,
+    response_ir::{Item, Response},
+    EnumTypeRef, InputObjectTypeStrongRef, InputType, IntoArc, ObjectTypeStrongRef, OutputType, QuerySchemaRef,
+    ScalarType,
+};
+use indexmap::IndexMap;
+use prisma_models::PrismaValue;
+use std::{collections::BTreeMap, sync::Arc};
+
+/// The built-in and custom scalars of the query schema. They are always part of the schema, like in the SDL.
+const SCALARS: &[&str] = &[
+    "Boolean", "Bytes", "DateTime", "Decimal", "Float", "ID", "Int", "Json", "String", "UUID",
+];
+
+/// Answers the GraphQL introspection fields (`__schema`, `__type` and `__typename`) of the given query document.
+///
+/// Introspection fields are removed from the operations of the document, operations that only consisted
+/// of introspection fields are removed entirely. The remaining document is returned next to the answers,
+/// which are built from the query schema without hitting the connector.
+///
+/// `__typename` fields selected inside of data fields are removed as well. The returned `Typenames` add
+/// them to the results of the data fields once the document was executed.
+pub fn answer_introspection(
+    query_doc: QueryDocument,
+    query_schema: &QuerySchemaRef,
+) -> PrismaResult<(QueryDocument, Vec<Response>, Typenames)> {
+    let mut schema: Option<IntrospectionSchema> = None;
+    let mut operations = vec![];
+    let mut responses = vec![];
+    let mut typenames = Typenames::default();
+
+    for operation in query_doc.operations {
+        let (root_type, selections) = match operation {
+            Operation::Read(mut op) => {
+                let (introspection, rest): (Vec<Selection>, Vec<Selection>) =
+                    op.selections.into_iter().partition(is_introspection);
+                op.selections = rest;
+                typenames
+                    .nested
+                    .extend(Typenames::strip(&mut op.selections, &query_schema.query()));
+
+                if !op.selections.is_empty() {
+                    operations.push(Operation::Read(op));
+                }
+
+                ("Query", introspection)
+            }
+            Operation::Write(mut op) => {
+                let (introspection, rest): (Vec<Selection>, Vec<Selection>) =
+                    op.selections.into_iter().partition(is_introspection);
+                op.selections = rest;
+                typenames
+                    .nested
+                    .extend(Typenames::strip(&mut op.selections, &query_schema.mutation()));
+
+                if !op.selections.is_empty() {
+                    operations.push(Operation::Write(op));
+                }
+
+                ("Mutation", introspection)
+            }
+        };
+
+        for selection in selections {
+            let item = match (root_type, selection.name.as_str()) {
+                (_, "__typename") => string(root_type),
+                ("Query", "__schema") => schema
+                    .get_or_insert_with(|| IntrospectionSchema::new(query_schema))
+                    .resolve_schema(&selection.sub_selections)?,
+                ("Query", "__type") => {
+                    let name = match selection.arguments.iter().find(|(name, _)| name == "name") {
+                        Some((_, QueryValue::String(name))) => name,
+                        _ => {
+                            return Err(PrismaError::QueryValidationError(
+                                "Field '__type' requires a String argument 'name'.".to_owned(),
+                            ))
+                        }
+                    };
+
+                    schema
+                        .get_or_insert_with(|| IntrospectionSchema::new(query_schema))
+                        .resolve_named_type(name, &selection.sub_selections)?
+                }
+                (_, name) => return Err(unknown_field(root_type, name)),
+            };
+
+            let key = selection.alias.unwrap_or(selection.name);
+            responses.push(Response::Data(key, item));
+        }
+    }
+
+    Ok((QueryDocument { operations }, responses, typenames))
+}
+
+fn is_introspection(selection: &Selection) -> bool {
+    selection.name.starts_with("__")
+}
+
+/// The `__typename` fields selected inside of data fields, by response key. The query schema has no
+/// `__typename` fields, so they are answered after execution, appended to the fields of each record.
+#[derive(Debug, Default)]
+pub struct Typenames {
+    type_name: String,
+    keys: Vec<String>,
+
+    /// A field selected in place of `__typename` when it was the only selection, removed again from the results.
+    placeholder: Option<String>,
+    nested: Vec<(String, Typenames)>,
+}
+
+impl Typenames {
+    /// Removes the `__typename` fields below the given selections on an object type.
+    fn strip(selections: &mut Vec<Selection>, obj: &ObjectTypeStrongRef) -> Vec<(String, Typenames)> {
+        selections
+            .iter_mut()
+            .filter_map(|selection| {
+                let field = obj.find_field(&selection.name)?;
+                let typenames = Self::collect(&mut selection.sub_selections, &object_type(&field.field_type)?);
+                let key = selection.alias.as_ref().unwrap_or(&selection.name).clone();
+
+                if typenames.keys.is_empty() && typenames.nested.is_empty() {
+                    None
+                } else {
+                    Some((key, typenames))
+                }
+            })
+            .collect()
+    }
+
+    fn collect(selections: &mut Vec<Selection>, obj: &ObjectTypeStrongRef) -> Self {
+        let (typenames, rest): (Vec<Selection>, Vec<Selection>) = std::mem::replace(selections, vec![])
+            .into_iter()
+            .partition(|selection| selection.name == "__typename");
+        *selections = rest;
+
+        // The query builder requires at least one selection, so a scalar field stands in for `__typename`.
+        let placeholder = if selections.is_empty() && !typenames.is_empty() {
+            obj.get_fields()
+                .iter()
+                .find(|field| field.arguments.is_empty() && object_type(&field.field_type).is_none())
+                .map(|field| field.name.clone())
+        } else {
+            None
+        };
+
+        if let Some(ref name) = placeholder {
+            selections.push(Selection {
+                name: name.clone(),
+                alias: None,
+                arguments: vec![],
+                sub_selections: vec![],
+            });
+        }
+
+        Self {
+            type_name: obj.name.clone(),
+            keys: typenames
+                .into_iter()
+                .map(|selection| selection.alias.unwrap_or(selection.name))
+                .collect(),
+            placeholder,
+            nested: Self::strip(selections, obj),
+        }
+    }
+
+    /// Adds the selected type names to the results of the data fields.
+    pub fn inject(&self, responses: &mut [Response]) {
+        for response in responses.iter_mut() {
+            if let Response::Data(key, item) = response {
+                if let Some((_, typenames)) = self.nested.iter().find(|(k, _)| k == key) {
+                    typenames.inject_item(item);
+                }
+            }
+        }
+    }
+
+    fn inject_item(&self, item: &mut Item) {
+        match item {
+            Item::Map(map) => {
+                if let Some(ref placeholder) = self.placeholder {
+                    map.shift_remove(placeholder);
+                }
+
+                for key in self.keys.iter() {
+                    map.insert(key.clone(), string(&self.type_name));
+                }
+
+                for (key, typenames) in self.nested.iter() {
+                    if let Some(item) = map.get_mut(key) {
+                        typenames.inject_item(item);
+                    }
+                }
+            }
+            Item::List(items) => items.iter_mut().for_each(|item| self.inject_item(item)),
+            Item::Ref(item) => self.inject_item(Arc::make_mut(item)),
+            Item::Value(_) => (),
+        }
+    }
+}
+
+/// The object type of a field, if the field returns objects.
+fn object_type(output_type: &OutputType) -> Option<ObjectTypeStrongRef> {
+    match output_type {
+        OutputType::Object(obj) => Some(obj.into_arc()),
+        OutputType::List(inner) | OutputType::Opt(inner) => object_type(inner),
+        _ => None,
+    }
+}
+
+/// A reference to a type, e.g. in a field or argument type.
+#[derive(Debug, Clone)]
+enum TypeRef {
+    Named(String),
+    List(Box<TypeRef>),
+    NonNull(Box<TypeRef>),
+}
+
+impl TypeRef {
+    fn named<T: Into<String>>(name: T) -> Self {
+        TypeRef::NonNull(Box::new(TypeRef::Named(name.into())))
+    }
+
+    fn list(inner: TypeRef) -> Self {
+        TypeRef::NonNull(Box::new(TypeRef::List(Box::new(inner))))
+    }
+
+    fn nullable(self) -> Self {
+        match self {
+            TypeRef::NonNull(inner) => *inner,
+            other => other,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum TypeKind {
+    Scalar,
+    Object,
+    InputObject,
+    Enum,
+}
+
+impl TypeKind {
+    fn as_str(&self) -> &'static str {
+        match self {
+            TypeKind::Scalar => "SCALAR",
+            TypeKind::Object => "OBJECT",
+            TypeKind::InputObject => "INPUT_OBJECT",
+            TypeKind::Enum => "ENUM",
+        }
+    }
+}
+
+#[derive(Debug)]
+struct TypeDef {
+    kind: TypeKind,
+    fields: Vec<FieldDef>,
+    input_fields: Vec<InputValueDef>,
+    enum_values: Vec<String>,
+}
+
+impl TypeDef {
+    fn new(kind: TypeKind) -> Self {
+        Self {
+            kind,
+            fields: vec![],
+            input_fields: vec![],
+            enum_values: vec![],
+        }
+    }
+}
+
+#[derive(Debug)]
+struct FieldDef {
+    name: String,
+    args: Vec<InputValueDef>,
+    field_type: TypeRef,
+}
+
+#[derive(Debug)]
+struct InputValueDef {
+    name: String,
+    value_type: TypeRef,
+    default_value: Option<String>,
+}
+
+/// The GraphQL view on the query schema: all types reachable from the query and mutation type, by name.
+/// Types are mapped the same way the SDL renderer maps them.
+struct IntrospectionSchema {
+    types: BTreeMap<String, TypeDef>,
+}
+
+impl IntrospectionSchema {
+    fn new(query_schema: &QuerySchemaRef) -> Self {
+        let mut schema = Self { types: BTreeMap::new() };
+
+        for scalar in SCALARS {
+            schema
+                .types
+                .insert((*scalar).to_owned(), TypeDef::new(TypeKind::Scalar));
+        }
+
+        schema.collect_object(&query_schema.query());
+        schema.collect_object(&query_schema.mutation());
+        schema
+    }
+
+    fn collect_object(&mut self, obj: &ObjectTypeStrongRef) {
+        if self.types.contains_key(&obj.name) {
+            return;
+        }
+
+        // Registered before the fields are traversed to terminate on recursive types.
+        self.types.insert(obj.name.clone(), TypeDef::new(TypeKind::Object));
+
+        let fields = obj
+            .get_fields()
+            .iter()
+            .map(|field| FieldDef {
+                name: field.name.clone(),
+                args: field
+                    .arguments
+                    .iter()
+                    .map(|arg| InputValueDef {
+                        name: arg.name.clone(),
+                        value_type: self.input_type_ref(&arg.argument_type),
+                        default_value: arg.default_value.as_ref().and_then(render_value),
+                    })
+                    .collect(),
+                field_type: self.output_type_ref(&field.field_type),
+            })
+            .collect();
+
+        self.types.get_mut(&obj.name).unwrap().fields = fields;
+    }
+
+    fn collect_input_object(&mut self, obj: &InputObjectTypeStrongRef) {
+        if self.types.contains_key(&obj.name) {
+            return;
+        }
+
+        self.types.insert(obj.name.clone(), TypeDef::new(TypeKind::InputObject));
+
+        let input_fields = obj
+            .get_fields()
+            .iter()
+            .map(|field| InputValueDef {
+                name: field.name.clone(),
+                value_type: self.input_type_ref(&field.field_type),
+                default_value: field.default_value.as_ref().and_then(render_value),
+            })
+            .collect();
+
+        self.types.get_mut(&obj.name).unwrap().input_fields = input_fields;
+    }
+
+    fn collect_enum(&mut self, et: &EnumTypeRef) {
+        if !self.types.contains_key(&et.name) {
+            let mut def = TypeDef::new(TypeKind::Enum);
+            def.enum_values = et.values.iter().map(|v| v.as_string()).collect();
+
+            self.types.insert(et.name.clone(), def);
+        }
+    }
+
+    fn output_type_ref(&mut self, o: &OutputType) -> TypeRef {
+        match o {
+            OutputType::Object(obj) => {
+                let obj = obj.into_arc();
+                self.collect_object(&obj);
+                TypeRef::named(obj.name.as_str())
+            }
+            OutputType::Enum(et) | OutputType::Scalar(ScalarType::Enum(et)) => {
+                self.collect_enum(et);
+                TypeRef::named(et.name.as_str())
+            }
+            OutputType::List(inner) => TypeRef::list(self.output_type_ref(inner)),
+            OutputType::Opt(inner) => self.output_type_ref(inner).nullable(),
+            OutputType::Scalar(scalar) => TypeRef::named(scalar_name(scalar)),
+        }
+    }
+
+    fn input_type_ref(&mut self, i: &InputType) -> TypeRef {
+        match i {
+            InputType::Object(obj) => {
+                let obj = obj.into_arc();
+                self.collect_input_object(&obj);
+                TypeRef::named(obj.name.as_str())
+            }
+            InputType::Enum(et) | InputType::Scalar(ScalarType::Enum(et)) => {
+                self.collect_enum(et);
+                TypeRef::named(et.name.as_str())
+            }
+            InputType::List(inner) => TypeRef::list(self.input_type_ref(inner)),
+            InputType::Opt(inner) => self.input_type_ref(inner).nullable(),
+            InputType::Scalar(scalar) => TypeRef::named(scalar_name(scalar)),
+        }
+    }
+
+    /// Resolves a selection on `__Schema`.
+    fn resolve_schema(&self, selections: &[Selection]) -> PrismaResult<Item> {
+        resolve_object("__Schema", selections, |selection| {
+            let sub_selections = &selection.sub_selections;
+
+            match selection.name.as_str() {
+                "description" | "subscriptionType" => Ok(null()),
+                "types" => self
+                    .types
+                    .keys()
+                    .map(|name| self.resolve_type(&TypeRef::Named(name.clone()), sub_selections))
+                    .collect::<PrismaResult<Vec<Item>>>()
+                    .map(Item::List),
+                "queryType" => self.resolve_type(&TypeRef::Named("Query".to_owned()), sub_selections),
+                "mutationType" => self.resolve_type(&TypeRef::Named("Mutation".to_owned()), sub_selections),
+                "directives" => ["include", "skip"]
+                    .iter()
+                    .map(|name| self.resolve_directive(name, sub_selections))
+                    .collect::<PrismaResult<Vec<Item>>>()
+                    .map(Item::List),
+                name => Err(unknown_field("__Schema", name)),
+            }
+        })
+    }
+
+    /// Resolves a selection on the `__Type` with the given name, or null if the type doesn't exist.
+    fn resolve_named_type(&self, name: &str, selections: &[Selection]) -> PrismaResult<Item> {
+        if self.types.contains_key(name) {
+            self.resolve_type(&TypeRef::Named(name.to_owned()), selections)
+        } else {
+            Ok(null())
+        }
+    }
+
+    /// Resolves a selection on `__Type`.
+    fn resolve_type(&self, type_ref: &TypeRef, selections: &[Selection]) -> PrismaResult<Item> {
+        let def = match type_ref {
+            TypeRef::Named(name) => self.types.get(name),
+            _ => None,
+        };
+
+        resolve_object("__Type", selections, |selection| {
+            let sub_selections = &selection.sub_selections;
+
+            match (selection.name.as_str(), type_ref, def) {
+                ("kind", TypeRef::List(_), _) => Ok(string("LIST")),
+                ("kind", TypeRef::NonNull(_), _) => Ok(string("NON_NULL")),
+                ("kind", _, Some(def)) => Ok(string(def.kind.as_str())),
+                ("name", TypeRef::Named(name), _) => Ok(string(name)),
+                ("ofType", TypeRef::List(inner), _) | ("ofType", TypeRef::NonNull(inner), _) => {
+                    self.resolve_type(inner, sub_selections)
+                }
+                ("fields", _, Some(def)) if def.kind == TypeKind::Object => def
+                    .fields
+                    .iter()
+                    .map(|field| self.resolve_field(field, sub_selections))
+                    .collect::<PrismaResult<Vec<Item>>>()
+                    .map(Item::List),
+                ("inputFields", _, Some(def)) if def.kind == TypeKind::InputObject => def
+                    .input_fields
+                    .iter()
+                    .map(|value| self.resolve_input_value(value, sub_selections))
+                    .collect::<PrismaResult<Vec<Item>>>()
+                    .map(Item::List),
+                ("enumValues", _, Some(def)) if def.kind == TypeKind::Enum => def
+                    .enum_values
+                    .iter()
+                    .map(|value| resolve_enum_value(value, sub_selections))
+                    .collect::<PrismaResult<Vec<Item>>>()
+                    .map(Item::List),
+                ("interfaces", _, Some(def)) if def.kind == TypeKind::Object => Ok(Item::List(vec![])),
+                ("kind", _, _)
+                | ("name", _, _)
+                | ("description", _, _)
+                | ("ofType", _, _)
+                | ("fields", _, _)
+                | ("inputFields", _, _)
+                | ("enumValues", _, _)
+                | ("interfaces", _, _)
+                | ("possibleTypes", _, _) => Ok(null()),
+                (name, _, _) => Err(unknown_field("__Type", name)),
+            }
+        })
+    }
+
+    /// Resolves a selection on `__Field`.
+    fn resolve_field(&self, field: &FieldDef, selections: &[Selection]) -> PrismaResult<Item> {
+        resolve_object("__Field", selections, |selection| {
+            let sub_selections = &selection.sub_selections;
+
+            match selection.name.as_str() {
+                "name" => Ok(string(&field.name)),
+                "description" | "deprecationReason" => Ok(null()),
+                "isDeprecated" => Ok(Item::Value(PrismaValue::Boolean(false))),
+                "type" => self.resolve_type(&field.field_type, sub_selections),
+                "args" => field
+                    .args
+                    .iter()
+                    .map(|arg| self.resolve_input_value(arg, sub_selections))
+                    .collect::<PrismaResult<Vec<Item>>>()
+                    .map(Item::List),
+                name => Err(unknown_field("__Field", name)),
+            }
+        })
+    }
+
+    /// Resolves a selection on `__InputValue`.
+    fn resolve_input_value(&self, value: &InputValueDef, selections: &[Selection]) -> PrismaResult<Item> {
+        resolve_object("__InputValue", selections, |selection| match selection.name.as_str() {
+            "name" => Ok(string(&value.name)),
+            "description" => Ok(null()),
+            "type" => self.resolve_type(&value.value_type, &selection.sub_selections),
+            "defaultValue" => Ok(value.default_value.as_ref().map(|v| string(v)).unwrap_or_else(null)),
+            name => Err(unknown_field("__InputValue", name)),
+        })
+    }
+
+    /// Resolves a selection on `__Directive`. Only `@include` and `@skip` are supported, which share a signature.
+    fn resolve_directive(&self, name: &str, selections: &[Selection]) -> PrismaResult<Item> {
+        let condition = InputValueDef {
+            name: "if".to_owned(),
+            value_type: TypeRef::named("Boolean"),
+            default_value: None,
+        };
+
+        resolve_object("__Directive", selections, |selection| {
+            let sub_selections = &selection.sub_selections;
+
+            match selection.name.as_str() {
+                "name" => Ok(string(name)),
+                "description" => Ok(null()),
+                "locations" => Ok(Item::List(
+                    ["FIELD", "FRAGMENT_SPREAD", "INLINE_FRAGMENT"]
+                        .iter()
+                        .map(|location| string(location))
+                        .collect(),
+                )),
+                "args" => Ok(Item::List(vec![self.resolve_input_value(&condition, sub_selections)?])),
+                name => Err(unknown_field("__Directive", name)),
+            }
+        })
+    }
+}
+
+/// Resolves a selection on `__EnumValue`.
+fn resolve_enum_value(value: &str, selections: &[Selection]) -> PrismaResult<Item> {
+    resolve_object("__EnumValue", selections, |selection| match selection.name.as_str() {
+        "name" => Ok(string(value)),
+        "description" | "deprecationReason" => Ok(null()),
+        "isDeprecated" => Ok(Item::Value(PrismaValue::Boolean(false))),
+        name => Err(unknown_field("__EnumValue", name)),
+    })
+}
+
+/// Resolves the given selections on an introspection type, using `resolve_field` for all fields but `__typename`.
+fn resolve_object<F>(type_name: &str, selections: &[Selection], resolve_field: F) -> PrismaResult<Item>
+where
+    F: Fn(&Selection) -> PrismaResult<Item>,
+{
+    let mut map = IndexMap::new();
+
+    for selection in selections {
+        let item = match selection.name.as_str() {
+            "__typename" => string(type_name),
+            _ => resolve_field(selection)?,
+        };
+
+        let key = selection.alias.as_ref().unwrap_or(&selection.name);
+        map.insert(key.clone(), item);
+    }
+
+    Ok(Item::Map(map))
+}
+
+fn scalar_name(scalar: &ScalarType) -> &'static str {
+    match scalar {
+        ScalarType::String => "String",
+        ScalarType::Int => "Int",
+        ScalarType::Boolean => "Boolean",
+        ScalarType::Float => "Float",
+        ScalarType::Decimal => "Decimal",
+        ScalarType::DateTime => "DateTime",
+        ScalarType::Json => "Json",
+        ScalarType::ID => "ID",
+        ScalarType::UUID => "UUID",
+        ScalarType::Bytes => "Bytes",
+        ScalarType::Enum(_) => unreachable!(), // Handled separately as enum type.
+    }
+}
+
+/// Renders a default value as GraphQL literal. Values without a literal representation are omitted.
+fn render_value(value: &PrismaValue) -> Option<String> {
+    match value {
+        PrismaValue::String(s) => Some(serde_json::Value::String(s.clone()).to_string()),
+        PrismaValue::Int(i) => Some(i.to_string()),
+        PrismaValue::Float(f) => Some(f.to_string()),
+        PrismaValue::Boolean(b) => Some(b.to_string()),
+        PrismaValue::Enum(e) => Some(e.as_string()),
+        PrismaValue::Null => Some("null".to_owned()),
+        _ => None,
+    }
+}
+
+fn unknown_field(type_name: &str, field: &str) -> PrismaError {
+    PrismaError::QueryValidationError(format!("Field '{}' does not exist on type '{}'.", field, type_name))
+}
+
+fn string(s: &str) -> Item {
+    Item::Value(PrismaValue::String(s.to_owned()))
+}
+
+fn null() -> Item {
+    Item::Value(PrismaValue::Null)
+}
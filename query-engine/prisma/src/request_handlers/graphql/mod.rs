@@ -1,6 +1,10 @@
 mod handler;
+mod introspection;
 mod protocol_adapter;
 mod schema_renderer;
 
 pub use handler::*;
 pub use schema_renderer::*;
+
+#[cfg(test)]
+mod tests;
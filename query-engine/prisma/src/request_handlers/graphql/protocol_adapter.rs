@@ -2,9 +2,17 @@ use crate::error::PrismaError::QueryValidationError;
 use crate::{error::PrismaError, PrismaResult};
 use core::query_document::*;
 use graphql_parser::query::{
-    Definition, Document, OperationDefinition, Selection as GqlSelection, SelectionSet, Value,
+    Definition, Directive, Document, FragmentDefinition, OperationDefinition, Selection as GqlSelection, SelectionSet,
+    Type, Value, VariableDefinition,
 };
-use std::collections::BTreeMap;
+use serde_json::Value as JsonValue;
+use std::collections::{BTreeMap, HashMap};
+
+/// The values of the variables declared by an operation, coerced against their declared types.
+type Variables = HashMap<String, QueryValue>;
+
+/// The fragment definitions of a GraphQL document, by name.
+type Fragments = HashMap<String, FragmentDefinition>;
 
 /// Protocol adapter for GraphQL -> Query Document.
 ///
@@ -15,115 +23,278 @@ use std::collections::BTreeMap;
 /// - If the JSON payload specifies an operation name, only that specific operation is picked and the rest ignored.
 /// - Fields on the queries are mapped to `Selection`s, including arguments.
 /// - Concrete values (e.g. in arguments) are mapped to `QueryValue`s.
+/// - Variables are coerced against their declared types and substituted where they are used, as are the
+///   default values of variables. Enums, input objects and custom scalars are validated against the query
+///   schema afterwards. `Json` variables are passed on serialized, like JSON literals are written.
+/// - Fragment spreads and inline fragments are expanded in place. The query schema has no interfaces
+///   or unions, so type conditions are not checked. Fields selected more than once are merged.
+/// - The `@skip` and `@include` directives are evaluated, excluded selections are dropped.
 ///
 /// Currently unsupported features:
-/// - Fragments in any form.
-/// - Variables.
 /// - Subscription queries.
+/// - Directives other than `@skip` and `@include`.
 ///
 pub struct GraphQLProtocolAdapter;
 
 impl GraphQLProtocolAdapter {
-    pub fn convert(gql_doc: Document, operation: Option<String>) -> PrismaResult<QueryDocument> {
+    pub fn convert(
+        gql_doc: Document,
+        operation: Option<String>,
+        variables: &HashMap<String, JsonValue>,
+    ) -> PrismaResult<QueryDocument> {
+        let mut fragments = Fragments::new();
+        let mut definitions = vec![];
+
+        for def in gql_doc.definitions {
+            match def {
+                Definition::Fragment(f) => {
+                    fragments.insert(f.name.clone(), f);
+                }
+                Definition::Operation(op) => definitions.push(op),
+            }
+        }
+
         let operations: Vec<Operation> = match operation {
-            Some(ref op) => gql_doc
-                .definitions
+            Some(ref op) => definitions
                 .into_iter()
                 .find(|def| Self::matches_operation(def, op))
-                .ok_or_else(|| QueryValidationError(format!(
-                    "Operation '{}' does not match any query.",
-                    op
-                )))
-                .and_then(|def| Self::convert_definition(def).map(|r| vec![r])),
-
-            None => gql_doc
-                .definitions
+                .ok_or_else(|| QueryValidationError(format!("Operation '{}' does not match any query.", op)))
+                .and_then(|def| Self::convert_definition(def, variables, &fragments).map(|r| vec![r])),
+
+            None => definitions
                 .into_iter()
-                .map(Self::convert_definition)
+                .map(|def| Self::convert_definition(def, variables, &fragments))
                 .collect::<PrismaResult<Vec<Operation>>>(),
         }?;
 
         Ok(QueryDocument { operations })
     }
 
-    fn convert_definition(def: Definition) -> PrismaResult<Operation> {
+    fn convert_definition(
+        def: OperationDefinition,
+        variables: &HashMap<String, JsonValue>,
+        fragments: &Fragments,
+    ) -> PrismaResult<Operation> {
         match def {
-            Definition::Fragment(f) => Err(PrismaError::UnsupportedFeatureError(
-                "Fragment definition",
-                format!("Fragment '{}', at position {}.", f.name, f.position),
+            OperationDefinition::Subscription(s) => Err(PrismaError::UnsupportedFeatureError(
+                "Subscription query",
+                format!("At position {}.", s.position),
             )),
-            Definition::Operation(op) => match op {
-                OperationDefinition::Subscription(s) => Err(PrismaError::UnsupportedFeatureError(
-                    "Subscription query",
-                    format!("At position {}.", s.position),
-                )),
-                OperationDefinition::SelectionSet(s) => Self::convert_query(None, s),
-                OperationDefinition::Query(q) => Self::convert_query(q.name, q.selection_set),
-                OperationDefinition::Mutation(m) => Self::convert_mutation(m.name, m.selection_set),
-            },
+            OperationDefinition::SelectionSet(s) => Self::convert_query(None, s, &Variables::new(), fragments),
+            OperationDefinition::Query(q) => {
+                let variables = Self::coerce_variables(&q.variable_definitions, variables)?;
+                Self::check_directives(&q.directives)?;
+                Self::convert_query(q.name, q.selection_set, &variables, fragments)
+            }
+            OperationDefinition::Mutation(m) => {
+                let variables = Self::coerce_variables(&m.variable_definitions, variables)?;
+                Self::check_directives(&m.directives)?;
+                Self::convert_mutation(m.name, m.selection_set, &variables, fragments)
+            }
         }
     }
 
-    fn convert_query(name: Option<String>, selection_set: SelectionSet) -> PrismaResult<Operation> {
-        Self::convert_selection_set(selection_set).map(|selections| Operation::Read(ReadOperation { name, selections }))
+    fn convert_query(
+        name: Option<String>,
+        selection_set: SelectionSet,
+        variables: &Variables,
+        fragments: &Fragments,
+    ) -> PrismaResult<Operation> {
+        Self::convert_selection_set(selection_set, variables, fragments, &[])
+            .map(|selections| Operation::Read(ReadOperation { name, selections }))
     }
 
-    fn convert_mutation(name: Option<String>, selection_set: SelectionSet) -> PrismaResult<Operation> {
-        Self::convert_selection_set(selection_set)
+    fn convert_mutation(
+        name: Option<String>,
+        selection_set: SelectionSet,
+        variables: &Variables,
+        fragments: &Fragments,
+    ) -> PrismaResult<Operation> {
+        Self::convert_selection_set(selection_set, variables, fragments, &[])
             .map(|selections| Operation::Write(WriteOperation { name, selections }))
     }
 
-    fn convert_selection_set(selection_set: SelectionSet) -> PrismaResult<Vec<Selection>> {
-        selection_set
-            .items
-            .into_iter()
-            .map(|item| match item {
+    /// Converts a selection set, expanding fragments and dropping selections excluded by directives.
+    /// `spreads` holds the names of the fragments that are currently being expanded, to detect cycles.
+    fn convert_selection_set(
+        selection_set: SelectionSet,
+        variables: &Variables,
+        fragments: &Fragments,
+        spreads: &[String],
+    ) -> PrismaResult<Vec<Selection>> {
+        let mut selections = vec![];
+
+        for item in selection_set.items {
+            match item {
                 GqlSelection::Field(f) => {
+                    if !Self::is_included(&f.directives, variables)? {
+                        continue;
+                    }
+
                     let arguments: Vec<(String, QueryValue)> = f
                         .arguments
                         .into_iter()
-                        .map(|(k, v)| Ok((k, Self::convert_value(v)?)))
+                        .map(|(k, v)| Ok((k, Self::convert_value(v, variables)?)))
                         .collect::<PrismaResult<Vec<_>>>()?;
 
-                    Ok(Selection {
+                    selections.push(Selection {
                         name: f.name,
                         alias: f.alias,
                         arguments,
-                        sub_selections: Self::convert_selection_set(f.selection_set)?,
-                    })
+                        sub_selections: Self::convert_selection_set(f.selection_set, variables, fragments, spreads)?,
+                    });
                 }
-                GqlSelection::FragmentSpread(fs) => Err(PrismaError::UnsupportedFeatureError(
-                    "Fragment spread",
-                    format!("Fragment '{}', at position {}.", fs.fragment_name, fs.position),
-                )),
-                GqlSelection::InlineFragment(i) => Err(PrismaError::UnsupportedFeatureError(
-                    "Inline fragment",
-                    format!("At position {}.", i.position),
-                )),
-            })
-            .collect()
+                GqlSelection::FragmentSpread(fs) => {
+                    if !Self::is_included(&fs.directives, variables)? {
+                        continue;
+                    }
+
+                    if spreads.contains(&fs.fragment_name) {
+                        return Err(QueryValidationError(format!(
+                            "Fragment '{}' spreads itself, at position {}.",
+                            fs.fragment_name, fs.position
+                        )));
+                    }
+
+                    let fragment = fragments.get(&fs.fragment_name).ok_or_else(|| {
+                        QueryValidationError(format!(
+                            "Fragment '{}' is not defined, at position {}.",
+                            fs.fragment_name, fs.position
+                        ))
+                    })?;
+
+                    Self::check_directives(&fragment.directives)?;
+
+                    let mut spreads = spreads.to_vec();
+                    spreads.push(fs.fragment_name);
+
+                    selections.extend(Self::convert_selection_set(
+                        fragment.selection_set.clone(),
+                        variables,
+                        fragments,
+                        &spreads,
+                    )?);
+                }
+                GqlSelection::InlineFragment(i) => {
+                    if !Self::is_included(&i.directives, variables)? {
+                        continue;
+                    }
+
+                    selections.extend(Self::convert_selection_set(
+                        i.selection_set,
+                        variables,
+                        fragments,
+                        spreads,
+                    )?);
+                }
+            }
+        }
+
+        Self::merge_selections(selections)
+    }
+
+    /// Merges selections with the same response key (alias or name), e.g. a field that is selected
+    /// directly and again through a fragment. The sub selections of merged fields are merged as well.
+    /// Selections of the same key must select the same field with the same arguments.
+    fn merge_selections(selections: Vec<Selection>) -> PrismaResult<Vec<Selection>> {
+        let mut merged: Vec<Selection> = vec![];
+
+        for selection in selections {
+            match merged
+                .iter_mut()
+                .find(|s| Self::response_key(s) == Self::response_key(&selection))
+            {
+                Some(existing) => {
+                    if existing.name != selection.name || !Self::same_arguments(existing, &selection) {
+                        return Err(QueryValidationError(format!(
+                            "Fields '{}' and '{}' conflict for response key '{}', they differ in field or arguments.",
+                            existing.name,
+                            selection.name,
+                            Self::response_key(&selection)
+                        )));
+                    }
+
+                    let mut sub_selections = std::mem::replace(&mut existing.sub_selections, vec![]);
+                    sub_selections.extend(selection.sub_selections);
+                    existing.sub_selections = Self::merge_selections(sub_selections)?;
+                }
+                None => merged.push(selection),
+            }
+        }
+
+        Ok(merged)
+    }
+
+    /// Arguments are compared regardless of their order.
+    fn same_arguments(a: &Selection, b: &Selection) -> bool {
+        a.arguments.len() == b.arguments.len()
+            && a.arguments
+                .iter()
+                .all(|(name, value)| b.arguments.iter().any(|(n, v)| n == name && v == value))
+    }
+
+    fn response_key(selection: &Selection) -> &str {
+        selection.alias.as_ref().unwrap_or(&selection.name)
     }
 
-    /// Checks if the given GraphQL definition matches the operation name that should be executed.
-    fn matches_operation(def: &Definition, operation: &str) -> bool {
+    /// Evaluates the `@skip` and `@include` directives of a selection.
+    fn is_included(directives: &[Directive], variables: &Variables) -> PrismaResult<bool> {
+        Self::check_directives(directives)?;
+
+        let mut included = true;
+
+        for directive in directives {
+            let condition = match directive.arguments.iter().find(|(name, _)| name == "if") {
+                Some((_, value)) => Self::convert_value(value.clone(), variables)?,
+                None => {
+                    return Err(QueryValidationError(format!(
+                        "Directive '@{}' requires an 'if' argument, at position {}.",
+                        directive.name, directive.position
+                    )))
+                }
+            };
+
+            match condition {
+                QueryValue::Boolean(b) => included &= b == (directive.name == "include"),
+                other => {
+                    return Err(QueryValidationError(format!(
+                        "Argument 'if' of directive '@{}' must be a Boolean, found {:?}, at position {}.",
+                        directive.name, other, directive.position
+                    )))
+                }
+            };
+        }
+
+        Ok(included)
+    }
+
+    fn check_directives(directives: &[Directive]) -> PrismaResult<()> {
+        match directives.iter().find(|d| d.name != "skip" && d.name != "include") {
+            Some(d) => Err(PrismaError::UnsupportedFeatureError(
+                "Directive",
+                format!("Directive '@{}', at position {}.", d.name, d.position),
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// Checks if the given GraphQL operation matches the operation name that should be executed.
+    fn matches_operation(def: &OperationDefinition, operation: &str) -> bool {
         let check = |n: Option<&String>| n.filter(|name| name.as_str() == operation).is_some();
         match def {
-            Definition::Fragment(_) => false,
-            Definition::Operation(op) => match op {
-                OperationDefinition::Subscription(s) => check(s.name.as_ref()),
-                OperationDefinition::SelectionSet(_) => false,
-                OperationDefinition::Query(q) => check(q.name.as_ref()),
-                OperationDefinition::Mutation(m) => check(m.name.as_ref()),
-            },
+            OperationDefinition::Subscription(s) => check(s.name.as_ref()),
+            OperationDefinition::SelectionSet(_) => false,
+            OperationDefinition::Query(q) => check(q.name.as_ref()),
+            OperationDefinition::Mutation(m) => check(m.name.as_ref()),
         }
     }
 
-    fn convert_value(value: Value) -> PrismaResult<QueryValue> {
+    fn convert_value(value: Value, variables: &Variables) -> PrismaResult<QueryValue> {
         match value {
-            Value::Variable(name) => Err(PrismaError::UnsupportedFeatureError(
-                "Variable usage",
-                format!("Variable '{}'.", name),
-            )),
+            Value::Variable(name) => variables
+                .get(&name)
+                .cloned()
+                .ok_or_else(|| QueryValidationError(format!("Variable '${}' is not defined by the operation.", name))),
             Value::Int(i) => match i.as_i64() {
                 Some(i) => Ok(QueryValue::Int(i)),
                 None => Err(PrismaError::QueryValidationError(format!(
@@ -139,7 +310,7 @@ impl GraphQLProtocolAdapter {
             Value::List(values) => {
                 let values: Vec<QueryValue> = values
                     .into_iter()
-                    .map(Self::convert_value)
+                    .map(|value| Self::convert_value(value, variables))
                     .collect::<PrismaResult<Vec<QueryValue>>>()?;
 
                 Ok(QueryValue::List(values))
@@ -147,11 +318,171 @@ impl GraphQLProtocolAdapter {
             Value::Object(map) => {
                 let values = map
                     .into_iter()
-                    .map(|(k, v)| Self::convert_value(v).map(|v| (k, v)))
+                    .map(|(k, v)| Self::convert_value(v, variables).map(|v| (k, v)))
                     .collect::<PrismaResult<BTreeMap<String, QueryValue>>>()?;
 
                 Ok(QueryValue::Object(values))
             }
         }
     }
+
+    /// Coerces the given variable values against the variables declared by an operation.
+    /// Variables that are not provided fall back to their default value, if any.
+    fn coerce_variables(
+        definitions: &[VariableDefinition],
+        values: &HashMap<String, JsonValue>,
+    ) -> PrismaResult<Variables> {
+        definitions
+            .iter()
+            .map(|definition| {
+                let path = format!("${}", definition.name);
+                let value = match (values.get(&definition.name), &definition.default_value) {
+                    (Some(value), _) => Self::coerce_variable(value, &definition.var_type, &path)?,
+                    (None, Some(default)) => {
+                        let default = Self::default_json_value(default, &path)?;
+                        Self::coerce_variable(&default, &definition.var_type, &path)?
+                    }
+                    (None, None) => match definition.var_type {
+                        Type::NonNullType(_) => {
+                            return Err(PrismaError::VariableValidationError {
+                                message: format!(
+                                    "Variable '{}' of required type '{}' was not provided.",
+                                    path,
+                                    Self::type_name(&definition.var_type)
+                                ),
+                                path,
+                            })
+                        }
+                        _ => QueryValue::Null,
+                    },
+                };
+
+                Ok((definition.name.clone(), value))
+            })
+            .collect()
+    }
+
+    /// Coerces a JSON value against a declared variable type.
+    /// The path designates the position of the value within the variable, e.g. `$ids[1]`.
+    fn coerce_variable(value: &JsonValue, var_type: &Type, path: &str) -> PrismaResult<QueryValue> {
+        match (var_type, value) {
+            (Type::NonNullType(_), JsonValue::Null) => Err(Self::invalid_variable(path, var_type, value)),
+            (Type::NonNullType(inner), _) => Self::coerce_variable(value, inner, path),
+            (_, JsonValue::Null) => Ok(QueryValue::Null),
+            (Type::ListType(inner), JsonValue::Array(values)) => values
+                .iter()
+                .enumerate()
+                .map(|(i, value)| Self::coerce_variable(value, inner, &format!("{}[{}]", path, i)))
+                .collect::<PrismaResult<Vec<QueryValue>>>()
+                .map(QueryValue::List),
+            // Like GraphQL input coercion, a single value is accepted as a list of one.
+            (Type::ListType(inner), _) => {
+                Self::coerce_variable(value, inner, path).map(|value| QueryValue::List(vec![value]))
+            }
+            (Type::NamedType(name), _) => match (name.as_str(), value) {
+                ("Int", JsonValue::Number(n)) | ("ID", JsonValue::Number(n)) if n.is_i64() => {
+                    Ok(QueryValue::Int(n.as_i64().unwrap()))
+                }
+                ("Float", JsonValue::Number(n)) => n
+                    .as_f64()
+                    .map(QueryValue::Float)
+                    .ok_or_else(|| Self::invalid_variable(path, var_type, value)),
+                ("String", JsonValue::String(s)) | ("ID", JsonValue::String(s)) => Ok(QueryValue::String(s.clone())),
+                ("Boolean", JsonValue::Bool(b)) => Ok(QueryValue::Boolean(*b)),
+                ("Int", _) | ("Float", _) | ("String", _) | ("ID", _) | ("Boolean", _) => {
+                    Err(Self::invalid_variable(path, var_type, value))
+                }
+
+                // Any JSON value is valid, serialized it can't be mistaken for a JSON literal string.
+                ("Json", _) => Ok(QueryValue::String(value.to_string())),
+
+                // Enums, input objects and custom scalars are validated by the query builder, which takes the
+                // strings of these variables for enum values where the query schema expects an enum.
+                _ => Ok(Self::convert_json_value(value)),
+            },
+        }
+    }
+
+    fn convert_json_value(value: &JsonValue) -> QueryValue {
+        match value {
+            JsonValue::Null => QueryValue::Null,
+            JsonValue::Bool(b) => QueryValue::Boolean(*b),
+            JsonValue::Number(n) => match n.as_i64() {
+                Some(i) => QueryValue::Int(i),
+                None => QueryValue::Float(n.as_f64().unwrap_or_default()),
+            },
+            JsonValue::String(s) => QueryValue::VariableString(s.clone()),
+            JsonValue::Array(values) => QueryValue::List(values.iter().map(Self::convert_json_value).collect()),
+            JsonValue::Object(map) => QueryValue::Object(
+                map.iter()
+                    .map(|(k, v)| (k.clone(), Self::convert_json_value(v)))
+                    .collect(),
+            ),
+        }
+    }
+
+    /// The JSON representation of the default value of a variable, to coerce it like a provided value.
+    /// Enum values become strings, like enum values of provided variables.
+    fn default_json_value(value: &Value, path: &str) -> PrismaResult<JsonValue> {
+        let value = match value {
+            Value::Int(i) => match i.as_i64() {
+                Some(i) => JsonValue::from(i),
+                None => {
+                    return Err(PrismaError::VariableValidationError {
+                        path: path.to_owned(),
+                        message: format!(
+                            "The default value of variable '{}' is not a valid 64 bit integer: {:?}.",
+                            path, i
+                        ),
+                    })
+                }
+            },
+            Value::Float(f) => JsonValue::from(*f),
+            Value::String(s) | Value::Enum(s) => JsonValue::String(s.clone()),
+            Value::Boolean(b) => JsonValue::Bool(*b),
+            Value::Null => JsonValue::Null,
+            Value::List(values) => JsonValue::Array(
+                values
+                    .iter()
+                    .map(|value| Self::default_json_value(value, path))
+                    .collect::<PrismaResult<Vec<JsonValue>>>()?,
+            ),
+            Value::Object(map) => JsonValue::Object(
+                map.iter()
+                    .map(|(k, v)| Self::default_json_value(v, path).map(|v| (k.clone(), v)))
+                    .collect::<PrismaResult<serde_json::Map<String, JsonValue>>>()?,
+            ),
+            Value::Variable(name) => {
+                return Err(PrismaError::VariableValidationError {
+                    path: path.to_owned(),
+                    message: format!(
+                        "The default value of variable '{}' can't use variable '${}'.",
+                        path, name
+                    ),
+                })
+            }
+        };
+
+        Ok(value)
+    }
+
+    fn invalid_variable(path: &str, var_type: &Type, value: &JsonValue) -> PrismaError {
+        PrismaError::VariableValidationError {
+            path: path.to_owned(),
+            message: format!(
+                "Invalid value for variable '{}': expected type '{}', found {}.",
+                path,
+                Self::type_name(var_type),
+                value
+            ),
+        }
+    }
+
+    fn type_name(var_type: &Type) -> String {
+        match var_type {
+            Type::NamedType(name) => name.clone(),
+            Type::ListType(inner) => format!("[{}]", Self::type_name(inner)),
+            Type::NonNullType(inner) => format!("{}!", Self::type_name(inner)),
+        }
+    }
 }
@@ -51,10 +51,12 @@ impl<'a> GqlTypeRenderer<'a> {
                     ScalarType::Int => "Int",
                     ScalarType::Boolean => "Boolean",
                     ScalarType::Float => "Float",
+                    ScalarType::Decimal => "Decimal",
                     ScalarType::DateTime => "DateTime",
-                    ScalarType::Json => "DateTime",
+                    ScalarType::Json => "Json",
                     ScalarType::ID => "ID",
                     ScalarType::UUID => "UUID",
+                    ScalarType::Bytes => "Bytes",
                     ScalarType::Enum(_) => unreachable!(), // Handled separately above.
                 };
 
@@ -92,10 +94,12 @@ impl<'a> GqlTypeRenderer<'a> {
                     ScalarType::Int => "Int",
                     ScalarType::Boolean => "Boolean",
                     ScalarType::Float => "Float",
+                    ScalarType::Decimal => "Decimal",
                     ScalarType::DateTime => "DateTime",
-                    ScalarType::Json => "DateTime",
+                    ScalarType::Json => "Json",
                     ScalarType::ID => "ID",
                     ScalarType::UUID => "UUID",
+                    ScalarType::Bytes => "Bytes",
                     ScalarType::Enum(_) => unreachable!(), // Handled separately above.
                 };
 
@@ -0,0 +1,404 @@
+use super::{
+    handler::{order_responses, response_keys, rolled_back_batch},
+    introspection::answer_introspection,
+    protocol_adapter::GraphQLProtocolAdapter,
+    GraphQlRequestBody,
+};
+use crate::{serializers::json, PrismaError, PrismaResult};
+use core::{
+    query_builders::QueryBuilder,
+    query_document::*,
+    response_ir::{Item, Response},
+    BuildMode, QuerySchemaBuilder, QuerySchemaRef, SupportedCapabilities,
+};
+use graphql_parser as gql;
+use indexmap::IndexMap;
+use prisma_models::{DatamodelConverter, PrismaValue};
+use serde_json::{json, Value};
+use std::{collections::HashMap, sync::Arc};
+
+#[test]
+fn fragment_spreads_must_be_expanded_in_place() {
+    let doc = convert(
+        r#"
+        query { findManyUser { ...userFields } }
+        fragment userFields on User { id name }
+        "#,
+        json!({}),
+    )
+    .unwrap();
+
+    assert_eq!(sub_selection_names(&doc), vec!["id", "name"]);
+}
+
+#[test]
+fn fields_selected_again_in_inline_fragments_must_be_merged() {
+    let doc = convert("{ findManyUser { id ... on User { id name } } }", json!({})).unwrap();
+
+    assert_eq!(sub_selection_names(&doc), vec!["id", "name"]);
+}
+
+#[test]
+fn fragments_spreading_themselves_must_be_rejected() {
+    let err = convert(
+        r#"
+        query { findManyUser { ...a } }
+        fragment a on User { id ...b }
+        fragment b on User { name ...a }
+        "#,
+        json!({}),
+    )
+    .unwrap_err();
+
+    assert!(format!("{}", err).contains("Fragment 'a' spreads itself"));
+}
+
+#[test]
+fn undefined_fragments_must_be_rejected() {
+    let err = convert("{ findManyUser { ...missing } }", json!({})).unwrap_err();
+
+    assert!(format!("{}", err).contains("Fragment 'missing' is not defined"));
+}
+
+#[test]
+fn skip_and_include_must_drop_excluded_selections() {
+    let query = r#"
+        query($skipName: Boolean!) {
+            findManyUser { id name @skip(if: $skipName) posts @include(if: false) { id } }
+        }
+    "#;
+
+    let doc = convert(query, json!({ "skipName": true })).unwrap();
+    assert_eq!(sub_selection_names(&doc), vec!["id"]);
+
+    let doc = convert(query, json!({ "skipName": false })).unwrap();
+    assert_eq!(sub_selection_names(&doc), vec!["id", "name"]);
+}
+
+#[test]
+fn fields_with_the_same_response_key_must_not_conflict() {
+    let err = convert("{ findManyUser { a: id a: name } }", json!({})).unwrap_err();
+    assert!(format!("{}", err).contains("conflict for response key 'a'"));
+
+    let err = convert(
+        "{ users: findManyUser(first: 1) { id } users: findManyUser(first: 2) { id } }",
+        json!({}),
+    )
+    .unwrap_err();
+    assert!(format!("{}", err).contains("conflict for response key 'users'"));
+}
+
+#[test]
+fn json_variables_must_be_passed_serialized() {
+    let query = "query($filter: Json) { findManyUser(where: $filter) { id } }";
+    let doc = convert(query, json!({ "filter": "not an object" })).unwrap();
+
+    match root_selection(&doc).arguments.as_slice() {
+        [(name, QueryValue::String(s))] => {
+            assert_eq!(name, "where");
+            assert_eq!(s, "\"not an object\"");
+        }
+        args => panic!("Unexpected arguments {:?}", args),
+    }
+}
+
+#[test]
+fn default_values_must_be_coerced_against_the_variable_type() {
+    let doc = convert(
+        "query($first: Float = 1) { findManyUser(first: $first) { id } }",
+        json!({}),
+    )
+    .unwrap();
+    assert_eq!(
+        root_selection(&doc).arguments,
+        vec![("first".to_owned(), QueryValue::Float(1.0))]
+    );
+
+    let err = convert(
+        "query($first: Int = \"one\") { findManyUser(first: $first) { id } }",
+        json!({}),
+    )
+    .unwrap_err();
+    assert!(format!("{}", err).contains("Invalid value for variable '$first'"));
+}
+
+#[test]
+fn variables_must_be_coerced_against_their_declared_types() {
+    let query =
+        "query($first: Int, $names: [String!]) { findManyUser(first: $first, where: { name_in: $names }) { id } }";
+
+    let doc = convert(query, json!({ "first": 2, "names": "Ada" })).unwrap();
+    let arguments = &root_selection(&doc).arguments;
+    assert!(arguments.contains(&("first".to_owned(), QueryValue::Int(2))));
+
+    // A single value is coerced to a list of one.
+    let names = QueryValue::List(vec![QueryValue::String("Ada".to_owned())]);
+    assert!(arguments.contains(&(
+        "where".to_owned(),
+        QueryValue::Object(vec![("name_in".to_owned(), names)].into_iter().collect())
+    )));
+
+    let err = convert(query, json!({ "first": "2" })).unwrap_err();
+    assert!(format!("{}", err).contains("Invalid value for variable '$first'"));
+
+    match convert(query, json!({ "names": ["Ada", 1] })).unwrap_err() {
+        PrismaError::VariableValidationError { path, .. } => assert_eq!(path, "$names[1]"),
+        err => panic!("Unexpected error {:?}", err),
+    }
+}
+
+#[test]
+fn required_variables_must_be_provided() {
+    let query = "query($first: Int!) { findManyUser(first: $first) { id } }";
+
+    match convert(query, json!({})).unwrap_err() {
+        PrismaError::VariableValidationError { path, message } => {
+            assert_eq!(path, "$first");
+            assert!(message.contains("of required type 'Int!' was not provided"));
+        }
+        err => panic!("Unexpected error {:?}", err),
+    }
+
+    let err = convert(query, json!({ "first": null })).unwrap_err();
+    assert!(format!("{}", err).contains("Invalid value for variable '$first'"));
+}
+
+#[test]
+fn strings_of_variables_must_only_be_taken_for_enum_values_where_an_enum_is_expected() {
+    let doc = convert(
+        "query($role: Role) { findManyUser(where: { role: $role }) { id } }",
+        json!({ "role": "ADMIN" }),
+    )
+    .unwrap();
+    assert!(QueryBuilder::new(query_schema()).build(doc).is_ok());
+
+    // Strings of input object variables are plain strings on string fields.
+    let doc = convert(
+        "query($where: UserWhereInput) { findManyUser(where: $where) { id } }",
+        json!({ "where": { "role": "ADMIN", "name": "Ada" } }),
+    )
+    .unwrap();
+    assert!(QueryBuilder::new(query_schema()).build(doc).is_ok());
+
+    // String literals are no enum values.
+    let doc = convert(r#"{ findManyUser(where: { role: "ADMIN" }) { id } }"#, json!({})).unwrap();
+    assert!(QueryBuilder::new(query_schema()).build(doc).is_err());
+}
+
+#[test]
+fn introspection_must_answer_the_schema_and_named_types() {
+    let doc = convert(
+        r#"{
+            __typename
+            __schema { queryType { name } mutationType { name } }
+            __type(name: "User") { kind name fields { name } }
+            missing: __type(name: "Missing") { name }
+        }"#,
+        json!({}),
+    )
+    .unwrap();
+
+    let (doc, responses, _) = answer_introspection(doc, &query_schema()).unwrap();
+    let result = json::serialize(responses);
+
+    assert!(doc.operations.is_empty());
+    assert_eq!(result["data"]["__typename"], json!("Query"));
+    assert_eq!(
+        result["data"]["__schema"],
+        json!({ "queryType": { "name": "Query" }, "mutationType": { "name": "Mutation" } })
+    );
+    assert_eq!(result["data"]["__type"]["kind"], json!("OBJECT"));
+    assert_eq!(result["data"]["__type"]["name"], json!("User"));
+    assert_eq!(result["data"]["missing"], Value::Null);
+
+    let field_names: Vec<&str> = result["data"]["__type"]["fields"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|field| field["name"].as_str().unwrap())
+        .collect();
+
+    for name in &["id", "name", "posts"] {
+        assert!(
+            field_names.contains(name),
+            "Missing field {} in {:?}",
+            name,
+            field_names
+        );
+    }
+}
+
+#[test]
+fn introspection_responses_must_keep_the_position_of_their_selections() {
+    let doc = convert(
+        "{ findManyUser { id } __typename users: findManyUser { id } }",
+        json!({}),
+    )
+    .unwrap();
+    let keys = response_keys(&doc);
+    let (_, introspection, _) = answer_introspection(doc, &query_schema()).unwrap();
+
+    let mut responses = vec![
+        Response::Data("findManyUser".to_owned(), Item::List(vec![])),
+        Response::Data("users".to_owned(), Item::List(vec![])),
+    ];
+
+    responses.extend(introspection);
+    order_responses(&keys, &mut responses);
+
+    let result = json::serialize(responses);
+    let fields: Vec<&String> = result["data"].as_object().unwrap().keys().collect();
+
+    assert_eq!(fields, vec!["findManyUser", "__typename", "users"]);
+}
+
+#[test]
+fn nested_typenames_must_be_answered_on_the_results() {
+    let doc = convert("{ findManyUser { id __typename posts { __typename } } }", json!({})).unwrap();
+    let (doc, _, typenames) = answer_introspection(doc, &query_schema()).unwrap();
+
+    // `__typename` is not part of the query schema, the only selection on posts is replaced by a scalar field.
+    assert_eq!(sub_selection_names(&doc), vec!["id", "posts"]);
+    let posts = &root_selection(&doc).sub_selections[1];
+    assert_eq!(posts.sub_selections.len(), 1);
+
+    let post = record(vec![(posts.sub_selections[0].name.as_str(), string("post-1"))]);
+    let user = record(vec![("id", string("user-1")), ("posts", Item::List(vec![post]))]);
+    let mut responses = vec![Response::Data("findManyUser".to_owned(), Item::List(vec![user]))];
+
+    typenames.inject(&mut responses);
+
+    assert_eq!(
+        json::serialize(responses),
+        json!({
+            "data": {
+                "findManyUser": [{ "id": "user-1", "posts": [{ "__typename": "Post" }], "__typename": "User" }]
+            }
+        })
+    );
+}
+
+#[test]
+fn request_bodies_must_be_chosen_by_their_shape() {
+    let single: GraphQlRequestBody = serde_json::from_value(json!({ "query": "{ a }" })).unwrap();
+    let multi: GraphQlRequestBody =
+        serde_json::from_value(json!([{ "query": "{ a }" }, { "query": "{ b }" }])).unwrap();
+    let batch: GraphQlRequestBody =
+        serde_json::from_value(json!({ "batch": [{ "query": "{ a }" }], "isTransaction": true })).unwrap();
+
+    assert!(match single {
+        GraphQlRequestBody::Single(_) => true,
+        _ => false,
+    });
+
+    assert!(match multi {
+        GraphQlRequestBody::Multi(ref bodies) => bodies.len() == 2,
+        _ => false,
+    });
+
+    assert!(match batch {
+        GraphQlRequestBody::Batch(_) => true,
+        _ => false,
+    });
+}
+
+#[test]
+fn invalid_request_bodies_must_report_the_actual_problem() {
+    let err = serde_json::from_value::<GraphQlRequestBody>(json!({ "variables": {} })).unwrap_err();
+    assert!(err.to_string().contains("missing field `query`"), "{}", err);
+
+    let err = serde_json::from_value::<GraphQlRequestBody>(json!([{ "query": 1 }])).unwrap_err();
+    assert!(err.to_string().contains("invalid type: integer `1`"), "{}", err);
+
+    let err = serde_json::from_value::<GraphQlRequestBody>(json!({ "batch": {} })).unwrap_err();
+    assert!(err.to_string().contains("expected a sequence"), "{}", err);
+}
+
+#[test]
+fn rolled_back_batches_must_report_the_error_once_at_the_failed_request() {
+    let response = rolled_back_batch(3, 1, PrismaError::TransactionError("Boom".into()));
+
+    assert_eq!(
+        response,
+        json!([
+            {
+                "data": {},
+                "errors": [{ "code": "P2028", "message": "Rolled back because request 1 of the batch failed.", "meta": {} }]
+            },
+            {
+                "data": {},
+                "errors": [{ "code": "P2028", "message": "Boom", "meta": {} }]
+            },
+            {
+                "data": {},
+                "errors": [{ "code": "P2028", "message": "Not executed because request 1 of the batch failed.", "meta": {} }]
+            }
+        ])
+    );
+}
+
+fn convert(query: &str, variables: Value) -> PrismaResult<QueryDocument> {
+    let variables: HashMap<String, Value> = serde_json::from_value(variables).unwrap();
+    let doc = gql::parse_query(query).unwrap();
+
+    GraphQLProtocolAdapter::convert(doc, None, &variables)
+}
+
+fn root_selection(doc: &QueryDocument) -> &Selection {
+    match &doc.operations[0] {
+        Operation::Read(op) => &op.selections[0],
+        Operation::Write(op) => &op.selections[0],
+    }
+}
+
+fn sub_selection_names(doc: &QueryDocument) -> Vec<&str> {
+    root_selection(doc)
+        .sub_selections
+        .iter()
+        .map(|s| s.name.as_str())
+        .collect()
+}
+
+fn query_schema() -> QuerySchemaRef {
+    let datamodel = datamodel::parse_datamodel(
+        r#"
+        model User {
+            id String @id @default(cuid())
+            name String
+            role Role @default(USER)
+            posts Post[]
+        }
+
+        enum Role {
+            USER
+            ADMIN
+        }
+
+        model Post {
+            id String @id @default(cuid())
+            author User
+        }
+        "#,
+    )
+    .unwrap();
+
+    let internal_data_model = DatamodelConverter::convert(&datamodel)
+        .unwrap()
+        .build("test".to_owned());
+    let capabilities = SupportedCapabilities::empty();
+
+    Arc::new(QuerySchemaBuilder::new(&internal_data_model, &capabilities, BuildMode::Modern).build())
+}
+
+fn record(fields: Vec<(&str, Item)>) -> Item {
+    Item::Map(
+        fields
+            .into_iter()
+            .map(|(k, v)| (k.to_owned(), v))
+            .collect::<IndexMap<String, Item>>(),
+    )
+}
+
+fn string(s: &str) -> Item {
+    Item::Value(PrismaValue::String(s.to_owned()))
+}
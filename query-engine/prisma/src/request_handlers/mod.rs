@@ -1,7 +1,7 @@
 pub mod graphql;
 
 pub use core::QuerySchemaRenderer;
-pub use graphql::{GraphQlBody, GraphQlRequestHandler};
+pub use graphql::{GraphQlBody, GraphQlRequestBody, GraphQlRequestHandler};
 
 use crate::{context::PrismaContext, server::RequestContext};
 use actix_web::HttpRequest;
@@ -19,10 +19,13 @@ pub struct PrismaRequest<T> {
     pub body: T,
     pub headers: HashMap<String, String>,
     pub path: String,
+
+    /// Id of the interactive transaction the request is executed in, if any.
+    pub transaction_id: Option<String>,
 }
 
-impl From<(GraphQlBody, HttpRequest<Arc<RequestContext>>)> for PrismaRequest<GraphQlBody> {
-    fn from((gql, req): (GraphQlBody, HttpRequest<Arc<RequestContext>>)) -> Self {
+impl From<(GraphQlRequestBody, HttpRequest<Arc<RequestContext>>)> for PrismaRequest<GraphQlRequestBody> {
+    fn from((gql, req): (GraphQlRequestBody, HttpRequest<Arc<RequestContext>>)) -> Self {
         PrismaRequest {
             body: gql,
             path: req.path().into(),
@@ -31,6 +34,7 @@ impl From<(GraphQlBody, HttpRequest<Arc<RequestContext>>)> for PrismaRequest<Gra
                 .iter()
                 .map(|(k, v)| (format!("{}", k), v.to_str().unwrap().into()))
                 .collect(),
+            transaction_id: req.match_info().get("id").map(String::from),
         }
     }
 }
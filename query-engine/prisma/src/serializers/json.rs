@@ -7,6 +7,10 @@ use prisma_models::{GraphqlId, PrismaValue};
 use serde_json::{Map, Number, Value};
 use std::sync::Arc;
 
+/// Serializes the responses into `{ "data": { ... }, "errors": [ ... ] }`.
+/// Every error is an object with a stable `code`, a human readable `message` and a `meta` object.
+///
+/// Note for clients: errors used to be `{ "error": "<message>" }`, the message is now in `message`.
 pub fn serialize(responses: Vec<Response>) -> Value {
     let mut outer_envelope = Map::new();
     let mut data_envelope = Map::new();
@@ -21,7 +25,9 @@ pub fn serialize(responses: Vec<Response>) -> Value {
             }
             Response::Error(err) => {
                 let mut error_map = Map::new();
-                error_map.insert("error".into(), Value::String(err.clone()));
+                error_map.insert("code".into(), Value::String(err.code.to_string()));
+                error_map.insert("message".into(), Value::String(err.message));
+                error_map.insert("meta".into(), err.meta);
                 errors.push(Value::Object(error_map));
             }
         }
@@ -71,6 +77,7 @@ fn serialize_prisma_value(value: PrismaValue) -> PrismaResult<Value> {
             Some(num) => num,
             None => return Err(PrismaError::SerializationError("`f64` number was invalid".into())),
         }),
+        PrismaValue::Decimal(x) => Value::String(x.to_string()),
         PrismaValue::Boolean(x) => Value::Bool(x),
         PrismaValue::DateTime(date) => Value::String(format!("{}", date.format("%Y-%m-%dT%H:%M:%S%.3fZ"))),
         PrismaValue::Enum(x) => Value::String(x.as_string()),
@@ -81,6 +88,7 @@ fn serialize_prisma_value(value: PrismaValue) -> PrismaResult<Value> {
         }),
         PrismaValue::Null => Value::Null,
         PrismaValue::Uuid(x) => Value::String(x.to_hyphenated().to_string()),
+        PrismaValue::Bytes(x) => Value::String(base64::encode(&x)),
         PrismaValue::GraphqlId(x) => serialize_graphql_id(&x)?,
         PrismaValue::List(_) => unreachable!(),
     })
@@ -94,5 +102,49 @@ fn serialize_graphql_id(id: &GraphqlId) -> PrismaResult<Value> {
             None => return Err(PrismaError::SerializationError("`f64` number was invalid".into())),
         }),
         GraphqlId::UUID(x) => Value::String(x.to_hyphenated().to_string()),
+        GraphqlId::Compound(parts) => {
+            let parts: PrismaResult<Vec<Value>> = parts.iter().map(serialize_graphql_id).collect();
+            Value::Array(parts?)
+        }
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::{query_builders::QueryValidationError, response_ir::ResponseError, CoreError, ErrorCode};
+    use serde_json::json;
+
+    #[test]
+    fn errors_must_be_serialized_with_code_message_and_meta() {
+        let error = CoreError::from(QueryValidationError::ObjectValidationError {
+            object_name: "Query".into(),
+            inner: Box::new(QueryValidationError::FieldValidationError {
+                field_name: "findOneUser".into(),
+                inner: Box::new(QueryValidationError::FieldNotFoundError),
+            }),
+        });
+
+        let message = format!("{}", error);
+        let result = serialize(vec![Response::Error(ResponseError::from(error))]);
+
+        assert_eq!(
+            result,
+            json!({
+                "errors": [{ "code": "P2009", "message": message, "meta": { "path": ["Query", "findOneUser"] } }],
+                "data": {}
+            })
+        );
+    }
+
+    #[test]
+    fn errors_without_additional_information_must_have_an_empty_meta_object() {
+        let error = ResponseError::from(PrismaError::TransactionError("Transaction not found.".into()));
+        let result = serialize(vec![Response::Error(error)]);
+
+        assert_eq!(
+            result["errors"],
+            json!([{ "code": ErrorCode::TransactionError.as_str(), "message": "Transaction not found.", "meta": {} }])
+        );
+    }
+}
@@ -2,15 +2,21 @@ use super::dmmf;
 use crate::{
     context::PrismaContext,
     request_handlers::{
-        graphql::{GraphQLSchemaRenderer, GraphQlBody, GraphQlRequestHandler},
+        graphql::{GraphQLSchemaRenderer, GraphQlRequestBody, GraphQlRequestHandler},
         PrismaRequest, RequestHandler,
     },
+    serializers::json,
+    transactions::TransactionRegistry,
     PrismaResult,
 };
 use actix_web::{http::Method, App, HttpRequest, HttpResponse, Json, Responder};
+use serde::Deserialize;
 use serde_json::json;
 use core::schema::QuerySchemaRenderer;
-use std::{sync::Arc, time::Instant};
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 #[derive(RustEmbed)]
 #[folder = "query-engine/prisma/static_files"]
@@ -23,6 +29,11 @@ pub(crate) struct RequestContext {
     graphql_request_handler: GraphQlRequestHandler,
 }
 
+#[derive(Debug, Deserialize)]
+pub(crate) struct StartTransactionBody {
+    pub(crate) timeout: Option<u64>,
+}
+
 pub struct HttpServer;
 
 impl HttpServer {
@@ -44,6 +55,14 @@ impl HttpServer {
                     r.method(Method::POST).with(Self::http_handler);
                     r.method(Method::GET).with(Self::playground_handler);
                 })
+                .resource("/transaction", |r| r.method(Method::POST).with(Self::start_transaction_handler))
+                .resource("/transaction/{id}", |r| r.method(Method::POST).with(Self::http_handler))
+                .resource("/transaction/{id}/commit", |r| {
+                    r.method(Method::POST).with(Self::commit_transaction_handler)
+                })
+                .resource("/transaction/{id}/rollback", |r| {
+                    r.method(Method::POST).with(Self::rollback_transaction_handler)
+                })
                 .resource("/sdl", |r| r.method(Method::GET).with(Self::sdl_handler))
                 .resource("/dmmf", |r| r.method(Method::GET).with(Self::dmmf_handler))
                 .resource("/status", |r| r.method(Method::GET).with(Self::status_handler))
@@ -60,17 +79,18 @@ impl HttpServer {
         Ok(())
     }
 
-    /// Main handler for query engine requests.
-    fn http_handler((json, req): (Json<Option<GraphQlBody>>, HttpRequest<Arc<RequestContext>>)) -> impl Responder {
+    /// Main handler for query engine requests. Accepts a single GraphQL body or a batch of bodies.
+    fn http_handler((json, req): (Json<GraphQlRequestBody>, HttpRequest<Arc<RequestContext>>)) -> impl Responder {
         let request_context = req.state();
-        let req: PrismaRequest<GraphQlBody> = PrismaRequest {
-            body: json.clone().unwrap(),
+        let req: PrismaRequest<GraphQlRequestBody> = PrismaRequest {
+            body: json.into_inner(),
             path: req.path().into(),
             headers: req
                 .headers()
                 .iter()
                 .map(|(k, v)| (format!("{}", k), v.to_str().unwrap().into()))
                 .collect(),
+            transaction_id: req.match_info().get("id").map(String::from),
         };
 
         let result = request_context
@@ -81,6 +101,53 @@ impl HttpServer {
         serde_json::to_string(&result)
     }
 
+    /// Starts an interactive transaction. Queries are executed within the transaction by posting them
+    /// to `/transaction/{id}`, until the transaction is committed or rolled back. Transactions
+    /// that are not ended within the timeout (in milliseconds) are rolled back.
+    fn start_transaction_handler(
+        (body, req): (Option<Json<StartTransactionBody>>, HttpRequest<Arc<RequestContext>>),
+    ) -> impl Responder {
+        let context = &req.state().context;
+        let timeout = body
+            .and_then(|body| body.timeout)
+            .map(Duration::from_millis)
+            .unwrap_or(TransactionRegistry::DEFAULT_TIMEOUT);
+
+        let response = match context.transactions().start(context.executor(), timeout) {
+            Ok(id) => json!({ "id": id, "timeout": timeout.as_millis() as u64 }),
+            Err(err) => json::serialize(vec![err.into()]),
+        };
+
+        HttpResponse::Ok()
+            .content_type("application/json")
+            .body(serde_json::to_string(&response).unwrap())
+    }
+
+    fn commit_transaction_handler(req: HttpRequest<Arc<RequestContext>>) -> impl Responder {
+        let id = req.match_info().get("id").unwrap_or_default();
+        let result = req.state().context.transactions().commit(id);
+
+        Self::end_transaction_response(result, "committed")
+    }
+
+    fn rollback_transaction_handler(req: HttpRequest<Arc<RequestContext>>) -> impl Responder {
+        let id = req.match_info().get("id").unwrap_or_default();
+        let result = req.state().context.transactions().rollback(id);
+
+        Self::end_transaction_response(result, "rolled back")
+    }
+
+    fn end_transaction_response(result: PrismaResult<()>, status: &str) -> HttpResponse {
+        let response = match result {
+            Ok(()) => json!({ "status": status }),
+            Err(err) => json::serialize(vec![err.into()]),
+        };
+
+        HttpResponse::Ok()
+            .content_type("application/json")
+            .body(serde_json::to_string(&response).unwrap())
+    }
+
     /// Serves playground html.
     fn playground_handler<T>(_: HttpRequest<T>) -> impl Responder {
         let index_html = StaticFiles::get("playground.html").unwrap();
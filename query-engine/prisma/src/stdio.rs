@@ -0,0 +1,363 @@
+use super::dmmf;
+use crate::{
+    context::PrismaContext,
+    request_handlers::{
+        graphql::{GraphQLSchemaRenderer, GraphQlRequestBody, GraphQlRequestHandler},
+        PrismaRequest, RequestHandler,
+    },
+    serializers::json,
+    server::StartTransactionBody,
+    transactions::TransactionRegistry,
+    PrismaError, PrismaResult,
+};
+use core::schema::QuerySchemaRenderer;
+use serde::{de::DeserializeOwned, Deserialize};
+use serde_json::{json, Value};
+use std::{
+    collections::HashMap,
+    io::{self, BufRead, Write},
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+#[derive(Debug, Deserialize)]
+struct RpcRequest {
+    /// Requests without id are notifications, which are executed without response.
+    #[serde(default)]
+    id: Option<Value>,
+    method: String,
+
+    #[serde(default)]
+    params: Value,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct QueryParams {
+    body: GraphQlRequestBody,
+    transaction_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct EndTransactionParams {
+    id: String,
+}
+
+/// Executes a method with its params.
+type Dispatch<'a> = dyn Fn(&str, Value) -> Result<Value, RpcError> + 'a;
+
+#[derive(Debug)]
+struct RpcError {
+    code: i64,
+    message: String,
+}
+
+impl RpcError {
+    const PARSE_ERROR: i64 = -32700;
+    const INVALID_REQUEST: i64 = -32600;
+    const METHOD_NOT_FOUND: i64 = -32601;
+    const INVALID_PARAMS: i64 = -32602;
+    const SERVER_ERROR: i64 = -32000;
+}
+
+/// Serves the query engine over stdin and stdout, for clients that can't open ports.
+///
+/// Every line on stdin is a JSON-RPC 2.0 request or a batch of requests, every response is written as a single
+/// line to stdout. Requests are handled in order, also within a batch, the server stops when stdin is closed.
+/// If the server can't start, a single error response with a `null` id is written before exiting. Methods:
+/// - `query`: Executes `{ "body": ..., "transactionId": ... }`, where the body is a single GraphQL body or
+///   a batch, as posted over HTTP. The transaction id is optional. The result is the usual `data`/`errors` JSON.
+/// - `startTransaction`, `commitTransaction`, `rollbackTransaction`: Like the `/transaction` endpoints,
+///   commit and rollback take the transaction `id` as parameter.
+/// - `sdl`, `dmmf`, `serverInfo`: Like the HTTP endpoints of the same name.
+pub struct StdioServer {
+    context: PrismaContext,
+}
+
+impl StdioServer {
+    /// Initializes the server. Errors returned here mean the server couldn't start.
+    pub fn new(legacy_mode: bool) -> PrismaResult<StdioServer> {
+        let now = Instant::now();
+        let context = PrismaContext::new(legacy_mode)?;
+
+        trace!("Initialized in {}ms", now.elapsed().as_millis());
+
+        Ok(StdioServer { context })
+    }
+
+    /// Serves requests until stdin is closed. Errors returned here mean reading stdin or writing stdout failed.
+    pub fn serve(&self) -> PrismaResult<()> {
+        let handler = GraphQlRequestHandler;
+
+        info!("Started stdio server");
+
+        let stdin = io::stdin();
+        let stdout = io::stdout();
+        let dispatch = |method: &str, params| Self::dispatch(method, params, &handler, &self.context);
+
+        for line in stdin.lock().lines() {
+            let line = line?;
+
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            if let Some(response) = Self::handle_line(&line, &dispatch) {
+                let mut stdout = stdout.lock();
+
+                writeln!(stdout, "{}", response)?;
+                stdout.flush()?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Writes an error that kept the server from starting as JSON-RPC error response to stdout.
+    pub fn write_error(err: &PrismaError) {
+        let error = RpcError {
+            code: RpcError::SERVER_ERROR,
+            message: format!("{}", err),
+        };
+
+        let mut stdout = io::stdout();
+
+        // Nothing left to report to if stdout itself is gone.
+        let _ = writeln!(stdout, "{}", Self::response(Value::Null, Err(error)));
+        let _ = stdout.flush();
+    }
+
+    /// Handles a line with a single request or a batch of requests. Returns the response or the array of
+    /// responses, `None` if the line only had notifications.
+    fn handle_line(line: &str, dispatch: &Dispatch) -> Option<Value> {
+        let value: Value = match serde_json::from_str(line) {
+            Ok(value) => value,
+            Err(err) => {
+                let error = RpcError {
+                    code: RpcError::PARSE_ERROR,
+                    message: format!("Invalid JSON: {}", err),
+                };
+
+                return Some(Self::response(Value::Null, Err(error)));
+            }
+        };
+
+        match value {
+            Value::Array(ref requests) if requests.is_empty() => Some(Self::invalid_request("Empty batch.")),
+            Value::Array(requests) => {
+                let responses: Vec<Value> = requests
+                    .into_iter()
+                    .filter_map(|request| Self::handle_request(request, dispatch))
+                    .collect();
+
+                if responses.is_empty() {
+                    None
+                } else {
+                    Some(Value::Array(responses))
+                }
+            }
+            request => Self::handle_request(request, dispatch),
+        }
+    }
+
+    fn handle_request(request: Value, dispatch: &Dispatch) -> Option<Value> {
+        let request: RpcRequest = match serde_json::from_value(request) {
+            Ok(request) => request,
+            Err(err) => return Some(Self::invalid_request(&format!("Invalid JSON-RPC request: {}", err))),
+        };
+
+        let result = dispatch(&request.method, request.params);
+        request.id.map(|id| Self::response(id, result))
+    }
+
+    fn invalid_request(message: &str) -> Value {
+        let error = RpcError {
+            code: RpcError::INVALID_REQUEST,
+            message: message.to_owned(),
+        };
+
+        Self::response(Value::Null, Err(error))
+    }
+
+    fn dispatch(
+        method: &str,
+        params: Value,
+        handler: &GraphQlRequestHandler,
+        context: &PrismaContext,
+    ) -> Result<Value, RpcError> {
+        match method {
+            "query" => {
+                let params: QueryParams = Self::parse_params(params)?;
+                let req = PrismaRequest {
+                    body: params.body,
+                    headers: HashMap::new(),
+                    path: "/".into(),
+                    transaction_id: params.transaction_id,
+                };
+
+                Ok(handler.handle(req, context))
+            }
+            "startTransaction" => {
+                let params: Option<StartTransactionBody> = Self::parse_params(params)?;
+                let timeout = params
+                    .and_then(|params| params.timeout)
+                    .map(Duration::from_millis)
+                    .unwrap_or(TransactionRegistry::DEFAULT_TIMEOUT);
+
+                Ok(match context.transactions().start(context.executor(), timeout) {
+                    Ok(id) => json!({ "id": id, "timeout": timeout.as_millis() as u64 }),
+                    Err(err) => json::serialize(vec![err.into()]),
+                })
+            }
+            "commitTransaction" => {
+                let params: EndTransactionParams = Self::parse_params(params)?;
+                Ok(Self::end_transaction_result(
+                    context.transactions().commit(&params.id),
+                    "committed",
+                ))
+            }
+            "rollbackTransaction" => {
+                let params: EndTransactionParams = Self::parse_params(params)?;
+                Ok(Self::end_transaction_result(
+                    context.transactions().rollback(&params.id),
+                    "rolled back",
+                ))
+            }
+            "sdl" => {
+                let sdl = GraphQLSchemaRenderer::render(Arc::clone(context.query_schema()));
+                Ok(Value::String(sdl))
+            }
+            "dmmf" => {
+                let dmmf = dmmf::render_dmmf(context.datamodel(), Arc::clone(context.query_schema()));
+                Ok(serde_json::to_value(&dmmf).unwrap())
+            }
+            "serverInfo" => Ok(json!({
+                "commit": env!("GIT_HASH"),
+                "version": env!("CARGO_PKG_VERSION"),
+                "primary_connector": context.primary_connector(),
+            })),
+            _ => Err(RpcError {
+                code: RpcError::METHOD_NOT_FOUND,
+                message: format!("Method '{}' does not exist.", method),
+            }),
+        }
+    }
+
+    fn parse_params<T: DeserializeOwned>(params: Value) -> Result<T, RpcError> {
+        serde_json::from_value(params).map_err(|err| RpcError {
+            code: RpcError::INVALID_PARAMS,
+            message: format!("Invalid params: {}", err),
+        })
+    }
+
+    fn end_transaction_result(result: PrismaResult<()>, status: &str) -> Value {
+        match result {
+            Ok(()) => json!({ "status": status }),
+            Err(err) => json::serialize(vec![err.into()]),
+        }
+    }
+
+    fn response(id: Value, result: Result<Value, RpcError>) -> Value {
+        match result {
+            Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+            Err(error) => json!({
+                "jsonrpc": "2.0",
+                "id": id,
+                "error": { "code": error.code, "message": error.message },
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Echoes the params of `echo`, fails every other method.
+    fn dispatch(method: &str, params: Value) -> Result<Value, RpcError> {
+        match method {
+            "echo" => Ok(params),
+            _ => Err(RpcError {
+                code: RpcError::METHOD_NOT_FOUND,
+                message: format!("Method '{}' does not exist.", method),
+            }),
+        }
+    }
+
+    fn handle(line: &str) -> Option<Value> {
+        StdioServer::handle_line(line, &dispatch)
+    }
+
+    #[test]
+    fn requests_must_be_answered_with_their_id() {
+        assert_eq!(
+            handle(r#"{ "jsonrpc": "2.0", "id": 1, "method": "echo", "params": { "a": 1 } }"#),
+            Some(json!({ "jsonrpc": "2.0", "id": 1, "result": { "a": 1 } }))
+        );
+
+        assert_eq!(
+            handle(r#"{ "jsonrpc": "2.0", "id": "x", "method": "missing" }"#),
+            Some(json!({
+                "jsonrpc": "2.0",
+                "id": "x",
+                "error": { "code": -32601, "message": "Method 'missing' does not exist." },
+            }))
+        );
+    }
+
+    #[test]
+    fn notifications_must_not_be_answered() {
+        assert_eq!(handle(r#"{ "jsonrpc": "2.0", "method": "echo", "params": 1 }"#), None);
+    }
+
+    #[test]
+    fn invalid_lines_must_be_answered_with_errors_without_id() {
+        let response = handle("{ not json").unwrap();
+        assert_eq!(response["id"], Value::Null);
+        assert_eq!(response["error"]["code"], json!(-32700));
+
+        let response = handle(r#"{ "jsonrpc": "2.0", "id": 1 }"#).unwrap();
+        assert_eq!(response["id"], Value::Null);
+        assert_eq!(response["error"]["code"], json!(-32600));
+    }
+
+    #[test]
+    fn batches_must_be_answered_in_order_without_notifications() {
+        let line = r#"[
+            { "jsonrpc": "2.0", "id": 1, "method": "echo", "params": "a" },
+            { "jsonrpc": "2.0", "method": "echo", "params": "b" },
+            { "jsonrpc": "2.0", "id": 2, "method": "missing" },
+            { "jsonrpc": "2.0", "id": 3, "method": "echo", "params": "c" }
+        ]"#;
+
+        let response = handle(&line.replace('\n', " ")).unwrap();
+        let ids: Vec<&Value> = response.as_array().unwrap().iter().map(|r| &r["id"]).collect();
+
+        assert_eq!(ids, vec![&json!(1), &json!(2), &json!(3)]);
+        assert_eq!(response[0]["result"], json!("a"));
+        assert_eq!(response[1]["error"]["code"], json!(-32601));
+        assert_eq!(response[2]["result"], json!("c"));
+    }
+
+    #[test]
+    fn batches_of_notifications_must_not_be_answered_and_empty_batches_are_invalid() {
+        assert_eq!(handle(r#"[{ "jsonrpc": "2.0", "method": "echo" }]"#), None);
+        assert_eq!(handle("[]").unwrap()["error"]["code"], json!(-32600));
+    }
+
+    #[test]
+    fn params_must_be_parsed_or_rejected_as_invalid() {
+        let params: QueryParams = StdioServer::parse_params(json!({
+            "body": { "query": "{ findManyUser { id } }" },
+            "transactionId": "tx",
+        }))
+        .unwrap();
+        assert_eq!(params.transaction_id, Some("tx".to_owned()));
+
+        let params: Option<StartTransactionBody> = StdioServer::parse_params(Value::Null).unwrap();
+        assert!(params.is_none());
+
+        let err = StdioServer::parse_params::<EndTransactionParams>(json!({})).unwrap_err();
+        assert_eq!(err.code, RpcError::INVALID_PARAMS);
+    }
+}
@@ -0,0 +1,162 @@
+use crate::{PrismaError, PrismaResult};
+use core::{executor::TransactionHandle, QueryExecutor};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, Weak},
+    thread,
+    time::{Duration, Instant},
+};
+use uuid::Uuid;
+
+type Transactions = Mutex<HashMap<String, RegisteredTransaction>>;
+
+struct RegisteredTransaction {
+    /// Executor running all queries within the transaction.
+    executor: Arc<QueryExecutor>,
+    handle: Box<dyn TransactionHandle + Send + Sync + 'static>,
+
+    /// Transactions not committed or rolled back until then are rolled back.
+    expires_at: Instant,
+}
+
+/// Registry of interactive transactions, open across several requests.
+/// Transactions are referenced by an id handed out when starting them.
+///
+/// Expired transactions are rolled back by a background thread, so that abandoned transactions
+/// don't hold on to their connection and locks until the next request touches the registry.
+pub struct TransactionRegistry {
+    transactions: Arc<Transactions>,
+}
+
+impl Default for TransactionRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TransactionRegistry {
+    pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(5);
+    pub const MAX_TIMEOUT: Duration = Duration::from_secs(60);
+
+    /// How often the background thread looks for expired transactions.
+    const REAP_INTERVAL: Duration = Duration::from_millis(500);
+
+    pub fn new() -> Self {
+        let transactions: Arc<Transactions> = Arc::new(Mutex::new(HashMap::new()));
+        let reaped = Arc::downgrade(&transactions);
+
+        thread::spawn(move || Self::reap(reaped));
+
+        Self { transactions }
+    }
+
+    /// Starts a new transaction that is rolled back if it isn't ended within the given timeout.
+    /// Returns the id of the transaction.
+    pub fn start(&self, executor: &QueryExecutor, timeout: Duration) -> PrismaResult<String> {
+        if timeout > Self::MAX_TIMEOUT {
+            return Err(PrismaError::TransactionError(format!(
+                "Transaction timeout must not exceed {}ms.",
+                Self::MAX_TIMEOUT.as_millis()
+            )));
+        }
+
+        self.roll_back_expired();
+
+        let (executor, handle) = executor.start_transaction()?;
+        let id = Uuid::new_v4().to_hyphenated().to_string();
+
+        let transaction = RegisteredTransaction {
+            executor: Arc::new(executor),
+            handle,
+            expires_at: Instant::now() + timeout,
+        };
+
+        self.transactions.lock().unwrap().insert(id.clone(), transaction);
+
+        Ok(id)
+    }
+
+    /// The executor running queries within the transaction with the given id.
+    pub fn executor(&self, id: &str) -> PrismaResult<Arc<QueryExecutor>> {
+        self.roll_back_expired();
+
+        let transactions = self.transactions.lock().unwrap();
+        let transaction = transactions.get(id).ok_or_else(|| Self::not_found(id))?;
+
+        Ok(Arc::clone(&transaction.executor))
+    }
+
+    pub fn commit(&self, id: &str) -> PrismaResult<()> {
+        let transaction = self.remove(id)?;
+        transaction
+            .handle
+            .commit()
+            .map_err(|err| PrismaError::CoreError(err.into()))
+    }
+
+    pub fn rollback(&self, id: &str) -> PrismaResult<()> {
+        let transaction = self.remove(id)?;
+        transaction
+            .handle
+            .rollback()
+            .map_err(|err| PrismaError::CoreError(err.into()))
+    }
+
+    fn remove(&self, id: &str) -> PrismaResult<RegisteredTransaction> {
+        self.roll_back_expired();
+        self.transactions
+            .lock()
+            .unwrap()
+            .remove(id)
+            .ok_or_else(|| Self::not_found(id))
+    }
+
+    /// Rolls back expired transactions until the registry is dropped.
+    fn reap(transactions: Weak<Transactions>) {
+        loop {
+            thread::sleep(Self::REAP_INTERVAL);
+
+            match transactions.upgrade() {
+                Some(transactions) => Self::roll_back_expired_in(&transactions),
+                None => break,
+            }
+        }
+    }
+
+    fn roll_back_expired(&self) {
+        Self::roll_back_expired_in(&self.transactions)
+    }
+
+    /// Rolls back and removes all transactions that exceeded their timeout.
+    /// The rollbacks run after releasing the lock, to not block other requests on the database.
+    fn roll_back_expired_in(transactions: &Transactions) {
+        let now = Instant::now();
+
+        let expired: Vec<(String, RegisteredTransaction)> = {
+            let mut transactions = transactions.lock().unwrap();
+
+            let ids: Vec<String> = transactions
+                .iter()
+                .filter(|(_, transaction)| transaction.expires_at <= now)
+                .map(|(id, _)| id.clone())
+                .collect();
+
+            ids.into_iter()
+                .filter_map(|id| transactions.remove(&id).map(|transaction| (id, transaction)))
+                .collect()
+        };
+
+        for (id, transaction) in expired {
+            if let Err(err) = transaction.handle.rollback() {
+                warn!("Rolling back expired transaction {} failed: {}", id, err);
+            }
+        }
+    }
+
+    fn not_found(id: &str) -> PrismaError {
+        PrismaError::TransactionError(format!(
+            "Transaction {} not found. It may have been committed, rolled back or expired.",
+            id
+        ))
+    }
+}